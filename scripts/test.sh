@@ -12,8 +12,11 @@ for crate in $crates; do
     if [[ "$crate" == "utoipa" ]]; then
         $CARGO test -p utoipa --features openapi_extensions,preserve_order,preserve_path_order,debug
     elif [[ "$crate" == "utoipa-gen" ]]; then
-        $CARGO test -p utoipa-gen --features utoipa/actix_extras,chrono,decimal,utoipa/uuid,uuid,utoipa/ulid,ulid,utoipa/url,url,utoipa/time,time,utoipa/repr,utoipa/smallvec,smallvec,rc_schema,utoipa/rc_schema
+        $CARGO test -p utoipa-gen --features utoipa/actix_extras,chrono,decimal,utoipa/uuid,uuid,utoipa/ulid,ulid,utoipa/url,url,utoipa/time,time,utoipa/repr,utoipa/smallvec,smallvec,rc_schema,utoipa/rc_schema,validate_pattern,validator
         $CARGO test -p utoipa-gen --test schema_derive_test --features decimal_float
+        $CARGO test -p utoipa-gen --test schema_derive_test --features openapi_31,utoipa/openapi_31
+        $CARGO test -p utoipa-gen --test path_derive --test path_parameter_derive_test --test request_body_derive_test --features openapi_31,utoipa/openapi_31
+        $CARGO test -p utoipa-gen --test schema_derive_test --features auto_title
 
         $CARGO test -p utoipa-gen --test path_derive_auto_into_responses --features auto_into_responses,utoipa/uuid,uuid
         $CARGO test -p utoipa-gen --test path_derive_actix --test path_parameter_derive_actix --features actix_extras,utoipa/uuid,uuid,utoipa/chrono,chrono,utoipa/time,time
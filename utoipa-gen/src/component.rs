@@ -1,9 +1,13 @@
 use std::borrow::Cow;
+use std::cell::RefCell;
 
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use syn::spanned::Spanned;
-use syn::{Attribute, GenericArgument, Path, PathArguments, PathSegment, Type, TypePath};
+use syn::{
+    Attribute, Expr, ExprLit, ExprPath, GenericArgument, Lit, LitStr, Meta, MetaNameValue, Path,
+    PathArguments, PathSegment, Type, TypePath,
+};
 
 use crate::doc_comment::CommentAttributes;
 use crate::schema_type::SchemaFormat;
@@ -11,7 +15,8 @@ use crate::{as_tokens_or_diagnostics, Diagnostics, OptionExt, ToTokensDiagnostic
 use crate::{schema_type::SchemaType, Deprecated};
 
 use self::features::{
-    pop_feature, Feature, FeaturesExt, IsInline, Minimum, Nullable, ToTokensExt, Validatable,
+    pop_feature, pop_feature_as_inner, Feature, FeaturesExt, IsInline, IsNoRecursion, Minimum,
+    Nullable, ToTokensExt, Validatable,
 };
 use self::schema::format_path_ref;
 use self::serde::{RenameRule, SerdeContainer, SerdeValue};
@@ -21,6 +26,92 @@ pub mod into_params;
 pub mod features;
 pub mod schema;
 pub mod serde;
+#[cfg(feature = "validator")]
+pub mod validator;
+
+std::thread_local! {
+    /// Paths of the types referenced by the [`ComponentSchema`]s while collecting them with
+    /// [`collect_schema_references`].
+    static SCHEMA_REFERENCES: RefCell<Option<Vec<Path>>> = const { RefCell::new(None) };
+    /// Warnings pushed while collecting them with [`collect_warnings`].
+    static WARNINGS: RefCell<Option<Vec<(Span, String)>>> = const { RefCell::new(None) };
+}
+
+/// Run `f` and return its result together with paths of the types the [`ComponentSchema`]s
+/// created within `f` either reference or inline.
+pub fn collect_schema_references<T>(f: impl FnOnce() -> T) -> (T, Vec<Path>) {
+    let previous = SCHEMA_REFERENCES.with(|references| references.replace(Some(Vec::new())));
+    let value = f();
+    let references = SCHEMA_REFERENCES
+        .with(|references| references.replace(previous))
+        .unwrap_or_default();
+
+    (value, references)
+}
+
+fn push_schema_reference(path: &Path) {
+    SCHEMA_REFERENCES.with(|references| {
+        if let Some(references) = references.borrow_mut().as_mut() {
+            if !references.contains(path) {
+                references.push(path.clone());
+            }
+        }
+    })
+}
+
+/// Run `f` and return its result together with tokens emitting the warnings pushed within `f`.
+/// Proc macros cannot emit warnings on stable Rust, thus each warning is emitted as use of a
+/// `#[deprecated]` constant with the warning as the note.
+pub fn collect_warnings<T>(f: impl FnOnce() -> T) -> (T, TokenStream) {
+    let previous = WARNINGS.with(|warnings| warnings.replace(Some(Vec::new())));
+    let value = f();
+    let warnings = WARNINGS
+        .with(|warnings| warnings.replace(previous))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(span, message)| {
+            let warning = quote_spanned! {span=> utoipa_warning };
+            quote! {
+                const _: () = {
+                    #[deprecated(note = #message)]
+                    #[allow(non_upper_case_globals)]
+                    const utoipa_warning: () = ();
+                    #warning
+                };
+            }
+        })
+        .collect();
+
+    (value, warnings)
+}
+
+fn push_warning(span: Span, message: String) {
+    WARNINGS.with(|warnings| {
+        if let Some(warnings) = warnings.borrow_mut().as_mut() {
+            if !warnings.iter().any(|(_, existing)| *existing == message) {
+                warnings.push((span, message));
+            }
+        }
+    })
+}
+
+/// Get tokens of the name of the schema of the type at `type_path`. Types implementing `ToSchema`
+/// are referenced by the name their schema is registered with e.g. container level `rename` or
+/// `as`, other types and types given by name only are referenced by the path of the type.
+pub(crate) fn schema_reference_name(type_path: &Path, name_only: bool) -> TokenStream {
+    let name = format_path_ref(type_path);
+    if name_only {
+        return name.to_token_stream();
+    }
+
+    quote! {
+        {
+            #[allow(unused_imports)]
+            use utoipa::__dev::{DefaultSchemaName as _, SchemaName as _};
+            (&utoipa::__dev::SchemaReference::<#type_path>::new()).schema_name(#name)
+        }
+    }
+}
 
 /// Check whether either serde `container_rule` or `field_rule` has _`default`_ attribute set.
 #[inline]
@@ -45,6 +136,47 @@ fn get_deprecated(attributes: &[Attribute]) -> Option<Deprecated> {
     })
 }
 
+/// Find `note` of `#[deprecated]` attribute from given attributes. Both `#[deprecated = "..."]`
+/// and `#[deprecated(note = "...")]` forms are supported.
+fn get_deprecated_note(attributes: &[Attribute]) -> Option<String> {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("deprecated"))
+        .find_map(|attribute| match &attribute.meta {
+            Meta::NameValue(MetaNameValue {
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(note),
+                        ..
+                    }),
+                ..
+            }) => Some(note.value()),
+            Meta::List(_) => {
+                let mut note = None;
+                attribute
+                    .parse_nested_meta(|meta| {
+                        let value = meta.value()?.parse::<LitStr>()?;
+                        if meta.path.is_ident("note") {
+                            note = Some(value.value());
+                        }
+                        Ok(())
+                    })
+                    .ok()?;
+                note
+            }
+            _ => None,
+        })
+}
+
+/// Append the `note` of `#[deprecated]` attribute to the given description if one is defined.
+fn append_deprecated_note(description: String, attributes: &[Attribute]) -> String {
+    match get_deprecated_note(attributes) {
+        Some(note) if description.is_empty() => format!("Deprecated: {note}"),
+        Some(note) => format!("{description}\n\nDeprecated: {note}"),
+        None => description,
+    }
+}
+
 /// Check whether field is required based on following rules.
 ///
 /// * If field has not serde's `skip_serializing_if`
@@ -61,8 +193,14 @@ enum TypeTreeValue<'t> {
     TypePath(&'t TypePath),
     Path(&'t Path),
     /// Slice and array types need to be manually defined, since they cannot be recognized from
-    /// generic arguments.
-    Array(Vec<TypeTreeValue<'t>>, Span),
+    /// generic arguments. Fixed size arrays with literal length will also carry the length and
+    /// arrays with const generic param length carry the param.
+    Array(
+        Vec<TypeTreeValue<'t>>,
+        Option<usize>,
+        Option<&'t Ident>,
+        Span,
+    ),
     UnitType,
     Tuple(Vec<TypeTreeValue<'t>>, Span),
 }
@@ -72,7 +210,9 @@ impl PartialEq for TypeTreeValue<'_> {
         match self {
             Self::Path(_) => self == other,
             Self::TypePath(_) => self == other,
-            Self::Array(array, _) => matches!(other, Self::Array(other, _) if other == array),
+            Self::Array(array, _, _, _) => {
+                matches!(other, Self::Array(other, _, _, _) if other == array)
+            }
             Self::Tuple(tuple, _) => matches!(other, Self::Tuple(other, _) if other == tuple),
             Self::UnitType => self == other,
         }
@@ -89,6 +229,16 @@ pub struct TypeTree<'t> {
     pub value_type: ValueType,
     pub generic_type: Option<GenericType>,
     pub children: Option<Vec<TypeTree<'t>>>,
+    /// Length of fixed size array e.g. `[T; 16]` if the length is a literal.
+    pub array_len: Option<usize>,
+    /// Const generic param used as the length of fixed size array e.g. `[T; N]`. Const argument
+    /// of an alias resolves it to the `array_len`.
+    pub array_len_param: Option<&'t Ident>,
+    /// Whether the type is a trait object e.g. `dyn Trait` which does not have a schema.
+    pub trait_object: bool,
+    /// Whether the type is only referenced by its name e.g. type given with `value_type = ...`,
+    /// thus it is not required to be an existing Rust type.
+    pub name_only: bool,
 }
 
 impl<'t> TypeTree<'t> {
@@ -115,8 +265,15 @@ impl<'t> TypeTree<'t> {
                 )]
             },
             Type::Group(group) => Self::get_type_tree_values(group.elem.as_ref())?,
-            Type::Slice(slice) => vec![TypeTreeValue::Array(Self::get_type_tree_values(&slice.elem)?, slice.bracket_token.span.join())],
-            Type::Array(array) => vec![TypeTreeValue::Array(Self::get_type_tree_values(&array.elem)?, array.bracket_token.span.join())],
+            Type::Slice(slice) => vec![TypeTreeValue::Array(Self::get_type_tree_values(&slice.elem)?, None, None, slice.bracket_token.span.join())],
+            Type::Array(array) => {
+                let (array_len, array_len_param) = match &array.len {
+                    Expr::Lit(ExprLit { lit: Lit::Int(len), .. }) => (len.base10_parse::<usize>().ok(), None),
+                    Expr::Path(ExprPath { path, .. }) => (None, path.get_ident()),
+                    _ => (None, None),
+                };
+                vec![TypeTreeValue::Array(Self::get_type_tree_values(&array.elem)?, array_len, array_len_param, array.bracket_token.span.join())]
+            },
             Type::TraitObject(trait_object) => {
                 trait_object
                     .bounds
@@ -145,8 +302,20 @@ impl<'t> TypeTree<'t> {
             .map(|value| {
                 let path = match value {
                     TypeTreeValue::TypePath(type_path) => &type_path.path,
-                    TypeTreeValue::Path(path) => path,
-                    TypeTreeValue::Array(value, span) => {
+                    TypeTreeValue::Path(path) => {
+                        return Ok(TypeTree {
+                            path: Some(Cow::Borrowed(path)),
+                            span: Some(path.span()),
+                            value_type: ValueType::Object,
+                            generic_type: None,
+                            children: None,
+                            array_len: None,
+                            array_len_param: None,
+                            trait_object: true,
+                            name_only: false,
+                        })
+                    }
+                    TypeTreeValue::Array(value, array_len, array_len_param, span) => {
                         let array: Path = Ident::new("Array", span).into();
                         return Ok(TypeTree {
                             path: Some(Cow::Owned(array)),
@@ -157,6 +326,10 @@ impl<'t> TypeTree<'t> {
                                 Ok(converted_values) => converted_values.collect(),
                                 Err(diagnostics) => return Err(diagnostics),
                             }),
+                            array_len,
+                            array_len_param,
+                            trait_object: false,
+                            name_only: false,
                         });
                     }
                     TypeTreeValue::Tuple(tuple, span) => {
@@ -169,6 +342,10 @@ impl<'t> TypeTree<'t> {
                             }),
                             generic_type: None,
                             value_type: ValueType::Tuple,
+                            array_len: None,
+                            array_len_param: None,
+                            trait_object: false,
+                            name_only: false,
                         })
                     }
                     TypeTreeValue::UnitType => {
@@ -178,6 +355,10 @@ impl<'t> TypeTree<'t> {
                             value_type: ValueType::Tuple,
                             generic_type: None,
                             children: None,
+                            array_len: None,
+                            array_len_param: None,
+                            trait_object: false,
+                            name_only: false,
                         })
                     }
                 };
@@ -278,6 +459,10 @@ impl<'t> TypeTree<'t> {
             },
             generic_type,
             children: None,
+            array_len: None,
+            array_len_param: None,
+            trait_object: false,
+            name_only: false,
         }
     }
 
@@ -330,21 +515,80 @@ impl<'t> TypeTree<'t> {
         is
     }
 
-    fn find_mut(&mut self, type_tree: &TypeTree) -> Option<&mut Self> {
+    /// Find all nodes of the [`TypeTree`] matching the given `type_tree`. Children of a matching
+    /// node are not visited.
+    fn find_all_mut<'s>(&'s mut self, type_tree: &TypeTree) -> Vec<&'s mut Self> {
         let is = self
             .path
-            .as_mut()
+            .as_ref()
             .map(|p| matches!(&type_tree.path, Some(path) if path.as_ref() == p.as_ref()))
             .unwrap_or(false);
 
         if is {
-            Some(self)
+            vec![self]
         } else {
-            self.children.as_mut().and_then(|children| {
-                children
-                    .iter_mut()
-                    .find_map(|child| Self::find_mut(child, type_tree))
-            })
+            self.children
+                .iter_mut()
+                .flatten()
+                .flat_map(|child| Self::find_all_mut(child, type_tree))
+                .collect()
+        }
+    }
+
+    /// Create [`TypeTree`] of a const generic argument of an alias. It does not represent a type
+    /// but carries the `len` used to resolve the length of fixed size arrays.
+    pub fn from_const_arg(len: usize, span: Span) -> TypeTree<'t> {
+        Self {
+            path: None,
+            span: Some(span),
+            value_type: ValueType::Primitive,
+            generic_type: None,
+            children: None,
+            array_len: Some(len),
+            array_len_param: None,
+            trait_object: false,
+            name_only: false,
+        }
+    }
+
+    /// Get the length carried by [`TypeTree`] created with [`TypeTree::from_const_arg`].
+    pub fn const_arg(&self) -> Option<usize> {
+        self.path.is_none().then_some(self.array_len).flatten()
+    }
+
+    /// Resolve the length of all fixed size arrays of the [`TypeTree`] which use the given const
+    /// generic `param` as their length.
+    pub fn resolve_array_len(&mut self, param: &Ident, len: usize) {
+        if self
+            .array_len_param
+            .is_some_and(|array_len_param| array_len_param == param)
+        {
+            self.array_len = Some(len);
+        }
+
+        for child in self.children.iter_mut().flatten() {
+            child.resolve_array_len(param, len);
+        }
+    }
+
+    /// Find all nodes of the [`TypeTree`] whose last path segment is the given type as [`str`].
+    /// Children of a matching node are not visited.
+    fn find_all_by_name_mut<'s>(&'s mut self, name: &str) -> Vec<&'s mut Self> {
+        let is = self
+            .path
+            .as_ref()
+            .and_then(|path| path.segments.last())
+            .map(|segment| segment.ident == name)
+            .unwrap_or(false);
+
+        if is {
+            vec![self]
+        } else {
+            self.children
+                .iter_mut()
+                .flatten()
+                .flat_map(|child| Self::find_all_by_name_mut(child, name))
+                .collect()
         }
     }
 
@@ -354,10 +598,10 @@ impl<'t> TypeTree<'t> {
         self.is("Object")
     }
 
-    /// `Value` virtual type is used when any JSON value is required in OpenAPI spec. Typically used
-    /// with `value_type` attribute for a member of type `serde_json::Value`.
+    /// `Value` virtual type or its alias `Any` is used when any JSON value is required in OpenAPI
+    /// spec. Typically used with `value_type` attribute for a member of type `serde_json::Value`.
     pub fn is_value(&self) -> bool {
-        self.is("Value")
+        self.is("Value") || self.is("Any")
     }
 
     /// Check whether the [`TypeTree`]'s `generic_type` is [`GenericType::Option`]
@@ -606,7 +850,7 @@ impl<'c> ComponentSchema {
                 features,
                 type_tree,
                 object_name,
-                description_stream,
+                description,
                 deprecated_stream,
             )?,
         };
@@ -622,11 +866,34 @@ impl<'c> ComponentSchema {
         description_stream: Option<TokenStream>,
         deprecated_stream: Option<TokenStream>,
     ) -> Result<(), Diagnostics> {
-        let example = features.pop_by(|feature| matches!(feature, Feature::Example(_)));
+        let example = features
+            .pop_by(|feature| matches!(feature, Feature::Example(_) | Feature::ExampleWith(_)));
+        let examples = features.pop_by(|feature| matches!(feature, Feature::Examples(_)));
         let additional_properties = pop_feature!(features => Feature::AdditionalProperties(_));
-        let nullable = pop_feature!(features => Feature::Nullable(_));
+        let additional_properties_description =
+            pop_feature_as_inner!(features => Feature::AdditionalPropertiesDescription(_v));
+        let (is_null_type, nullable) =
+            ComponentSchema::split_null_type(pop_feature!(features => Feature::Nullable(_)));
         let default = pop_feature!(features => Feature::Default(_));
         let default_tokens = as_tokens_or_diagnostics!(&default);
+        let properties_bounds = ComponentSchema::pop_properties_bounds(&mut features, type_tree)?;
+        let extensions = pop_feature!(features => Feature::Extensions(_));
+        let extensions_tokens = as_tokens_or_diagnostics!(&extensions);
+        let title = pop_feature!(features => Feature::Title(_));
+        let title_tokens = as_tokens_or_diagnostics!(&title);
+
+        if let (Some(_), Some(description)) =
+            (&additional_properties, &additional_properties_description)
+        {
+            return Err(Diagnostics::with_span(
+                description.span(),
+                "`additional_properties_description` cannot be used with `additional_properties`",
+            )
+            .help("Describe the type given to `additional_properties` instead"));
+        }
+        let value_description = additional_properties_description
+            .as_ref()
+            .map(|description| CommentAttributes(vec![description.description().to_string()]));
 
         let additional_properties = additional_properties
             .as_ref()
@@ -645,7 +912,7 @@ impl<'c> ComponentSchema {
                         .nth(1)
                         .expect("ComponentSchema Map type should have 2 child"),
                     features: Some(features),
-                    description: None,
+                    description: value_description.as_ref(),
                     deprecated: None,
                     object_name,
                 })?;
@@ -656,15 +923,29 @@ impl<'c> ComponentSchema {
                 ))
             })?;
 
+        let property_names = ComponentSchema::get_map_property_names(type_tree);
+        let schema_type = is_null_type.then(|| {
+            let schema_type = ComponentSchema::get_null_type_array(
+                quote! { utoipa::openapi::SchemaType::Object },
+            );
+            quote! { .schema_type(#schema_type) }
+        });
+
         tokens.extend(quote! {
             utoipa::openapi::ObjectBuilder::new()
+                #schema_type
                 #additional_properties
+                #property_names
+                #properties_bounds
                 #description_stream
                 #deprecated_stream
                 #default_tokens
+                #title_tokens
+                #extensions_tokens
         });
 
         example.to_tokens(tokens)?;
+        examples.to_tokens(tokens)?;
         nullable.to_tokens(tokens)
     }
 
@@ -676,12 +957,18 @@ impl<'c> ComponentSchema {
         description_stream: Option<TokenStream>,
         deprecated_stream: Option<TokenStream>,
     ) -> Result<(), Diagnostics> {
-        let example = pop_feature!(features => Feature::Example(_));
+        let example = features
+            .pop_by(|feature| matches!(feature, Feature::Example(_) | Feature::ExampleWith(_)));
+        let examples = pop_feature!(features => Feature::Examples(_));
         let xml = features.extract_vec_xml_feature(type_tree)?;
         let max_items = pop_feature!(features => Feature::MaxItems(_));
         let min_items = pop_feature!(features => Feature::MinItems(_));
-        let nullable = pop_feature!(features => Feature::Nullable(_));
+        let unique_items = pop_feature_as_inner!(features => Feature::UniqueItems(_v));
+        let (is_null_type, nullable) =
+            ComponentSchema::split_null_type(pop_feature!(features => Feature::Nullable(_)));
         let default = pop_feature!(features => Feature::Default(_));
+        let extensions = pop_feature!(features => Feature::Extensions(_));
+        let title = pop_feature!(features => Feature::Title(_));
 
         let child = type_tree
             .children
@@ -704,20 +991,34 @@ impl<'c> ComponentSchema {
             child
         };
 
-        let unique = matches!(type_tree.generic_type, Some(GenericType::Set));
+        let unique = matches!(type_tree.generic_type, Some(GenericType::Set))
+            || unique_items
+                .map(|unique_items| unique_items.is_unique())
+                .unwrap_or(false);
 
-        // is octet-stream
-        let schema = if child
-            .path
-            .as_ref()
-            .map(|path| SchemaType(path).is_byte())
-            .unwrap_or(false)
+        // is octet-stream, fixed size arrays with known length are rendered as arrays
+        let (schema, schema_type) = if type_tree.array_len.is_none()
+            && child
+                .path
+                .as_ref()
+                .map(|path| SchemaType(path).is_byte())
+                .unwrap_or(false)
         {
-            quote! {
-                utoipa::openapi::ObjectBuilder::new()
-                    .schema_type(utoipa::openapi::schema::SchemaType::String)
+            // explicit format e.g. `format = Byte` overrides the default `binary` format
+            let format = match pop_feature!(features => Feature::Format(_)) {
+                Some(format) => as_tokens_or_diagnostics!(&format),
+                None => quote! {
                     .format(Some(utoipa::openapi::SchemaFormat::KnownFormat(utoipa::openapi::KnownFormat::Binary)))
-            }
+                },
+            };
+            (
+                quote! {
+                    utoipa::openapi::ObjectBuilder::new()
+                        .schema_type(utoipa::openapi::schema::SchemaType::String)
+                        #format
+                },
+                quote! { utoipa::openapi::schema::SchemaType::String },
+            )
         } else {
             let component_schema = ComponentSchema::new(ComponentSchemaProps {
                 type_tree: child,
@@ -735,33 +1036,48 @@ impl<'c> ComponentSchema {
                 false => quote! {},
             };
 
-            quote! {
-                utoipa::openapi::schema::ArrayBuilder::new()
-                    .items(#component_schema_tokens)
-                    #unique
-            }
+            (
+                quote! {
+                    utoipa::openapi::schema::ArrayBuilder::new()
+                        .items(#component_schema_tokens)
+                        #unique
+                },
+                quote! { utoipa::openapi::schema::SchemaType::Array },
+            )
         };
+        let schema_type = is_null_type.then(|| {
+            let schema_type = ComponentSchema::get_null_type_array(schema_type);
+            quote! { .schema_type(#schema_type) }
+        });
 
         let validate = |feature: &Feature| {
             let type_path = &**type_tree.path.as_ref().unwrap();
             let schema_type = SchemaType(type_path);
-            feature.validate(&schema_type, type_tree);
+            match feature.validate(&schema_type, type_tree) {
+                Some(diagnostics) => Err(diagnostics),
+                None => Ok(()),
+            }
         };
 
         tokens.extend(quote! {
             #schema
+            #schema_type
             #deprecated_stream
             #description_stream
         });
 
         if let Some(max_items) = max_items {
-            validate(&max_items);
+            validate(&max_items)?;
             tokens.extend(max_items.to_token_stream())
+        } else if let Some(array_len) = type_tree.array_len {
+            tokens.extend(quote! { .max_items(Some(#array_len)) })
         }
 
         if let Some(min_items) = min_items {
-            validate(&min_items);
+            validate(&min_items)?;
             tokens.extend(min_items.to_token_stream())
+        } else if let Some(array_len) = type_tree.array_len {
+            tokens.extend(quote! { .min_items(Some(#array_len)) })
         }
 
         if let Some(default) = default {
@@ -769,8 +1085,11 @@ impl<'c> ComponentSchema {
         }
 
         example.to_tokens(tokens)?;
+        examples.to_tokens(tokens)?;
         xml.to_tokens(tokens)?;
         nullable.to_tokens(tokens)?;
+        title.to_tokens(tokens)?;
+        extensions.to_tokens(tokens)?;
 
         Ok(())
     }
@@ -780,11 +1099,17 @@ impl<'c> ComponentSchema {
         mut features: Vec<Feature>,
         type_tree: &TypeTree,
         object_name: &str,
-        description_stream: Option<TokenStream>,
+        description: Option<&CommentAttributes>,
         deprecated_stream: Option<TokenStream>,
     ) -> Result<(), Diagnostics> {
-        let nullable = pop_feature!(features => Feature::Nullable(_));
+        let description_stream = ComponentSchema::get_description(description);
+        let (is_null_type, nullable) =
+            ComponentSchema::split_null_type(pop_feature!(features => Feature::Nullable(_)));
         let nullable_tokens = as_tokens_or_diagnostics!(&nullable);
+        let extensions = pop_feature!(features => Feature::Extensions(_));
+        let extensions_tokens = as_tokens_or_diagnostics!(&extensions);
+        let title = pop_feature!(features => Feature::Title(_));
+        let title_tokens = as_tokens_or_diagnostics!(&title);
 
         match type_tree.value_type {
             ValueType::Primitive => {
@@ -793,15 +1118,39 @@ impl<'c> ComponentSchema {
                 if schema_type.is_unsigned_integer() {
                     // add default minimum feature only when there is no explicit minimum
                     // provided
-                    if !features
-                        .iter()
-                        .any(|feature| matches!(&feature, Feature::Minimum(_)))
-                    {
-                        features.push(Minimum::new(0f64, type_path.span()).into());
+                    if !features.iter().any(|feature| {
+                        matches!(&feature, Feature::Minimum(_) | Feature::ExclusiveMinimum(_))
+                    }) {
+                        let minimum = if schema_type.is_non_zero_integer() {
+                            1f64
+                        } else {
+                            0f64
+                        };
+                        features.push(Minimum::new(minimum, type_path.span()).into());
                     }
                 }
+                // signed non zero integers cannot be constrained with JSON Schema keywords thus
+                // the constraint is appended to the description
+                let description_stream =
+                    if schema_type.is_non_zero_integer() && !schema_type.is_unsigned_integer() {
+                        let description = match description
+                            .map(CommentAttributes::as_formatted_string)
+                            .filter(|description| !description.is_empty())
+                        {
+                            Some(description) => format!("{description}\n\nValue must not be zero"),
+                            None => String::from("Value must not be zero"),
+                        };
+                        Some(quote! { .description(Some(#description)) })
+                    } else {
+                        description_stream
+                    };
 
                 let schema_type_tokens = as_tokens_or_diagnostics!(&schema_type);
+                let schema_type_tokens = if is_null_type {
+                    ComponentSchema::get_null_type_array(schema_type_tokens)
+                } else {
+                    schema_type_tokens
+                };
                 tokens.extend(quote! {
                     utoipa::openapi::ObjectBuilder::new().schema_type(#schema_type_tokens)
                 });
@@ -815,67 +1164,190 @@ impl<'c> ComponentSchema {
 
                 tokens.extend(description_stream);
                 tokens.extend(deprecated_stream);
-                for feature in features.iter().filter(|feature| feature.is_validatable()) {
-                    feature.validate(&schema_type, type_tree);
-                }
+                ComponentSchema::validate_features(&features, &schema_type, type_tree)?;
                 tokens.extend(features.to_token_stream()?);
                 nullable.to_tokens(tokens)?;
+                tokens.extend(title_tokens);
+                tokens.extend(extensions_tokens);
             }
             ValueType::Value => {
+                let schema_type = SchemaType(type_tree.path.as_ref().unwrap());
+                ComponentSchema::validate_features(&features, &schema_type, type_tree)?;
+
+                // any JSON value already allows `null`, thus with OpenAPI 3.1 no `null` type is
+                // added for nullable value
                 if type_tree.is_value() {
                     tokens.extend(quote! {
                         utoipa::openapi::ObjectBuilder::new()
                             .schema_type(utoipa::openapi::schema::SchemaType::Value)
                             #description_stream #deprecated_stream #nullable_tokens
+                            #title_tokens #extensions_tokens
                     })
                 }
             }
             ValueType::Object => {
+                if type_tree.trait_object {
+                    return Err(Diagnostics::with_span(
+                        type_tree.span.unwrap_or_else(Span::call_site),
+                        "trait objects do not have a schema",
+                    )
+                    .help("Try using `value_type = ...` or `schema_with = ...` to define the schema of the type"));
+                }
                 let is_inline = features.is_inline();
+                let schema_type = SchemaType(type_tree.path.as_ref().unwrap());
+                ComponentSchema::validate_features(&features, &schema_type, type_tree)?;
 
                 if type_tree.is_object() {
+                    let properties_bounds =
+                        ComponentSchema::pop_properties_bounds(&mut features, type_tree)?;
+                    let additional_properties =
+                        pop_feature!(features => Feature::AdditionalProperties(_));
+                    let additional_properties_tokens =
+                        as_tokens_or_diagnostics!(&additional_properties);
+                    let schema_type = is_null_type.then(|| {
+                        let schema_type = ComponentSchema::get_null_type_array(
+                            quote! { utoipa::openapi::SchemaType::Object },
+                        );
+                        quote! { .schema_type(#schema_type) }
+                    });
                     tokens.extend(quote! {
                         utoipa::openapi::ObjectBuilder::new()
+                            #schema_type
+                            #additional_properties_tokens
+                            #properties_bounds
                             #description_stream #deprecated_stream #nullable_tokens
+                            #title_tokens #extensions_tokens
                     })
                 } else {
                     let type_path = &**type_tree.path.as_ref().unwrap();
-                    if is_inline {
+                    // only unqualified paths may refer to the enclosing type, `other::Node` is
+                    // a different type than the `Node` being derived
+                    let is_recursive = type_path.segments.len() == 1
+                        && type_path.segments.first().is_some_and(|segment| {
+                            segment.ident == "Self"
+                                || (!object_name.is_empty() && segment.ident == object_name)
+                        });
+                    let is_no_recursion = is_recursive && features.is_no_recursion();
+                    if is_inline && !is_no_recursion {
+                        if is_recursive {
+                            return Err(Diagnostics::with_span(
+                                type_path.span(),
+                                "Recursive types cannot be inlined",
+                            )
+                            .help("Remove `inline` from the recursive field to use a reference instead")
+                            .help("Or add `no_recursion` to always reference the enclosing type"));
+                        }
+
                         let default = pop_feature!(features => Feature::Default(_));
                         let default_tokens = as_tokens_or_diagnostics!(&default);
-                        let schema = if default.is_some() || nullable.is_some() {
+                        let xml = pop_feature!(features => Feature::XmlAttr(_));
+                        let xml_tokens = as_tokens_or_diagnostics!(&xml);
+                        let read_only = pop_feature!(features => Feature::ReadOnly(_));
+                        let write_only = pop_feature!(features => Feature::WriteOnly(_));
+                        let read_only_tokens = as_tokens_or_diagnostics!(&read_only);
+                        let write_only_tokens = as_tokens_or_diagnostics!(&write_only);
+                        if !type_tree.name_only {
+                            push_schema_reference(type_path);
+                        }
+                        // types inlining each other are referenced by their registered name once
+                        // a cycle is reached
+                        let schema = quote_spanned! {type_path.span()=>
+                            utoipa::__dev::inline_schema::<#type_path>()
+                        };
+                        let schema = if is_null_type {
+                            ComponentSchema::get_null_one_of(schema)
+                        } else {
+                            schema
+                        };
+                        let schema = if default.is_some()
+                            || nullable.is_some()
+                            || xml.is_some()
+                            || extensions.is_some()
+                            || title.is_some()
+                            || read_only.is_some()
+                            || write_only.is_some()
+                        {
                             quote_spanned! {type_path.span()=>
                                 utoipa::openapi::schema::AllOfBuilder::new()
                                     #nullable_tokens
-                                    .item(<#type_path as utoipa::ToSchema>::schema().1)
+                                    .item(#schema)
                                     #default_tokens
+                                    #xml_tokens
+                                    #title_tokens
+                                    #read_only_tokens
+                                    #write_only_tokens
+                                    #extensions_tokens
                             }
                         } else {
-                            quote_spanned! {type_path.span() =>
-                                <#type_path as utoipa::ToSchema>::schema().1
-                            }
+                            schema
                         };
 
                         schema.to_tokens(tokens);
                     } else {
-                        let mut name = Cow::Owned(format_path_ref(type_path));
-                        if name == "Self" && !object_name.is_empty() {
-                            name = Cow::Borrowed(object_name);
+                        if !is_recursive && !type_tree.name_only {
+                            push_schema_reference(type_path);
                         }
+                        let name = match is_recursive {
+                            // references to the enclosing type use its registered name
+                            true if !object_name.is_empty() => {
+                                quote! { <Self as utoipa::ToSchema>::name() }
+                            }
+                            true => format_path_ref(type_path).to_token_stream(),
+                            // fields of schemas are Rust types while e.g. path operations may
+                            // reference schemas by name only
+                            false => schema_reference_name(
+                                type_path,
+                                type_tree.name_only || object_name.is_empty(),
+                            ),
+                        };
 
                         let default = pop_feature!(features => Feature::Default(_));
                         let default_tokens = as_tokens_or_diagnostics!(&default);
-
-                        let schema = if default.is_some() || nullable.is_some() {
+                        // referenced schema cannot be formatted, thus xml is defined on a wrapper
+                        let xml = pop_feature!(features => Feature::XmlAttr(_));
+                        let xml_tokens = as_tokens_or_diagnostics!(&xml);
+                        // `$ref` cannot be marked read or write only, thus it is done on a wrapper
+                        let read_only = pop_feature!(features => Feature::ReadOnly(_));
+                        let write_only = pop_feature!(features => Feature::WriteOnly(_));
+                        let read_only_tokens = as_tokens_or_diagnostics!(&read_only);
+                        let write_only_tokens = as_tokens_or_diagnostics!(&write_only);
+                        // OpenAPI 3.1 allows description as a sibling of `$ref` while 3.0 ignores
+                        // the siblings, thus with 3.0 the description is defined on a wrapper
+                        let is_sibling_description = cfg!(feature = "openapi_31");
+                        let has_wrapper_description =
+                            description_stream.is_some() && !is_sibling_description;
+
+                        let schema = quote! { utoipa::openapi::Ref::from_schema_name(#name) };
+                        let schema = if is_null_type {
+                            ComponentSchema::get_null_one_of(schema)
+                        } else {
+                            schema
+                        };
+                        let schema = if default.is_some()
+                            || nullable.is_some()
+                            || xml.is_some()
+                            || extensions.is_some()
+                            || title.is_some()
+                            || read_only.is_some()
+                            || write_only.is_some()
+                            || has_wrapper_description
+                        {
                             quote! {
                                 utoipa::openapi::schema::AllOfBuilder::new()
                                     #nullable_tokens
-                                    .item(utoipa::openapi::Ref::from_schema_name(#name))
+                                    .item(#schema)
                                     #default_tokens
+                                    #xml_tokens
+                                    #title_tokens
+                                    #read_only_tokens
+                                    #write_only_tokens
+                                    #extensions_tokens
+                                    #description_stream
                             }
                         } else {
                             quote! {
-                                utoipa::openapi::Ref::from_schema_name(#name)
+                                #schema
+                                    #description_stream
                             }
                         };
 
@@ -919,12 +1391,22 @@ impl<'c> ComponentSchema {
                                 },
                             );
 
+                        let schema_type = is_null_type.then(|| {
+                            let schema_type = ComponentSchema::get_null_type_array(
+                                quote! { utoipa::openapi::SchemaType::Array },
+                            );
+                            quote! { .schema_type(#schema_type) }
+                        });
+
                         Result::<TokenStream, Diagnostics>::Ok(quote! {
                             utoipa::openapi::schema::ArrayBuilder::new()
                                 .items(#all_of)
+                            #schema_type
                             #nullable_tokens
                             #description_stream
                             #deprecated_stream
+                            #title_tokens
+                            #extensions_tokens
                         })
                     })?
                     .unwrap_or_else(|| quote!(utoipa::openapi::schema::empty()))
@@ -935,6 +1417,24 @@ impl<'c> ComponentSchema {
         Ok(())
     }
 
+    /// Validate all validatable features against the resolved [`SchemaType`] returning the first
+    /// failed validation as [`Diagnostics`].
+    fn validate_features(
+        features: &[Feature],
+        schema_type: &SchemaType,
+        type_tree: &TypeTree,
+    ) -> Result<(), Diagnostics> {
+        match features
+            .iter()
+            .filter(|feature| feature.is_validatable())
+            .find_map(|feature| feature.validate(schema_type, type_tree))
+            .or_else(|| features::validate_bounds(features))
+        {
+            Some(diagnostics) => Err(diagnostics),
+            None => Ok(()),
+        }
+    }
+
     fn get_description(comments: Option<&'c CommentAttributes>) -> Option<TokenStream> {
         comments
             .and_then(|comments| {
@@ -951,6 +1451,84 @@ impl<'c> ComponentSchema {
     fn get_deprecated(deprecated: Option<&'c Deprecated>) -> Option<TokenStream> {
         deprecated.map(|deprecated| quote! { .deprecated(Some(#deprecated)) })
     }
+
+    /// OpenAPI 3.1 has no _`nullable`_ keyword, thus nullable schemas are given the `null` type
+    /// instead. Returns whether the schema must be given the `null` type and the _`nullable`_
+    /// feature to be rendered as is.
+    fn split_null_type(nullable: Option<Feature>) -> (bool, Option<Feature>) {
+        match nullable {
+            Some(Feature::Nullable(nullable)) if cfg!(feature = "openapi_31") => {
+                (nullable.is_nullable(), None)
+            }
+            nullable => (false, nullable),
+        }
+    }
+
+    /// Get type array of given `schema_type` and `null` e.g. _`["string", "null"]`_.
+    fn get_null_type_array(schema_type: TokenStream) -> TokenStream {
+        quote! {
+            utoipa::openapi::schema::SchemaTypes::nullable(#schema_type)
+        }
+    }
+
+    /// Get _`oneOf`_ of `null` and given `schema` for schemas which cannot have a type array such
+    /// as _`$ref`_.
+    fn get_null_one_of(schema: TokenStream) -> TokenStream {
+        quote! {
+            utoipa::openapi::schema::OneOfBuilder::new()
+                .item(utoipa::openapi::ObjectBuilder::new().schema_type(utoipa::openapi::schema::Type::Null))
+                .item(#schema)
+        }
+    }
+
+    /// Pop _`max_properties`_ and _`min_properties`_ features from given `features` and validate
+    /// them against the map or object `type_tree` they are applied to.
+    fn pop_properties_bounds(
+        features: &mut Vec<Feature>,
+        type_tree: &TypeTree,
+    ) -> Result<TokenStream, Diagnostics> {
+        let max_properties = pop_feature!(features => Feature::MaxProperties(_));
+        let min_properties = pop_feature!(features => Feature::MinProperties(_));
+        let schema_type = SchemaType(type_tree.path.as_ref().unwrap());
+
+        [max_properties, min_properties]
+            .into_iter()
+            .flatten()
+            .try_fold(TokenStream::new(), |mut tokens, feature| {
+                if let Some(diagnostics) = feature.validate(&schema_type, type_tree) {
+                    return Err(diagnostics);
+                }
+                tokens.extend(feature.to_token_stream());
+                Ok(tokens)
+            })
+    }
+
+    /// Get `propertyNames` of a map with integer keys. Integer keys are serialized as strings
+    /// thus the property names are described with a numeric pattern. `propertyNames` is only
+    /// available in OpenAPI 3.1.
+    fn get_map_property_names(type_tree: &TypeTree) -> Option<TokenStream> {
+        if !cfg!(feature = "openapi_31") {
+            return None;
+        }
+
+        let key = type_tree.children.as_ref()?.first()?;
+        let schema_type = SchemaType(key.path.as_deref()?);
+        if !schema_type.is_integer() {
+            return None;
+        }
+
+        let pattern = if schema_type.is_unsigned_integer() {
+            "^[0-9]+$"
+        } else {
+            "^-?[0-9]+$"
+        };
+
+        Some(quote! {
+            .property_names(Some(utoipa::openapi::ObjectBuilder::new()
+                .schema_type(utoipa::openapi::schema::SchemaType::String)
+                .pattern(Some(#pattern))))
+        })
+    }
 }
 
 impl ToTokensDiagnostics for ComponentSchema {
@@ -963,6 +1541,8 @@ impl ToTokensDiagnostics for ComponentSchema {
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct FlattenedMapSchema {
     tokens: TokenStream,
+    property_names: Option<TokenStream>,
+    properties_bounds: TokenStream,
 }
 
 impl FlattenedMapSchema {
@@ -980,10 +1560,13 @@ impl FlattenedMapSchema {
         let deprecated_stream = ComponentSchema::get_deprecated(deprecated);
         let description_stream = ComponentSchema::get_description(description);
 
-        let example = features.pop_by(|feature| matches!(feature, Feature::Example(_)));
+        let example = features
+            .pop_by(|feature| matches!(feature, Feature::Example(_) | Feature::ExampleWith(_)));
+        let examples = features.pop_by(|feature| matches!(feature, Feature::Examples(_)));
         let nullable = pop_feature!(features => Feature::Nullable(_));
         let default = pop_feature!(features => Feature::Default(_));
         let default_tokens = as_tokens_or_diagnostics!(&default);
+        let properties_bounds = ComponentSchema::pop_properties_bounds(&mut features, type_tree)?;
 
         // Maps are treated as generic objects with no named properties and
         // additionalProperties denoting the type
@@ -1012,9 +1595,24 @@ impl FlattenedMapSchema {
         });
 
         example.to_tokens(&mut tokens)?;
+        examples.to_tokens(&mut tokens)?;
         nullable.to_tokens(&mut tokens)?;
 
-        Ok(Self { tokens })
+        Ok(Self {
+            tokens,
+            property_names: ComponentSchema::get_map_property_names(type_tree),
+            properties_bounds,
+        })
+    }
+
+    /// Get `propertyNames` of the object the map is flattened to.
+    pub fn property_names(&self) -> Option<&TokenStream> {
+        self.property_names.as_ref()
+    }
+
+    /// Get `maxProperties` and `minProperties` of the object the map is flattened to.
+    pub fn properties_bounds(&self) -> &TokenStream {
+        &self.properties_bounds
     }
 }
 
@@ -1,8 +1,11 @@
-use std::{fmt::Display, mem, str::FromStr};
+use std::{borrow::Cow, fmt::Display, mem, str::FromStr};
 
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens};
-use syn::{parenthesized, parse::ParseStream, LitFloat, LitInt, LitStr, TypePath};
+use syn::{
+    parenthesized, parse::ParseStream, punctuated::Punctuated, token::Comma, LitFloat, LitInt,
+    LitStr, TypePath, WherePredicate,
+};
 
 use crate::{
     as_tokens_or_diagnostics, parse_utils,
@@ -84,8 +87,13 @@ pub trait Parse {
 #[derive(Clone)]
 pub enum Feature {
     Example(Example),
+    Examples(Examples),
+    ExampleWith(ExampleWith),
     Default(Default),
     Inline(Inline),
+    NoRecursion(NoRecursion),
+    Ignore(Ignore),
+    DecimalAs(DecimalAs),
     XmlAttr(XmlAttr),
     Format(Format),
     ValueType(ValueType),
@@ -95,6 +103,8 @@ pub enum Feature {
     Nullable(Nullable),
     Rename(Rename),
     RenameAll(RenameAll),
+    RenameAllFields(RenameAllFields),
+    RenameContext(RenameContext),
     Style(Style),
     AllowReserved(AllowReserved),
     Explode(Explode),
@@ -108,16 +118,27 @@ pub enum Feature {
     MaxLength(MaxLength),
     MinLength(MinLength),
     Pattern(Pattern),
+    ContentEncoding(ContentEncoding),
+    ContentMediaType(ContentMediaType),
     MaxItems(MaxItems),
     MinItems(MinItems),
+    UniqueItems(UniqueItems),
     MaxProperties(MaxProperties),
     MinProperties(MinProperties),
     SchemaWith(SchemaWith),
     Description(Description),
     Deprecated(Deprecated),
     As(As),
+    Bound(Bound),
+    AsConst(AsConst),
+    VariantsAsOneOf(VariantsAsOneOf),
+    ConstVariants(ConstVariants),
+    UntaggedAs(UntaggedAs),
+    Discriminator(Discriminator),
     AdditionalProperties(AdditionalProperties),
+    AdditionalPropertiesDescription(AdditionalPropertiesDescription),
     Required(Required),
+    Extensions(Extensions),
 }
 
 impl Feature {
@@ -141,14 +162,26 @@ impl Feature {
                 ValidatorChain::new(&IsString(schema_type)).next(&AboveZeroUsize(min_length.0)),
             ),
             Feature::Pattern(pattern) => pattern.validate(IsString(schema_type)),
+            Feature::ContentEncoding(content_encoding) => {
+                content_encoding.validate(IsString(schema_type))
+            }
+            Feature::ContentMediaType(content_media_type) => {
+                content_media_type.validate(IsString(schema_type))
+            }
             Feature::MaxItems(max_items) => max_items.validate(
                 ValidatorChain::new(&AboveZeroUsize(max_items.0)).next(&IsVec(type_tree)),
             ),
             Feature::MinItems(min_items) => min_items.validate(
                 ValidatorChain::new(&AboveZeroUsize(min_items.0)).next(&IsVec(type_tree)),
             ),
+            Feature::UniqueItems(unique_items) => unique_items.validate(IsVec(type_tree)),
+            Feature::MaxProperties(max_properties) => max_properties.validate(IsMap(type_tree)),
+            Feature::MinProperties(min_properties) => min_properties.validate(IsMap(type_tree)),
+            Feature::AdditionalPropertiesDescription(additional_properties_description) => {
+                additional_properties_description.validate(IsGenericMap(type_tree))
+            }
             _unsupported_variant => {
-                const SUPPORTED_VARIANTS: [&str; 10] = [
+                const SUPPORTED_VARIANTS: [&str; 16] = [
                     "multiple_of",
                     "maximum",
                     "minimum",
@@ -157,8 +190,14 @@ impl Feature {
                     "max_length",
                     "min_length",
                     "pattern",
+                    "content_encoding",
+                    "content_media_type",
                     "max_items",
                     "min_items",
+                    "unique_items",
+                    "max_properties",
+                    "min_properties",
+                    "additional_properties_description",
                 ];
                 panic!(
                     "Unsupported variant: `{variant}` for Validate::validate, expected one of: {variants}",
@@ -170,11 +209,80 @@ impl Feature {
     }
 }
 
+/// Check that given `features` do not define contradicting bounds e.g. both _`minimum`_
+/// and _`exclusive_minimum`_ or _`max_length`_ less than _`min_length`_.
+pub fn validate_bounds(features: &[Feature]) -> Option<Diagnostics> {
+    let has_minimum = features
+        .iter()
+        .any(|feature| matches!(feature, Feature::Minimum(_)));
+    let has_maximum = features
+        .iter()
+        .any(|feature| matches!(feature, Feature::Maximum(_)));
+    let min_length = features.iter().find_map(|feature| match feature {
+        Feature::MinLength(MinLength(min_length, _)) => Some(*min_length),
+        _ => None,
+    });
+
+    features.iter().find_map(|feature| match feature {
+        Feature::ExclusiveMinimum(ExclusiveMinimum(_, ident)) if has_minimum => Some(
+            Diagnostics::with_span(
+                ident.span(),
+                "`exclusive_minimum` cannot be used together with `minimum`",
+            )
+            .help("Try removing either `minimum` or `exclusive_minimum`"),
+        ),
+        Feature::ExclusiveMaximum(ExclusiveMaximum(_, ident)) if has_maximum => Some(
+            Diagnostics::with_span(
+                ident.span(),
+                "`exclusive_maximum` cannot be used together with `maximum`",
+            )
+            .help("Try removing either `maximum` or `exclusive_maximum`"),
+        ),
+        Feature::MaxLength(MaxLength(max_length, ident))
+            if min_length.is_some_and(|min_length| *max_length < min_length) =>
+        {
+            Some(Diagnostics::with_span(
+                ident.span(),
+                "`max_length` must be greater than or equal to `min_length`",
+            ))
+        }
+        _ => None,
+    })
+}
+
+/// Check that given `features` do not mark a field both _`read_only`_ and _`write_only`_.
+pub fn validate_read_write_only(features: &[Feature], span: Span) -> Option<Diagnostics> {
+    let is_read_only = features
+        .iter()
+        .any(|feature| matches!(feature, Feature::ReadOnly(ReadOnly(true))));
+    let is_write_only = features
+        .iter()
+        .any(|feature| matches!(feature, Feature::WriteOnly(WriteOnly(true))));
+
+    if is_read_only && is_write_only {
+        Some(
+            Diagnostics::with_span(
+                span,
+                "`read_only` cannot be used together with `write_only`",
+            )
+            .help("Try removing either `read_only` or `write_only`"),
+        )
+    } else {
+        None
+    }
+}
+
 impl ToTokensDiagnostics for Feature {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) -> Result<(), Diagnostics> {
         let feature = match &self {
                 Feature::Default(default) => quote! { .default(#default) },
+                Feature::Example(Example(None)) => {
+                    return Err(Diagnostics::new("`example` without value is only supported on named field struct level")
+                        .help("Define the example value e.g. `example = json!(...)` or use `example` on the struct to serialize its `Default` implementation as the example"))
+                }
                 Feature::Example(example) => quote! { .example(Some(#example)) },
+                Feature::Examples(examples) => quote! { .examples(#examples) },
+                Feature::ExampleWith(example_with) => quote! { .example(Some(#example_with)) },
                 Feature::XmlAttr(xml) => quote! { .xml(Some(#xml)) },
                 Feature::Format(format) => quote! { .format(Some(#format)) },
                 Feature::WriteOnly(write_only) => quote! { .write_only(Some(#write_only)) },
@@ -200,23 +308,37 @@ impl ToTokensDiagnostics for Feature {
                 Feature::MaxLength(max_length) => quote! { .max_length(Some(#max_length)) },
                 Feature::MinLength(min_length) => quote! { .min_length(Some(#min_length)) },
                 Feature::Pattern(pattern) => quote! { .pattern(Some(#pattern)) },
+                Feature::ContentEncoding(content_encoding) => {
+                    quote! { .content_encoding(Some(#content_encoding)) }
+                }
+                Feature::ContentMediaType(content_media_type) => {
+                    quote! { .content_media_type(Some(#content_media_type)) }
+                }
                 Feature::MaxItems(max_items) => quote! { .max_items(Some(#max_items)) },
                 Feature::MinItems(min_items) => quote! { .min_items(Some(#min_items)) },
+                Feature::UniqueItems(unique_items) => quote! { .unique_items(#unique_items) },
                 Feature::MaxProperties(max_properties) => {
                     quote! { .max_properties(Some(#max_properties)) }
                 }
                 Feature::MinProperties(min_properties) => {
-                    quote! { .max_properties(Some(#min_properties)) }
+                    quote! { .min_properties(Some(#min_properties)) }
                 }
                 Feature::SchemaWith(schema_with) => schema_with.to_token_stream(),
                 Feature::Description(description) => quote! { .description(Some(#description)) },
                 Feature::Deprecated(deprecated) => quote! { .deprecated(Some(#deprecated)) },
                 Feature::AdditionalProperties(additional_properties) => {
+                    let additional_properties = as_tokens_or_diagnostics!(additional_properties);
                     quote! { .additional_properties(Some(#additional_properties)) }
                 }
                 Feature::RenameAll(_) => {
                     return Err(Diagnostics::new("RenameAll feature does not support `ToTokens`"))
                 }
+                Feature::RenameAllFields(_) => {
+                    return Err(Diagnostics::new("RenameAllFields feature does not support `ToTokens`"))
+                }
+                Feature::RenameContext(_) => {
+                    return Err(Diagnostics::new("RenameContext feature does not support `ToTokens`"))
+                }
                 Feature::ValueType(_) => {
                     return Err(Diagnostics::new("ValueType feature does not support `ToTokens`")
                         .help("ValueType is supposed to be used with `TypeTree` in same manner as a resolved struct/field type."))
@@ -225,6 +347,18 @@ impl ToTokensDiagnostics for Feature {
                     // inline feature is ignored by `ToTokens`
                     TokenStream::new()
                 }
+                Feature::NoRecursion(_) => {
+                    // no_recursion feature is ignored by `ToTokens`
+                    TokenStream::new()
+                }
+                Feature::Ignore(_) => {
+                    return Err(Diagnostics::new("Ignore does not support `ToTokens`")
+                        .help("Ignore is only used with named fields to leave the field out of the schema."))
+                }
+                Feature::DecimalAs(_) => {
+                    return Err(Diagnostics::new("DecimalAs does not support `ToTokens`")
+                        .help("DecimalAs is only used with named fields to change the `TypeTree` of `Decimal` types."))
+                }
                 Feature::IntoParamsNames(_) => {
                     return Err(Diagnostics::new("Names feature does not support `ToTokens`")
                         .help("Names is only used with IntoParams to artificially give names for unnamed struct type `IntoParams`."))
@@ -232,10 +366,39 @@ impl ToTokensDiagnostics for Feature {
                 Feature::As(_) => {
                     return Err(Diagnostics::new("As does not support `ToTokens`"))
                 }
+                Feature::Bound(_) => {
+                    return Err(Diagnostics::new("Bound does not support `ToTokens`")
+                        .help("Bound is only used to define where clause of the generated `ToSchema` implementation."))
+                }
+                Feature::AsConst(_) => {
+                    return Err(Diagnostics::new("AsConst does not support `ToTokens`")
+                        .help("AsConst is only used with unit enums to render single variant as `const` value."))
+                }
+                Feature::VariantsAsOneOf(_) => {
+                    return Err(Diagnostics::new("VariantsAsOneOf does not support `ToTokens`")
+                        .help("VariantsAsOneOf is only used with unit enums to render variants as `oneOf` of `const` values."))
+                }
+                Feature::ConstVariants(_) => {
+                    return Err(Diagnostics::new("ConstVariants does not support `ToTokens`")
+                        .help("ConstVariants is only used with repr enums to render variants as `oneOf` of `const` values."))
+                }
+                Feature::UntaggedAs(_) => {
+                    return Err(Diagnostics::new("UntaggedAs does not support `ToTokens`")
+                        .help("UntaggedAs is only used with untagged enums to choose between `oneOf` and `anyOf` composition."))
+                }
+                Feature::Discriminator(_) => {
+                    return Err(Diagnostics::new("Discriminator does not support `ToTokens`")
+                        .help("Discriminator is only used with untagged enums to define discriminator of the `oneOf`."))
+                }
+                Feature::AdditionalPropertiesDescription(_) => {
+                    return Err(Diagnostics::new("AdditionalPropertiesDescription does not support `ToTokens`")
+                        .help("AdditionalPropertiesDescription is only used with maps to describe the value schema of `additionalProperties`."))
+                }
                 Feature::Required(required) => {
                     let name = <Required as Name>::get_name();
                     quote! { .#name(#required) }
                 }
+                Feature::Extensions(extensions) => quote! { .extensions(Some(#extensions)) },
             };
 
         tokens.extend(feature);
@@ -259,6 +422,8 @@ impl Display for Feature {
         match self {
             Feature::Default(default) => default.fmt(f),
             Feature::Example(example) => example.fmt(f),
+            Feature::Examples(examples) => examples.fmt(f),
+            Feature::ExampleWith(example_with) => example_with.fmt(f),
             Feature::XmlAttr(xml) => xml.fmt(f),
             Feature::Format(format) => format.fmt(f),
             Feature::WriteOnly(write_only) => write_only.fmt(f),
@@ -271,8 +436,13 @@ impl Display for Feature {
             Feature::AllowReserved(allow_reserved) => allow_reserved.fmt(f),
             Feature::Explode(explode) => explode.fmt(f),
             Feature::RenameAll(rename_all) => rename_all.fmt(f),
+            Feature::RenameAllFields(rename_all_fields) => rename_all_fields.fmt(f),
+            Feature::RenameContext(rename_context) => rename_context.fmt(f),
             Feature::ValueType(value_type) => value_type.fmt(f),
             Feature::Inline(inline) => inline.fmt(f),
+            Feature::NoRecursion(no_recursion) => no_recursion.fmt(f),
+            Feature::Ignore(ignore) => ignore.fmt(f),
+            Feature::DecimalAs(decimal_as) => decimal_as.fmt(f),
             Feature::IntoParamsNames(names) => names.fmt(f),
             Feature::MultipleOf(multiple_of) => multiple_of.fmt(f),
             Feature::Maximum(maximum) => maximum.fmt(f),
@@ -282,16 +452,29 @@ impl Display for Feature {
             Feature::MaxLength(max_length) => max_length.fmt(f),
             Feature::MinLength(min_length) => min_length.fmt(f),
             Feature::Pattern(pattern) => pattern.fmt(f),
+            Feature::ContentEncoding(content_encoding) => content_encoding.fmt(f),
+            Feature::ContentMediaType(content_media_type) => content_media_type.fmt(f),
             Feature::MaxItems(max_items) => max_items.fmt(f),
             Feature::MinItems(min_items) => min_items.fmt(f),
+            Feature::UniqueItems(unique_items) => unique_items.fmt(f),
             Feature::MaxProperties(max_properties) => max_properties.fmt(f),
             Feature::MinProperties(min_properties) => min_properties.fmt(f),
             Feature::SchemaWith(schema_with) => schema_with.fmt(f),
             Feature::Description(description) => description.fmt(f),
             Feature::Deprecated(deprecated) => deprecated.fmt(f),
             Feature::As(as_feature) => as_feature.fmt(f),
+            Feature::Bound(bound) => bound.fmt(f),
+            Feature::AsConst(as_const) => as_const.fmt(f),
+            Feature::VariantsAsOneOf(variants_as_one_of) => variants_as_one_of.fmt(f),
+            Feature::ConstVariants(const_variants) => const_variants.fmt(f),
+            Feature::UntaggedAs(untagged_as) => untagged_as.fmt(f),
+            Feature::Discriminator(discriminator) => discriminator.fmt(f),
             Feature::AdditionalProperties(additional_properties) => additional_properties.fmt(f),
+            Feature::AdditionalPropertiesDescription(additional_properties_description) => {
+                additional_properties_description.fmt(f)
+            }
             Feature::Required(required) => required.fmt(f),
+            Feature::Extensions(extensions) => extensions.fmt(f),
         }
     }
 }
@@ -301,6 +484,8 @@ impl Validatable for Feature {
         match &self {
             Feature::Default(default) => default.is_validatable(),
             Feature::Example(example) => example.is_validatable(),
+            Feature::Examples(examples) => examples.is_validatable(),
+            Feature::ExampleWith(example_with) => example_with.is_validatable(),
             Feature::XmlAttr(xml) => xml.is_validatable(),
             Feature::Format(format) => format.is_validatable(),
             Feature::WriteOnly(write_only) => write_only.is_validatable(),
@@ -313,8 +498,13 @@ impl Validatable for Feature {
             Feature::AllowReserved(allow_reserved) => allow_reserved.is_validatable(),
             Feature::Explode(explode) => explode.is_validatable(),
             Feature::RenameAll(rename_all) => rename_all.is_validatable(),
+            Feature::RenameAllFields(rename_all_fields) => rename_all_fields.is_validatable(),
+            Feature::RenameContext(rename_context) => rename_context.is_validatable(),
             Feature::ValueType(value_type) => value_type.is_validatable(),
             Feature::Inline(inline) => inline.is_validatable(),
+            Feature::NoRecursion(no_recursion) => no_recursion.is_validatable(),
+            Feature::Ignore(ignore) => ignore.is_validatable(),
+            Feature::DecimalAs(decimal_as) => decimal_as.is_validatable(),
             Feature::IntoParamsNames(names) => names.is_validatable(),
             Feature::MultipleOf(multiple_of) => multiple_of.is_validatable(),
             Feature::Maximum(maximum) => maximum.is_validatable(),
@@ -324,18 +514,31 @@ impl Validatable for Feature {
             Feature::MaxLength(max_length) => max_length.is_validatable(),
             Feature::MinLength(min_length) => min_length.is_validatable(),
             Feature::Pattern(pattern) => pattern.is_validatable(),
+            Feature::ContentEncoding(content_encoding) => content_encoding.is_validatable(),
+            Feature::ContentMediaType(content_media_type) => content_media_type.is_validatable(),
             Feature::MaxItems(max_items) => max_items.is_validatable(),
             Feature::MinItems(min_items) => min_items.is_validatable(),
+            Feature::UniqueItems(unique_items) => unique_items.is_validatable(),
             Feature::MaxProperties(max_properties) => max_properties.is_validatable(),
             Feature::MinProperties(min_properties) => min_properties.is_validatable(),
             Feature::SchemaWith(schema_with) => schema_with.is_validatable(),
             Feature::Description(description) => description.is_validatable(),
             Feature::Deprecated(deprecated) => deprecated.is_validatable(),
             Feature::As(as_feature) => as_feature.is_validatable(),
+            Feature::Bound(bound) => bound.is_validatable(),
+            Feature::AsConst(as_const) => as_const.is_validatable(),
+            Feature::VariantsAsOneOf(variants_as_one_of) => variants_as_one_of.is_validatable(),
+            Feature::ConstVariants(const_variants) => const_variants.is_validatable(),
+            Feature::UntaggedAs(untagged_as) => untagged_as.is_validatable(),
+            Feature::Discriminator(discriminator) => discriminator.is_validatable(),
             Feature::AdditionalProperties(additional_properties) => {
                 additional_properties.is_validatable()
             }
+            Feature::AdditionalPropertiesDescription(additional_properties_description) => {
+                additional_properties_description.is_validatable()
+            }
             Feature::Required(required) => required.is_validatable(),
+            Feature::Extensions(extensions) => extensions.is_validatable(),
         }
     }
 }
@@ -355,6 +558,8 @@ macro_rules! is_validatable {
 is_validatable! {
     Default => false,
     Example => false,
+    Examples => false,
+    ExampleWith => false,
     XmlAttr => false,
     Format => false,
     WriteOnly => false,
@@ -367,8 +572,13 @@ is_validatable! {
     AllowReserved => false,
     Explode => false,
     RenameAll => false,
+    RenameAllFields => false,
+    RenameContext => false,
     ValueType => false,
     Inline => false,
+    NoRecursion => false,
+    Ignore => false,
+    DecimalAs => false,
     Names => false,
     MultipleOf => true,
     Maximum => true,
@@ -378,25 +588,49 @@ is_validatable! {
     MaxLength => true,
     MinLength => true,
     Pattern => true,
+    ContentEncoding => true,
+    ContentMediaType => true,
     MaxItems => true,
     MinItems => true,
-    MaxProperties => false,
-    MinProperties => false,
+    UniqueItems => true,
+    MaxProperties => true,
+    MinProperties => true,
     SchemaWith => false,
     Description => false,
     Deprecated => false,
     As => false,
+    Bound => false,
+    AsConst => false,
+    VariantsAsOneOf => false,
+    ConstVariants => false,
+    UntaggedAs => false,
+    Discriminator => false,
     AdditionalProperties => false,
-    Required => false
+    AdditionalPropertiesDescription => true,
+    Required => false,
+    Extensions => false
 }
 
 #[derive(Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct Example(AnyValue);
+pub struct Example(pub(crate) Option<AnyValue>);
+
+impl Example {
+    /// Example serialized from the [`Default`] implementation of the `Self` type.
+    pub fn new_default_trait() -> Self {
+        Self(Some(AnyValue::Json(
+            quote! { <Self as ::core::default::Default>::default() },
+        )))
+    }
+}
 
 impl Parse for Example {
     fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
-        parse_utils::parse_next(input, || AnyValue::parse_any(input)).map(Self)
+        if input.peek(syn::Token![=]) {
+            parse_utils::parse_next(input, || AnyValue::parse_any(input)).map(|any| Self(Some(any)))
+        } else {
+            Ok(Self(None))
+        }
     }
 }
 
@@ -414,6 +648,72 @@ impl From<Example> for Feature {
 
 name!(Example = "example");
 
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Examples(Vec<AnyValue>);
+
+impl Parse for Examples {
+    fn parse(input: ParseStream, attribute: Ident) -> syn::Result<Self> {
+        if !cfg!(feature = "openapi_31") {
+            return Err(syn::Error::new(
+                attribute.span(),
+                "`examples` of schema is only supported in OpenAPI 3.1, enable `openapi_31` feature to use it",
+            ));
+        }
+
+        let examples;
+        parenthesized!(examples in input);
+
+        Ok(Self(
+            Punctuated::<AnyValue, Comma>::parse_terminated_with(&examples, AnyValue::parse_any)?
+                .into_iter()
+                .collect(),
+        ))
+    }
+}
+
+impl ToTokens for Examples {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let examples = &self.0;
+        tokens.extend(quote! { [#(#examples),*] })
+    }
+}
+
+impl From<Examples> for Feature {
+    fn from(value: Examples) -> Self {
+        Feature::Examples(value)
+    }
+}
+
+name!(Examples = "examples");
+
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ExampleWith(TypePath);
+
+impl Parse for ExampleWith {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self> {
+        parse_utils::parse_next(input, || input.parse::<TypePath>().map(Self))
+    }
+}
+
+impl ToTokens for ExampleWith {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let path = &self.0;
+        tokens.extend(quote! {
+            #path()
+        })
+    }
+}
+
+impl From<ExampleWith> for Feature {
+    fn from(value: ExampleWith) -> Self {
+        Feature::ExampleWith(value)
+    }
+}
+
+name!(ExampleWith = "example_with");
+
 #[derive(Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct Default(pub(crate) Option<AnyValue>);
@@ -475,25 +775,135 @@ impl From<Inline> for Feature {
 
 name!(Inline = "inline");
 
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct NoRecursion(bool);
+
+impl Parse for NoRecursion {
+    fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
+        parse_utils::parse_bool_or_true(input).map(Self)
+    }
+}
+
+impl From<NoRecursion> for Feature {
+    fn from(value: NoRecursion) -> Self {
+        Feature::NoRecursion(value)
+    }
+}
+
+name!(NoRecursion = "no_recursion");
+
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Ignore(bool);
+
+impl Ignore {
+    pub fn is_true(&self) -> bool {
+        self.0
+    }
+}
+
+impl Parse for Ignore {
+    fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
+        parse_utils::parse_bool_or_true(input).map(Self)
+    }
+}
+
+impl From<Ignore> for Feature {
+    fn from(value: Ignore) -> Self {
+        Feature::Ignore(value)
+    }
+}
+
+name!(Ignore = "ignore");
+
+/// Defines whether `Decimal` types of a field are rendered as `number` or as `string` regardless
+/// of the `decimal` and `decimal_float` feature flags.
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct DecimalAs(bool, Ident);
+
+impl DecimalAs {
+    /// Replace all `Decimal` types of the `type_tree` with `f64` if number representation is
+    /// used or with `String` otherwise.
+    pub fn substitute(&self, type_tree: &mut TypeTree) -> Result<(), Diagnostics> {
+        let decimals = type_tree.find_all_by_name_mut("Decimal");
+        if decimals.is_empty() {
+            return Err(Diagnostics::with_span(
+                self.1.span(),
+                "decimal_as can only be used with fields having `Decimal` type",
+            ));
+        }
+
+        for decimal in decimals {
+            let span = decimal.span.unwrap_or_else(|| self.1.span());
+            let name = if self.0 { "f64" } else { "String" };
+            *decimal = TypeTree {
+                path: Some(Cow::Owned(Ident::new(name, span).into())),
+                span: Some(span),
+                value_type: super::ValueType::Primitive,
+                generic_type: None,
+                children: None,
+                array_len: None,
+                array_len_param: None,
+                trait_object: false,
+                name_only: false,
+            };
+        }
+
+        Ok(())
+    }
+}
+
+impl Parse for DecimalAs {
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        let litstr = parse_utils::parse_next(input, || input.parse::<LitStr>())?;
+
+        match &*litstr.value() {
+            "number" => Ok(Self(true, ident)),
+            "string" => Ok(Self(false, ident)),
+            _ => Err(syn::Error::new(
+                litstr.span(),
+                r#"unexpected decimal representation, expected one of: "number", "string""#,
+            )),
+        }
+    }
+}
+
+impl From<DecimalAs> for Feature {
+    fn from(value: DecimalAs) -> Self {
+        Feature::DecimalAs(value)
+    }
+}
+
+name!(DecimalAs = "decimal_as");
+
 #[derive(Default, Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct XmlAttr(schema::xml::XmlAttr);
 
 impl XmlAttr {
-    /// Split [`XmlAttr`] for [`GenericType::Vec`] returning tuple of [`XmlAttr`]s where first
-    /// one is for a vec and second one is for object field.
+    /// Split [`XmlAttr`] for [`GenericType::Vec`] like types returning tuple of [`XmlAttr`]s
+    /// where first one is for a vec and second one is for object field. Object field
+    /// [`XmlAttr`] is `None` if it does not define any attributes.
     pub fn split_for_vec(
         &mut self,
         type_tree: &TypeTree,
     ) -> Result<(Option<XmlAttr>, Option<XmlAttr>), Diagnostics> {
-        if matches!(type_tree.generic_type, Some(GenericType::Vec)) {
+        if IsVec(type_tree).is_valid().is_ok() {
             let mut value_xml = mem::take(self);
             let vec_xml = schema::xml::XmlAttr::with_wrapped(
                 mem::take(&mut value_xml.0.is_wrapped),
                 mem::take(&mut value_xml.0.wrap_name),
             );
 
-            Ok((Some(XmlAttr(vec_xml)), Some(value_xml)))
+            Ok((
+                Some(XmlAttr(vec_xml)),
+                (!value_xml.0.is_empty()).then_some(value_xml),
+            ))
         } else {
             self.validate_xml(&self.0)?;
 
@@ -568,7 +978,19 @@ pub struct ValueType(syn::Type);
 impl ValueType {
     /// Create [`TypeTree`] from current [`syn::Type`].
     pub fn as_type_tree(&self) -> Result<TypeTree, Diagnostics> {
-        TypeTree::from_type(&self.0)
+        fn set_name_only(type_tree: &mut TypeTree) {
+            type_tree.name_only = true;
+            type_tree
+                .children
+                .iter_mut()
+                .flatten()
+                .for_each(set_name_only);
+        }
+
+        let mut type_tree = TypeTree::from_type(&self.0)?;
+        set_name_only(&mut type_tree);
+
+        Ok(type_tree)
     }
 }
 
@@ -590,6 +1012,12 @@ name!(ValueType = "value_type");
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct WriteOnly(bool);
 
+impl WriteOnly {
+    pub fn new() -> Self {
+        Self(true)
+    }
+}
+
 impl Parse for WriteOnly {
     fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
         parse_utils::parse_bool_or_true(input).map(Self)
@@ -614,6 +1042,12 @@ name!(WriteOnly = "write_only");
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct ReadOnly(bool);
 
+impl ReadOnly {
+    pub fn new() -> Self {
+        Self(true)
+    }
+}
+
 impl Parse for ReadOnly {
     fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
         parse_utils::parse_bool_or_true(input).map(Self)
@@ -656,6 +1090,13 @@ impl From<Title> for Feature {
     }
 }
 
+#[cfg(feature = "auto_title")]
+impl From<String> for Title {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
 name!(Title = "title");
 
 #[derive(Clone, Copy)]
@@ -666,6 +1107,10 @@ impl Nullable {
     pub fn new() -> Self {
         Self(true)
     }
+
+    pub fn is_nullable(&self) -> bool {
+        self.0
+    }
 }
 
 impl Parse for Nullable {
@@ -748,6 +1193,75 @@ impl From<RenameAll> for Feature {
 
 name!(RenameAll = "rename_all");
 
+impl From<RenameRule> for RenameAll {
+    fn from(value: RenameRule) -> Self {
+        Self(value)
+    }
+}
+
+/// Rename rule for the fields of all named field variants of an enum.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct RenameAllFields(RenameRule);
+
+impl RenameAllFields {
+    pub fn as_rename_rule(&self) -> &RenameRule {
+        &self.0
+    }
+}
+
+impl Parse for RenameAllFields {
+    fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
+        let litstr = parse_utils::parse_next(input, || input.parse::<LitStr>())?;
+
+        litstr
+            .value()
+            .parse::<RenameRule>()
+            .map_err(|error| syn::Error::new(litstr.span(), error.to_string()))
+            .map(Self)
+    }
+}
+
+impl From<RenameAllFields> for Feature {
+    fn from(value: RenameAllFields) -> Self {
+        Feature::RenameAllFields(value)
+    }
+}
+
+name!(RenameAllFields = "rename_all_fields");
+
+/// Defines which serde _`rename(serialize = "...", deserialize = "...")`_ name is used for the
+/// schema since OpenAPI can only have one name for a field or a variant.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub enum RenameContext {
+    Serialize,
+    Deserialize,
+}
+
+impl Parse for RenameContext {
+    fn parse(input: syn::parse::ParseStream, _: Ident) -> syn::Result<Self> {
+        let litstr = parse_utils::parse_next(input, || input.parse::<LitStr>())?;
+
+        match &*litstr.value() {
+            "serialize" => Ok(Self::Serialize),
+            "deserialize" => Ok(Self::Deserialize),
+            _ => Err(syn::Error::new(
+                litstr.span(),
+                r#"unexpected rename context, expected one of: "serialize", "deserialize""#,
+            )),
+        }
+    }
+}
+
+impl From<RenameContext> for Feature {
+    fn from(value: RenameContext) -> Self {
+        Feature::RenameContext(value)
+    }
+}
+
+name!(RenameContext = "rename_context");
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
 pub struct Style(ParameterStyle);
@@ -918,6 +1432,13 @@ name!(MultipleOf = "multiple_of");
 #[derive(Clone)]
 pub struct Maximum(f64, Ident);
 
+#[cfg(feature = "validator")]
+impl Maximum {
+    pub fn new(value: f64, span: Span) -> Self {
+        Self(value, Ident::new("empty", span))
+    }
+}
+
 impl Validate for Maximum {
     fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
         match validator.is_valid() {
@@ -1000,6 +1521,13 @@ name!(Minimum = "minimum");
 #[derive(Clone)]
 pub struct ExclusiveMaximum(f64, Ident);
 
+#[cfg(feature = "validator")]
+impl ExclusiveMaximum {
+    pub fn new(value: f64, span: Span) -> Self {
+        Self(value, Ident::new("empty", span))
+    }
+}
+
 impl Validate for ExclusiveMaximum {
     fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
         match validator.is_valid() {
@@ -1037,6 +1565,13 @@ name!(ExclusiveMaximum = "exclusive_maximum");
 #[derive(Clone)]
 pub struct ExclusiveMinimum(f64, Ident);
 
+#[cfg(feature = "validator")]
+impl ExclusiveMinimum {
+    pub fn new(value: f64, span: Span) -> Self {
+        Self(value, Ident::new("empty", span))
+    }
+}
+
 impl Validate for ExclusiveMinimum {
     fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
         match validator.is_valid() {
@@ -1084,6 +1619,13 @@ impl Validate for MaxLength {
     }
 }
 
+#[cfg(feature = "validator")]
+impl MaxLength {
+    pub fn new(value: usize, ident: Ident) -> Self {
+        Self(value, ident)
+    }
+}
+
 impl Parse for MaxLength {
     fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
     where
@@ -1121,6 +1663,13 @@ impl Validate for MinLength {
     }
 }
 
+#[cfg(feature = "validator")]
+impl MinLength {
+    pub fn new(value: usize, ident: Ident) -> Self {
+        Self(value, ident)
+    }
+}
+
 impl Parse for MinLength {
     fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
     where
@@ -1164,8 +1713,17 @@ impl Parse for Pattern {
     where
         Self: Sized,
     {
-        parse_utils::parse_next(input, || input.parse::<LitStr>())
-            .map(|pattern| Self(pattern.value(), ident))
+        let pattern = parse_utils::parse_next(input, || input.parse::<LitStr>())?;
+
+        #[cfg(feature = "validate_pattern")]
+        if let Err(error) = regex::Regex::new(&pattern.value()) {
+            return Err(syn::Error::new(
+                pattern.span(),
+                format!("invalid `pattern`: {error}"),
+            ));
+        }
+
+        Ok(Self(pattern.value(), ident))
     }
 }
 
@@ -1185,88 +1743,247 @@ name!(Pattern = "pattern");
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub struct MaxItems(usize, Ident);
+pub struct ContentEncoding(String, Ident);
 
-impl Validate for MaxItems {
+impl Validate for ContentEncoding {
     fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
         match validator.is_valid() {
-            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`max_items` error: {}", error))
-                .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-maxitems")),
+            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`content_encoding` error: {}", error))
+                .help("See more details: `https://json-schema.org/draft/2020-12/json-schema-validation#name-contentencoding`")
+            ),
             _ => None,
         }
     }
 }
 
-impl Parse for MaxItems {
+impl Parse for ContentEncoding {
     fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
     where
         Self: Sized,
     {
-        parse_number(input).map(|max_items| Self(max_items, ident))
+        if !cfg!(feature = "openapi_31") {
+            return Err(syn::Error::new(
+                ident.span(),
+                "`content_encoding` is only supported in OpenAPI 3.1, enable `openapi_31` feature to use it",
+            ));
+        }
+
+        parse_utils::parse_next(input, || input.parse::<LitStr>())
+            .map(|content_encoding| Self(content_encoding.value(), ident))
     }
 }
 
-impl ToTokens for MaxItems {
+impl ToTokens for ContentEncoding {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         self.0.to_tokens(tokens);
     }
 }
 
-impl From<MaxItems> for Feature {
-    fn from(value: MaxItems) -> Self {
-        Feature::MaxItems(value)
+impl From<ContentEncoding> for Feature {
+    fn from(value: ContentEncoding) -> Self {
+        Feature::ContentEncoding(value)
     }
 }
 
-name!(MaxItems = "max_items");
+name!(ContentEncoding = "content_encoding");
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub struct MinItems(usize, Ident);
+pub struct ContentMediaType(String, Ident);
 
-impl Validate for MinItems {
+impl Validate for ContentMediaType {
     fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
         match validator.is_valid() {
-            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`min_items` error: {}", error))
-                .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-minitems")),
+            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`content_media_type` error: {}", error))
+                .help("See more details: `https://json-schema.org/draft/2020-12/json-schema-validation#name-contentmediatype`")
+            ),
             _ => None,
         }
     }
 }
 
-impl Parse for MinItems {
+impl Parse for ContentMediaType {
     fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
     where
         Self: Sized,
     {
-        parse_number(input).map(|max_items| Self(max_items, ident))
+        if !cfg!(feature = "openapi_31") {
+            return Err(syn::Error::new(
+                ident.span(),
+                "`content_media_type` is only supported in OpenAPI 3.1, enable `openapi_31` feature to use it",
+            ));
+        }
+
+        parse_utils::parse_next(input, || input.parse::<LitStr>())
+            .map(|content_media_type| Self(content_media_type.value(), ident))
     }
 }
 
-impl ToTokens for MinItems {
+impl ToTokens for ContentMediaType {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         self.0.to_tokens(tokens);
     }
 }
 
-impl From<MinItems> for Feature {
-    fn from(value: MinItems) -> Self {
-        Feature::MinItems(value)
+impl From<ContentMediaType> for Feature {
+    fn from(value: ContentMediaType) -> Self {
+        Feature::ContentMediaType(value)
     }
 }
 
-name!(MinItems = "min_items");
+name!(ContentMediaType = "content_media_type");
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub struct MaxProperties(usize, ());
+pub struct MaxItems(usize, Ident);
 
-impl Parse for MaxProperties {
-    fn parse(input: ParseStream, _ident: Ident) -> syn::Result<Self>
-    where
-        Self: Sized,
-    {
-        parse_integer(input).map(|max_properties| Self(max_properties, ()))
+impl Validate for MaxItems {
+    fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
+        match validator.is_valid() {
+            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`max_items` error: {}", error))
+                .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-maxitems")),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "validator")]
+impl MaxItems {
+    pub fn new(value: usize, ident: Ident) -> Self {
+        Self(value, ident)
+    }
+}
+
+impl Parse for MaxItems {
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
+    where
+        Self: Sized,
+    {
+        parse_number(input).map(|max_items| Self(max_items, ident))
+    }
+}
+
+impl ToTokens for MaxItems {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+impl From<MaxItems> for Feature {
+    fn from(value: MaxItems) -> Self {
+        Feature::MaxItems(value)
+    }
+}
+
+name!(MaxItems = "max_items");
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct MinItems(usize, Ident);
+
+impl Validate for MinItems {
+    fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
+        match validator.is_valid() {
+            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`min_items` error: {}", error))
+                .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-minitems")),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "validator")]
+impl MinItems {
+    pub fn new(value: usize, ident: Ident) -> Self {
+        Self(value, ident)
+    }
+}
+
+impl Parse for MinItems {
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
+    where
+        Self: Sized,
+    {
+        parse_number(input).map(|max_items| Self(max_items, ident))
+    }
+}
+
+impl ToTokens for MinItems {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+impl From<MinItems> for Feature {
+    fn from(value: MinItems) -> Self {
+        Feature::MinItems(value)
+    }
+}
+
+name!(MinItems = "min_items");
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct UniqueItems(bool, Ident);
+
+impl UniqueItems {
+    pub fn is_unique(&self) -> bool {
+        self.0
+    }
+}
+
+impl Validate for UniqueItems {
+    fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
+        match validator.is_valid() {
+            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`unique_items` error: {}", error))
+                .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-uniqueitems")),
+            _ => None,
+        }
+    }
+}
+
+impl Parse for UniqueItems {
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
+    where
+        Self: Sized,
+    {
+        parse_utils::parse_bool_or_true(input).map(|unique_items| Self(unique_items, ident))
+    }
+}
+
+impl ToTokens for UniqueItems {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+impl From<UniqueItems> for Feature {
+    fn from(value: UniqueItems) -> Self {
+        Feature::UniqueItems(value)
+    }
+}
+
+name!(UniqueItems = "unique_items");
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct MaxProperties(usize, Ident);
+
+impl Validate for MaxProperties {
+    fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
+        match validator.is_valid() {
+            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`max_properties` error: {}", error))
+                .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-maxproperties")),
+            _ => None,
+        }
+    }
+}
+
+impl Parse for MaxProperties {
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
+    where
+        Self: Sized,
+    {
+        parse_integer(input).map(|max_properties| Self(max_properties, ident))
     }
 }
 
@@ -1286,14 +2003,24 @@ name!(MaxProperties = "max_properties");
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub struct MinProperties(usize, ());
+pub struct MinProperties(usize, Ident);
+
+impl Validate for MinProperties {
+    fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
+        match validator.is_valid() {
+            Err(error) => Some(Diagnostics::with_span(self.1.span(), format!("`min_properties` error: {}", error))
+                .help("See more details: `http://json-schema.org/draft/2020-12/json-schema-validation.html#name-minproperties")),
+            _ => None,
+        }
+    }
+}
 
 impl Parse for MinProperties {
-    fn parse(input: ParseStream, _ident: Ident) -> syn::Result<Self>
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
     where
         Self: Sized,
     {
-        parse_integer(input).map(|min_properties| Self(min_properties, ()))
+        parse_integer(input).map(|min_properties| Self(min_properties, ident))
     }
 }
 
@@ -1407,6 +2134,18 @@ name!(Deprecated = "deprecated");
 #[derive(Clone)]
 pub struct As(pub TypePath);
 
+impl As {
+    /// Get [`Title`] of the name defined by the path e.g. _`path.to.Pet`_.
+    pub fn to_title(&self) -> Title {
+        Title(schema::format_path_ref(&self.0.path))
+    }
+
+    /// Get [`ValueType`] referencing schema with the name defined by the path.
+    pub fn to_value_type(&self) -> ValueType {
+        ValueType(syn::Type::Path(self.0.clone()))
+    }
+}
+
 impl Parse for As {
     fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
     where
@@ -1426,25 +2165,268 @@ name!(As = "as");
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone)]
-pub struct AdditionalProperties(bool);
+pub struct Bound(pub Punctuated<WherePredicate, Comma>);
 
-impl Parse for AdditionalProperties {
+impl Parse for Bound {
     fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
     where
         Self: std::marker::Sized,
     {
-        parse_utils::parse_bool_or_true(input).map(Self)
+        let bound = parse_utils::parse_next(input, || input.parse::<LitStr>())?;
+        bound
+            .parse_with(Punctuated::<WherePredicate, Comma>::parse_terminated)
+            .map(Self)
     }
 }
 
-impl ToTokens for AdditionalProperties {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        let additional_properties = &self.0;
-        tokens.extend(quote!(
-            utoipa::openapi::schema::AdditionalProperties::FreeForm(
-                #additional_properties
-            )
-        ))
+impl From<Bound> for Feature {
+    fn from(value: Bound) -> Self {
+        Self::Bound(value)
+    }
+}
+
+name!(Bound = "bound");
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct AsConst(bool, Ident);
+
+impl AsConst {
+    pub fn is_const(&self) -> bool {
+        self.0
+    }
+
+    pub fn span(&self) -> Span {
+        self.1.span()
+    }
+}
+
+impl Parse for AsConst {
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        parse_utils::parse_bool_or_true(input).map(|as_const| Self(as_const, ident))
+    }
+}
+
+impl From<AsConst> for Feature {
+    fn from(value: AsConst) -> Self {
+        Self::AsConst(value)
+    }
+}
+
+name!(AsConst = "as_const");
+
+/// Renders unit enum variants as `oneOf` of `const` schemas carrying per variant description
+/// instead of compact `enum` of values.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct VariantsAsOneOf(bool, Ident);
+
+impl VariantsAsOneOf {
+    pub fn is_true(&self) -> bool {
+        self.0
+    }
+
+    pub fn span(&self) -> Span {
+        self.1.span()
+    }
+}
+
+impl Parse for VariantsAsOneOf {
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        parse_utils::parse_bool_or_true(input)
+            .map(|variants_as_one_of| Self(variants_as_one_of, ident))
+    }
+}
+
+impl From<VariantsAsOneOf> for Feature {
+    fn from(value: VariantsAsOneOf) -> Self {
+        Self::VariantsAsOneOf(value)
+    }
+}
+
+name!(VariantsAsOneOf = "variants_as_one_of");
+
+/// Renders `repr` enum variants as `oneOf` of `const` schemas carrying per variant title and
+/// description instead of compact `enum` of values.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(not(feature = "repr"), allow(dead_code))]
+#[derive(Clone)]
+pub struct ConstVariants(bool, Ident);
+
+#[cfg_attr(not(feature = "repr"), allow(dead_code))]
+impl ConstVariants {
+    pub fn is_const_variants(&self) -> bool {
+        self.0
+    }
+
+    pub fn span(&self) -> Span {
+        self.1.span()
+    }
+}
+
+impl Parse for ConstVariants {
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        parse_utils::parse_bool_or_true(input).map(|const_variants| Self(const_variants, ident))
+    }
+}
+
+impl From<ConstVariants> for Feature {
+    fn from(value: ConstVariants) -> Self {
+        Self::ConstVariants(value)
+    }
+}
+
+name!(ConstVariants = "const_variants");
+
+/// Defines whether untagged enum variants are composed with exclusive `oneOf` or inclusive
+/// `anyOf`.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct UntaggedAs(bool, Ident);
+
+impl UntaggedAs {
+    pub fn is_any_of(&self) -> bool {
+        self.0
+    }
+
+    pub fn span(&self) -> Span {
+        self.1.span()
+    }
+}
+
+impl Parse for UntaggedAs {
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        let litstr = parse_utils::parse_next(input, || input.parse::<LitStr>())?;
+
+        match &*litstr.value() {
+            "oneOf" => Ok(Self(false, ident)),
+            "anyOf" => Ok(Self(true, ident)),
+            _ => Err(syn::Error::new(
+                litstr.span(),
+                r#"unexpected untagged composition, expected one of: "oneOf", "anyOf""#,
+            )),
+        }
+    }
+}
+
+impl From<UntaggedAs> for Feature {
+    fn from(value: UntaggedAs) -> Self {
+        Self::UntaggedAs(value)
+    }
+}
+
+name!(UntaggedAs = "untagged_as");
+
+/// Defines name of the property shared by the variant payloads of untagged enum which is used as
+/// the discriminator of the enum.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct Discriminator(LitStr);
+
+impl Discriminator {
+    pub fn value(&self) -> String {
+        self.0.value()
+    }
+
+    pub fn span(&self) -> Span {
+        self.0.span()
+    }
+}
+
+impl Parse for Discriminator {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        parse_utils::parse_next(input, || input.parse::<LitStr>()).map(Self)
+    }
+}
+
+impl From<Discriminator> for Feature {
+    fn from(value: Discriminator) -> Self {
+        Self::Discriminator(value)
+    }
+}
+
+name!(Discriminator = "discriminator");
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+enum AdditionalPropertiesValue {
+    FreeForm(bool),
+    Schema(Box<syn::Type>),
+}
+
+/// Defines `additionalProperties` either as free form _`true`_ or _`false`_ or as a schema of the
+/// given type.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct AdditionalProperties(AdditionalPropertiesValue, Ident);
+
+impl AdditionalProperties {
+    /// Check whether any additional properties are allowed, i.e. it is free form _`true`_.
+    pub fn is_free_form(&self) -> bool {
+        matches!(self.0, AdditionalPropertiesValue::FreeForm(true))
+    }
+
+    pub fn span(&self) -> Span {
+        self.1.span()
+    }
+}
+
+impl Parse for AdditionalProperties {
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        if input.peek(syn::Token![=]) && !input.peek2(syn::LitBool) {
+            parse_utils::parse_next(input, || input.parse::<syn::Type>())
+                .map(|ty| Self(AdditionalPropertiesValue::Schema(Box::new(ty)), ident))
+        } else {
+            parse_utils::parse_bool_or_true(input).map(|additional_properties| {
+                Self(
+                    AdditionalPropertiesValue::FreeForm(additional_properties),
+                    ident,
+                )
+            })
+        }
+    }
+}
+
+impl ToTokensDiagnostics for AdditionalProperties {
+    fn to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
+        match &self.0 {
+            AdditionalPropertiesValue::FreeForm(additional_properties) => tokens.extend(quote!(
+                utoipa::openapi::schema::AdditionalProperties::FreeForm(
+                    #additional_properties
+                )
+            )),
+            AdditionalPropertiesValue::Schema(ty) => {
+                let type_tree = TypeTree::from_type(ty)?;
+                let schema = super::ComponentSchema::new(super::ComponentSchemaProps {
+                    type_tree: &type_tree,
+                    features: None,
+                    description: None,
+                    deprecated: None,
+                    object_name: "",
+                })?;
+                tokens.extend(as_tokens_or_diagnostics!(&schema))
+            }
+        }
+
+        Ok(())
     }
 }
 
@@ -1456,6 +2438,51 @@ impl From<AdditionalProperties> for Feature {
     }
 }
 
+/// Description of the value schema of a map defined as `additionalProperties`.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct AdditionalPropertiesDescription(String, Ident);
+
+impl AdditionalPropertiesDescription {
+    pub fn description(&self) -> &str {
+        &self.0
+    }
+
+    pub fn span(&self) -> Span {
+        self.1.span()
+    }
+}
+
+impl Validate for AdditionalPropertiesDescription {
+    fn validate(&self, validator: impl Validator) -> Option<Diagnostics> {
+        match validator.is_valid() {
+            Err(error) => Some(Diagnostics::with_span(
+                self.1.span(),
+                format!("`additional_properties_description` error: {}", error),
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl Parse for AdditionalPropertiesDescription {
+    fn parse(input: ParseStream, ident: Ident) -> syn::Result<Self>
+    where
+        Self: Sized,
+    {
+        parse_utils::parse_next(input, || input.parse::<LitStr>())
+            .map(|description| Self(description.value(), ident))
+    }
+}
+
+impl From<AdditionalPropertiesDescription> for Feature {
+    fn from(value: AdditionalPropertiesDescription) -> Self {
+        Self::AdditionalPropertiesDescription(value)
+    }
+}
+
+name!(AdditionalPropertiesDescription = "additional_properties_description");
+
 #[derive(Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct Required(pub bool);
@@ -1505,6 +2532,53 @@ impl From<Required> for Feature {
 
 name!(Required = "required");
 
+/// Vendor extensions of the schema given as _`"x-name" = value`_ pairs.
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Extensions(Vec<(LitStr, AnyValue)>);
+
+impl Parse for Extensions {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self> {
+        let extensions;
+        parenthesized!(extensions in input);
+
+        Punctuated::<(LitStr, AnyValue), Comma>::parse_terminated_with(&extensions, |input| {
+            let name = input.parse::<LitStr>()?;
+            if !name.value().starts_with("x-") {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!("extension `{}` must start with `x-`", name.value()),
+                ));
+            }
+            input.parse::<syn::Token![=]>()?;
+
+            Ok((name, AnyValue::parse_any(input)?))
+        })
+        .map(|extensions| Self(extensions.into_iter().collect()))
+    }
+}
+
+impl ToTokens for Extensions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let extensions = self
+            .0
+            .iter()
+            .map(|(name, value)| quote! { (::std::string::String::from(#name), #value) });
+
+        tokens.extend(quote! {
+            ::std::collections::HashMap::from([#(#extensions),*])
+        })
+    }
+}
+
+impl From<Extensions> for Feature {
+    fn from(value: Extensions) -> Self {
+        Feature::Extensions(value)
+    }
+}
+
+name!(Extensions = "extensions");
+
 pub trait Validator {
     fn is_valid(&self) -> Result<(), &'static str>;
 }
@@ -1549,10 +2623,37 @@ pub struct IsVec<'a>(&'a TypeTree<'a>);
 
 impl Validator for IsVec<'_> {
     fn is_valid(&self) -> Result<(), &'static str> {
-        if self.0.generic_type == Some(GenericType::Vec) {
+        match self.0.generic_type {
+            Some(GenericType::Vec) | Some(GenericType::LinkedList) | Some(GenericType::Set) => {
+                Ok(())
+            }
+            #[cfg(feature = "smallvec")]
+            Some(GenericType::SmallVec) => Ok(()),
+            _ => Err("can only be used with `Vec`, `array` or `slice` types"),
+        }
+    }
+}
+
+pub struct IsMap<'a>(&'a TypeTree<'a>);
+
+impl Validator for IsMap<'_> {
+    fn is_valid(&self) -> Result<(), &'static str> {
+        if self.0.generic_type == Some(GenericType::Map) || self.0.is_object() {
+            Ok(())
+        } else {
+            Err("can only be used with map or `Object` types")
+        }
+    }
+}
+
+pub struct IsGenericMap<'a>(&'a TypeTree<'a>);
+
+impl Validator for IsGenericMap<'_> {
+    fn is_valid(&self) -> Result<(), &'static str> {
+        if self.0.generic_type == Some(GenericType::Map) {
             Ok(())
         } else {
-            Err("can only be used with `Vec`, `array` or `slice` types")
+            Err("can only be used with map types")
         }
     }
 }
@@ -1676,6 +2777,17 @@ impl IsInline for Vec<Feature> {
     }
 }
 
+pub trait IsNoRecursion {
+    fn is_no_recursion(&self) -> bool;
+}
+
+impl IsNoRecursion for Vec<Feature> {
+    fn is_no_recursion(&self) -> bool {
+        self.iter()
+            .any(|feature| matches!(feature, Feature::NoRecursion(no_recursion) if no_recursion.0))
+    }
+}
+
 pub trait ToTokensExt {
     fn to_token_stream(&self) -> Result<TokenStream, Diagnostics>;
 }
@@ -1705,6 +2817,9 @@ pub trait FeaturesExt {
     /// Pop [`RenameAll`] feature if exists in [`Vec<Feature>`] list.
     fn pop_rename_all_feature(&mut self) -> Option<RenameAll>;
 
+    /// Pop [`RenameContext`] feature if exists in [`Vec<Feature>`] list.
+    fn pop_rename_context_feature(&mut self) -> Option<RenameContext>;
+
     /// Extract [`XmlAttr`] feature for given `type_tree` if it has generic type [`GenericType::Vec`]
     fn extract_vec_xml_feature(
         &mut self,
@@ -1743,28 +2858,36 @@ impl FeaturesExt for Vec<Feature> {
             })
     }
 
+    fn pop_rename_context_feature(&mut self) -> Option<RenameContext> {
+        self.pop_by(|feature| matches!(feature, Feature::RenameContext(_)))
+            .and_then(|feature| match feature {
+                Feature::RenameContext(rename_context) => Some(rename_context),
+                _ => None,
+            })
+    }
+
     fn extract_vec_xml_feature(
         &mut self,
         type_tree: &TypeTree,
     ) -> Result<Option<Feature>, Diagnostics> {
-        self.iter_mut()
-            .find_map(|feature| match feature {
-                Feature::XmlAttr(xml_feature) => {
-                    match xml_feature.split_for_vec(type_tree) {
-                        Ok((vec_xml, value_xml)) => {
-                            // replace the original xml attribute with split value xml
-                            if let Some(mut xml) = value_xml {
-                                mem::swap(xml_feature, &mut xml)
-                            }
-
-                            Some(Ok(vec_xml.map(Feature::XmlAttr)))
-                        }
-                        Err(diagnostics) => Some(Err(diagnostics)),
+        self.iter()
+            .position(|feature| matches!(feature, Feature::XmlAttr(_)))
+            .and_then_try(|index| {
+                let (vec_xml, value_xml) = match &mut self[index] {
+                    Feature::XmlAttr(xml_feature) => xml_feature.split_for_vec(type_tree)?,
+                    _ => unreachable!("feature at index must be `XmlAttr`"),
+                };
+
+                // replace the original xml attribute with split value xml
+                match value_xml {
+                    Some(xml) => self[index] = Feature::XmlAttr(xml),
+                    None => {
+                        self.swap_remove(index);
                     }
                 }
-                _ => None,
+
+                Ok(vec_xml.map(Feature::XmlAttr))
             })
-            .and_then_try(|value| value)
     }
 }
 
@@ -1788,6 +2911,11 @@ impl FeaturesExt for Option<Vec<Feature>> {
             .and_then(|features| features.pop_rename_all_feature())
     }
 
+    fn pop_rename_context_feature(&mut self) -> Option<RenameContext> {
+        self.as_mut()
+            .and_then(|features| features.pop_rename_context_feature())
+    }
+
     fn extract_vec_xml_feature(
         &mut self,
         type_tree: &TypeTree,
@@ -14,7 +14,7 @@ use crate::{
             self, AdditionalProperties, AllowReserved, Example, ExclusiveMaximum, ExclusiveMinimum,
             Explode, Format, Inline, MaxItems, MaxLength, Maximum, MinItems, MinLength, Minimum,
             MultipleOf, Names, Nullable, Pattern, ReadOnly, Rename, RenameAll, SchemaWith, Style,
-            WriteOnly, XmlAttr,
+            UniqueItems, WriteOnly, XmlAttr,
         },
         FieldRename,
     },
@@ -113,7 +113,7 @@ impl ToTokensDiagnostics for IntoParams {
             .collect::<Result<Vec<_>, Diagnostics>>()?
             .into_iter()
             .filter_map(|(index, field, field_serde_params)| {
-                if !field_serde_params.skip {
+                if !field_serde_params.is_skipped() {
                     Some((index, field, field_serde_params))
                 } else {
                     None
@@ -282,6 +282,7 @@ impl Parse for FieldFeatures {
             Pattern,
             MaxItems,
             MinItems,
+            UniqueItems,
             AdditionalProperties
         )))
     }
@@ -350,6 +351,7 @@ impl Param<'_> {
                     | Feature::Pattern(_)
                     | Feature::MaxItems(_)
                     | Feature::MinItems(_)
+                    | Feature::UniqueItems(_)
                     | Feature::AdditionalProperties(_) => {
                         schema_features.push(feature);
                     }
@@ -393,7 +395,7 @@ impl ToTokensDiagnostics for Param<'_> {
             .as_deref()
             .map(Cow::Borrowed)
             .or(rename.map(Cow::Owned));
-        let rename_all = self.serde_container.rename_all.as_ref().or(self
+        let rename_all = self.serde_container.rename_all_for(None).or(self
             .container_attributes
             .rename_all
             .map(|rename_all| rename_all.as_rename_rule()));
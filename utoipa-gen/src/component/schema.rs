@@ -1,11 +1,14 @@
-use std::borrow::{Borrow, Cow};
+use std::{
+    borrow::{Borrow, Cow},
+    collections::HashMap,
+};
 
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use quote::{format_ident, quote, ToTokens};
 use syn::{
     parse::Parse, parse_quote, punctuated::Punctuated, spanned::Spanned, token::Comma, Attribute,
-    Data, Field, Fields, FieldsNamed, FieldsUnnamed, GenericArgument, GenericParam, Generics,
-    Lifetime, LifetimeParam, Path, PathArguments, Token, Type, Variant, Visibility,
+    Data, Expr, ExprLit, Field, Fields, FieldsNamed, FieldsUnnamed, GenericArgument, GenericParam,
+    Generics, Lifetime, LifetimeParam, Lit, Path, PathArguments, Token, Type, Variant, Visibility,
 };
 
 use crate::{
@@ -23,17 +26,21 @@ use self::{
     features::{
         ComplexEnumFeatures, EnumFeatures, EnumNamedFieldVariantFeatures,
         EnumUnnamedFieldVariantFeatures, FromAttributes, NamedFieldFeatures,
-        NamedFieldStructFeatures, UnnamedFieldStructFeatures,
+        NamedFieldStructFeatures, UnnamedFieldFeatures, UnnamedFieldStructFeatures,
     },
 };
 
 use super::{
+    collect_schema_references, collect_warnings,
     features::{
-        parse_features, pop_feature, pop_feature_as_inner, As, Feature, FeaturesExt, IntoInner,
-        RenameAll, ToTokensExt,
+        parse_features, pop_feature, pop_feature_as_inner, As, AsConst, Bound, Discriminator,
+        Feature, FeaturesExt, IntoInner, IsInline, RenameAll, RenameContext, ToTokensExt,
+        UntaggedAs, Validatable, VariantsAsOneOf,
     },
-    serde::{self, SerdeContainer, SerdeEnumRepr, SerdeValue},
-    ComponentSchema, FieldRename, FlattenedMapSchema, TypeTree, ValueType, VariantRename,
+    schema_reference_name,
+    serde::{self, RenameRule, SerdeContainer, SerdeEnumRepr, SerdeValue},
+    ComponentSchema, FieldRename, FlattenedMapSchema, GenericType, TypeTree, ValueType,
+    VariantRename,
 };
 
 mod enum_variant;
@@ -58,7 +65,7 @@ impl<'a> Schema<'a> {
         generics: &'a Generics,
         vis: &'a Visibility,
     ) -> Result<Self, Diagnostics> {
-        let aliases = if generics.type_params().count() > 0 {
+        let aliases = if generics.type_params().count() > 0 || generics.const_params().count() > 0 {
             parse_aliases(attributes)?
         } else {
             None
@@ -73,6 +80,37 @@ impl<'a> Schema<'a> {
             vis,
         })
     }
+
+    /// Get lifetime of the `ToSchema` trait which does not clash with lifetimes of the type.
+    fn to_schema_lifetime(&self) -> Lifetime {
+        let mut name = String::from(Schema::TO_SCHEMA_LIFETIME);
+        while self
+            .generics
+            .lifetimes()
+            .any(|param| param.lifetime.ident == name[1..])
+        {
+            name.push('_');
+        }
+
+        Lifetime::new(&name, Span::call_site())
+    }
+
+    /// Check whether the referenced type `path` contains type or const generic parameters of the
+    /// type.
+    fn is_generic_reference(&self, path: &Path) -> bool {
+        fn contains_generic(tokens: TokenStream, generics: &Generics) -> bool {
+            tokens.into_iter().any(|token| match token {
+                TokenTree::Ident(ident) => {
+                    generics.type_params().any(|param| param.ident == ident)
+                        || generics.const_params().any(|param| param.ident == ident)
+                }
+                TokenTree::Group(group) => contains_generic(group.stream(), generics),
+                _ => false,
+            })
+        }
+
+        contains_generic(path.to_token_stream(), self.generics)
+    }
 }
 
 impl ToTokensDiagnostics for Schema<'_> {
@@ -85,39 +123,74 @@ impl ToTokensDiagnostics for Schema<'_> {
             self.generics,
             None::<Vec<(TypeTree, &TypeTree)>>,
         )?;
+        #[cfg(feature = "auto_title")]
+        let variant = variant.with_auto_title(&ident.to_string());
+
+        let mut bounded_generics = self.generics.clone();
+        if let Some(bound) = variant.get_schema_bound() {
+            bounded_generics
+                .make_where_clause()
+                .predicates
+                .extend(bound.0.iter().cloned());
+        }
+        let (_, ty_generics, where_clause) = bounded_generics.split_for_impl();
 
-        let (_, ty_generics, where_clause) = self.generics.split_for_impl();
-
-        let life = &Lifetime::new(Schema::TO_SCHEMA_LIFETIME, Span::call_site());
+        let life = &self.to_schema_lifetime();
 
-        let schema_ty: Type = parse_quote!(#ident #ty_generics);
+        // const generic arguments are not part of the type tree, thus only type params are used
+        // to resolve the aliased types
+        let type_params = self.generics.type_params().map(|param| &param.ident);
+        let schema_ty: Type = parse_quote!(#ident < #(#type_params),* >);
         let schema_children = &*TypeTree::from_type(&schema_ty)?
             .children
             .unwrap_or_default();
+        // const generic params are matched by name to resolve the length of fixed size arrays
+        let const_param_types = self
+            .generics
+            .const_params()
+            .map(|param| {
+                let ident = &param.ident;
+                parse_quote!(#ident)
+            })
+            .collect::<Vec<Type>>();
+        let const_params = &*const_param_types
+            .iter()
+            .map(TypeTree::from_type)
+            .collect::<Result<Vec<_>, Diagnostics>>()?;
 
         let aliases = self.aliases.as_ref().map_try(|aliases| {
             let alias_schemas = aliases
                 .iter()
                 .map(|alias| {
                     let name = &*alias.name;
-                    let alias_type_tree = TypeTree::from_type(&alias.ty);
+                    let alias_type_tree = TypeTree::from_type(&alias.ty)?;
+                    let const_args = alias.get_const_args().zip(const_params).filter_map(
+                        |(const_arg, const_param)| {
+                            const_arg.map(|const_arg| (const_arg, const_param))
+                        },
+                    );
 
-                    SchemaVariant::new(
+                    let variant = SchemaVariant::new(
                         self.data,
                         self.attributes,
                         ident,
                         self.generics,
-                        alias_type_tree?
-                            .children
-                            .map(|children| children.into_iter().zip(schema_children)),
-                    )
-                    .and_then(|variant| {
-                        let mut alias_tokens = TokenStream::new();
-                        match variant.to_tokens(&mut alias_tokens) {
-                            Ok(_) => Ok(quote! { (#name, #alias_tokens.into()) }),
-                            Err(diagnostics) => Err(diagnostics),
-                        }
-                    })
+                        Some(
+                            alias_type_tree
+                                .children
+                                .into_iter()
+                                .flatten()
+                                .zip(schema_children)
+                                .chain(const_args),
+                        ),
+                    )?
+                    .with_alias_features(ident, &alias.features)?;
+                    #[cfg(feature = "auto_title")]
+                    let variant = variant.with_auto_title(name);
+
+                    let mut alias_tokens = TokenStream::new();
+                    variant.to_tokens(&mut alias_tokens)?;
+                    Ok(quote! { (#name, #alias_tokens.into()) })
                 })
                 .collect::<Result<Array<TokenStream>, Diagnostics>>()?;
 
@@ -143,17 +216,29 @@ impl ToTokensDiagnostics for Schema<'_> {
                         },
                     );
 
+                    let allow_non_camel_case = alias
+                        .is_derived_name
+                        .then(|| quote! { #[allow(non_camel_case_types)] });
+
                     Ok(quote! {
+                        #allow_non_camel_case
                         #vis type #name < #name_generics > = #ty;
                     })
                 })
                 .collect::<Result<TokenStream, Diagnostics>>()
         })?;
 
-        let name = if let Some(schema_as) = variant.get_schema_as() {
-            format_path_ref(&schema_as.0.path)
-        } else {
-            ident.to_string()
+        let name = match (variant.get_schema_rename(), variant.get_schema_as()) {
+            (Some(_), Some(_)) => {
+                return Err(Diagnostics::with_span(
+                    ident.span(),
+                    "`rename` and `as` cannot be used together",
+                )
+                .help("Use either `rename` or `as` to define the schema name"))
+            }
+            (Some(schema_rename), None) => schema_rename.to_string(),
+            (None, Some(schema_as)) => format_path_ref(&schema_as.0.path),
+            (None, None) => ident.to_string(),
         };
 
         let schema_lifetime: GenericParam = LifetimeParam::new(life.clone()).into();
@@ -167,7 +252,22 @@ impl ToTokensDiagnostics for Schema<'_> {
         let (impl_generics, _, _) = impl_generics.split_for_impl();
 
         let mut variant_tokens = TokenStream::new();
-        variant.to_tokens(&mut variant_tokens)?;
+        let ((result, schema_references), warnings) = collect_warnings(|| {
+            collect_schema_references(|| variant.to_tokens(&mut variant_tokens))
+        });
+        result?;
+
+        // generic arguments are not bound to implement `ToSchema`, thus types referring them
+        // are not collected. Referenced types not implementing `ToSchema` are skipped.
+        let schema_references = schema_references
+            .iter()
+            .filter(|path| !self.is_generic_reference(path))
+            .map(|path| {
+                quote! {
+                    (&utoipa::__dev::SchemaReference::<#path>::new()).collect_into(schemas);
+                }
+            })
+            .collect::<TokenStream>();
 
         tokens.extend(quote! {
             impl #impl_generics utoipa::ToSchema #schema_generics for #ident #ty_generics #where_clause {
@@ -175,10 +275,28 @@ impl ToTokensDiagnostics for Schema<'_> {
                     (#name, #variant_tokens.into())
                 }
 
+                fn name() -> & #life str {
+                    #name
+                }
+
+                fn collect_schemas(schemas: &mut Vec<(& #life str, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>) {
+                    let (name, schema) = <Self as utoipa::ToSchema<#life>>::schema();
+                    if schemas.iter().any(|(existing, _)| *existing == name) {
+                        return;
+                    }
+                    schemas.push((name, schema));
+
+                    #[allow(unused_imports)]
+                    use utoipa::__dev::{CollectSchemas as _, SkipSchemas as _};
+                    #schema_references
+                }
+
                 #aliases
             }
 
             #type_aliases
+
+            #warnings
         });
         Ok(())
     }
@@ -209,12 +327,20 @@ impl<'a> SchemaVariant<'a> {
                         .into_inner();
 
                     let schema_as = pop_feature_as_inner!(unnamed_features => Feature::As(_v));
+                    let schema_rename =
+                        pop_feature_as_inner!(unnamed_features => Feature::Rename(_v))
+                            .map(Rename::into_value);
+                    let schema_bound =
+                        pop_feature_as_inner!(unnamed_features => Feature::Bound(_v));
                     Ok(Self::Unnamed(UnnamedStructSchema {
                         struct_name: Cow::Owned(ident.to_string()),
                         attributes,
                         features: unnamed_features,
                         fields: unnamed,
+                        aliases: aliases.map(|aliases| aliases.into_iter().collect()),
                         schema_as,
+                        schema_rename,
+                        schema_bound,
                     }))
                 }
                 Fields::Named(fields) => {
@@ -223,15 +349,27 @@ impl<'a> SchemaVariant<'a> {
                         .parse_features::<NamedFieldStructFeatures>()?
                         .into_inner();
                     let schema_as = pop_feature_as_inner!(named_features => Feature::As(_v));
+                    let schema_rename =
+                        pop_feature_as_inner!(named_features => Feature::Rename(_v))
+                            .map(Rename::into_value);
+                    let schema_bound = pop_feature_as_inner!(named_features => Feature::Bound(_v));
+                    if let Some(diagnostics) = named_features.as_deref().and_then(|features| {
+                        super::features::validate_read_write_only(features, ident.span())
+                    }) {
+                        return Err(diagnostics);
+                    }
 
                     Ok(Self::Named(NamedStructSchema {
                         struct_name: Cow::Owned(ident.to_string()),
                         attributes,
                         rename_all: named_features.pop_rename_all_feature(),
+                        rename_context: named_features.pop_rename_context_feature(),
                         features: named_features,
                         fields: named,
                         generics: Some(generics),
                         schema_as,
+                        schema_rename,
+                        schema_bound,
                         aliases: aliases.map(|aliases| aliases.into_iter().collect()),
                     }))
                 }
@@ -241,6 +379,7 @@ impl<'a> SchemaVariant<'a> {
                 Cow::Owned(ident.to_string()),
                 &content.variants,
                 attributes,
+                aliases.map(|aliases| aliases.into_iter().collect()),
             )?)),
             _ => Err(Diagnostics::with_span(
                 ident.span(),
@@ -249,6 +388,77 @@ impl<'a> SchemaVariant<'a> {
         }
     }
 
+    /// Apply features defined for a single alias e.g. _`description`_ to the schema of the alias.
+    /// Features of the alias take precedence over the features of the schema.
+    fn with_alias_features(
+        mut self,
+        ident: &Ident,
+        alias_features: &[Feature],
+    ) -> Result<Self, Diagnostics> {
+        if alias_features.is_empty() {
+            return Ok(self);
+        }
+
+        match &mut self {
+            Self::Named(schema) => {
+                let features = schema.features.get_or_insert_with(Vec::new);
+                for alias_feature in alias_features {
+                    features.retain(|feature| {
+                        std::mem::discriminant(feature) != std::mem::discriminant(alias_feature)
+                    });
+                    features.push(alias_feature.clone());
+                }
+
+                Ok(self)
+            }
+            _ => Err(Diagnostics::with_span(
+                ident.span(),
+                "Alias features are only supported with named field structs",
+            )
+            .help("Remove the `as ...` clause from the aliases")),
+        }
+    }
+
+    /// Use the given `name` of the type as the _`title`_ of named field struct and enum schemas
+    /// unless the schema defines the title explicitly.
+    #[cfg(feature = "auto_title")]
+    fn with_auto_title(mut self, name: &str) -> Self {
+        let features = match &mut self {
+            // transparent struct is serialized as its only field thus it does not have a title
+            Self::Named(schema)
+                if serde::parse_container(schema.attributes)
+                    .is_ok_and(|container_rules| container_rules.transparent) =>
+            {
+                return self
+            }
+            Self::Named(schema) => schema.features.get_or_insert_with(Vec::new),
+            Self::Enum(schema) => match &mut schema.schema_type {
+                EnumSchemaType::Simple(simple) => &mut simple.enum_features,
+                #[cfg(feature = "repr")]
+                EnumSchemaType::Repr(repr) => &mut repr.enum_features,
+                // `anyOf` composite schema does not have a title
+                EnumSchemaType::Complex(complex)
+                    if complex
+                        .untagged_as
+                        .as_ref()
+                        .is_some_and(UntaggedAs::is_any_of) =>
+                {
+                    return self
+                }
+                EnumSchemaType::Complex(complex) => &mut complex.enum_features,
+            },
+            Self::Unnamed(_) | Self::Unit(_) => return self,
+        };
+        if !features
+            .iter()
+            .any(|feature| matches!(feature, Feature::Title(_)))
+        {
+            features.push(super::features::Title::from(name.to_string()).into());
+        }
+
+        self
+    }
+
     fn get_schema_as(&self) -> &Option<As> {
         match self {
             Self::Enum(schema) => &schema.schema_as,
@@ -257,6 +467,24 @@ impl<'a> SchemaVariant<'a> {
             _ => &None,
         }
     }
+
+    fn get_schema_bound(&self) -> Option<&Bound> {
+        match self {
+            Self::Enum(schema) => schema.schema_bound.as_ref(),
+            Self::Named(schema) => schema.schema_bound.as_ref(),
+            Self::Unnamed(schema) => schema.schema_bound.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn get_schema_rename(&self) -> Option<&str> {
+        match self {
+            Self::Enum(schema) => schema.schema_rename.as_deref(),
+            Self::Named(schema) => schema.schema_rename.as_deref(),
+            Self::Unnamed(schema) => schema.schema_rename.as_deref(),
+            _ => None,
+        }
+    }
 }
 
 impl ToTokensDiagnostics for SchemaVariant<'_> {
@@ -291,9 +519,12 @@ pub struct NamedStructSchema<'a> {
     pub attributes: &'a [Attribute],
     pub features: Option<Vec<Feature>>,
     pub rename_all: Option<RenameAll>,
+    pub rename_context: Option<RenameContext>,
     pub generics: Option<&'a Generics>,
     pub aliases: Option<Vec<(TypeTree<'a>, &'a TypeTree<'a>)>>,
     pub schema_as: Option<As>,
+    pub schema_rename: Option<String>,
+    pub schema_bound: Option<Bound>,
 }
 
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -304,27 +535,103 @@ struct NamedStructFieldOptions<'a> {
     is_option: bool,
 }
 
-impl NamedStructSchema<'_> {
+/// Replace generic arguments of the `type_tree` with the concrete types of the `aliases`. Const
+/// generic arguments resolve the length of fixed size arrays instead.
+fn substitute_aliases<'a: 't, 't>(
+    aliases: &Option<Vec<(TypeTree<'a>, &'a TypeTree<'a>)>>,
+    type_tree: &mut TypeTree<'t>,
+) {
+    if let Some(aliases) = aliases {
+        for (new_generic, old_generic_matcher) in aliases.iter() {
+            if let Some(len) = new_generic.const_arg() {
+                if let Some(param) = old_generic_matcher
+                    .path
+                    .as_ref()
+                    .and_then(|path| path.get_ident())
+                {
+                    type_tree.resolve_array_len(param, len);
+                }
+                continue;
+            }
+
+            for generic_match in type_tree.find_all_mut(old_generic_matcher) {
+                *generic_match = new_generic.clone();
+            }
+        }
+    }
+}
+
+impl<'a> NamedStructSchema<'a> {
+    /// Replace generic arguments of the `type_tree` with the concrete types of the aliases.
+    fn substitute_aliases<'t>(&self, type_tree: &mut TypeTree<'t>)
+    where
+        'a: 't,
+    {
+        substitute_aliases(&self.aliases, type_tree)
+    }
+
     fn get_named_struct_field_options(
         &self,
         field: &Field,
         field_rules: &SerdeValue,
         container_rules: &SerdeContainer,
-    ) -> Result<NamedStructFieldOptions<'_>, Diagnostics> {
-        let type_tree = &mut TypeTree::from_type(&field.ty)?;
-        if let Some(aliases) = &self.aliases {
-            for (new_generic, old_generic_matcher) in aliases.iter() {
-                if let Some(generic_match) = type_tree.find_mut(old_generic_matcher) {
-                    *generic_match = new_generic.clone();
-                }
-            }
-        }
-
+    ) -> Result<Option<NamedStructFieldOptions<'_>>, Diagnostics> {
         let mut field_features = field
             .attrs
             .parse_features::<NamedFieldFeatures>()?
             .into_inner();
 
+        let ignore = pop_feature_as_inner!(field_features => Feature::Ignore(_v));
+        if ignore
+            .as_ref()
+            .is_some_and(super::features::Ignore::is_true)
+        {
+            return Ok(None);
+        }
+
+        let type_tree = &mut TypeTree::from_type(&field.ty)?;
+        self.substitute_aliases(type_tree);
+
+        // fields skipped by serde in one direction are only present in the other direction
+        let serde_access: Option<Feature> =
+            match (field_rules.skip_serializing, field_rules.skip_deserializing) {
+                (true, false) => Some(super::features::WriteOnly::new().into()),
+                (false, true) => Some(super::features::ReadOnly::new().into()),
+                _ => None,
+            };
+        if let Some(access) = serde_access {
+            let features_inner = field_features.get_or_insert(vec![]);
+            if !features_inner
+                .iter()
+                .any(|f| matches!(f, Feature::ReadOnly(_) | Feature::WriteOnly(_)))
+            {
+                features_inner.push(access);
+            }
+        }
+
+        // container level `read_only` or `write_only` is applied to every field which does not
+        // define either of them
+        if let Some(access) = self
+            .features
+            .iter()
+            .flatten()
+            .find(|feature| matches!(feature, Feature::ReadOnly(_) | Feature::WriteOnly(_)))
+        {
+            let features_inner = field_features.get_or_insert(vec![]);
+            if !features_inner
+                .iter()
+                .any(|f| matches!(f, Feature::ReadOnly(_) | Feature::WriteOnly(_)))
+            {
+                features_inner.push(access.clone());
+            }
+        }
+
+        if let Some(diagnostics) = field_features.as_deref().and_then(|features| {
+            super::features::validate_read_write_only(features, field.ident.span())
+        }) {
+            return Err(diagnostics);
+        }
+
         let schema_default = self
             .features
             .as_ref()
@@ -364,16 +671,55 @@ impl NamedStructSchema<'_> {
         let value_type = field_features
             .as_mut()
             .and_then(|features| features.pop_value_type_feature());
-        let override_type_tree = value_type
+        let mut override_type_tree = value_type
             .as_ref()
             .map_try(|value_type| value_type.as_type_tree())?;
-        let comments = CommentAttributes::from_attributes(&field.attrs);
+        if let Some(override_type_tree) = override_type_tree.as_mut() {
+            self.substitute_aliases(override_type_tree);
+        }
+        if let Some(decimal_as) = pop_feature_as_inner!(field_features => Feature::DecimalAs(_v)) {
+            decimal_as.substitute(override_type_tree.as_mut().unwrap_or(&mut *type_tree))?;
+        }
+        let mut comments = CommentAttributes::from_attributes(&field.attrs);
+        // transparent struct is serialized as its only field thus the description of the struct
+        // describes the field the same way as with unnamed field structs
+        if container_rules.transparent {
+            let struct_comments = CommentAttributes::from_attributes(self.attributes);
+            if !struct_comments.is_empty() {
+                comments = struct_comments;
+            }
+        }
+        if !field_rules.aliases.is_empty() {
+            if !comments.is_empty() {
+                comments.0.push(String::new());
+            }
+            comments.0.push(format!(
+                "Accepts aliases: {}",
+                field_rules.aliases.join(", ")
+            ));
+        }
         let schema_with = pop_feature!(field_features => Feature::SchemaWith(_));
         let required = pop_feature_as_inner!(field_features => Feature::Required(_v));
         let type_tree = override_type_tree.as_ref().unwrap_or(type_tree);
         let is_option = type_tree.is_option();
 
-        Ok(NamedStructFieldOptions {
+        #[cfg(feature = "validator")]
+        {
+            use super::validator::{Length, Range, Regex};
+
+            if let Some(regex) = Regex::from_attributes(&field.attrs)? {
+                regex.validate(field_features.as_deref().unwrap_or_default())?;
+            }
+
+            if let Some(length) = Length::from_attributes(&field.attrs)? {
+                length.apply_to(type_tree, field_features.get_or_insert(vec![]));
+            }
+            if let Some(range) = Range::from_attributes(&field.attrs)? {
+                range.apply_to(type_tree, field_features.get_or_insert(vec![]));
+            }
+        }
+
+        Ok(Some(NamedStructFieldOptions {
             property: if let Some(schema_with) = schema_with {
                 Property::SchemaWith(schema_with)
             } else {
@@ -385,6 +731,7 @@ impl NamedStructSchema<'_> {
                     object_name: self.struct_name.as_ref(),
                 };
                 if is_flatten(field_rules) && type_tree.is_map() {
+                    self.validate_flattened_map_key(type_tree, field)?;
                     Property::FlattenedMap(FlattenedMapSchema::new(cs)?)
                 } else {
                     Property::Schema(ComponentSchema::new(cs)?)
@@ -393,7 +740,119 @@ impl NamedStructSchema<'_> {
             rename_field_value: rename_field,
             required,
             is_option,
-        })
+        }))
+    }
+
+    /// Flattened map keys become the property names of the struct. Keys of user defined types
+    /// e.g. enums constrain the property names which cannot be described yet, thus report an error
+    /// instead of generating an object allowing any property names.
+    fn validate_flattened_map_key(
+        &self,
+        type_tree: &TypeTree,
+        field: &Field,
+    ) -> Result<(), Diagnostics> {
+        let key = type_tree
+            .children
+            .as_ref()
+            .and_then(|children| children.first());
+        let Some(TypeTree {
+            path: Some(path),
+            value_type: ValueType::Object,
+            ..
+        }) = key
+        else {
+            return Ok(());
+        };
+
+        let is_generic_param = path.get_ident().is_some_and(|ident| {
+            self.generics
+                .is_some_and(|generics| generics.type_params().any(|param| &param.ident == ident))
+        });
+        if is_generic_param {
+            return Ok(());
+        }
+
+        Err(Diagnostics::with_span(
+            field.span(),
+            format!(
+                "flattened map with `{}` keys is not supported",
+                path.to_token_stream().to_string().replace(' ', "")
+            ),
+        )
+        .note("Keys of user defined types e.g. enums constrain the property names of the struct which cannot be described with `propertyNames` yet")
+        .help("Use `value_type` to override the map with `String` keys or `schema_with` to describe the field manually"))
+    }
+
+    /// Get `additionalProperties` of the struct from its flattened map fields. Multiple flattened
+    /// maps are merged to single `additionalProperties` with `oneOf` of the map value schemas.
+    fn flattened_maps_to_tokens(
+        &self,
+        flattened_maps: &[(&FlattenedMapSchema, &Field)],
+    ) -> Result<TokenStream, Diagnostics> {
+        match flattened_maps {
+            [] => Ok(TokenStream::new()),
+            [(flattened_map, _)] => {
+                let map_schema = as_tokens_or_diagnostics!(*flattened_map);
+                let property_names = flattened_map.property_names();
+                let properties_bounds = flattened_map.properties_bounds();
+
+                Ok(quote! {
+                    .additional_properties(Some(#map_schema))
+                    #property_names
+                    #properties_bounds
+                })
+            }
+            flattened_maps => {
+                let map_schemas = flattened_maps
+                    .iter()
+                    .map(|(flattened_map, _)| Ok(as_tokens_or_diagnostics!(*flattened_map)))
+                    .collect::<Result<Vec<_>, Diagnostics>>()?;
+
+                for (index, map_schema) in map_schemas.iter().enumerate() {
+                    let duplicate = map_schemas[..index]
+                        .iter()
+                        .position(|other| other.to_string() == map_schema.to_string());
+                    if let Some(duplicate) = duplicate {
+                        return Err(Diagnostics::with_span(
+                            self.fields.span(),
+                            format!("The structure `{}` contains multiple flattened map fields with same value type.", self.struct_name))
+                            .note(format!("first flattened map field was declared here as `{}`",
+                                flattened_maps[duplicate].1.ident.as_ref().unwrap()))
+                            .note(format!("second flattened map field was declared here as `{}`",
+                                flattened_maps[index].1.ident.as_ref().unwrap()))
+                            .help("Try combining the flattened map fields to a single map field")
+                        );
+                    }
+                }
+
+                if let Some((_, field)) = flattened_maps
+                    .iter()
+                    .find(|(flattened_map, _)| !flattened_map.properties_bounds().is_empty())
+                {
+                    return Err(Diagnostics::with_span(
+                        field.span(),
+                        "`max_properties` and `min_properties` are not supported with multiple flattened map fields",
+                    ));
+                }
+
+                // property names can be described only when every map shares the same key pattern
+                let property_names = flattened_maps
+                    .iter()
+                    .map(|(flattened_map, _)| {
+                        flattened_map.property_names().map(ToString::to_string)
+                    })
+                    .reduce(|first, next| if first == next { first } else { None })
+                    .flatten()
+                    .and(flattened_maps[0].0.property_names());
+
+                Ok(quote! {
+                    .additional_properties(Some(utoipa::openapi::schema::OneOfBuilder::new()
+                        #( .item(#map_schemas) )*
+                    ))
+                    #property_names
+                })
+            }
+        }
     }
 }
 
@@ -420,73 +879,148 @@ impl ToTokensDiagnostics for NamedStructSchema<'_> {
                     self.get_named_struct_field_options(field, &field_rules, &container_rules);
 
                 match field_options {
-                    Ok(field_options) => Ok((field_options, field_rules, field_name, field)),
+                    Ok(field_options) => Ok(field_options
+                        .map(|field_options| (field_options, field_rules, field_name, field))),
                     Err(options_diagnostics) => Err(options_diagnostics),
                 }
             })
-            .collect::<Result<Vec<_>, Diagnostics>>()?;
+            .collect::<Result<Vec<_>, Diagnostics>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
 
-        let mut object_tokens = fields
+        if container_rules.transparent {
+            // transparent struct is serialized as its only non skipped field
+            let mut transparent_fields = fields
+                .iter()
+                .filter(|(_, field_rules, ..)| is_not_skipped(field_rules));
+
+            return match (transparent_fields.next(), transparent_fields.next()) {
+                (Some((NamedStructFieldOptions { property, .. }, field_rules, _, field)), None) => {
+                    if is_flatten(field_rules) {
+                        return Err(Diagnostics::with_span(
+                            field.span(),
+                            "serde `flatten` is not supported with serde `transparent`",
+                        ));
+                    }
+                    if let Some(feature) = self.features.iter().flatten().next() {
+                        return Err(Diagnostics::with_span(
+                            field.span(),
+                            format!("`{feature}` is not supported on serde `transparent` struct"),
+                        )
+                        .help("Define the schema attributes on the transparent field instead"));
+                    }
+
+                    tokens.extend(as_tokens_or_diagnostics!(property));
+                    Ok(())
+                }
+                _ => Err(Diagnostics::with_span(
+                    self.fields.span(),
+                    "serde `transparent` struct must have exactly one field that is not skipped",
+                )),
+            };
+        }
+
+        let mut struct_features = self.features.clone();
+        let additional_properties =
+            pop_feature_as_inner!(struct_features => Feature::AdditionalProperties(_v));
+        // container level `read_only` and `write_only` are applied to the fields instead
+        if let Some(struct_features) = struct_features.as_mut() {
+            struct_features
+                .retain(|feature| !matches!(feature, Feature::ReadOnly(_) | Feature::WriteOnly(_)));
+
+            // container level `example` without value is serialized from the `Default` implementation
+            if pop_feature!(struct_features => Feature::Example(crate::features::Example(None)))
+                .is_some()
+            {
+                struct_features.push(Feature::Example(
+                    crate::features::Example::new_default_trait(),
+                ));
+            }
+        }
+
+        let properties = fields
             .iter()
-            .filter(|(_, field_rules, ..)| is_not_skipped(field_rules) && !is_flatten(field_rules))
+            .filter(|(_, field_rules, ..)| {
+                is_field_not_skipped(field_rules) && !is_flatten(field_rules)
+            })
             .map(|(property, field_rules, field_name, field)| {
+                let rename_to = field_rules
+                    .rename_for(self.rename_context.as_ref())
+                    .map(Cow::Borrowed)
+                    .or(property.rename_field_value.as_ref().cloned());
+                let rename_all = container_rules
+                    .rename_all_for(self.rename_context.as_ref())
+                    .or(self
+                        .rename_all
+                        .as_ref()
+                        .map(|rename_all| rename_all.as_rename_rule()));
+
+                let name = super::rename::<FieldRename>(field_name.borrow(), rename_to, rename_all)
+                    .unwrap_or(Cow::Borrowed(field_name.borrow()));
+
                 Ok((
                     property,
                     field_rules,
-                    field_name,
+                    name,
                     field,
                     as_tokens_or_diagnostics!(&property.property),
                 ))
             })
-            .collect::<Result<Vec<_>, Diagnostics>>()?
-            .into_iter()
-            .fold(
-                quote! { utoipa::openapi::ObjectBuilder::new() },
-                |mut object_tokens,
-                 (
-                    NamedStructFieldOptions {
-                        rename_field_value,
-                        required,
-                        is_option,
-                        ..
-                    },
-                    field_rules,
-                    field_name,
-                    _field,
-                    field_schema,
-                )| {
-                    let rename_to = field_rules
-                        .rename
-                        .as_deref()
-                        .map(Cow::Borrowed)
-                        .or(rename_field_value.as_ref().cloned());
-                    let rename_all = container_rules.rename_all.as_ref().or(self
-                        .rename_all
-                        .as_ref()
-                        .map(|rename_all| rename_all.as_rename_rule()));
+            .collect::<Result<Vec<_>, Diagnostics>>()?;
 
-                    let name =
-                        super::rename::<FieldRename>(field_name.borrow(), rename_to, rename_all)
-                            .unwrap_or(Cow::Borrowed(field_name.borrow()));
+        // properties with the same serialized name would silently replace each other
+        let mut property_fields = HashMap::with_capacity(properties.len());
+        for (_, _, name, field, _) in &properties {
+            if let Some(previous) = property_fields.insert(name.as_ref(), *field) {
+                return Err(Diagnostics::with_span(
+                    field.span(),
+                    format!(
+                        "field `{}` has the same serialized name `{name}` as field `{}`",
+                        field.ident.as_ref().unwrap(),
+                        previous.ident.as_ref().unwrap(),
+                    ),
+                )
+                .help("Use `rename` to give the fields distinct names"));
+            }
+        }
 
-                    object_tokens.extend(quote! {
-                        .property(#name, #field_schema)
+        let mut object_tokens = properties.into_iter().fold(
+            quote! { utoipa::openapi::ObjectBuilder::new() },
+            |mut object_tokens,
+             (
+                NamedStructFieldOptions {
+                    required,
+                    is_option,
+                    ..
+                },
+                field_rules,
+                name,
+                _field,
+                field_schema,
+            )| {
+                object_tokens.extend(quote! {
+                    .property(#name, #field_schema)
+                });
+
+                // explicit `required` feature overrides the requirement resolved from the type
+                // and serde attributes in both directions
+                let is_required = required
+                    .as_ref()
+                    .map(super::features::Required::is_true)
+                    .unwrap_or_else(|| {
+                        !is_option && super::is_required(field_rules, &container_rules)
                     });
 
-                    if (!is_option && super::is_required(field_rules, &container_rules))
-                        || required
-                            .as_ref()
-                            .map(super::features::Required::is_true)
-                            .unwrap_or(false)
-                    {
-                        object_tokens.extend(quote! {
-                            .required(#name)
-                        })
-                    }
+                if is_required {
+                    object_tokens.extend(quote! {
+                        .required(#name)
+                    })
+                }
 
-                    object_tokens
-                },
-            );
+                object_tokens
+            },
+        );
 
         let flatten_fields = fields
             .iter()
@@ -495,39 +1029,35 @@ impl ToTokensDiagnostics for NamedStructSchema<'_> {
 
         let all_of = if !flatten_fields.is_empty() {
             let mut flattened_tokens = TokenStream::new();
-            let mut flattened_map_field = None;
+            let mut flattened_maps = Vec::new();
 
             for (options, _, _, field) in flatten_fields {
                 let NamedStructFieldOptions { property, .. } = options;
-                let property_schema = as_tokens_or_diagnostics!(property);
 
                 match property {
                     Property::Schema(_) | Property::SchemaWith(_) => {
+                        let property_schema = as_tokens_or_diagnostics!(property);
                         flattened_tokens.extend(quote! { .item(#property_schema) })
                     }
-                    Property::FlattenedMap(_) => {
-                        match flattened_map_field {
-                            None => {
-                                object_tokens.extend(
-                                    quote! { .additional_properties(Some(#property_schema)) },
-                                );
-                                flattened_map_field = Some(field);
-                            }
-                            Some(flattened_map_field) => {
-                                return Err(Diagnostics::with_span(
-                                    self.fields.span(),
-                                    format!("The structure `{}` contains multiple flattened map fields.", self.struct_name))
-                                    .note(
-                                        format!("first flattened map field was declared here as `{}`",
-                                        flattened_map_field.ident.as_ref().unwrap()))
-                                    .note(format!("second flattened map field was declared here as `{}`", field.ident.as_ref().unwrap()))
-                                );
-                            }
-                        }
+                    Property::FlattenedMap(flattened_map) => {
+                        flattened_maps.push((flattened_map, *field))
                     }
                 }
             }
 
+            if !flattened_maps.is_empty() {
+                if let Some(additional_properties) = &additional_properties {
+                    return Err(Diagnostics::with_span(
+                        additional_properties.span(),
+                        "additional_properties cannot be used with flattened map fields",
+                    )
+                    .help(
+                        "The flattened maps already define the additional properties of the schema",
+                    ));
+                }
+            }
+            object_tokens.extend(self.flattened_maps_to_tokens(&flattened_maps)?);
+
             if flattened_tokens.is_empty() {
                 tokens.extend(object_tokens);
                 false
@@ -544,22 +1074,55 @@ impl ToTokensDiagnostics for NamedStructSchema<'_> {
             false
         };
 
-        if !all_of && container_rules.deny_unknown_fields {
-            tokens.extend(quote! {
-                .additional_properties(Some(utoipa::openapi::schema::AdditionalProperties::FreeForm(false)))
-            });
+        // explicit `additional_properties` takes precedence over serde `deny_unknown_fields`
+        let additional_properties_tokens = match &additional_properties {
+            Some(additional_properties) => Some(as_tokens_or_diagnostics!(additional_properties)),
+            None if container_rules.deny_unknown_fields => Some(quote! {
+                utoipa::openapi::schema::AdditionalProperties::FreeForm(false)
+            }),
+            None => None,
+        };
+        if let Some(additional_properties_tokens) = additional_properties_tokens {
+            if !all_of {
+                tokens.extend(quote! {
+                    .additional_properties(Some(#additional_properties_tokens))
+                });
+            } else if cfg!(feature = "openapi_31") {
+                // additionalProperties cannot see properties of the allOf items but
+                // unevaluatedProperties of OpenAPI 3.1 can
+                tokens.extend(quote! {
+                    .unevaluated_properties(Some(#additional_properties_tokens))
+                });
+            } else if let Some(additional_properties) = additional_properties
+                .as_ref()
+                .filter(|additional_properties| !additional_properties.is_free_form())
+            {
+                return Err(Diagnostics::with_span(
+                    additional_properties.span(),
+                    "only `additional_properties = true` is supported with flattened fields",
+                )
+                .help("Enable `openapi_31` feature to forbid additional properties with `unevaluatedProperties`"));
+            }
         }
 
         if let Some(deprecated) = super::get_deprecated(self.attributes) {
             tokens.extend(quote! { .deprecated(Some(#deprecated)) });
         }
 
-        if let Some(struct_features) = self.features.as_ref() {
+        if let Some(struct_features) = struct_features.as_ref() {
             tokens.extend(struct_features.to_token_stream()?)
         }
 
-        let description = CommentAttributes::from_attributes(self.attributes).as_formatted_string();
-        if !description.is_empty() {
+        let has_description_feature = self
+            .features
+            .iter()
+            .flatten()
+            .any(|feature| matches!(feature, Feature::Description(_)));
+        let description = super::append_deprecated_note(
+            CommentAttributes::from_attributes(self.attributes).as_formatted_string(),
+            self.attributes,
+        );
+        if !has_description_feature && !description.is_empty() {
             tokens.extend(quote! {
                 .description(Some(#description))
             })
@@ -575,34 +1138,61 @@ struct UnnamedStructSchema<'a> {
     fields: &'a Punctuated<Field, Comma>,
     attributes: &'a [Attribute],
     features: Option<Vec<Feature>>,
+    aliases: Option<Vec<(TypeTree<'a>, &'a TypeTree<'a>)>>,
     schema_as: Option<As>,
+    schema_rename: Option<String>,
+    schema_bound: Option<Bound>,
 }
 
 impl ToTokensDiagnostics for UnnamedStructSchema<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
         let fields_len = self.fields.len();
         let first_field = self.fields.first().unwrap();
-        let first_part = &TypeTree::from_type(&first_field.ty)?;
+        let field_type_trees = self
+            .fields
+            .iter()
+            .map(|field| {
+                let mut type_tree = TypeTree::from_type(&field.ty)?;
+                substitute_aliases(&self.aliases, &mut type_tree);
+                Ok(type_tree)
+            })
+            .collect::<Result<Vec<TypeTree>, Diagnostics>>()?;
+        let first_part = &field_type_trees[0];
 
         let all_fields_are_same = fields_len == 1
-            || self
-                .fields
+            || field_type_trees
                 .iter()
                 .skip(1)
-                .map(|field| TypeTree::from_type(&field.ty))
-                .collect::<Result<Vec<TypeTree>, Diagnostics>>()?
-                .iter()
                 .all(|schema_part| first_part == schema_part);
 
+        let is_prefix_items = !all_fields_are_same && cfg!(feature = "openapi_31");
+
+        if !all_fields_are_same {
+            if let Some(feature) = self
+                .features
+                .iter()
+                .flatten()
+                .find(|feature| feature.is_validatable())
+            {
+                return Err(Diagnostics::with_span(
+                    first_field.span(),
+                    format!("`{feature}` can only be used with unnamed field structs where all fields have same type"),
+                ));
+            }
+        }
+
         let deprecated = super::get_deprecated(self.attributes);
         if all_fields_are_same {
             let mut unnamed_struct_features = self.features.clone();
             let value_type = unnamed_struct_features
                 .as_mut()
                 .and_then(|features| features.pop_value_type_feature());
-            let override_type_tree = value_type
+            let mut override_type_tree = value_type
                 .as_ref()
                 .map_try(|value_type| value_type.as_type_tree())?;
+            if let Some(override_type_tree) = override_type_tree.as_mut() {
+                substitute_aliases(&self.aliases, override_type_tree);
+            }
 
             if fields_len == 1 {
                 if let Some(ref mut features) = unnamed_struct_features {
@@ -628,6 +1218,49 @@ impl ToTokensDiagnostics for UnnamedStructSchema<'_> {
                 })?
                 .to_token_stream(),
             );
+        } else if is_prefix_items {
+            // With OpenAPI 3.1 each unnamed field is described by its position with `prefixItems`
+            let prefix_items = self
+                .fields
+                .iter()
+                .zip(&field_type_trees)
+                .map(|(field, type_tree)| {
+                    let field_features = field
+                        .attrs
+                        .parse_features::<UnnamedFieldFeatures>()?
+                        .map(IntoInner::into_inner);
+                    let component_schema = ComponentSchema::new(super::ComponentSchemaProps {
+                        type_tree,
+                        features: field_features,
+                        description: None,
+                        deprecated: None,
+                        object_name: self.struct_name.as_ref(),
+                    })?;
+
+                    Ok(as_tokens_or_diagnostics!(&component_schema))
+                })
+                .collect::<Result<Vec<_>, Diagnostics>>()?;
+            let description =
+                CommentAttributes::from_attributes(self.attributes).as_formatted_string();
+            let description =
+                (!description.is_empty()).then(|| quote! { .description(Some(#description)) });
+
+            tokens.extend(quote! {
+                utoipa::openapi::schema::ArrayBuilder::new()
+                    .items(utoipa::openapi::ObjectBuilder::new().schema_type(utoipa::openapi::schema::SchemaType::Value))
+                    .prefix_items([#( utoipa::openapi::RefOr::<utoipa::openapi::schema::Schema>::from(#prefix_items) ),*])
+                    #description
+                    .max_items(Some(#fields_len))
+                    .min_items(Some(#fields_len))
+            });
+
+            if let Some(deprecated) = deprecated {
+                tokens.extend(quote! { .deprecated(Some(#deprecated)) });
+            }
+
+            if let Some(ref attrs) = self.features {
+                tokens.extend(attrs.to_token_stream()?)
+            }
         } else {
             // Struct that has multiple unnamed fields is serialized to array by default with serde.
             // See: https://serde.rs/json.html
@@ -646,11 +1279,13 @@ impl ToTokensDiagnostics for UnnamedStructSchema<'_> {
             }
         }
 
-        if fields_len > 1 {
+        if fields_len > 1 && !is_prefix_items {
             let description =
                 CommentAttributes::from_attributes(self.attributes).as_formatted_string();
+            let description =
+                (!description.is_empty()).then(|| quote! { .description(Some(#description)) });
             tokens.extend(
-                quote! { .to_array_builder().description(Some(#description)).max_items(Some(#fields_len)).min_items(Some(#fields_len)) },
+                quote! { .to_array_builder() #description .max_items(Some(#fields_len)).min_items(Some(#fields_len)) },
             )
         }
 
@@ -662,6 +1297,8 @@ impl ToTokensDiagnostics for UnnamedStructSchema<'_> {
 pub struct EnumSchema<'a> {
     schema_type: EnumSchemaType<'a>,
     schema_as: Option<As>,
+    schema_rename: Option<String>,
+    schema_bound: Option<Bound>,
 }
 
 impl<'e> EnumSchema<'e> {
@@ -669,6 +1306,7 @@ impl<'e> EnumSchema<'e> {
         enum_name: Cow<'e, str>,
         variants: &'e Punctuated<Variant, Comma>,
         attributes: &'e [Attribute],
+        aliases: Option<Vec<(TypeTree<'e>, &'e TypeTree<'e>)>>,
     ) -> Result<Self, Diagnostics> {
         if variants
             .iter()
@@ -692,21 +1330,34 @@ impl<'e> EnumSchema<'e> {
                                     input as super::features::Example,
                                     super::features::Default,
                                     super::features::Title,
-                                    As
+                                    As,
+                                    Rename,
+                                    Bound,
+                                    super::features::ConstVariants
                                 ))
                             })?
                             .unwrap_or_default();
 
                         let schema_as =
                             pop_feature_as_inner!(repr_enum_features => Feature::As(_v));
+                        let schema_rename =
+                            pop_feature_as_inner!(repr_enum_features => Feature::Rename(_v))
+                                .map(Rename::into_value);
+                        let schema_bound =
+                            pop_feature_as_inner!(repr_enum_features => Feature::Bound(_v));
+                        let const_variants =
+                            pop_feature_as_inner!(repr_enum_features => Feature::ConstVariants(_v));
                         Result::<EnumSchema, Diagnostics>::Ok(Self {
                             schema_type: EnumSchemaType::Repr(ReprEnum {
                                 variants,
                                 attributes,
                                 enum_type,
                                 enum_features: repr_enum_features,
+                                const_variants,
                             }),
                             schema_as,
+                            schema_rename,
+                            schema_bound,
                         })
                     })?;
 
@@ -719,7 +1370,16 @@ impl<'e> EnumSchema<'e> {
                             .unwrap_or_default();
                         let schema_as =
                             pop_feature_as_inner!(simple_enum_features => Feature::As(_v));
+                        let schema_rename =
+                            pop_feature_as_inner!(simple_enum_features => Feature::Rename(_v))
+                                .map(Rename::into_value);
+                        let schema_bound =
+                            pop_feature_as_inner!(simple_enum_features => Feature::Bound(_v));
+                        let as_const =
+                            pop_feature_as_inner!(simple_enum_features => Feature::AsConst(_v));
+                        let variants_as_one_of = pop_feature_as_inner!(simple_enum_features => Feature::VariantsAsOneOf(_v));
                         let rename_all = simple_enum_features.pop_rename_all_feature();
+                        let rename_context = simple_enum_features.pop_rename_context_feature();
 
                         Ok(Self {
                             schema_type: EnumSchemaType::Simple(SimpleEnum {
@@ -727,8 +1387,13 @@ impl<'e> EnumSchema<'e> {
                                 variants,
                                 enum_features: simple_enum_features,
                                 rename_all,
+                                rename_context,
+                                as_const,
+                                variants_as_one_of,
                             }),
                             schema_as,
+                            schema_rename,
+                            schema_bound,
                         })
                     }
                 }
@@ -741,7 +1406,16 @@ impl<'e> EnumSchema<'e> {
                     .into_inner()
                     .unwrap_or_default();
                 let schema_as = pop_feature_as_inner!(simple_enum_features => Feature::As(_v));
+                let schema_rename =
+                    pop_feature_as_inner!(simple_enum_features => Feature::Rename(_v))
+                        .map(Rename::into_value);
+                let schema_bound =
+                    pop_feature_as_inner!(simple_enum_features => Feature::Bound(_v));
+                let as_const = pop_feature_as_inner!(simple_enum_features => Feature::AsConst(_v));
+                let variants_as_one_of =
+                    pop_feature_as_inner!(simple_enum_features => Feature::VariantsAsOneOf(_v));
                 let rename_all = simple_enum_features.pop_rename_all_feature();
+                let rename_context = simple_enum_features.pop_rename_context_feature();
 
                 Ok(Self {
                     schema_type: EnumSchemaType::Simple(SimpleEnum {
@@ -749,8 +1423,13 @@ impl<'e> EnumSchema<'e> {
                         variants,
                         enum_features: simple_enum_features,
                         rename_all,
+                        rename_context,
+                        as_const,
+                        variants_as_one_of,
                     }),
                     schema_as,
+                    schema_rename,
+                    schema_bound,
                 })
             }
         } else {
@@ -759,17 +1438,39 @@ impl<'e> EnumSchema<'e> {
                 .into_inner()
                 .unwrap_or_default();
             let schema_as = pop_feature_as_inner!(enum_features => Feature::As(_v));
+            let schema_rename =
+                pop_feature_as_inner!(enum_features => Feature::Rename(_v)).map(Rename::into_value);
+            let schema_bound = pop_feature_as_inner!(enum_features => Feature::Bound(_v));
             let rename_all = enum_features.pop_rename_all_feature();
+            let rename_context = enum_features.pop_rename_context_feature();
+            let rename_all_fields =
+                pop_feature_as_inner!(enum_features => Feature::RenameAllFields(_v));
+            // serde `rename_all_fields` takes precedence over the schema one
+            let rename_all_fields = serde::parse_container(attributes)?
+                .rename_all_fields_for(rename_context.as_ref())
+                .or(rename_all_fields
+                    .as_ref()
+                    .map(|rename_all_fields| rename_all_fields.as_rename_rule()))
+                .cloned();
+            let untagged_as = pop_feature_as_inner!(enum_features => Feature::UntaggedAs(_v));
+            let discriminator = pop_feature_as_inner!(enum_features => Feature::Discriminator(_v));
 
             Ok(Self {
                 schema_type: EnumSchemaType::Complex(ComplexEnum {
-                    enum_name,
+                    enum_name: schema_rename.clone().map(Cow::Owned).unwrap_or(enum_name),
                     attributes,
                     variants,
                     rename_all,
+                    rename_all_fields,
+                    rename_context,
+                    untagged_as,
+                    discriminator,
                     enum_features,
+                    aliases,
                 }),
                 schema_as,
+                schema_rename,
+                schema_bound,
             })
         }
     }
@@ -811,7 +1512,10 @@ impl ToTokensDiagnostics for EnumSchemaType<'_> {
             tokens.extend(quote! { .deprecated(Some(#deprecated)) });
         }
 
-        let description = CommentAttributes::from_attributes(attributes).as_formatted_string();
+        let description = super::append_deprecated_note(
+            CommentAttributes::from_attributes(attributes).as_formatted_string(),
+            attributes,
+        );
         if !description.is_empty() {
             tokens.extend(quote! {
                 .description(Some(#description))
@@ -829,13 +1533,27 @@ struct ReprEnum<'a> {
     attributes: &'a [Attribute],
     enum_type: syn::TypePath,
     enum_features: Vec<Feature>,
+    const_variants: Option<super::features::ConstVariants>,
 }
 
 #[cfg(feature = "repr")]
 impl ToTokensDiagnostics for ReprEnum<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
         let container_rules = serde::parse_container(self.attributes)?;
-        let enum_variants = self
+        let const_variants = self
+            .const_variants
+            .as_ref()
+            .filter(|const_variants| const_variants.is_const_variants());
+        if let Some(const_variants) = const_variants {
+            if !matches!(container_rules.enum_repr, SerdeEnumRepr::ExternallyTagged) {
+                return Err(Diagnostics::with_span(
+                    const_variants.span(),
+                    "`const_variants` is not supported with tagged or untagged enum representations",
+                ));
+            }
+        }
+
+        let variants = self
             .variants
             .iter()
             .map(|variant| match serde::parse_value(&variant.attrs) {
@@ -844,17 +1562,73 @@ impl ToTokensDiagnostics for ReprEnum<'_> {
             })
             .collect::<Result<Vec<_>, Diagnostics>>()?
             .into_iter()
-            .filter_map(|(variant, variant_rules)| {
+            .filter(|(_, variant_rules)| is_not_skipped(variant_rules))
+            .map(|(variant, _)| {
+                let rename = features::parse_schema_features_with(&variant.attrs, |input| {
+                    Ok(parse_features!(input as Rename))
+                })?
+                .and_then(|mut features| features.pop_rename_feature());
+
+                Ok((variant, rename))
+            })
+            .collect::<Result<Vec<_>, Diagnostics>>()?;
+
+        let repr_type = &self.enum_type;
+        if const_variants.is_some() {
+            let schema_type =
+                as_tokens_or_diagnostics!(&crate::schema_type::SchemaType(&repr_type.path));
+            let format = crate::schema_type::SchemaFormat::from(&repr_type.path);
+            let format = format
+                .is_known_format()
+                .then(|| quote! { .format(Some(#format)) });
+            let items = variants.iter().map(|(variant, rename)| {
                 let variant_type = &variant.ident;
+                let title = rename
+                    .as_ref()
+                    .map(|rename| rename.clone().into_value())
+                    .unwrap_or_else(|| variant_type.to_string());
+                let description =
+                    CommentAttributes::from_attributes(&variant.attrs).as_formatted_string();
+                let description =
+                    (!description.is_empty()).then(|| quote! { .description(Some(#description)) });
+                let const_value =
+                    const_value_to_tokens(quote! { Self::#variant_type as #repr_type });
+
+                quote! {
+                    .item(utoipa::openapi::ObjectBuilder::new()
+                        .schema_type(#schema_type)
+                        #format
+                        #const_value
+                        .title(Some(#title))
+                        #description
+                    )
+                }
+            });
 
-                if is_not_skipped(&variant_rules) {
-                    let repr_type = &self.enum_type;
-                    Some(enum_variant::ReprVariant {
-                        value: quote! { Self::#variant_type as #repr_type },
-                        type_path: repr_type,
-                    })
-                } else {
-                    None
+            tokens.extend(quote! {
+                utoipa::openapi::schema::OneOfBuilder::new()
+                    #( #items )*
+            });
+            tokens.extend(self.enum_features.to_token_stream()?);
+
+            return Ok(());
+        }
+
+        if let Some((variant, _)) = variants.iter().find(|(_, rename)| rename.is_some()) {
+            return Err(Diagnostics::with_span(
+                variant.ident.span(),
+                "`rename` of repr enum variant requires `const_variants` on the enum",
+            )
+            .help("Add `#[schema(const_variants)]` to the enum to render variants with titles"));
+        }
+
+        let enum_variants = variants
+            .into_iter()
+            .map(|(variant, _)| {
+                let variant_type = &variant.ident;
+                enum_variant::ReprVariant {
+                    value: quote! { Self::#variant_type as #repr_type },
+                    type_path: repr_type,
                 }
             })
             .collect::<Vec<enum_variant::ReprVariant<TokenStream>>>();
@@ -876,17 +1650,17 @@ fn rename_enum_variant<'a>(
     variant_rules: &'a SerdeValue,
     container_rules: &'a SerdeContainer,
     rename_all: &'a Option<RenameAll>,
+    rename_context: Option<&RenameContext>,
 ) -> Option<Cow<'a, str>> {
     let rename = features
         .pop_rename_feature()
         .map(|rename| rename.into_value());
     let rename_to = variant_rules
-        .rename
-        .as_deref()
+        .rename_for(rename_context)
         .map(Cow::Borrowed)
         .or(rename.map(Cow::Owned));
 
-    let rename_all = container_rules.rename_all.as_ref().or(rename_all
+    let rename_all = container_rules.rename_all_for(rename_context).or(rename_all
         .as_ref()
         .map(|rename_all| rename_all.as_rename_rule()));
 
@@ -899,6 +1673,9 @@ struct SimpleEnum<'a> {
     attributes: &'a [Attribute],
     enum_features: Vec<Feature>,
     rename_all: Option<RenameAll>,
+    rename_context: Option<RenameContext>,
+    as_const: Option<AsConst>,
+    variants_as_one_of: Option<VariantsAsOneOf>,
 }
 
 impl ToTokensDiagnostics for SimpleEnum<'_> {
@@ -943,6 +1720,7 @@ impl ToTokensDiagnostics for SimpleEnum<'_> {
                     &variant_rules,
                     &container_rules,
                     &self.rename_all,
+                    self.rename_context.as_ref(),
                 );
 
                 variant_name
@@ -954,9 +1732,50 @@ impl ToTokensDiagnostics for SimpleEnum<'_> {
                             value: name.to_token_stream(),
                         })
                     })
+                    .map(|simple_enum_variant| (simple_enum_variant, variant))
             })
+            .collect::<Vec<_>>();
+
+        if let Some(variants_as_one_of) = self
+            .variants_as_one_of
+            .as_ref()
+            .filter(|variants_as_one_of| variants_as_one_of.is_true())
+        {
+            if self.as_const.as_ref().is_some_and(AsConst::is_const) {
+                return Err(Diagnostics::with_span(
+                    variants_as_one_of.span(),
+                    "`variants_as_one_of` cannot be used together with `as_const`",
+                ));
+            }
+
+            return one_of_enum_to_tokens(
+                tokens,
+                variants_as_one_of,
+                &container_rules,
+                self.enum_features.to_token_stream()?,
+                simple_enum_variant,
+            );
+        }
+
+        let simple_enum_variant = simple_enum_variant
+            .into_iter()
+            .map(|(simple_enum_variant, _)| simple_enum_variant)
             .collect::<Vec<SimpleEnumVariant<TokenStream>>>();
 
+        if let Some(as_const) = self
+            .as_const
+            .as_ref()
+            .filter(|as_const| as_const.is_const())
+        {
+            return const_enum_to_tokens(
+                tokens,
+                as_const,
+                &container_rules,
+                self.enum_features.to_token_stream()?,
+                simple_enum_variant,
+            );
+        }
+
         regular_enum_to_tokens(
             tokens,
             &container_rules,
@@ -968,6 +1787,92 @@ impl ToTokensDiagnostics for SimpleEnum<'_> {
     }
 }
 
+/// Get tokens of constant `value` of the schema. OpenAPI 3.0 has no `const` keyword thus the
+/// value is rendered as single value `enum` without `openapi_31` feature.
+fn const_value_to_tokens(value: TokenStream) -> TokenStream {
+    if cfg!(feature = "openapi_31") {
+        quote! { .const_value(Some(#value)) }
+    } else {
+        quote! { .enum_values(Some([#value])) }
+    }
+}
+
+/// Render single variant unit enum as `const` value instead of single value `enum`.
+fn const_enum_to_tokens(
+    tokens: &mut TokenStream,
+    as_const: &AsConst,
+    container_rules: &SerdeContainer,
+    enum_variant_features: TokenStream,
+    variants: Vec<SimpleEnumVariant<TokenStream>>,
+) -> Result<(), Diagnostics> {
+    if !matches!(container_rules.enum_repr, SerdeEnumRepr::ExternallyTagged) {
+        return Err(Diagnostics::with_span(
+            as_const.span(),
+            "`as_const` is not supported with tagged or untagged enum representations",
+        ));
+    }
+
+    let [variant] = <[_; 1]>::try_from(variants).map_err(|_| {
+        Diagnostics::with_span(
+            as_const.span(),
+            "`as_const` can only be used with enums that have exactly one variant",
+        )
+    })?;
+    let (schema_type, _) = enum_variant::Variant::get_type(&variant);
+    let const_value = const_value_to_tokens(enum_variant::Variant::to_tokens(&variant));
+
+    tokens.extend(quote! {
+        utoipa::openapi::ObjectBuilder::new()
+            .schema_type(#schema_type)
+            #const_value
+            #enum_variant_features
+    });
+
+    Ok(())
+}
+
+/// Render unit enum as `oneOf` of `const` values where each value carries the description of its
+/// variant instead of single `enum` of values.
+fn one_of_enum_to_tokens(
+    tokens: &mut TokenStream,
+    variants_as_one_of: &VariantsAsOneOf,
+    container_rules: &SerdeContainer,
+    enum_variant_features: TokenStream,
+    variants: Vec<(SimpleEnumVariant<TokenStream>, &Variant)>,
+) -> Result<(), Diagnostics> {
+    if !matches!(container_rules.enum_repr, SerdeEnumRepr::ExternallyTagged) {
+        return Err(Diagnostics::with_span(
+            variants_as_one_of.span(),
+            "`variants_as_one_of` is not supported with tagged or untagged enum representations",
+        ));
+    }
+
+    let items = variants.iter().map(|(simple_enum_variant, variant)| {
+        let (schema_type, _) = enum_variant::Variant::get_type(simple_enum_variant);
+        let const_value =
+            const_value_to_tokens(enum_variant::Variant::to_tokens(simple_enum_variant));
+        let description = CommentAttributes::from_attributes(&variant.attrs).as_formatted_string();
+        let description =
+            (!description.is_empty()).then(|| quote! { .description(Some(#description)) });
+
+        quote! {
+            .item(utoipa::openapi::ObjectBuilder::new()
+                .schema_type(#schema_type)
+                #const_value
+                #description
+            )
+        }
+    });
+
+    tokens.extend(quote! {
+        utoipa::openapi::schema::OneOfBuilder::new()
+            #( #items )*
+            #enum_variant_features
+    });
+
+    Ok(())
+}
+
 fn regular_enum_to_tokens<T: self::enum_variant::Variant>(
     tokens: &mut TokenStream,
     container_rules: &SerdeContainer,
@@ -1009,14 +1914,118 @@ struct ComplexEnum<'a> {
     enum_name: Cow<'a, str>,
     enum_features: Vec<Feature>,
     rename_all: Option<RenameAll>,
+    rename_all_fields: Option<RenameRule>,
+    rename_context: Option<RenameContext>,
+    untagged_as: Option<UntaggedAs>,
+    discriminator: Option<Discriminator>,
+    aliases: Option<Vec<(TypeTree<'a>, &'a TypeTree<'a>)>>,
 }
 
-impl ComplexEnum<'_> {
+impl ComplexEnum<'_> {
+    /// Pop rename rule for the fields of a named field variant from variant `features`. Variant
+    /// level `rename_all` takes precedence over enum level `rename_all_fields`.
+    fn variant_fields_rename_all(&self, features: &mut Vec<Feature>) -> Option<RenameAll> {
+        features
+            .pop_rename_all_feature()
+            .or_else(|| self.rename_all_fields.clone().map(RenameAll::from))
+    }
+
+    /// Check that payload of given untagged `variant` can contain the `discriminator` property.
+    /// Payloads of referenced types cannot be inspected thus only their type is checked.
+    fn validate_discriminator_property(
+        &self,
+        discriminator: &Discriminator,
+        variant: &Variant,
+    ) -> Result<(), Diagnostics> {
+        let property = discriminator.value();
+        match &variant.fields {
+            Fields::Named(named_fields) => {
+                let variant_rules = serde::parse_container(&variant.attrs)?;
+                let variant_rename_all = variant
+                    .attrs
+                    .parse_features::<EnumNamedFieldVariantFeatures>()?
+                    .into_inner()
+                    .and_then(|mut features| features.pop_rename_all_feature());
+                let rename_all = variant_rules
+                    .rename_all_for(self.rename_context.as_ref())
+                    .or(variant_rename_all
+                        .as_ref()
+                        .map(|rename_all| rename_all.as_rename_rule()))
+                    .or(self.rename_all_fields.as_ref());
+
+                let mut has_flatten = false;
+                let mut has_property = false;
+                for field in &named_fields.named {
+                    let field_rules = serde::parse_value(&field.attrs)?;
+                    if !is_field_not_skipped(&field_rules) {
+                        continue;
+                    }
+                    has_flatten = has_flatten || is_flatten(&field_rules);
+
+                    let schema_rename = field
+                        .attrs
+                        .parse_features::<NamedFieldFeatures>()?
+                        .into_inner()
+                        .and_then(|mut features| features.pop_rename_feature())
+                        .map(|rename| Cow::Owned(rename.into_value()));
+                    let field_name = field.ident.as_ref().unwrap().to_string();
+                    let field_name = field_name.trim_start_matches("r#");
+                    let name = super::rename::<FieldRename>(
+                        field_name,
+                        field_rules
+                            .rename_for(self.rename_context.as_ref())
+                            .map(Cow::Borrowed)
+                            .or(schema_rename),
+                        rename_all,
+                    )
+                    .unwrap_or(Cow::Borrowed(field_name));
+                    has_property = has_property || name == property;
+                }
+
+                if has_property || has_flatten {
+                    Ok(())
+                } else {
+                    Err(Diagnostics::with_span(
+                        variant.ident.span(),
+                        format!(
+                            "Variant `{}` does not have discriminator property `{property}`",
+                            variant.ident
+                        ),
+                    ))
+                }
+            }
+            Fields::Unnamed(unnamed_fields) if unnamed_fields.unnamed.len() == 1 => {
+                let field = unnamed_fields.unnamed.first().unwrap();
+                if is_internally_taggable(&TypeTree::from_type(&field.ty)?) {
+                    Ok(())
+                } else {
+                    Err(Diagnostics::with_span(
+                        field.ty.span(),
+                        format!(
+                            "Variant `{}` payload cannot have discriminator property `{property}`",
+                            variant.ident
+                        ),
+                    )
+                    .help("Discriminator property must be in object payload e.g. struct"))
+                }
+            }
+            _ => Err(Diagnostics::with_span(
+                variant.ident.span(),
+                format!(
+                    "Variant `{}` payload cannot have discriminator property `{property}`",
+                    variant.ident
+                ),
+            )
+            .help("Discriminator property must be in object payload e.g. struct")),
+        }
+    }
+
     /// Produce tokens that represent a variant of a [`ComplexEnum`].
     fn variant_tokens(
         &self,
         name: Cow<'_, str>,
         variant: &Variant,
+        variant_features: Vec<Feature>,
         variant_rules: &SerdeValue,
         container_rules: &SerdeContainer,
         rename_all: &Option<RenameAll>,
@@ -1024,21 +2033,19 @@ impl ComplexEnum<'_> {
         // TODO need to be able to split variant.attrs for variant and the struct representation!
         match &variant.fields {
             Fields::Named(named_fields) => {
-                let (title_features, mut named_struct_features) = variant
-                    .attrs
-                    .parse_features::<EnumNamedFieldVariantFeatures>()?
-                    .into_inner()
-                    .map(|features| features.split_for_title())
-                    .unwrap_or_default();
+                let (title_features, mut named_struct_features) =
+                    variant_features.split_for_title();
                 let variant_name = rename_enum_variant(
                     name.as_ref(),
                     &mut named_struct_features,
                     variant_rules,
                     container_rules,
                     rename_all,
+                    self.rename_context.as_ref(),
                 );
 
                 let example = pop_feature!(named_struct_features => Feature::Example(_));
+                let examples = pop_feature!(named_struct_features => Feature::Examples(_));
 
                 Ok(self::enum_variant::Variant::to_tokens(&ObjectVariant {
                     name: variant_name.unwrap_or(Cow::Borrowed(&name)),
@@ -1046,34 +2053,36 @@ impl ComplexEnum<'_> {
                         .first()
                         .map(ToTokensDiagnostics::to_token_stream),
                     example: example.as_ref().map(ToTokensDiagnostics::to_token_stream),
+                    examples: examples.as_ref().map(ToTokensDiagnostics::to_token_stream),
                     item: as_tokens_or_diagnostics!(&NamedStructSchema {
                         struct_name: Cow::Borrowed(&*self.enum_name),
                         attributes: &variant.attrs,
-                        rename_all: named_struct_features.pop_rename_all_feature(),
+                        rename_all: self.variant_fields_rename_all(&mut named_struct_features),
+                        rename_context: self.rename_context.clone(),
                         features: Some(named_struct_features),
                         fields: &named_fields.named,
                         generics: None,
-                        aliases: None,
+                        aliases: self.aliases.clone(),
                         schema_as: None,
+                        schema_rename: None,
+                        schema_bound: None,
                     }),
                 }))
             }
             Fields::Unnamed(unnamed_fields) => {
-                let (title_features, mut unnamed_struct_features) = variant
-                    .attrs
-                    .parse_features::<EnumUnnamedFieldVariantFeatures>()?
-                    .into_inner()
-                    .map(|features| features.split_for_title())
-                    .unwrap_or_default();
+                let (title_features, mut unnamed_struct_features) =
+                    variant_features.split_for_title();
                 let variant_name = rename_enum_variant(
                     name.as_ref(),
                     &mut unnamed_struct_features,
                     variant_rules,
                     container_rules,
                     rename_all,
+                    self.rename_context.as_ref(),
                 );
 
                 let example = pop_feature!(unnamed_struct_features => Feature::Example(_));
+                let examples = pop_feature!(unnamed_struct_features => Feature::Examples(_));
 
                 Ok(self::enum_variant::Variant::to_tokens(&ObjectVariant {
                     name: variant_name.unwrap_or(Cow::Borrowed(&name)),
@@ -1081,12 +2090,16 @@ impl ComplexEnum<'_> {
                         .first()
                         .map(ToTokensDiagnostics::to_token_stream),
                     example: example.as_ref().map(ToTokensDiagnostics::to_token_stream),
+                    examples: examples.as_ref().map(ToTokensDiagnostics::to_token_stream),
                     item: as_tokens_or_diagnostics!(&UnnamedStructSchema {
                         struct_name: Cow::Borrowed(&*self.enum_name),
                         attributes: &variant.attrs,
                         features: Some(unnamed_struct_features),
                         fields: &unnamed_fields.unnamed,
+                        aliases: self.aliases.clone(),
                         schema_as: None,
+                        schema_rename: None,
+                        schema_bound: None,
                     }),
                 }))
             }
@@ -1097,7 +2110,8 @@ impl ComplexEnum<'_> {
                             input as super::features::Title,
                             RenameAll,
                             Rename,
-                            Example
+                            Example,
+                            super::features::Default
                         ))
                     })?
                     .unwrap_or_default();
@@ -1108,6 +2122,7 @@ impl ComplexEnum<'_> {
                     variant_rules,
                     container_rules,
                     rename_all,
+                    self.rename_context.as_ref(),
                 );
 
                 let example: Option<Feature> = pop_feature!(unit_features => Feature::Example(_));
@@ -1137,45 +2152,72 @@ impl ComplexEnum<'_> {
 
     /// Produce tokens that represent a variant of a [`ComplexEnum`] where serde enum attribute
     /// `untagged` applies.
-    fn untagged_variant_tokens(&self, variant: &Variant) -> Result<TokenStream, Diagnostics> {
+    fn untagged_variant_tokens(
+        &self,
+        variant: &Variant,
+        variant_features: Vec<Feature>,
+    ) -> Result<TokenStream, Diagnostics> {
         match &variant.fields {
             Fields::Named(named_fields) => {
-                let mut named_struct_features = variant
-                    .attrs
-                    .parse_features::<EnumNamedFieldVariantFeatures>()?
-                    .into_inner()
-                    .unwrap_or_default();
+                let mut named_struct_features = variant_features;
 
                 Ok(as_tokens_or_diagnostics!(&NamedStructSchema {
                     struct_name: Cow::Borrowed(&*self.enum_name),
                     attributes: &variant.attrs,
-                    rename_all: named_struct_features.pop_rename_all_feature(),
+                    rename_all: self.variant_fields_rename_all(&mut named_struct_features),
+                    rename_context: self.rename_context.clone(),
                     features: Some(named_struct_features),
                     fields: &named_fields.named,
                     generics: None,
-                    aliases: None,
+                    aliases: self.aliases.clone(),
                     schema_as: None,
+                    schema_rename: None,
+                    schema_bound: None,
                 }))
             }
             Fields::Unnamed(unnamed_fields) => {
-                let unnamed_struct_features = variant
-                    .attrs
-                    .parse_features::<EnumUnnamedFieldVariantFeatures>()?
-                    .into_inner()
-                    .unwrap_or_default();
+                let mut unnamed_struct_features = variant_features;
+
+                // title cannot be set on a reference thus referenced payload is wrapped in allOf
+                let is_reference = unnamed_fields.unnamed.len() == 1
+                    && TypeTree::from_type(&unnamed_fields.unnamed[0].ty)?.value_type
+                        == ValueType::Object;
+                let title = if is_reference {
+                    pop_feature!(unnamed_struct_features => Feature::Title(_))
+                } else {
+                    None
+                };
 
-                Ok(as_tokens_or_diagnostics!(&UnnamedStructSchema {
+                let unnamed_enum_tokens = as_tokens_or_diagnostics!(&UnnamedStructSchema {
                     struct_name: Cow::Borrowed(&*self.enum_name),
                     attributes: &variant.attrs,
                     features: Some(unnamed_struct_features),
                     fields: &unnamed_fields.unnamed,
+                    aliases: self.aliases.clone(),
                     schema_as: None,
-                }))
+                    schema_rename: None,
+                    schema_bound: None,
+                });
+
+                match title {
+                    Some(title) => {
+                        let title = as_tokens_or_diagnostics!(&title);
+                        Ok(quote! {
+                            utoipa::openapi::schema::AllOfBuilder::new()
+                                #title
+                                .item(#unnamed_enum_tokens)
+                        })
+                    }
+                    None => Ok(unnamed_enum_tokens),
+                }
             }
             Fields::Unit => {
                 let mut unit_features =
                     features::parse_schema_features_with(&variant.attrs, |input| {
-                        Ok(parse_features!(input as super::features::Title))
+                        Ok(parse_features!(
+                            input as super::features::Title,
+                            super::features::Default
+                        ))
                     })
                     .unwrap_or_default();
                 let title = pop_feature!(unit_features => Feature::Title(_));
@@ -1187,40 +2229,42 @@ impl ComplexEnum<'_> {
 
     /// Produce tokens that represent a variant of a [`ComplexEnum`] where serde enum attribute
     /// `tag = ` applies.
+    #[allow(clippy::too_many_arguments)]
     fn tagged_variant_tokens(
         &self,
         tag: &str,
         name: Cow<'_, str>,
         variant: &Variant,
+        variant_features: Vec<Feature>,
         variant_rules: &SerdeValue,
         container_rules: &SerdeContainer,
         rename_all: &Option<RenameAll>,
     ) -> Result<TokenStream, Diagnostics> {
         match &variant.fields {
             Fields::Named(named_fields) => {
-                let (title_features, mut named_struct_features) = variant
-                    .attrs
-                    .parse_features::<EnumNamedFieldVariantFeatures>()?
-                    .into_inner()
-                    .map(|features| features.split_for_title())
-                    .unwrap_or_default();
+                let (title_features, mut named_struct_features) =
+                    variant_features.split_for_title();
                 let variant_name = rename_enum_variant(
                     name.as_ref(),
                     &mut named_struct_features,
                     variant_rules,
                     container_rules,
                     rename_all,
+                    self.rename_context.as_ref(),
                 );
 
                 let named_enum = NamedStructSchema {
                     struct_name: Cow::Borrowed(&*self.enum_name),
                     attributes: &variant.attrs,
-                    rename_all: named_struct_features.pop_rename_all_feature(),
+                    rename_all: self.variant_fields_rename_all(&mut named_struct_features),
+                    rename_context: self.rename_context.clone(),
                     features: Some(named_struct_features),
                     fields: &named_fields.named,
                     generics: None,
-                    aliases: None,
+                    aliases: self.aliases.clone(),
                     schema_as: None,
+                    schema_rename: None,
+                    schema_bound: None,
                 };
                 let named_enum_tokens = as_tokens_or_diagnostics!(&named_enum);
                 let title = title_features
@@ -1241,18 +2285,39 @@ impl ComplexEnum<'_> {
             }
             Fields::Unnamed(unnamed_fields) => {
                 if unnamed_fields.unnamed.len() == 1 {
-                    let (title_features, mut unnamed_struct_features) = variant
-                        .attrs
-                        .parse_features::<EnumUnnamedFieldVariantFeatures>()?
-                        .into_inner()
-                        .map(|features| features.split_for_title())
-                        .unwrap_or_default();
+                    let (title_features, mut unnamed_struct_features) =
+                        variant_features.split_for_title();
+
+                    // serde can only internally tag payloads that serialize as map e.g. structs
+                    let field = unnamed_fields
+                        .unnamed
+                        .first()
+                        .expect("variant should have 1 field");
+                    let payload_type_tree =
+                        match unnamed_struct_features
+                            .iter()
+                            .find_map(|feature| match feature {
+                                Feature::ValueType(value_type) => Some(value_type),
+                                _ => None,
+                            }) {
+                            Some(value_type) => value_type.as_type_tree()?,
+                            None => TypeTree::from_type(&field.ty)?,
+                        };
+                    if !is_internally_taggable(&payload_type_tree) {
+                        return Err(Diagnostics::with_span(field.ty.span(),
+                            "Unnamed (tuple) enum variants with non object payload are unsupported for internally tagged enums using the `tag = ` serde attribute")
+                            .help("Try wrapping the payload in a struct or using a different serde enum representation")
+                            .note("See more about enum limitations here: `https://serde.rs/enum-representations.html#internally-tagged`")
+                        );
+                    }
+
                     let variant_name = rename_enum_variant(
                         name.as_ref(),
                         &mut unnamed_struct_features,
                         variant_rules,
                         container_rules,
                         rename_all,
+                        self.rename_context.as_ref(),
                     );
 
                     let unnamed_enum = UnnamedStructSchema {
@@ -1260,7 +2325,10 @@ impl ComplexEnum<'_> {
                         attributes: &variant.attrs,
                         features: Some(unnamed_struct_features),
                         fields: &unnamed_fields.unnamed,
+                        aliases: self.aliases.clone(),
                         schema_as: None,
+                        schema_rename: None,
+                        schema_bound: None,
                     };
                     let unnamed_enum_tokens = as_tokens_or_diagnostics!(&unnamed_enum);
 
@@ -1312,7 +2380,11 @@ impl ComplexEnum<'_> {
             Fields::Unit => {
                 let mut unit_features =
                     features::parse_schema_features_with(&variant.attrs, |input| {
-                        Ok(parse_features!(input as super::features::Title, Rename))
+                        Ok(parse_features!(
+                            input as super::features::Title,
+                            Rename,
+                            super::features::Default
+                        ))
                     })?
                     .unwrap_or_default();
                 let title = pop_feature!(unit_features => Feature::Title(_));
@@ -1324,6 +2396,7 @@ impl ComplexEnum<'_> {
                     variant_rules,
                     container_rules,
                     rename_all,
+                    self.rename_context.as_ref(),
                 );
 
                 // Unit variant is just simple enum with single variant.
@@ -1343,6 +2416,76 @@ impl ComplexEnum<'_> {
         }
     }
 
+    /// Resolve discriminator mapping entry for a newtype variant wrapping a named schema.
+    /// Returns the tag value of the variant and tokens of the `$ref` location of its payload schema.
+    fn discriminator_mapping_entry(
+        &self,
+        variant: &Variant,
+        variant_rules: &SerdeValue,
+        container_rules: &SerdeContainer,
+    ) -> Result<Option<(String, TokenStream)>, Diagnostics> {
+        let field = match &variant.fields {
+            Fields::Unnamed(unnamed_fields) if unnamed_fields.unnamed.len() == 1 => {
+                unnamed_fields.unnamed.first().unwrap()
+            }
+            _ => return Ok(None),
+        };
+
+        let mut features = variant
+            .attrs
+            .parse_features::<EnumUnnamedFieldVariantFeatures>()?
+            .into_inner()
+            .unwrap_or_default();
+        // inlined payload is not a component thus there is nothing to reference
+        if features.is_inline() {
+            return Ok(None);
+        }
+        let value_type = features.pop_value_type_feature();
+        let type_tree = match &value_type {
+            Some(value_type) => value_type.as_type_tree()?,
+            None => TypeTree::from_type(&field.ty)?,
+        };
+        let type_tree = match type_tree.generic_type {
+            Some(GenericType::Box) => type_tree
+                .children
+                .as_ref()
+                .and_then(|children| children.first())
+                .unwrap_or(&type_tree),
+            _ => &type_tree,
+        };
+
+        let path = match (
+            &type_tree.value_type,
+            &type_tree.generic_type,
+            &type_tree.path,
+        ) {
+            (ValueType::Object, None, Some(path)) if !type_tree.is_object() => path,
+            _ => return Ok(None),
+        };
+        let schema_name = if path.is_ident("Self") {
+            quote! { <Self as utoipa::ToSchema>::name() }
+        } else {
+            schema_reference_name(path, type_tree.name_only)
+        };
+
+        let name = variant.ident.to_string();
+        let variant_name = rename_enum_variant(
+            &name,
+            &mut features,
+            variant_rules,
+            container_rules,
+            &self.rename_all,
+            self.rename_context.as_ref(),
+        )
+        .map(Cow::into_owned)
+        .unwrap_or(name.clone());
+
+        Ok(Some((
+            variant_name,
+            quote! { format!("#/components/schemas/{}", #schema_name) },
+        )))
+    }
+
     // FIXME perhaps design this better to lessen the amount of args.
     #[allow(clippy::too_many_arguments)]
     fn adjacently_tagged_variant_tokens(
@@ -1351,35 +2494,36 @@ impl ComplexEnum<'_> {
         content: &str,
         name: Cow<'_, str>,
         variant: &Variant,
+        variant_features: Vec<Feature>,
         variant_rules: &SerdeValue,
         container_rules: &SerdeContainer,
         rename_all: &Option<RenameAll>,
     ) -> Result<TokenStream, Diagnostics> {
         match &variant.fields {
             Fields::Named(named_fields) => {
-                let (title_features, mut named_struct_features) = variant
-                    .attrs
-                    .parse_features::<EnumNamedFieldVariantFeatures>()?
-                    .into_inner()
-                    .map(|features| features.split_for_title())
-                    .unwrap_or_default();
+                let (title_features, mut named_struct_features) =
+                    variant_features.split_for_title();
                 let variant_name = rename_enum_variant(
                     name.as_ref(),
                     &mut named_struct_features,
                     variant_rules,
                     container_rules,
                     rename_all,
+                    self.rename_context.as_ref(),
                 );
 
                 let named_enum = NamedStructSchema {
                     struct_name: Cow::Borrowed(&*self.enum_name),
                     attributes: &variant.attrs,
-                    rename_all: named_struct_features.pop_rename_all_feature(),
+                    rename_all: self.variant_fields_rename_all(&mut named_struct_features),
+                    rename_context: self.rename_context.clone(),
                     features: Some(named_struct_features),
                     fields: &named_fields.named,
                     generics: None,
-                    aliases: None,
+                    aliases: self.aliases.clone(),
                     schema_as: None,
+                    schema_rename: None,
+                    schema_bound: None,
                 };
                 let named_enum_tokens = as_tokens_or_diagnostics!(&named_enum);
                 let title = title_features
@@ -1402,63 +2546,60 @@ impl ComplexEnum<'_> {
                 })
             }
             Fields::Unnamed(unnamed_fields) => {
-                if unnamed_fields.unnamed.len() == 1 {
-                    let (title_features, mut unnamed_struct_features) = variant
-                        .attrs
-                        .parse_features::<EnumUnnamedFieldVariantFeatures>()?
-                        .into_inner()
-                        .map(|features| features.split_for_title())
-                        .unwrap_or_default();
-                    let variant_name = rename_enum_variant(
-                        name.as_ref(),
-                        &mut unnamed_struct_features,
-                        variant_rules,
-                        container_rules,
-                        rename_all,
-                    );
+                let (title_features, mut unnamed_struct_features) =
+                    variant_features.split_for_title();
+                let variant_name = rename_enum_variant(
+                    name.as_ref(),
+                    &mut unnamed_struct_features,
+                    variant_rules,
+                    container_rules,
+                    rename_all,
+                    self.rename_context.as_ref(),
+                );
 
-                    let unnamed_enum = UnnamedStructSchema {
-                        struct_name: Cow::Borrowed(&*self.enum_name),
-                        attributes: &variant.attrs,
-                        features: Some(unnamed_struct_features),
-                        fields: &unnamed_fields.unnamed,
-                        schema_as: None,
-                    };
-                    let unnamed_enum_tokens = as_tokens_or_diagnostics!(&unnamed_enum);
+                // tuple variant with multiple fields is rendered under `content` as fixed size
+                // array of the field types
+                let unnamed_enum = UnnamedStructSchema {
+                    struct_name: Cow::Borrowed(&*self.enum_name),
+                    attributes: &variant.attrs,
+                    features: Some(unnamed_struct_features),
+                    fields: &unnamed_fields.unnamed,
+                    aliases: self.aliases.clone(),
+                    schema_as: None,
+                    schema_rename: None,
+                    schema_bound: None,
+                };
+                let unnamed_enum_tokens = as_tokens_or_diagnostics!(&unnamed_enum);
 
-                    let title = title_features
-                        .first()
-                        .map(ToTokensDiagnostics::to_token_stream);
-                    let variant_name_tokens = Enum::new([SimpleEnumVariant {
-                        value: variant_name
-                            .unwrap_or(Cow::Borrowed(&name))
-                            .to_token_stream(),
-                    }]);
+                let title = title_features
+                    .first()
+                    .map(ToTokensDiagnostics::to_token_stream);
+                let variant_name_tokens = Enum::new([SimpleEnumVariant {
+                    value: variant_name
+                        .unwrap_or(Cow::Borrowed(&name))
+                        .to_token_stream(),
+                }]);
 
-                    Ok(quote! {
-                        utoipa::openapi::schema::ObjectBuilder::new()
-                            #title
-                            .schema_type(utoipa::openapi::schema::SchemaType::Object)
-                            .property(#tag, #variant_name_tokens)
-                            .required(#tag)
-                            .property(#content, #unnamed_enum_tokens)
-                            .required(#content)
-                    })
-                } else {
-                    Err(
-                        Diagnostics::with_span(variant.span(),
-                            "Unnamed (tuple) enum variants are unsupported for adjacently tagged enums using the `tag = <tag>, content = <content>` serde attribute")
-                            .help("Try using a different serde enum representation")
-                            .note("See more about enum limitations here: `https://serde.rs/enum-representations.html#adjacently-tagged`")
-                    )
-                }
+                Ok(quote! {
+                    utoipa::openapi::schema::ObjectBuilder::new()
+                        #title
+                        .schema_type(utoipa::openapi::schema::SchemaType::Object)
+                        .property(#tag, #variant_name_tokens)
+                        .required(#tag)
+                        .property(#content, #unnamed_enum_tokens)
+                        .required(#content)
+                })
             }
             Fields::Unit => {
                 // In this case `content` is simply ignored - there is nothing to put in it.
 
                 let mut unit_features =
                     features::parse_schema_features_with(&variant.attrs, |input| {
-                        Ok(parse_features!(input as super::features::Title, Rename))
+                        Ok(parse_features!(
+                            input as super::features::Title,
+                            Rename,
+                            super::features::Default
+                        ))
                     })?
                     .unwrap_or_default();
                 let title = pop_feature!(unit_features => Feature::Title(_));
@@ -1470,6 +2611,7 @@ impl ComplexEnum<'_> {
                     variant_rules,
                     container_rules,
                     rename_all,
+                    self.rename_context.as_ref(),
                 );
 
                 // Unit variant is just simple enum with single variant.
@@ -1504,7 +2646,28 @@ impl ToTokensDiagnostics for ComplexEnum<'_> {
             | SerdeEnumRepr::UnfinishedAdjacentlyTagged { .. } => None,
         };
 
-        self.variants
+        if let Some(untagged_as) = &self.untagged_as {
+            if !matches!(enum_repr, SerdeEnumRepr::Untagged) {
+                return Err(Diagnostics::with_span(
+                    untagged_as.span(),
+                    "`untagged_as` can only be used with `#[serde(untagged)]` enums",
+                ));
+            }
+        }
+
+        if let Some(discriminator) = &self.discriminator {
+            if !matches!(enum_repr, SerdeEnumRepr::Untagged) {
+                return Err(Diagnostics::with_span(
+                    discriminator.span(),
+                    "`discriminator` can only be used with `#[serde(untagged)]` enums",
+                )
+                .note("Externally tagged enums wrap the variant payload in a property named after the variant, thus the payload properties cannot be used as discriminator")
+                .note("Internally and adjacently tagged enums use the serde `tag` as discriminator"));
+            }
+        }
+
+        let variants = self
+            .variants
             .iter()
             .map(|variant| match serde::parse_value(&variant.attrs) {
                 Ok(variant_rules) => Ok((variant, variant_rules)),
@@ -1519,14 +2682,53 @@ impl ToTokensDiagnostics for ComplexEnum<'_> {
                     None
                 }
             })
+            .collect::<Vec<_>>();
+
+        if let Some(discriminator) = &self.discriminator {
+            variants.iter().try_for_each(|(variant, _)| {
+                self.validate_discriminator_property(discriminator, variant)
+            })?;
+        }
+
+        // only internally tagged payloads contain the tag property, adjacently tagged payloads are
+        // under the content property
+        let discriminator_mapping = if matches!(enum_repr, SerdeEnumRepr::InternallyTagged { .. }) {
+            let mut mapping_targets = Vec::new();
+            variants
+                .iter()
+                .map(|(variant, variant_rules)| {
+                    self.discriminator_mapping_entry(variant, variant_rules, &container_rules)
+                })
+                .collect::<Result<Vec<_>, Diagnostics>>()?
+                .into_iter()
+                .flatten()
+                // schema wrapped by multiple variants is only mapped by the first tag value
+                .filter(|(_, reference)| {
+                    let target = reference.to_string();
+                    if mapping_targets.contains(&target) {
+                        false
+                    } else {
+                        mapping_targets.push(target);
+                        true
+                    }
+                })
+                .collect::<Vec<_>>()
+        } else {
+            Vec::new()
+        };
+
+        variants
+            .iter()
             .map(|(variant, variant_serde_rules)| {
                 let variant_name = &*variant.ident.to_string();
+                let variant_features = parse_variant_features(variant)?;
 
                 match &enum_repr {
                     SerdeEnumRepr::ExternallyTagged => self.variant_tokens(
                         Cow::Borrowed(variant_name),
                         variant,
-                        &variant_serde_rules,
+                        variant_features,
+                        variant_serde_rules,
                         &container_rules,
                         &self.rename_all,
                     ),
@@ -1534,18 +2736,22 @@ impl ToTokensDiagnostics for ComplexEnum<'_> {
                         tag,
                         Cow::Borrowed(variant_name),
                         variant,
-                        &variant_serde_rules,
+                        variant_features,
+                        variant_serde_rules,
                         &container_rules,
                         &self.rename_all,
                     ),
-                    SerdeEnumRepr::Untagged => self.untagged_variant_tokens(variant),
+                    SerdeEnumRepr::Untagged => {
+                        self.untagged_variant_tokens(variant, variant_features)
+                    }
                     SerdeEnumRepr::AdjacentlyTagged { tag, content } => self
                         .adjacently_tagged_variant_tokens(
                             tag,
                             content,
                             Cow::Borrowed(variant_name),
                             variant,
-                            &variant_serde_rules,
+                            variant_features,
+                            variant_serde_rules,
                             &container_rules,
                             &self.rename_all,
                         ),
@@ -1555,9 +2761,45 @@ impl ToTokensDiagnostics for ComplexEnum<'_> {
                 }
             })
             .collect::<Result<CustomEnum<'_, TokenStream>, Diagnostics>>()?
-            .with_discriminator(tag.map(|t| Cow::Borrowed(t.as_str())))
+            .with_any_of(self.untagged_as.as_ref().is_some_and(UntaggedAs::is_any_of))
+            .with_discriminator(
+                tag.map(|t| Cow::Borrowed(t.as_str())).or(self
+                    .discriminator
+                    .as_ref()
+                    .map(|discriminator| Cow::Owned(discriminator.value()))),
+            )
+            .with_discriminator_mapping(discriminator_mapping)
             .to_tokens(tokens);
 
+        let mut default_variants = self.variants.iter().filter_map(|variant| {
+            is_default_variant(variant)
+                .map(|is_default| is_default.then_some(variant))
+                .transpose()
+        });
+        if let Some(variant) = default_variants.next().transpose()? {
+            if let Some(other) = default_variants.next().transpose()? {
+                return Err(Diagnostics::with_span(
+                    other.span(),
+                    "Only one variant can be marked as default with `#[default]` or `#[schema(default)]`",
+                ));
+            }
+
+            // the default is only used when the `Default` implementation returns the marked variant
+            let variant_ident = &variant.ident;
+            let variant_pattern = match &variant.fields {
+                Fields::Named(_) => quote! { Self::#variant_ident { .. } },
+                Fields::Unnamed(_) => quote! { Self::#variant_ident(..) },
+                Fields::Unit => quote! { Self::#variant_ident },
+            };
+            tokens.extend(quote! {
+                .default(match <Self as Default>::default() {
+                    default @ #variant_pattern => serde_json::to_value(default).ok(),
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                })
+            });
+        }
+
         tokens.extend(self.enum_features.to_token_stream()?);
         Ok(())
     }
@@ -1606,9 +2848,110 @@ pub(crate) fn format_path_ref(path: &Path) -> String {
     path.to_token_stream().to_string().replace(" :: ", ".")
 }
 
+/// Check whether payload of given `type_tree` can be internally tagged by serde. Only payloads
+/// serialized as map e.g. structs and maps can be internally tagged.
+fn is_internally_taggable(type_tree: &TypeTree) -> bool {
+    match type_tree.generic_type {
+        Some(GenericType::Map) => true,
+        Some(GenericType::Vec) | Some(GenericType::LinkedList) | Some(GenericType::Set) => false,
+        #[cfg(feature = "smallvec")]
+        Some(GenericType::SmallVec) => false,
+        Some(_) => type_tree
+            .children
+            .iter()
+            .flatten()
+            .next()
+            .map(is_internally_taggable)
+            .unwrap_or(true),
+        None => matches!(type_tree.value_type, ValueType::Object | ValueType::Value),
+    }
+}
+
 #[inline]
 fn is_not_skipped(rule: &SerdeValue) -> bool {
-    !rule.skip
+    !rule.is_skipped()
+}
+
+/// Check whether named field is part of the schema. Fields skipped only in one direction are
+/// part of the schema but marked as read only or write only.
+fn is_field_not_skipped(rule: &SerdeValue) -> bool {
+    !(rule.skip || rule.skip_serializing && rule.skip_deserializing)
+}
+
+/// Parse features of named or unnamed field `variant` of [`ComplexEnum`]. The default variant
+/// marker is popped from the features since the default variant is handled on the enum level.
+/// Unit variants parse their features on their own thus empty features are returned for them.
+fn parse_variant_features(variant: &Variant) -> Result<Vec<Feature>, Diagnostics> {
+    match &variant.fields {
+        Fields::Named(_) => {
+            let mut features = variant
+                .attrs
+                .parse_features::<EnumNamedFieldVariantFeatures>()?
+                .into_inner()
+                .unwrap_or_default();
+            // named field variant is rendered from its fields thus it cannot define a default
+            // value of its own
+            if let Some(Feature::Default(crate::features::Default(Some(_)))) =
+                pop_feature!(features => Feature::Default(_))
+            {
+                return Err(Diagnostics::with_span(
+                    variant.ident.span(),
+                    "`default = ...` is not supported on named field enum variants",
+                )
+                .help("Use `#[schema(default)]` to mark the variant as the default of the enum"));
+            }
+
+            Ok(features)
+        }
+        Fields::Unnamed(_) => {
+            let mut features = variant
+                .attrs
+                .parse_features::<EnumUnnamedFieldVariantFeatures>()?
+                .into_inner()
+                .unwrap_or_default();
+            pop_feature!(features => Feature::Default(crate::features::Default(None)));
+
+            Ok(features)
+        }
+        Fields::Unit => Ok(Vec::new()),
+    }
+}
+
+/// Check whether enum variant is marked as the default variant with Rust's `#[default]` attribute
+/// of `#[derive(Default)]` or with `#[schema(default)]`.
+fn is_default_variant(variant: &Variant) -> Result<bool, Diagnostics> {
+    if variant
+        .attrs
+        .iter()
+        .any(|attribute| attribute.path().is_ident("default"))
+    {
+        return Ok(true);
+    }
+
+    let features = match &variant.fields {
+        Fields::Named(_) => variant
+            .attrs
+            .parse_features::<EnumNamedFieldVariantFeatures>()?
+            .into_inner(),
+        Fields::Unnamed(_) => variant
+            .attrs
+            .parse_features::<EnumUnnamedFieldVariantFeatures>()?
+            .into_inner(),
+        Fields::Unit => features::parse_schema_features_with(&variant.attrs, |input| {
+            Ok(parse_features!(
+                input as super::features::Title,
+                RenameAll,
+                Rename,
+                Example,
+                super::features::Default
+            ))
+        })?,
+    };
+
+    Ok(features
+        .iter()
+        .flatten()
+        .any(|feature| matches!(feature, Feature::Default(crate::features::Default(None)))))
 }
 
 #[inline]
@@ -1620,9 +2963,49 @@ fn is_flatten(rule: &SerdeValue) -> bool {
 pub struct AliasSchema {
     pub name: String,
     pub ty: Type,
+    /// Whether the `name` is derived from the `ty` instead of being defined explicitly.
+    pub is_derived_name: bool,
+    /// Features applied only to the schema of this alias e.g. _`description`_ or _`example`_.
+    pub features: Vec<Feature>,
 }
 
 impl AliasSchema {
+    /// Get const generic arguments of the alias type in order. Only integer literal arguments can
+    /// be resolved, other const arguments are `None`.
+    fn get_const_args(&self) -> impl Iterator<Item = Option<TypeTree<'static>>> + '_ {
+        let args = match &self.ty {
+            Type::Path(type_path) => {
+                type_path
+                    .path
+                    .segments
+                    .last()
+                    .and_then(|segment| match &segment.arguments {
+                        PathArguments::AngleBracketed(angle_bracketed_args) => {
+                            Some(&angle_bracketed_args.args)
+                        }
+                        _ => None,
+                    })
+            }
+            _ => None,
+        };
+
+        args.into_iter()
+            .flatten()
+            .filter_map(|arg| match arg {
+                GenericArgument::Const(expr) => Some(expr),
+                _ => None,
+            })
+            .map(|expr| match expr {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Int(lit), ..
+                }) => lit
+                    .base10_parse::<usize>()
+                    .ok()
+                    .map(|len| TypeTree::from_const_arg(len, lit.span())),
+                _ => None,
+            })
+    }
+
     fn get_lifetimes(&self) -> Result<impl Iterator<Item = &GenericArgument>, Diagnostics> {
         fn lifetimes_from_type(
             ty: &Type,
@@ -1657,13 +3040,114 @@ impl AliasSchema {
 
 impl Parse for AliasSchema {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let name = input.parse::<Ident>()?;
-        input.parse::<Token![=]>()?;
+        if input.peek(syn::Ident) && input.peek2(Token![=]) {
+            let name = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
 
-        Ok(Self {
-            name: name.to_string(),
-            ty: input.parse::<Type>()?,
-        })
+            Ok(Self {
+                name: name.to_string(),
+                ty: input.parse::<Type>()?,
+                is_derived_name: false,
+                features: parse_alias_features(input)?,
+            })
+        } else {
+            let ty = input.parse::<Type>()?;
+            let name = format_alias_name(&ty);
+            if name.is_empty() {
+                return Err(syn::Error::new(
+                    ty.span(),
+                    "Cannot derive alias name from the type, define it explicitly e.g. `Name = Type`",
+                ));
+            }
+
+            Ok(Self {
+                name,
+                ty,
+                is_derived_name: true,
+                features: parse_alias_features(input)?,
+            })
+        }
+    }
+}
+
+/// Parse optional trailing features of an alias. Single feature can be defined directly after
+/// `as` e.g. _`as description = "..."`_ and multiple features within parentheses
+/// e.g. _`as (description = "...", example = json!(...))`_.
+fn parse_alias_features(input: syn::parse::ParseStream) -> syn::Result<Vec<Feature>> {
+    if !input.peek(Token![as]) {
+        return Ok(Vec::new());
+    }
+    input.parse::<Token![as]>()?;
+
+    if input.peek(syn::token::Paren) {
+        let content;
+        syn::parenthesized!(content in input);
+        let features = &content;
+
+        Ok(parse_features!(
+            features as super::features::Description,
+            Example
+        ))
+    } else {
+        let ident = input.parse::<Ident>()?;
+        match &*ident.to_string() {
+            "description" => Ok(vec![Feature::Description(
+                <super::features::Description as super::features::Parse>::parse(input, ident)?,
+            )]),
+            "example" => Ok(vec![Feature::Example(
+                <Example as super::features::Parse>::parse(input, ident)?,
+            )]),
+            name => Err(syn::Error::new(
+                ident.span(),
+                format!("unexpected attribute: {name}, expected any of: description, example"),
+            )),
+        }
+    }
+}
+
+/// Format spec compliant alias name from the type joining the type and its generic arguments
+/// with `_` e.g. _`MyType<User>`_ will be formatted as _`MyType_User`_.
+fn format_alias_name(ty: &Type) -> String {
+    fn sanitize(value: String) -> String {
+        value
+            .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("_")
+    }
+
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| {
+                let arguments = match &segment.arguments {
+                    PathArguments::AngleBracketed(angle_bracketed_args) => angle_bracketed_args
+                        .args
+                        .iter()
+                        .filter_map(|argument| match argument {
+                            GenericArgument::Type(ty) => Some(format_alias_name(ty)),
+                            GenericArgument::Const(expr) => {
+                                Some(sanitize(expr.to_token_stream().to_string()))
+                            }
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>(),
+                    _ => Vec::new(),
+                };
+
+                std::iter::once(segment.ident.to_string())
+                    .chain(arguments)
+                    .filter(|part| !part.is_empty())
+                    .collect::<Vec<_>>()
+                    .join("_")
+            })
+            .unwrap_or_default(),
+        Type::Reference(reference) => format_alias_name(&reference.elem),
+        Type::Group(group) => format_alias_name(&group.elem),
+        Type::Paren(paren) => format_alias_name(&paren.elem),
+        ty => sanitize(ty.to_token_stream().to_string()),
     }
 }
 
@@ -6,7 +6,7 @@ use quote::{quote, ToTokens};
 use syn::{parse_quote, TypePath};
 
 use crate::component::features::Feature;
-use crate::schema_type::SchemaType;
+use crate::schema_type::{SchemaFormat, SchemaType};
 use crate::{Array, Diagnostics, ToTokensDiagnostics};
 
 pub trait Variant {
@@ -20,6 +20,11 @@ pub trait Variant {
             quote! {&str},
         )
     }
+
+    /// Get enum variant format. By default enum variant has no format
+    fn get_format(&self) -> Option<TokenStream> {
+        None
+    }
 }
 
 pub struct SimpleEnumVariant<T: ToTokens> {
@@ -54,12 +59,20 @@ where
             self.type_path.to_token_stream(),
         )
     }
+
+    fn get_format(&self) -> Option<TokenStream> {
+        let format = SchemaFormat::from(&self.type_path.path);
+        format
+            .is_known_format()
+            .then(|| quote! { .format(Some(#format)) })
+    }
 }
 
 pub struct ObjectVariant<'o, T: ToTokens> {
     pub item: T,
     pub title: Option<TokenStream>,
     pub example: Option<TokenStream>,
+    pub examples: Option<TokenStream>,
     pub name: Cow<'o, str>,
 }
 
@@ -70,6 +83,7 @@ where
     fn to_tokens(&self) -> TokenStream {
         let title = &self.title;
         let example = &self.example;
+        let examples = &self.examples;
         let variant = &self.item;
         let name = &self.name;
 
@@ -77,6 +91,7 @@ where
             utoipa::openapi::schema::ObjectBuilder::new()
                 #title
                 #example
+                #examples
                 .property(#name, #variant)
                 .required(#name)
         }
@@ -89,6 +104,7 @@ pub struct Enum<'e, V: Variant> {
     len: usize,
     items: Array<'e, TokenStream>,
     schema_type: TokenStream,
+    format: Option<TokenStream>,
     enum_type: TokenStream,
     description: Option<TokenStream>,
     _p: PhantomData<V>,
@@ -128,6 +144,7 @@ where
         let example = &self.example;
         let items = &self.items;
         let schema_type = &self.schema_type;
+        let format = &self.format;
         let enum_type = &self.enum_type;
         let description = &self.description;
 
@@ -137,6 +154,7 @@ where
                 #description
                 #example
                 .schema_type(#schema_type)
+                #format
                 .enum_values::<[#enum_type; #len], #enum_type>(Some(#items))
         })
     }
@@ -145,8 +163,10 @@ where
 impl<V: Variant> FromIterator<V> for Enum<'_, V> {
     fn from_iter<T: IntoIterator<Item = V>>(iter: T) -> Self {
         let mut len = 0;
-        let mut schema_type: TokenStream = quote! {};
-        let mut enum_type: TokenStream = quote! {};
+        // enum without variants has nothing to derive the type from, default to `string`
+        let mut schema_type: TokenStream = SchemaType(&parse_quote!(str)).to_token_stream();
+        let mut enum_type: TokenStream = quote! {&str};
+        let mut format: Option<TokenStream> = None;
 
         let items = iter
             .into_iter()
@@ -154,6 +174,7 @@ impl<V: Variant> FromIterator<V> for Enum<'_, V> {
             .map(|(index, variant)| {
                 if index == 0 {
                     (schema_type, enum_type) = variant.get_type();
+                    format = variant.get_format();
                 }
                 len = index + 1;
                 variant.to_tokens()
@@ -167,6 +188,7 @@ impl<V: Variant> FromIterator<V> for Enum<'_, V> {
             len,
             items,
             schema_type,
+            format,
             enum_type,
             _p: PhantomData,
         }
@@ -211,6 +233,7 @@ impl<'t, V: Variant> FromIterator<(Cow<'t, str>, V)> for TaggedEnum<V> {
                 len = index + 1;
 
                 let (schema_type, enum_type) = variant.get_type();
+                let format = variant.get_format();
                 let item = variant.to_tokens();
                 quote! {
                     .item(
@@ -219,6 +242,7 @@ impl<'t, V: Variant> FromIterator<(Cow<'t, str>, V)> for TaggedEnum<V> {
                                 #tag,
                                 utoipa::openapi::schema::ObjectBuilder::new()
                                     .schema_type(#schema_type)
+                                    #format
                                     .enum_values::<[#enum_type; 1], #enum_type>(Some([#item]))
                             )
                             .required(#tag)
@@ -253,9 +277,16 @@ impl ToTokensDiagnostics for UntaggedEnum {
     fn to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
         let title = &self.title.to_token_stream();
 
+        // OpenAPI 3.1 has `null` type for the unit variant serialized as `null`
+        let nullable = if cfg!(feature = "openapi_31") {
+            quote! { .schema_type(utoipa::openapi::schema::Type::Null) }
+        } else {
+            quote! { .nullable(true) }
+        };
+
         tokens.extend(quote! {
             utoipa::openapi::schema::ObjectBuilder::new()
-                .nullable(true)
+                #nullable
                 .default(Some(serde_json::Value::Null))
                 #title
         });
@@ -301,6 +332,7 @@ impl<'t, V: Variant> FromIterator<(Cow<'t, str>, Cow<'t, str>, V)> for Adjacentl
                 len = index + 1;
 
                 let (schema_type, enum_type) = variant.get_type();
+                let format = variant.get_format();
                 let item = variant.to_tokens();
                 quote! {
                     .item(
@@ -316,6 +348,7 @@ impl<'t, V: Variant> FromIterator<(Cow<'t, str>, Cow<'t, str>, V)> for Adjacentl
                                 #content,
                                 utoipa::openapi::schema::ObjectBuilder::new()
                                     .schema_type(#schema_type)
+                                    #format
                                     .enum_values::<[#enum_type; 1], #enum_type>(Some([#item]))
                             )
                             .required(#content)
@@ -338,15 +371,33 @@ impl<'t, V: Variant> FromIterator<(Cow<'t, str>, Cow<'t, str>, V)> for Adjacentl
 pub struct CustomEnum<'c, T: ToTokens> {
     // pub items: Cow<'c, >,
     items: T,
+    len: usize,
+    any_of: bool,
     tag: Option<Cow<'c, str>>,
+    mapping: Vec<(String, TokenStream)>,
 }
 
 impl<'c, T: ToTokens> CustomEnum<'c, T> {
+    /// Compose the variants with inclusive `anyOf` instead of the default exclusive `oneOf`.
+    pub fn with_any_of(mut self, any_of: bool) -> Self {
+        self.any_of = any_of;
+
+        self
+    }
+
     pub fn with_discriminator(mut self, discriminator: Option<Cow<'c, str>>) -> Self {
         self.tag = discriminator;
 
         self
     }
+
+    /// Add explicit discriminator mapping from tag value to `$ref` location of the variant
+    /// schema. Mapping is only rendered when discriminator is defined.
+    pub fn with_discriminator_mapping(mut self, mapping: Vec<(String, TokenStream)>) -> Self {
+        self.mapping = mapping;
+
+        self
+    }
 }
 
 impl<'c, T> ToTokens for CustomEnum<'c, T>
@@ -354,13 +405,35 @@ where
     T: ToTokens,
 {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        self.items.to_tokens(tokens);
+        let len = self.len;
+        let items = &self.items;
+        if self.any_of {
+            tokens.extend(quote! {
+                Into::<utoipa::openapi::schema::AnyOfBuilder>::into(utoipa::openapi::schema::AnyOf::with_capacity(#len))
+                    #items
+            });
+        } else {
+            tokens.extend(quote! {
+                Into::<utoipa::openapi::schema::OneOfBuilder>::into(utoipa::openapi::OneOf::with_capacity(#len))
+                    #items
+            });
+        }
 
         // currently uses serde `tag` attribute as a discriminator. This discriminator
         // feature needs some refinement.
         let discriminator = self.tag.as_ref().map(|tag| {
-            quote! {
-                .discriminator(Some(utoipa::openapi::schema::Discriminator::new(#tag)))
+            if self.mapping.is_empty() {
+                quote! {
+                    .discriminator(Some(utoipa::openapi::schema::Discriminator::new(#tag)))
+                }
+            } else {
+                let mapping = self
+                    .mapping
+                    .iter()
+                    .map(|(value, reference)| quote! { (#value, #reference) });
+                quote! {
+                    .discriminator(Some(utoipa::openapi::schema::Discriminator::with_mapping(#tag, [#(#mapping),*])))
+                }
             }
         });
 
@@ -387,16 +460,12 @@ impl FromIterator<TokenStream> for CustomEnum<'_, TokenStream> {
             })
             .collect::<TokenStream>();
 
-        let mut tokens = TokenStream::new();
-
-        tokens.extend(quote! {
-            Into::<utoipa::openapi::schema::OneOfBuilder>::into(utoipa::openapi::OneOf::with_capacity(#len))
-                #items
-        });
-
         CustomEnum {
-            items: tokens,
+            items,
+            len,
+            any_of: false,
             tag: None,
+            mapping: Vec::new(),
         }
     }
 }
@@ -1,15 +1,21 @@
+use std::mem;
+
 use syn::{
     parse::{Parse, ParseBuffer, ParseStream},
+    spanned::Spanned,
     Attribute,
 };
 
 use crate::{
     component::features::{
-        impl_into_inner, impl_merge, parse_features, AdditionalProperties, As, Default, Deprecated,
-        Example, ExclusiveMaximum, ExclusiveMinimum, Feature, Format, Inline, IntoInner, MaxItems,
-        MaxLength, MaxProperties, Maximum, Merge, MinItems, MinLength, MinProperties, Minimum,
-        MultipleOf, Nullable, Pattern, ReadOnly, Rename, RenameAll, Required, SchemaWith, Title,
-        ValueType, WriteOnly, XmlAttr,
+        impl_into_inner, impl_merge, parse_features, pop_feature_as_inner, AdditionalProperties,
+        AdditionalPropertiesDescription, As, AsConst, Bound, ContentEncoding, ContentMediaType,
+        DecimalAs, Default, Deprecated, Discriminator, Example, ExampleWith, Examples,
+        ExclusiveMaximum, ExclusiveMinimum, Extensions, Feature, FeaturesExt, Format, Ignore,
+        Inline, IntoInner, MaxItems, MaxLength, MaxProperties, Maximum, Merge, MinItems, MinLength,
+        MinProperties, Minimum, MultipleOf, NoRecursion, Nullable, Pattern, ReadOnly, Rename,
+        RenameAll, RenameAllFields, RenameContext, Required, SchemaWith, Title, UniqueItems,
+        UntaggedAs, ValueType, VariantsAsOneOf, WriteOnly, XmlAttr,
     },
     Diagnostics,
 };
@@ -21,14 +27,24 @@ impl Parse for NamedFieldStructFeatures {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         Ok(NamedFieldStructFeatures(parse_features!(
             input as Example,
+            Examples,
+            ExampleWith,
             XmlAttr,
             Title,
             RenameAll,
+            RenameContext,
             MaxProperties,
             MinProperties,
             As,
+            Inline,
+            Bound,
+            Rename,
             Default,
-            Deprecated
+            AdditionalProperties,
+            ReadOnly,
+            WriteOnly,
+            Deprecated,
+            Extensions
         )))
     }
 }
@@ -42,12 +58,22 @@ impl Parse for UnnamedFieldStructFeatures {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         Ok(UnnamedFieldStructFeatures(parse_features!(
             input as Example,
+            Examples,
+            ExampleWith,
             Default,
             Title,
             Format,
             ValueType,
             As,
-            Deprecated
+            Bound,
+            Rename,
+            Deprecated,
+            MultipleOf,
+            MaxLength,
+            MinLength,
+            ContentEncoding,
+            ContentMediaType,
+            Extensions
         )))
     }
 }
@@ -60,10 +86,18 @@ impl Parse for EnumFeatures {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         Ok(EnumFeatures(parse_features!(
             input as Example,
+            Examples,
+            ExampleWith,
             Default,
             Title,
             RenameAll,
+            RenameContext,
             As,
+            Inline,
+            Bound,
+            Rename,
+            AsConst,
+            VariantsAsOneOf,
             Deprecated
         )))
     }
@@ -77,9 +111,18 @@ impl Parse for ComplexEnumFeatures {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         Ok(ComplexEnumFeatures(parse_features!(
             input as Example,
+            Examples,
+            ExampleWith,
             Default,
             RenameAll,
+            RenameAllFields,
+            RenameContext,
             As,
+            Inline,
+            Bound,
+            Rename,
+            UntaggedAs,
+            Discriminator,
             Deprecated
         )))
     }
@@ -93,6 +136,8 @@ impl Parse for NamedFieldFeatures {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         Ok(NamedFieldFeatures(parse_features!(
             input as Example,
+            Examples,
+            ExampleWith,
             ValueType,
             Format,
             Default,
@@ -100,6 +145,7 @@ impl Parse for NamedFieldFeatures {
             ReadOnly,
             XmlAttr,
             Inline,
+            NoRecursion,
             Nullable,
             Rename,
             MultipleOf,
@@ -110,30 +156,59 @@ impl Parse for NamedFieldFeatures {
             MaxLength,
             MinLength,
             Pattern,
+            ContentEncoding,
+            ContentMediaType,
             MaxItems,
             MinItems,
+            UniqueItems,
+            MaxProperties,
+            MinProperties,
             SchemaWith,
             AdditionalProperties,
+            AdditionalPropertiesDescription,
             Required,
-            Deprecated
+            Ignore,
+            DecimalAs,
+            Deprecated,
+            Extensions
         )))
     }
 }
 
 impl_into_inner!(NamedFieldFeatures);
 
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct UnnamedFieldFeatures(Vec<Feature>);
+
+impl Parse for UnnamedFieldFeatures {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(UnnamedFieldFeatures(parse_features!(input as Title)))
+    }
+}
+
+impl_into_inner!(UnnamedFieldFeatures);
+
 pub struct EnumNamedFieldVariantFeatures(Vec<Feature>);
 
 impl Parse for EnumNamedFieldVariantFeatures {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        Ok(EnumNamedFieldVariantFeatures(parse_features!(
+        let features = parse_features!(
             input as Example,
+            Examples,
             XmlAttr,
             Title,
             Rename,
             RenameAll,
+            Default,
+            As,
             Deprecated
-        )))
+        );
+
+        // named field variants are inlined thus `as` names the variant schema with title
+        Ok(EnumNamedFieldVariantFeatures(replace_variant_as(
+            features,
+            |schema_as| Feature::Title(schema_as.to_title()),
+        )?))
     }
 }
 
@@ -143,20 +218,54 @@ pub struct EnumUnnamedFieldVariantFeatures(Vec<Feature>);
 
 impl Parse for EnumUnnamedFieldVariantFeatures {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        Ok(EnumUnnamedFieldVariantFeatures(parse_features!(
+        let features = parse_features!(
             input as Example,
+            Examples,
             Default,
             Title,
             Format,
             ValueType,
+            Inline,
             Rename,
+            As,
             Deprecated
-        )))
+        );
+
+        // unnamed field variants reference the payload schema by the `as` name
+        Ok(EnumUnnamedFieldVariantFeatures(replace_variant_as(
+            features,
+            |schema_as| Feature::ValueType(schema_as.to_value_type()),
+        )?))
     }
 }
 
 impl_into_inner!(EnumUnnamedFieldVariantFeatures);
 
+/// Replace variant level `as` with the feature naming the schema of the variant. Cannot be used
+/// together with explicitly defined feature of the same kind.
+fn replace_variant_as(
+    mut features: Vec<Feature>,
+    into_feature: impl FnOnce(&As) -> Feature,
+) -> syn::Result<Vec<Feature>> {
+    let Some(schema_as) = pop_feature_as_inner!(features => Feature::As(_v)) else {
+        return Ok(features);
+    };
+    let feature = into_feature(&schema_as);
+
+    if let Some(defined) = features
+        .iter()
+        .find(|defined| mem::discriminant(*defined) == mem::discriminant(&feature))
+    {
+        return Err(syn::Error::new(
+            schema_as.0.span(),
+            format!("`as` cannot be used together with `{defined}` on enum variant"),
+        ));
+    }
+    features.push(feature);
+
+    Ok(features)
+}
+
 pub trait FromAttributes {
     fn parse_features<T>(&self) -> Result<Option<T>, Diagnostics>
     where
@@ -187,6 +296,7 @@ impl_merge!(
     EnumFeatures,
     ComplexEnumFeatures,
     NamedFieldFeatures,
+    UnnamedFieldFeatures,
     EnumNamedFieldVariantFeatures,
     EnumUnnamedFieldVariantFeatures
 );
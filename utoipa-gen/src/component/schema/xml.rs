@@ -23,6 +23,16 @@ impl XmlAttr {
             ..Default::default()
         }
     }
+
+    /// Check whether none of the xml attributes are defined.
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && self.namespace.is_none()
+            && self.prefix.is_none()
+            && !self.is_attribute
+            && self.is_wrapped.is_none()
+            && self.wrap_name.is_none()
+    }
 }
 
 impl Parse for XmlAttr {
@@ -2,22 +2,31 @@
 
 use std::str::FromStr;
 
-use proc_macro2::{Ident, Span, TokenTree};
+use proc_macro2::{Delimiter, Ident, Span, TokenTree};
 use syn::{buffer::Cursor, Attribute, Error};
 
 use crate::Diagnostics;
 
+use super::features::RenameContext;
+use super::push_warning;
+
+/// Get span of the serde attribute keyword `token` or the call site if there is none.
+fn token_span(token: &Option<Ident>) -> Span {
+    token
+        .as_ref()
+        .map(Ident::span)
+        .unwrap_or_else(Span::call_site)
+}
+
 #[inline]
 fn parse_next_lit_str(next: Cursor) -> Option<(String, Span)> {
     match next.token_tree() {
-        Some((tt, next)) => match tt {
-            TokenTree::Punct(punct) if punct.as_char() == '=' => parse_next_lit_str(next),
-            TokenTree::Literal(literal) => {
-                Some((literal.to_string().replace('\"', ""), literal.span()))
-            }
-            _ => None,
-        },
-        _ => None,
+        Some((TokenTree::Punct(punct), next)) if punct.as_char() == '=' => parse_next_lit_str(next),
+        // literal is looked up through `None` delimited groups as well which are produced e.g. by
+        // `macro_rules!` fragments
+        _ => next
+            .literal()
+            .map(|(literal, _)| (literal.to_string().replace('\"', ""), literal.span())),
     }
 }
 
@@ -26,15 +35,71 @@ fn parse_next_lit_str(next: Cursor) -> Option<(String, Span)> {
 #[cfg_attr(test, derive(PartialEq, Eq))]
 pub struct SerdeValue {
     pub skip: bool,
+    pub skip_serializing: bool,
+    pub skip_deserializing: bool,
     pub rename: Option<String>,
+    pub rename_deserialize: Option<String>,
+    /// The _`rename`_ keyword of the serde attribute used to locate the warnings of the rename.
+    pub rename_token: Option<Ident>,
     pub default: bool,
     pub flatten: bool,
     pub skip_serializing_if: bool,
     pub double_option: bool,
+    pub aliases: Vec<String>,
 }
 
 impl SerdeValue {
     const SERDE_WITH_DOUBLE_OPTION: &'static str = "::serde_with::rust::double_option";
+
+    /// Check whether the value is skipped in either serialization or deserialization.
+    pub fn is_skipped(&self) -> bool {
+        self.skip || self.skip_serializing || self.skip_deserializing
+    }
+
+    /// Get serde rename value for given [`RenameContext`]. If no context is provided the
+    /// _`serialize`_ name is used and a warning is emitted if the names differ.
+    pub fn rename_for(&self, rename_context: Option<&RenameContext>) -> Option<&str> {
+        match rename_context {
+            Some(RenameContext::Deserialize) => self.rename_deserialize.as_deref(),
+            Some(RenameContext::Serialize) => self.rename.as_deref(),
+            None => {
+                if self.rename != self.rename_deserialize {
+                    let name = |name: Option<&str>| {
+                        name.map(|name| format!("`{name}`"))
+                            .unwrap_or_else(|| String::from("the original name"))
+                    };
+                    push_warning(
+                        token_span(&self.rename_token),
+                        format!(
+                            "serde `rename` uses {} when serializing and {} when deserializing, \
+                            the schema uses the serialize name. Use `rename_context` to choose the name",
+                            name(self.rename.as_deref()),
+                            name(self.rename_deserialize.as_deref())
+                        ),
+                    );
+                }
+                self.rename.as_deref()
+            }
+        }
+    }
+
+    /// Parse _`serialize`_ and _`deserialize`_ names from serde _`rename(...)`_ attribute.
+    fn parse_rename_group(&mut self, cursor: Cursor) {
+        let mut rest = cursor;
+        while let Some((tt, next)) = rest.token_tree() {
+            match tt {
+                TokenTree::Ident(ident) if ident == "serialize" => {
+                    self.rename = parse_next_lit_str(next).map(|(literal, _)| literal);
+                }
+                TokenTree::Ident(ident) if ident == "deserialize" => {
+                    self.rename_deserialize = parse_next_lit_str(next).map(|(literal, _)| literal);
+                }
+                _ => (),
+            }
+
+            rest = next;
+        }
+    }
 }
 
 impl SerdeValue {
@@ -45,12 +110,12 @@ impl SerdeValue {
             let mut rest = *cursor;
             while let Some((tt, next)) = rest.token_tree() {
                 match tt {
-                    TokenTree::Ident(ident)
-                        if ident == "skip"
-                            || ident == "skip_serializing"
-                            || ident == "skip_deserializing" =>
-                    {
-                        value.skip = true
+                    TokenTree::Ident(ident) if ident == "skip" => value.skip = true,
+                    TokenTree::Ident(ident) if ident == "skip_serializing" => {
+                        value.skip_serializing = true
+                    }
+                    TokenTree::Ident(ident) if ident == "skip_deserializing" => {
+                        value.skip_deserializing = true
                     }
                     TokenTree::Ident(ident) if ident == "skip_serializing_if" => {
                         value.skip_serializing_if = true
@@ -68,11 +133,20 @@ impl SerdeValue {
                     }
                     TokenTree::Ident(ident) if ident == "flatten" => value.flatten = true,
                     TokenTree::Ident(ident) if ident == "rename" => {
+                        value.rename_token = Some(ident);
                         if let Some((literal, _)) = parse_next_lit_str(next) {
-                            value.rename = Some(literal)
+                            value.rename_deserialize = Some(literal.clone());
+                            value.rename = Some(literal);
+                        } else if let Some((group, _, _)) = next.group(Delimiter::Parenthesis) {
+                            value.parse_rename_group(group);
                         };
                     }
                     TokenTree::Ident(ident) if ident == "default" => value.default = true,
+                    TokenTree::Ident(ident) if ident == "alias" => {
+                        if let Some((literal, _)) = parse_next_lit_str(next) {
+                            value.aliases.push(literal);
+                        }
+                    }
                     _ => (),
                 }
 
@@ -115,29 +189,143 @@ pub enum SerdeEnumRepr {
 #[cfg_attr(test, derive(PartialEq, Eq))]
 pub struct SerdeContainer {
     pub rename_all: Option<RenameRule>,
+    pub rename_all_deserialize: Option<RenameRule>,
+    pub rename_all_fields: Option<RenameRule>,
+    pub rename_all_fields_deserialize: Option<RenameRule>,
+    /// The _`rename_all`_ and _`rename_all_fields`_ keywords of the serde attributes used to
+    /// locate the warnings of the rules.
+    pub rename_all_token: Option<Ident>,
+    pub rename_all_fields_token: Option<Ident>,
     pub enum_repr: SerdeEnumRepr,
     pub default: bool,
     pub deny_unknown_fields: bool,
+    pub transparent: bool,
 }
 
 impl SerdeContainer {
+    /// Get serde rename all rule for given [`RenameContext`]. If no context is provided the
+    /// _`serialize`_ rule is used and a warning is emitted if the rules differ.
+    pub fn rename_all_for(&self, rename_context: Option<&RenameContext>) -> Option<&RenameRule> {
+        Self::rule_for(
+            (&self.rename_all, &self.rename_all_deserialize),
+            &self.rename_all_token,
+            rename_context,
+        )
+    }
+
+    /// Get serde rename all fields rule of enum variants for given [`RenameContext`]. If no
+    /// context is provided the _`serialize`_ rule is used and a warning is emitted if the rules
+    /// differ.
+    pub fn rename_all_fields_for(
+        &self,
+        rename_context: Option<&RenameContext>,
+    ) -> Option<&RenameRule> {
+        Self::rule_for(
+            (&self.rename_all_fields, &self.rename_all_fields_deserialize),
+            &self.rename_all_fields_token,
+            rename_context,
+        )
+    }
+
+    fn rule_for<'r>(
+        (serialize, deserialize): (&'r Option<RenameRule>, &'r Option<RenameRule>),
+        token: &Option<Ident>,
+        rename_context: Option<&RenameContext>,
+    ) -> Option<&'r RenameRule> {
+        match rename_context {
+            Some(RenameContext::Deserialize) => deserialize.as_ref(),
+            Some(RenameContext::Serialize) => serialize.as_ref(),
+            None => {
+                if serialize != deserialize {
+                    let rule = |rule: &Option<RenameRule>| {
+                        rule.as_ref()
+                            .map(|rule| format!("`{}`", rule.as_str()))
+                            .unwrap_or_else(|| String::from("no rule"))
+                    };
+                    let attribute = token.as_ref().map(ToString::to_string).unwrap_or_default();
+                    push_warning(
+                        token_span(token),
+                        format!(
+                            "serde `{attribute}` uses {} when serializing and {} when deserializing, \
+                            the schema uses the serialize rule. Use `rename_context` to choose the rule",
+                            rule(serialize),
+                            rule(deserialize)
+                        ),
+                    );
+                }
+                serialize.as_ref()
+            }
+        }
+    }
+
+    /// Parse _`serialize`_ and _`deserialize`_ rules from serde _`rename_all(...)`_ or
+    /// _`rename_all_fields(...)`_ attribute.
+    fn parse_rename_rule_group(
+        cursor: Cursor,
+    ) -> syn::Result<(Option<RenameRule>, Option<RenameRule>)> {
+        let mut rules = (None, None);
+        let mut rest = cursor;
+        while let Some((tt, next)) = rest.token_tree() {
+            if let TokenTree::Ident(ident) = tt {
+                if ident == "serialize" || ident == "deserialize" {
+                    let rule = parse_next_lit_str(next)
+                        .map(|(literal, span)| {
+                            literal
+                                .parse::<RenameRule>()
+                                .map_err(|error| Error::new(span, error.to_string()))
+                        })
+                        .transpose()?;
+
+                    if ident == "serialize" {
+                        rules.0 = rule;
+                    } else {
+                        rules.1 = rule;
+                    }
+                }
+            }
+
+            rest = next;
+        }
+
+        Ok(rules)
+    }
+
+    /// Parse _`serialize`_ and _`deserialize`_ rules of _`rename_all = ...`_ or
+    /// _`rename_all(...)`_ styled attribute.
+    fn parse_rename_rules(next: Cursor) -> syn::Result<(Option<RenameRule>, Option<RenameRule>)> {
+        if let Some((literal, span)) = parse_next_lit_str(next) {
+            let rule = literal
+                .parse::<RenameRule>()
+                .map_err(|error| Error::new(span, error.to_string()))?;
+            Ok((Some(rule.clone()), Some(rule)))
+        } else if let Some((group, _, _)) = next.group(Delimiter::Parenthesis) {
+            Self::parse_rename_rule_group(group)
+        } else {
+            Ok((None, None))
+        }
+    }
+
     /// Parse a single serde attribute, currently supported attributes are:
     ///     * `rename_all = ...`
+    ///     * `rename_all(serialize = ..., deserialize = ...)`
+    ///     * `rename_all_fields = ...`
+    ///     * `rename_all_fields(serialize = ..., deserialize = ...)`
     ///     * `tag = ...`
     ///     * `content = ...`
     ///     * `untagged = ...`
     ///     * `default = ...`
     ///     * `deny_unknown_fields`
+    ///     * `transparent`
     fn parse_attribute(&mut self, ident: Ident, next: Cursor) -> syn::Result<()> {
         match ident.to_string().as_str() {
             "rename_all" => {
-                if let Some((literal, span)) = parse_next_lit_str(next) {
-                    self.rename_all = Some(
-                        literal
-                            .parse::<RenameRule>()
-                            .map_err(|error| Error::new(span, error.to_string()))?,
-                    );
-                }
+                (self.rename_all, self.rename_all_deserialize) = Self::parse_rename_rules(next)?;
+                self.rename_all_token = Some(ident);
+            }
+            "rename_all_fields" => {
+                (self.rename_all_fields, self.rename_all_fields_deserialize) =
+                    Self::parse_rename_rules(next)?;
+                self.rename_all_fields_token = Some(ident);
             }
             "tag" => {
                 if let Some((literal, span)) = parse_next_lit_str(next) {
@@ -192,6 +380,9 @@ impl SerdeContainer {
             "deny_unknown_fields" => {
                 self.deny_unknown_fields = true;
             }
+            "transparent" => {
+                self.transparent = true;
+            }
             _ => {}
         }
         Ok(())
@@ -232,12 +423,24 @@ pub fn parse_value(attributes: &[Attribute]) -> Result<SerdeValue, Diagnostics>
             if value.skip {
                 acc.skip = value.skip;
             }
+            if value.skip_serializing {
+                acc.skip_serializing = value.skip_serializing;
+            }
+            if value.skip_deserializing {
+                acc.skip_deserializing = value.skip_deserializing;
+            }
             if value.skip_serializing_if {
                 acc.skip_serializing_if = value.skip_serializing_if;
             }
             if value.rename.is_some() {
                 acc.rename = value.rename;
             }
+            if value.rename_deserialize.is_some() {
+                acc.rename_deserialize = value.rename_deserialize;
+            }
+            if value.rename_token.is_some() {
+                acc.rename_token = value.rename_token;
+            }
             if value.flatten {
                 acc.flatten = value.flatten;
             }
@@ -247,6 +450,7 @@ pub fn parse_value(attributes: &[Attribute]) -> Result<SerdeValue, Diagnostics>
             if value.double_option {
                 acc.double_option = value.double_option;
             }
+            acc.aliases.extend(value.aliases);
 
             acc
         }))
@@ -270,6 +474,9 @@ pub fn parse_container(attributes: &[Attribute]) -> Result<SerdeContainer, Diagn
             if value.deny_unknown_fields {
                 acc.deny_unknown_fields = value.deny_unknown_fields;
             }
+            if value.transparent {
+                acc.transparent = value.transparent;
+            }
             match value.enum_repr {
                 SerdeEnumRepr::ExternallyTagged => {}
                 SerdeEnumRepr::Untagged
@@ -282,14 +489,28 @@ pub fn parse_container(attributes: &[Attribute]) -> Result<SerdeContainer, Diagn
             if value.rename_all.is_some() {
                 acc.rename_all = value.rename_all;
             }
+            if value.rename_all_deserialize.is_some() {
+                acc.rename_all_deserialize = value.rename_all_deserialize;
+            }
+            if value.rename_all_fields.is_some() {
+                acc.rename_all_fields = value.rename_all_fields;
+            }
+            if value.rename_all_fields_deserialize.is_some() {
+                acc.rename_all_fields_deserialize = value.rename_all_fields_deserialize;
+            }
+            if value.rename_all_token.is_some() {
+                acc.rename_all_token = value.rename_all_token;
+            }
+            if value.rename_all_fields_token.is_some() {
+                acc.rename_all_fields_token = value.rename_all_fields_token;
+            }
 
             acc
         }))
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[cfg_attr(test, derive(PartialEq, Eq))]
 pub enum RenameRule {
     Lower,
     Upper,
@@ -329,8 +550,12 @@ impl RenameRule {
             RenameRule::Snake => value.to_string(),
             RenameRule::ScreamingSnake => Self::Snake.rename(value).to_ascii_uppercase(),
             RenameRule::Pascal => {
-                let mut pascal_case = String::from(&value[..1].to_ascii_uppercase());
-                pascal_case.push_str(&Self::Camel.rename(&value[1..]));
+                let mut chars = value.chars();
+                let mut pascal_case = chars
+                    .next()
+                    .map(|first| first.to_ascii_uppercase().to_string())
+                    .unwrap_or_default();
+                pascal_case.push_str(&Self::Camel.rename(chars.as_str()));
 
                 pascal_case
             }
@@ -339,15 +564,27 @@ impl RenameRule {
         }
     }
 
+    /// Get the name of the rule as it is defined in serde attributes.
+    fn as_str(&self) -> &'static str {
+        RENAME_RULE_NAME_MAPPING
+            .iter()
+            .find_map(|(name, rule)| (rule == self).then_some(*name))
+            .unwrap_or_default()
+    }
+
     pub fn rename_variant(&self, variant: &str) -> String {
         match self {
             RenameRule::Lower => variant.to_ascii_lowercase(),
             RenameRule::Upper => variant.to_ascii_uppercase(),
             RenameRule::Camel => {
-                let mut snake_case = String::from(&variant[..1].to_ascii_lowercase());
-                snake_case.push_str(&variant[1..]);
+                let mut chars = variant.chars();
+                let mut camel_case = chars
+                    .next()
+                    .map(|first| first.to_ascii_lowercase().to_string())
+                    .unwrap_or_default();
+                camel_case.push_str(chars.as_str());
 
-                snake_case
+                camel_case
             }
             RenameRule::Snake => {
                 let mut snake_case = String::new();
@@ -403,7 +640,12 @@ impl FromStr for RenameRule {
 
 #[cfg(test)]
 mod tests {
-    use super::{parse_container, RenameRule, SerdeContainer, RENAME_RULE_NAME_MAPPING};
+    use super::{
+        parse_container, parse_value, RenameRule, SerdeContainer, RENAME_RULE_NAME_MAPPING,
+    };
+    use crate::component::features::RenameContext;
+    use proc_macro2::{Delimiter, Group, Ident, Span};
+    use quote::quote;
     use syn::{parse_quote, Attribute};
 
     macro_rules! test_rename_rule {
@@ -448,6 +690,9 @@ mod tests {
         RenameRule::ScreamingSnake=> "multi_value" = "MULTI_VALUE"
         RenameRule::Kebab=> "multi_value" = "multi-value"
         RenameRule::ScreamingKebab=> "multi_value" = "MULTI-VALUE"
+
+        RenameRule::Pascal=> "ä_value" = "äValue"
+        RenameRule::Pascal=> "" = ""
     }
 
     test_rename_variant_rule! {
@@ -468,6 +713,8 @@ mod tests {
         RenameRule::ScreamingSnake=> "MultiValue" = "MULTI_VALUE"
         RenameRule::Kebab=> "MultiValue" = "multi-value"
         RenameRule::ScreamingKebab=> "MultiValue" = "MULTI-VALUE"
+
+        RenameRule::Camel=> "ÄValue" = "ÄValue"
     }
 
     #[test]
@@ -488,6 +735,9 @@ mod tests {
         let deny_unknown_fields_attribute: syn::Attribute = parse_quote! {
             #[serde(deny_unknown_fields)]
         };
+        let transparent_attribute: syn::Attribute = parse_quote! {
+            #[serde(transparent)]
+        };
         let unsupported_attribute: syn::Attribute = parse_quote! {
             #[serde(expecting = "...")]
         };
@@ -495,16 +745,143 @@ mod tests {
             default_attribute_1,
             default_attribute_2,
             deny_unknown_fields_attribute,
+            transparent_attribute,
             unsupported_attribute,
         ];
 
         let expected = SerdeContainer {
             default: true,
             deny_unknown_fields: true,
+            transparent: true,
             ..Default::default()
         };
 
         let result = parse_container(attributes).expect("parse succes");
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn test_serde_parse_container_rename_all_serialize_deserialize() {
+        let attributes: &[Attribute] = &[parse_quote! {
+            #[serde(rename_all(serialize = "camelCase", deserialize = "snake_case"))]
+        }];
+
+        let result = parse_container(attributes).expect("parse success");
+        assert_eq!(
+            SerdeContainer {
+                rename_all: Some(RenameRule::Camel),
+                rename_all_deserialize: Some(RenameRule::Snake),
+                rename_all_token: Some(Ident::new("rename_all", Span::call_site())),
+                ..Default::default()
+            },
+            result
+        );
+        assert_eq!(Some(&RenameRule::Camel), result.rename_all_for(None));
+        assert_eq!(
+            Some(&RenameRule::Snake),
+            result.rename_all_for(Some(&RenameContext::Deserialize))
+        );
+
+        let attributes: &[Attribute] = &[parse_quote! {
+            #[serde(rename_all(deserialize = "kebab-case"))]
+        }];
+        let result = parse_container(attributes).expect("parse success");
+        assert_eq!(None, result.rename_all_for(None));
+        assert_eq!(
+            Some(&RenameRule::Kebab),
+            result.rename_all_for(Some(&RenameContext::Deserialize))
+        );
+    }
+
+    #[test]
+    fn test_serde_parse_container_rename_all_fields() {
+        let attributes: &[Attribute] = &[parse_quote! {
+            #[serde(rename_all = "snake_case", rename_all_fields = "camelCase")]
+        }];
+
+        let result = parse_container(attributes).expect("parse success");
+        assert_eq!(Some(&RenameRule::Snake), result.rename_all_for(None));
+        assert_eq!(Some(&RenameRule::Camel), result.rename_all_fields_for(None));
+        assert_eq!(
+            Some(&RenameRule::Camel),
+            result.rename_all_fields_for(Some(&RenameContext::Deserialize))
+        );
+
+        let attributes: &[Attribute] = &[parse_quote! {
+            #[serde(rename_all_fields(serialize = "PascalCase"))]
+        }];
+        let result = parse_container(attributes).expect("parse success");
+        assert_eq!(None, result.rename_all_for(None));
+        assert_eq!(
+            Some(&RenameRule::Pascal),
+            result.rename_all_fields_for(None)
+        );
+        assert_eq!(
+            None,
+            result.rename_all_fields_for(Some(&RenameContext::Deserialize))
+        );
+    }
+
+    #[test]
+    fn test_serde_parse_container_rename_all_in_none_delimited_group() {
+        // `macro_rules!` fragments are passed to the derive within `None` delimited groups
+        let rename_all = Group::new(Delimiter::None, quote! { "SCREAMING-KEBAB-CASE" });
+        let attributes: &[Attribute] = &[parse_quote! {
+            #[serde(rename_all = #rename_all)]
+        }];
+
+        let expected = SerdeContainer {
+            rename_all: Some(RenameRule::ScreamingKebab),
+            rename_all_deserialize: Some(RenameRule::ScreamingKebab),
+            rename_all_token: Some(Ident::new("rename_all", Span::call_site())),
+            ..Default::default()
+        };
+
+        let result = parse_container(attributes).expect("parse success");
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_serde_parse_value_aliases() {
+        let attributes: &[Attribute] = &[
+            parse_quote! {
+                #[serde(alias = "old_name", alias = "legacy_name")]
+            },
+            parse_quote! {
+                #[serde(alias = "older_name")]
+            },
+        ];
+
+        let value = parse_value(attributes).expect("parse success");
+        assert_eq!(value.aliases, ["old_name", "legacy_name", "older_name"]);
+    }
+
+    #[test]
+    fn test_serde_parse_value_split_rename() {
+        let attributes: &[Attribute] = &[parse_quote! {
+            #[serde(rename(serialize = "ser_name", deserialize = "de_name"))]
+        }];
+
+        let value = parse_value(attributes).expect("parse success");
+        assert_eq!(value.rename_for(None), Some("ser_name"));
+        assert_eq!(
+            value.rename_for(Some(&RenameContext::Serialize)),
+            Some("ser_name")
+        );
+        assert_eq!(
+            value.rename_for(Some(&RenameContext::Deserialize)),
+            Some("de_name")
+        );
+
+        let attributes: &[Attribute] = &[parse_quote! {
+            #[serde(rename = "name")]
+        }];
+
+        let value = parse_value(attributes).expect("parse success");
+        assert_eq!(value.rename_for(None), Some("name"));
+        assert_eq!(
+            value.rename_for(Some(&RenameContext::Deserialize)),
+            Some("name")
+        );
+    }
 }
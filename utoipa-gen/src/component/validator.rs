@@ -0,0 +1,292 @@
+//! Provides `validator` crate related features parsing `#[validate(...)]` attributes of fields.
+
+use proc_macro2::{Ident, TokenStream};
+use syn::{
+    meta::ParseNestedMeta, parenthesized, Attribute, Expr, ExprLit, ExprUnary, Lit, Token, UnOp,
+};
+
+use crate::{schema_type::SchemaType, Diagnostics};
+
+use super::{
+    features::{
+        ExclusiveMaximum, ExclusiveMinimum, Feature, MaxItems, MaxLength, Maximum, MinItems,
+        MinLength, Minimum,
+    },
+    GenericType, TypeTree, ValueType,
+};
+
+/// Bounds of `validator` crate's _`length(...)`_ validation.
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Length {
+    min: Option<(usize, Ident)>,
+    max: Option<(usize, Ident)>,
+}
+
+impl Length {
+    /// Parse _`length(...)`_ of _`#[validate(...)]`_ attributes. Only integer literal bounds are
+    /// supported, other validations and bounds given as expressions are ignored.
+    pub fn from_attributes(attributes: &[Attribute]) -> Result<Option<Self>, Diagnostics> {
+        let mut length = Length::default();
+        let has_length = parse_validation(attributes, "length", |ident, meta| {
+            match &*ident.to_string() {
+                "min" => length.min = parse_usize(meta)?.map(|min| (min, ident)),
+                "max" => length.max = parse_usize(meta)?.map(|max| (max, ident)),
+                "equal" => {
+                    if let Some(equal) = parse_usize(meta)? {
+                        length.min = Some((equal, ident.clone()));
+                        length.max = Some((equal, ident));
+                    }
+                }
+                _ => skip_meta_value(meta)?,
+            };
+
+            Ok(())
+        })?;
+
+        Ok(has_length.map(|_| length))
+    }
+
+    /// Add the bounds as _`min_length`_ and _`max_length`_ for strings or _`min_items`_ and
+    /// _`max_items`_ for sequences unless the `features` already define them. Bounds of other
+    /// types are ignored.
+    pub fn apply_to(self, type_tree: &TypeTree, features: &mut Vec<Feature>) {
+        let type_tree = unwrap_wrapper_types(type_tree);
+        let is_sequence = matches!(
+            type_tree.generic_type,
+            Some(GenericType::Vec | GenericType::LinkedList | GenericType::Set)
+        );
+        #[cfg(feature = "smallvec")]
+        let is_sequence =
+            is_sequence || matches!(type_tree.generic_type, Some(GenericType::SmallVec));
+        let is_string = type_tree.generic_type.is_none()
+            && type_tree.value_type == ValueType::Primitive
+            && type_tree
+                .path
+                .as_ref()
+                .is_some_and(|path| SchemaType(path).is_string());
+
+        let has_feature = |matcher: fn(&Feature) -> bool| features.iter().any(matcher);
+        let (min, max): (Option<Feature>, Option<Feature>) = if is_string {
+            (
+                self.min
+                    .filter(|_| !has_feature(|f| matches!(f, Feature::MinLength(_))))
+                    .map(|(min, ident)| MinLength::new(min, ident).into()),
+                self.max
+                    .filter(|_| !has_feature(|f| matches!(f, Feature::MaxLength(_))))
+                    .map(|(max, ident)| MaxLength::new(max, ident).into()),
+            )
+        } else if is_sequence {
+            (
+                self.min
+                    .filter(|_| !has_feature(|f| matches!(f, Feature::MinItems(_))))
+                    .map(|(min, ident)| MinItems::new(min, ident).into()),
+                self.max
+                    .filter(|_| !has_feature(|f| matches!(f, Feature::MaxItems(_))))
+                    .map(|(max, ident)| MaxItems::new(max, ident).into()),
+            )
+        } else {
+            (None, None)
+        };
+
+        features.extend(min.into_iter().chain(max));
+    }
+}
+
+/// Bounds of `validator` crate's _`range(...)`_ validation.
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Range {
+    min: Option<(f64, Ident)>,
+    max: Option<(f64, Ident)>,
+    exclusive_min: Option<(f64, Ident)>,
+    exclusive_max: Option<(f64, Ident)>,
+}
+
+impl Range {
+    /// Parse _`range(...)`_ of _`#[validate(...)]`_ attributes. Only number literal bounds are
+    /// supported, other validations and bounds given as expressions are ignored.
+    pub fn from_attributes(attributes: &[Attribute]) -> Result<Option<Self>, Diagnostics> {
+        let mut range = Range::default();
+        let has_range = parse_validation(attributes, "range", |ident, meta| {
+            match &*ident.to_string() {
+                "min" => range.min = parse_f64(meta)?.map(|min| (min, ident)),
+                "max" => range.max = parse_f64(meta)?.map(|max| (max, ident)),
+                "exclusive_min" => range.exclusive_min = parse_f64(meta)?.map(|min| (min, ident)),
+                "exclusive_max" => range.exclusive_max = parse_f64(meta)?.map(|max| (max, ident)),
+                _ => skip_meta_value(meta)?,
+            };
+
+            Ok(())
+        })?;
+
+        Ok(has_range.map(|_| range))
+    }
+
+    /// Add the bounds as _`minimum`_, _`maximum`_, _`exclusive_minimum`_ and
+    /// _`exclusive_maximum`_ for numbers unless the `features` already define lower or upper
+    /// bound respectively. Bounds of other types are ignored.
+    pub fn apply_to(self, type_tree: &TypeTree, features: &mut Vec<Feature>) {
+        let type_tree = unwrap_wrapper_types(type_tree);
+        let is_number = type_tree.generic_type.is_none()
+            && type_tree.value_type == ValueType::Primitive
+            && type_tree
+                .path
+                .as_ref()
+                .is_some_and(|path| SchemaType(path).is_number());
+        if !is_number {
+            return;
+        }
+
+        let has_lower_bound = features
+            .iter()
+            .any(|f| matches!(f, Feature::Minimum(_) | Feature::ExclusiveMinimum(_)));
+        if !has_lower_bound {
+            if let Some((min, ident)) = self.min {
+                features.push(Minimum::new(min, ident.span()).into());
+            }
+            if let Some((min, ident)) = self.exclusive_min {
+                features.push(ExclusiveMinimum::new(min, ident.span()).into());
+            }
+        }
+
+        let has_upper_bound = features
+            .iter()
+            .any(|f| matches!(f, Feature::Maximum(_) | Feature::ExclusiveMaximum(_)));
+        if !has_upper_bound {
+            if let Some((max, ident)) = self.max {
+                features.push(Maximum::new(max, ident.span()).into());
+            }
+            if let Some((max, ident)) = self.exclusive_max {
+                features.push(ExclusiveMaximum::new(max, ident.span()).into());
+            }
+        }
+    }
+}
+
+/// `validator` crate's _`regex(...)`_ validation.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Regex(Ident);
+
+impl Regex {
+    /// Parse _`regex(...)`_ or _`regex = ...`_ of _`#[validate(...)]`_ attributes.
+    pub fn from_attributes(attributes: &[Attribute]) -> Result<Option<Self>, Diagnostics> {
+        Ok(parse_validation(attributes, "regex", |_, meta| skip_meta_value(meta))?.map(Self))
+    }
+
+    /// The regular expression is a static value which cannot be read at compile time thus the
+    /// `features` must define the _`pattern`_ explicitly.
+    pub fn validate(&self, features: &[Feature]) -> Result<(), Diagnostics> {
+        if features
+            .iter()
+            .any(|feature| matches!(feature, Feature::Pattern(_)))
+        {
+            return Ok(());
+        }
+
+        Err(Diagnostics::with_span(
+            self.0.span(),
+            "regular expression of `#[validate(regex(...))]` cannot be read at compile time",
+        )
+        .help(r#"Define the pattern of the field with `#[schema(pattern = "...")]`"#))
+    }
+}
+
+/// Parse nested meta of `name(...)` validation of _`#[validate(...)]`_ attributes with `parse`
+/// called with name of each nested meta. Validation given as _`name = value`_ is found but not
+/// parsed. Returns the name of the validation if it was found.
+fn parse_validation(
+    attributes: &[Attribute],
+    name: &str,
+    mut parse: impl FnMut(Ident, &ParseNestedMeta) -> syn::Result<()>,
+) -> Result<Option<Ident>, Diagnostics> {
+    let mut found = None;
+
+    for attribute in attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("validate"))
+    {
+        attribute
+            .parse_nested_meta(|meta| {
+                if !meta.path.is_ident(name) {
+                    return skip_meta_value(&meta);
+                }
+
+                found = meta.path.get_ident().cloned();
+                if meta.input.peek(Token![=]) {
+                    return skip_meta_value(&meta);
+                }
+                meta.parse_nested_meta(|meta| match meta.path.get_ident().cloned() {
+                    Some(ident) => parse(ident, &meta),
+                    None => skip_meta_value(&meta),
+                })
+            })
+            .map_err(Diagnostics::from)?;
+    }
+
+    Ok(found)
+}
+
+/// Get the type wrapped within _`Option`_ and smart pointers e.g. _`Box`_.
+fn unwrap_wrapper_types<'t>(type_tree: &'t TypeTree<'t>) -> &'t TypeTree<'t> {
+    let is_wrapper = match type_tree.generic_type {
+        Some(GenericType::Option | GenericType::Box | GenericType::Cow | GenericType::RefCell) => {
+            true
+        }
+        #[cfg(feature = "rc_schema")]
+        Some(GenericType::Arc | GenericType::Rc) => true,
+        _ => false,
+    };
+
+    match type_tree.children.as_deref() {
+        Some([child]) if is_wrapper => unwrap_wrapper_types(child),
+        _ => type_tree,
+    }
+}
+
+/// Parse integer literal value of the meta. Other expressions e.g. constants are ignored.
+fn parse_usize(meta: &ParseNestedMeta) -> syn::Result<Option<usize>> {
+    match meta.value()?.parse::<Expr>()? {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse().map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Parse possibly negative integer or float literal value of the meta. Other expressions e.g.
+/// constants are ignored.
+fn parse_f64(meta: &ParseNestedMeta) -> syn::Result<Option<f64>> {
+    fn literal_value(expr: &Expr) -> syn::Result<Option<f64>> {
+        match expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(int), ..
+            }) => int.base10_parse().map(Some),
+            Expr::Lit(ExprLit {
+                lit: Lit::Float(float),
+                ..
+            }) => float.base10_parse().map(Some),
+            Expr::Unary(ExprUnary {
+                op: UnOp::Neg(_),
+                expr,
+                ..
+            }) => literal_value(expr).map(|value| value.map(|value| -value)),
+            _ => Ok(None),
+        }
+    }
+
+    literal_value(&meta.value()?.parse::<Expr>()?)
+}
+
+/// Skip the value of unsupported meta which can be either _`= value`_ or _`(...)`_ list.
+fn skip_meta_value(meta: &ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        let content;
+        parenthesized!(content in meta.input);
+        content.parse::<TokenStream>()?;
+    }
+
+    Ok(())
+}
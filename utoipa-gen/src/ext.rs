@@ -120,6 +120,10 @@ impl ToTokensDiagnostics for RequestBody<'_> {
                 value_type: crate::component::ValueType::Object,
                 span: Some(path.span()),
                 path,
+                array_len: None,
+                array_len_param: None,
+                trait_object: false,
+                name_only: false,
             }
         };
 
@@ -33,7 +33,7 @@ use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     token::Bracket,
-    DeriveInput, ExprPath, ItemFn, Lit, LitStr, Member, Token,
+    DeriveInput, Expr, ItemFn, Lit, LitStr, Member, Token,
 };
 
 mod component;
@@ -48,6 +48,7 @@ use crate::path::{Path, PathAttr};
 
 use self::{
     component::{
+        collect_warnings,
         features::{self, Feature},
         ComponentSchema, ComponentSchemaProps, TypeTree,
     },
@@ -69,11 +70,14 @@ use self::{
 /// reflect to the generated OpenAPI spec.
 ///
 /// `#[deprecated]` attribute supports adding additional details such as a reason and or since version but this is is not supported in
-/// OpenAPI. OpenAPI has only a boolean flag to determine deprecation. While it is totally okay to declare deprecated with reason
-/// `#[deprecated  = "There is better way to do this"]` the reason would not render in OpenAPI spec.
+/// OpenAPI. OpenAPI has only a boolean flag to determine deprecation. However on struct and enum level the reason e.g.
+/// `#[deprecated  = "There is better way to do this"]` or `#[deprecated(note = "There is better way to do this")]` will be
+/// appended to the description of the schema as _`Deprecated: There is better way to do this`_.
 ///
 /// Doc comments on fields will resolve to field descriptions in generated OpenAPI doc. On struct
-/// level doc comments will resolve to object descriptions.
+/// level doc comments will resolve to object descriptions. Fields referencing other schemas are
+/// wrapped with _`allOf`_ to carry the description since OpenAPI 3.0 ignores siblings of _`$ref`_.
+/// With _`openapi_31`_ feature the description is rendered next to the _`$ref`_ instead.
 ///
 /// ```rust
 /// /// This is a pet
@@ -84,9 +88,32 @@ use self::{
 /// }
 /// ```
 ///
+/// Unsigned integer fields get _`minimum`_ of _`0`_ unless explicit minimum is defined. The
+/// `std::num::NonZero*` integer types are rendered as their primitive integer type and unsigned
+/// ones get _`minimum`_ of _`1`_ instead. Signed non zero integers cannot be described with JSON
+/// Schema keywords thus _`Value must not be zero`_ is appended to the description of the field.
+///
+/// Dynamic JSON types of _`serde_json`_ are recognized without _`value_type`_ override.
+/// _`Value`_ is rendered as any value, _`Map<String, Value>`_ as an object with free form
+/// _`additionalProperties`_ and _`Number`_ as a _`number`_.
+///
+/// Smart pointers `Box<T>`, `Rc<T>` and `Arc<T>` (the latter two with `rc_schema` feature) are
+/// transparent and render the schema of `T`. Trait objects such as `Box<dyn Trait>` do not have a
+/// schema and will result a compile error unless the field defines `value_type` or `schema_with`.
+///
 /// # Struct Optional Configuration Options for `#[schema(...)]`
 /// * `example = ...` Can be _`json!(...)`_. _`json!(...)`_ should be something that
-///   _`serde_json::json!`_ can parse as a _`serde_json::Value`_.
+///   _`serde_json::json!`_ can parse as a _`serde_json::Value`_. Can also be method reference,
+///   constant e.g. _`Self::SAMPLE`_ or other expression e.g. _`Self::new("bob")`_ whose value is
+///   serialized with _`serde_json`_ at runtime.
+/// * `example` Without a value the struct's [`Default`] implementation is serialized with
+///   _`serde_json`_ and used as the _`example`_. Requires the struct to implement [`Default`] and
+///   _`serde::Serialize`_.
+/// * `examples(...)` Comma separated list of examples. Each example can be method reference or
+///   _`json!(...)`_. E.g _`examples(json!(1), json!(2))`_. Renders as OpenAPI 3.1 _`examples`_
+///   array thus requires _`openapi_31`_ feature.
+/// * `example_with = ...` Function reference returning _`serde_json::Value`_ which is called at
+///   runtime and used as the _`example`_. E.g _`example_with = path::to::example_fn`_.
 /// * `xml(...)` Can be used to define [`Xml`][xml] object properties applicable to Structs.
 /// * `title = ...` Literal string value. Can be used to define title for struct in OpenAPI
 ///   document. Some OpenAPI code generation libraries also use this field as a name for the
@@ -94,17 +121,52 @@ use self::{
 /// * `rename_all = ...` Supports same syntax as _serde_ _`rename_all`_ attribute. Will rename all fields
 ///   of the structs accordingly. If both _serde_ `rename_all` and _schema_ _`rename_all`_ are defined
 ///   __serde__ will take precedence.
+/// * `rename_context = ...` Defines which name of _serde_ _`rename(serialize = "...", deserialize = "...")`_
+///   and which rule of _serde_ _`rename_all(serialize = "...", deserialize = "...")`_ will be used for
+///   the fields. Accepts either _`"serialize"`_ or _`"deserialize"`_. If not defined the _`serialize`_
+///   name will be used.
 /// * `as = ...` Can be used to define alternative path and name for the schema what will be used in
 ///   the OpenAPI. E.g _`as = path::to::Pet`_. This would make the schema appear in the generated
 ///   OpenAPI spec as _`path.to.Pet`_.
+/// * `inline` Can be used together with _`as = ...`_ to mark the schema as intended to be
+///   inlined. The schema is still registered under its name and the consumers decide whether to
+///   reference it or to inline it with field level _`inline`_ attribute. Has no effect on the
+///   generated schema itself.
+/// * `rename = ...` Literal string value. Can be used to define custom name for the schema what
+///   will be used in the OpenAPI and in the references to the schema. E.g _`rename = "Pet"`_.
+///   Fields of other schemas referencing the type resolve the custom name automatically while
+///   path operations reference schemas by name only, thus use the custom name there,
+///   e.g. _`request_body = Pet`_.
+///   __Note!__ ___Cannot be used together with _`as = ...`_.___
+/// * `bound = ...` Literal string value of additional where clause predicates for the generated
+///   `ToSchema` implementation. E.g _`bound = "T: Serialize"`_. The predicates are added to the
+///   where clause of the type in same manner as with _serde_ _`bound`_ attribute.
 /// * `default` Can be used to populate default values on all fields using the struct's
 ///   [`Default`] implementation.
 /// * `deprecated` Can be used to mark all fields as deprecated in the generated OpenAPI spec but
 ///   not in the code. If you'd like to mark the fields as deprecated in the code as well use
 ///   Rust's own `#[deprecated]` attribute instead.
+/// * `additional_properties = ...` Can be used to explicitly allow _(`true`)_ or forbid _(`false`)_
+///   additional properties of the struct, or a type e.g. _`additional_properties = Extra`_ whose
+///   schema the additional properties must match. Takes precedence over _serde_
+///   _`deny_unknown_fields`_. With flattened fields it renders as _`unevaluatedProperties`_ thus
+///   anything but _`true`_ requires _`openapi_31`_ feature. Cannot be used together with flattened
+///   map fields.
+/// * `read_only` or `write_only` Can be used to mark every field of the struct as read only or
+///   write only. Fields defining their own `read_only` or `write_only` take precedence. Fields
+///   referencing other schemas are wrapped in _`allOf`_ since `$ref` cannot carry other keywords.
+/// * `extensions(...)` Comma separated list of vendor extensions added to the schema. Each
+///   extension is a _`"x-..." = value`_ pair where value can be literal, method reference or
+///   _`json!(...)`_. E.g _`extensions("x-internal" = true, "x-owner" = json!({"team": "pets"}))`_.
+///   Extension names must start with _`x-`_.
 
 /// # Enum Optional Configuration Options for `#[schema(...)]`
 /// * `example = ...` Can be method reference or _`json!(...)`_.
+/// * `examples(...)` Comma separated list of examples. Each example can be method reference or
+///   _`json!(...)`_. E.g _`examples(json!(1), json!(2))`_. Renders as OpenAPI 3.1 _`examples`_
+///   array thus requires _`openapi_31`_ feature.
+/// * `example_with = ...` Function reference returning _`serde_json::Value`_ which is called at
+///   runtime and used as the _`example`_. E.g _`example_with = path::to::example_fn`_.
 /// * `default = ...` Can be method reference or _`json!(...)`_.
 /// * `title = ...` Literal string value. Can be used to define title for enum in OpenAPI
 ///   document. Some OpenAPI code generation libraries also use this field as a name for the
@@ -112,12 +174,55 @@ use self::{
 /// * `rename_all = ...` Supports same syntax as _serde_ _`rename_all`_ attribute. Will rename all
 ///   variants of the enum accordingly. If both _serde_ `rename_all` and _schema_ _`rename_all`_
 ///   are defined __serde__ will take precedence.
+/// * `rename_all_fields = ...` Supports same syntax as _serde_ _`rename_all_fields`_ attribute.
+///   Will rename fields of all named field variants of complex enum accordingly. Variant level
+///   _`rename_all`_ takes precedence over it. If both _serde_ `rename_all_fields` and _schema_
+///   _`rename_all_fields`_ are defined __serde__ will take precedence.
+/// * `rename_context = ...` Defines which name of _serde_ _`rename(serialize = "...", deserialize = "...")`_
+///   and which rule of _serde_ _`rename_all(serialize = "...", deserialize = "...")`_ will be used for
+///   the variants and the fields of the variants. Accepts either _`"serialize"`_ or _`"deserialize"`_.
+///   If not defined the _`serialize`_ name will be used.
 /// * `as = ...` Can be used to define alternative path and name for the schema what will be used in
 ///   the OpenAPI. E.g _`as = path::to::Pet`_. This would make the schema appear in the generated
 ///   OpenAPI spec as _`path.to.Pet`_.
+/// * `inline` Can be used together with _`as = ...`_ to mark the schema as intended to be
+///   inlined. The schema is still registered under its name and the consumers decide whether to
+///   reference it or to inline it with field level _`inline`_ attribute. Has no effect on the
+///   generated schema itself.
+/// * `rename = ...` Literal string value. Can be used to define custom name for the schema what
+///   will be used in the OpenAPI and in the references to the schema. E.g _`rename = "Pet"`_.
+///   Fields of other schemas referencing the type resolve the custom name automatically while
+///   path operations reference schemas by name only, thus use the custom name there,
+///   e.g. _`request_body = Pet`_.
+///   __Note!__ ___Cannot be used together with _`as = ...`_.___
+/// * `bound = ...` Literal string value of additional where clause predicates for the generated
+///   `ToSchema` implementation. E.g _`bound = "T: Serialize"`_. The predicates are added to the
+///   where clause of the type in same manner as with _serde_ _`bound`_ attribute.
 /// * `deprecated` Can be used to mark the enum as deprecated in the generated OpenAPI spec but
 ///   not in the code. If you'd like to mark the enum as deprecated in the code as well use
 ///   Rust's own `#[deprecated]` attribute instead.
+/// * `as_const` Can be used to render unit enum with exactly one variant as _`const`_ value instead
+///   of single value _`enum`_. The _`const`_ value respects _`rename`_ and _`rename_all`_ attributes.
+///   _`const`_ is only available in OpenAPI 3.1 thus without _`openapi_31`_ feature the value is
+///   rendered as single value _`enum`_.
+///   __Note!__ ___Only supported with unit enums using default serde enum representation.___
+/// * `variants_as_one_of` Can be used to render unit enum as _`oneOf`_ of _`const`_ values instead
+///   of compact _`enum`_ of values. Each _`const`_ value carries the doc comment of its variant as
+///   _`description`_. The values respect _`rename`_ and _`rename_all`_ attributes. Without
+///   _`openapi_31`_ feature the values are rendered as single value _`enum`_s instead of _`const`_.
+///   __Note!__ ___Only supported with unit enums using default serde enum representation.___
+/// * `untagged_as = ...` Defines whether variants of complex enum with _serde_ _`untagged`_
+///   representation are rendered as exclusive _`"oneOf"`_ or inclusive _`"anyOf"`_. Use
+///   _`"anyOf"`_ when variant schemas overlap and a value may be valid against multiple of them.
+///   If not defined _`"oneOf"`_ will be used.
+///   __Note!__ ___Only supported with complex enums using _serde_ _`untagged`_ representation.___
+/// * `discriminator = ...` Literal string value of a property shared by all variant payloads which
+///   will be used as [discriminator][discriminator] of the enum e.g. _`discriminator = "kind"`_.
+///   Inline variant payloads are checked to contain the property at compile time. Referenced
+///   payloads cannot be inspected thus they are only checked to be object types.
+///   __Note!__ ___Only supported with complex enums using _serde_ _`untagged`_ representation.
+///   Externally tagged enums wrap the payload to a property named after the variant, and
+///   internally and adjacently tagged enums use the serde _`tag`_ as discriminator.___
 ///
 /// # Enum Variant Optional Configuration Options for `#[schema(...)]`
 /// Supports all variant specific configuration options e.g. if variant is _`UnnamedStruct`_ then
@@ -127,39 +232,109 @@ use self::{
 /// _`rename`_ attribute. It behaves similarly to serde's _`rename`_ attribute. If both _serde_
 /// _`rename`_ and _schema_ _`rename`_ are defined __serde__ will take precedence.
 ///
+/// Variants of complex enum with fields also support _`as = ...`_ attribute to name the schema of
+/// the variant e.g. _`#[schema(as = path::to::Cat)]`_. Unnamed field variants reference the
+/// payload schema by the name _`path.to.Cat`_ instead of the name of the Rust type, which allows
+/// referencing payload types renamed with _`as`_. Named field variants are inlined thus the
+/// name is used as the _`title`_ of the variant schema. __Note!__ ___Cannot be used together with
+/// _`value_type`_ or _`title`_ respectively.___
+///
+/// Variants of complex enum (enum with other than unit variants) can also be marked as default
+/// variant with _`#[schema(default)]`_ or with Rust's _`#[default]`_ attribute of
+/// _`#[derive(Default)]`_. The enum must implement [`Default`] and _`serde::Serialize`_ and the
+/// serialized value of the enum's [`Default`] implementation will be used as the _`default`_ of
+/// the enum schema. The _`default`_ is left out if the [`Default`] implementation returns other
+/// than the marked variant or the value cannot be serialized. Only one variant can be marked as
+/// default. Named field variants cannot define a default value with _`default = ...`_.
+///
+/// Unnamed field variants wrapping a type implementing [`ToSchema`][to_schema] reference the
+/// payload schema by default. The payload can be inlined to the variant schema instead with
+/// _`#[schema(inline)]`_ on the variant. Inlined payloads are not included in the discriminator
+/// _`mapping`_.
+///
+/// Variants of complex enum with fields support _`example = ...`_ and _`examples(...)`_ of the
+/// variant schema e.g. _`#[schema(examples(json!({"Created": {"id": 1}})))]`_. The examples
+/// describe the serialized variant thus externally tagged variants include the variant name.
+/// _`examples(...)`_ requires _`openapi_31`_ feature.
+///
 /// # Unnamed Field Struct Optional Configuration Options for `#[schema(...)]`
 /// * `example = ...` Can be method reference or _`json!(...)`_.
+/// * `examples(...)` Comma separated list of examples. Each example can be method reference or
+///   _`json!(...)`_. E.g _`examples(json!(1), json!(2))`_. Renders as OpenAPI 3.1 _`examples`_
+///   array thus requires _`openapi_31`_ feature.
+/// * `example_with = ...` Function reference returning _`serde_json::Value`_ which is called at
+///   runtime and used as the _`example`_. E.g _`example_with = path::to::example_fn`_.
 /// * `default = ...` Can be method reference or _`json!(...)`_. If no value is specified, and the struct has
 ///   only one field, the field's default value in the schema will be set from the struct's
 ///   [`Default`] implementation.
 /// * `format = ...` May either be variant of the [`KnownFormat`][known_format] enum, or otherwise
 ///   an open value as a string. By default the format is derived from the type of the property
-///   according OpenApi spec.
+///   according OpenApi spec. E.g. _`format = "ipv4"`_ on a _`String`_ field. With arrays the
+///   format is applied to the items.
 /// * `value_type = ...` Can be used to override default type derived from type of the field used in OpenAPI spec.
 ///   This is useful in cases where the default type does not correspond to the actual type e.g. when
 ///   any third-party types are used which are not [`ToSchema`][to_schema]s nor [`primitive` types][primitive].
 ///   The value can be any Rust type what normally could be used to serialize to JSON or either virtual type _`Object`_
 ///   or _`Value`_, or an alias defined using `#[aliases(..)]`.
 ///   _`Object`_ will be rendered as generic OpenAPI object _(`type: object`)_.
-///   _`Value`_ will be rendered as any OpenAPI value (i.e. no `type` restriction).
+///   _`Value`_ or its alias _`Any`_ will be rendered as any OpenAPI value (i.e. no `type` restriction)
+///   which is also the schema of _`serde_json::Value`_.
 /// * `title = ...` Literal string value. Can be used to define title for struct in OpenAPI
 ///   document. Some OpenAPI code generation libraries also use this field as a name for the
 ///   struct.
 /// * `as = ...` Can be used to define alternative path and name for the schema what will be used in
 ///   the OpenAPI. E.g _`as = path::to::Pet`_. This would make the schema appear in the generated
 ///   OpenAPI spec as _`path.to.Pet`_.
+/// * `rename = ...` Literal string value. Can be used to define custom name for the schema what
+///   will be used in the OpenAPI and in the references to the schema. E.g _`rename = "Pet"`_.
+///   Fields of other schemas referencing the type resolve the custom name automatically while
+///   path operations reference schemas by name only, thus use the custom name there,
+///   e.g. _`request_body = Pet`_.
+///   __Note!__ ___Cannot be used together with _`as = ...`_.___
+/// * `bound = ...` Literal string value of additional where clause predicates for the generated
+///   `ToSchema` implementation. E.g _`bound = "T: Serialize"`_. The predicates are added to the
+///   where clause of the type in same manner as with _serde_ _`bound`_ attribute.
 /// * `deprecated` Can be used to mark the field as deprecated in the generated OpenAPI spec but
 ///   not in the code. If you'd like to mark the field as deprecated in the code as well use
 ///   Rust's own `#[deprecated]` attribute instead.
+/// * `multiple_of = ...` Can be used to define multiplier for a value of a newtype struct wrapping
+///   a `number`. Value is considered valid division will result an `integer`. Value must be strictly
+///   above _`0`_.
+/// * `max_length = ...` Can be used to define maximum length for newtype struct wrapping a `string`.
+/// * `min_length = ...` Can be used to define minimum length for newtype struct wrapping a `string`.
+/// * `content_encoding = ...` Can be used to define encoding of newtype struct wrapping a `string`
+///   e.g. _`"base64"`_. Requires _`openapi_31`_ feature.
+/// * `content_media_type = ...` Can be used to define media type of newtype struct wrapping a `string`
+///   e.g. _`"image/png"`_. Requires _`openapi_31`_ feature.
+/// * `extensions(...)` Comma separated list of _`"x-..." = value`_ vendor extensions added to the
+///   schema. See the struct options for details.
+///
+/// # Unnamed Field Optional Configuration Options for `#[schema(...)]`
+/// With _`openapi_31`_ feature a tuple struct having fields of different types is rendered as
+/// _`prefixItems`_ array where each field is described by its position. Fields of such struct
+/// support following options.
+/// * `title = ...` Literal string value. Can be used to label the positional item of the field
+///   e.g. _`struct Point(#[schema(title = "x")] f64, #[schema(title = "y")] f64, String)`_.
 ///
 /// # Named Fields Optional Configuration Options for `#[schema(...)]`
-/// * `example = ...` Can be method reference or _`json!(...)`_.
-/// * `default = ...` Can be method reference or _`json!(...)`_.
+/// * `example = ...` Can be method reference, _`json!(...)`_, constant e.g. _`Self::NAME`_ or
+///   other expression of serializable value e.g. _`Self::NAME.len()`_.
+/// * `examples(...)` Comma separated list of examples. Each example can be method reference or
+///   _`json!(...)`_. E.g _`examples(json!(1), json!(2))`_. Renders as OpenAPI 3.1 _`examples`_
+///   array thus requires _`openapi_31`_ feature.
+/// * `example_with = ...` Function reference returning _`serde_json::Value`_ which is called at
+///   runtime and used as the _`example`_. E.g _`example_with = path::to::example_fn`_.
+/// * `default = ...` Can be literal value, method reference or _`json!(...)`_. E.g
+///   _`default = "active"`_, _`default = 42`_ or _`default = true`_. Paths starting with uppercase
+///   letter e.g. _`default = Status::Active`_ are treated as values instead of method references
+///   and serialized with _`serde_json`_ thus enum variants and constants can be used as well.
 /// * `format = ...` May either be variant of the [`KnownFormat`][known_format] enum, or otherwise
 ///   an open value as a string. By default the format is derived from the type of the property
-///   according OpenApi spec.
+///   according OpenApi spec. E.g. _`format = "ipv4"`_ on a _`String`_ field. With arrays the
+///   format is applied to the items.
 /// * `write_only` Defines property is only used in **write** operations *POST,PUT,PATCH* but not in *GET*
 /// * `read_only` Defines property is only used in **read** operations *GET* but not in *POST,PUT,PATCH*
+///   Field cannot be both _`read_only`_ and _`write_only`_.
 /// * `xml(...)` Can be used to define [`Xml`][xml] object properties applicable to named fields.
 ///    See configuration options at xml attributes of [`ToSchema`][to_schema_xml]
 /// * `value_type = ...` Can be used to override default type derived from type of the field used in OpenAPI spec.
@@ -168,28 +343,81 @@ use self::{
 ///   The value can be any Rust type what normally could be used to serialize to JSON, or either virtual type _`Object`_
 ///   or _`Value`_, or an alias defined using `#[aliases(..)]`.
 ///   _`Object`_ will be rendered as generic OpenAPI object _(`type: object`)_.
-///   _`Value`_ will be rendered as any OpenAPI value (i.e. no `type` restriction).
+///   _`Value`_ or its alias _`Any`_ will be rendered as any OpenAPI value (i.e. no `type` restriction)
+///   which is also the schema of _`serde_json::Value`_. Validation attributes such as `minimum` or
+///   `max_length` are applied to and validated against the overridden type.
 /// * `inline` If the type of this field implements [`ToSchema`][to_schema], then the schema definition
-///   will be inlined. **warning:** Don't use this for recursive data types!
+///   will be inlined. Fields referencing the enclosing type either with `Self` or with the
+///   unqualified name of the type cannot be inlined and will result in a compile error. Types
+///   inlining each other e.g. `Author` inlining `Book` which inlines `Author` are inlined until
+///   the cycle is reached where a `$ref` to the registered name of the type is used instead. The
+///   cycle is only known once the schema is generated at runtime, thus no compile time note is
+///   emitted of the fallback. The referenced type must be registered as a component e.g. with
+///   _`components(schemas(...))`_. [`ToSchema::schemas`][to_schema_schemas] of the enclosing
+///   type includes the inlined types.
+/// * `no_recursion` Breaks the cycle of a self-referential field e.g. `children: Vec<Tree>` by always
+///   rendering it as a `$ref` to the enclosing schema, also when used together with `inline`. The
+///   `$ref` targets the name the enclosing schema is registered with.
 /// * `required = ...` Can be used to enforce required status for the field. [See
 ///   rules][derive@ToSchema#field-nullability-and-required-rules]
 /// * `nullable` Defines property is nullable (note this is different to non-required).
+/// * `ignore` or `ignore = bool` Leaves the field out of the schema properties and required list
+///   while it is still serialized as usual. Unlike _serde_ _`skip`_ it only affects the schema.
+/// * `decimal_as = ...` Overrides the representation of `Decimal` types of the field. Value is
+///   either _`"string"`_ or _`"number"`_ and it takes precedence over the `decimal` and
+///   `decimal_float` feature flags. Wrapper types such as `Option<Decimal>` and `Vec<Decimal>`
+///   keep their wrapping.
 /// * `rename = ...` Supports same syntax as _serde_ _`rename`_ attribute. Will rename field
 ///   accordingly. If both _serde_ `rename` and _schema_ _`rename`_ are defined __serde__ will take
 ///   precedence.
+///   __Note!__ ___Fields of the struct must have distinct names after renaming, duplicate
+///   names are reported as compile error.___
 /// * `multiple_of = ...` Can be used to define multiplier for a value. Value is considered valid
 ///   division will result an `integer`. Value must be strictly above _`0`_.
 /// * `maximum = ...` Can be used to define inclusive upper bound to a `number` value.
 /// * `minimum = ...` Can be used to define inclusive lower bound to a `number` value.
 /// * `exclusive_maximum = ...` Can be used to define exclusive upper bound to a `number` value.
 /// * `exclusive_minimum = ...` Can be used to define exclusive lower bound to a `number` value.
+///
+///   With _`validator`_ feature enabled number literal bounds of the _`validator`_ crate's
+///   _`#[validate(range(min = ..., max = ..., exclusive_min = ..., exclusive_max = ...))]`_
+///   attribute are used as the bounds of `number` fields unless the field defines lower or upper
+///   bound explicitly.
 /// * `max_length = ...` Can be used to define maximum length for `string` types.
 /// * `min_length = ...` Can be used to define minimum length for `string` types.
 /// * `pattern = ...` Can be used to define valid regular expression in _ECMA-262_ dialect the field value must match.
+///   With _`validate_pattern`_ feature enabled the regular expression is compiled at compile time
+///   and invalid pattern will result a compile error.
+///
+///   With _`validator`_ feature enabled fields validated with _`validator`_ crate's
+///   _`#[validate(regex(path = ...))]`_ must define the _`pattern`_ as well. The regular
+///   expression of the validation is a static value which cannot be read at compile time, thus
+///   omitting the _`pattern`_ will result a compile error.
+/// * `content_encoding = ...` Can be used to define encoding of `string` content e.g. _`"base64"`_.
+///   Requires _`openapi_31`_ feature.
+/// * `content_media_type = ...` Can be used to define media type of `string` content e.g.
+///   _`"image/png"`_. Requires _`openapi_31`_ feature.
 /// * `max_items = ...` Can be used to define maximum items allowed for `array` fields. Value must
 ///   be non-negative integer.
 /// * `min_items = ...` Can be used to define minimum items allowed for `array` fields. Value must
 ///   be non-negative integer.
+///
+///   Fixed size arrays with literal length e.g. _`[u8; 16]`_ will have both _`max_items`_ and
+///   _`min_items`_ set to the length of the array unless explicitly defined otherwise.
+///
+///   With _`validator`_ feature enabled integer bounds of the _`validator`_ crate's
+///   _`#[validate(length(min = ..., max = ..., equal = ...))]`_ attribute are used as
+///   _`min_length`_ and _`max_length`_ of `string` fields and as _`min_items`_ and _`max_items`_ of
+///   `array` fields unless explicitly defined otherwise.
+/// * `unique_items` Can be used to define that items of an `array` field must be unique. Can also
+///   be given as boolean e.g. _`unique_items = false`_. `HashSet` and `BTreeSet` fields are always
+///   rendered with unique items.
+/// * `max_properties = ...` Can be used to define maximum number of properties allowed for map
+///   fields and fields with _`value_type = Object`_. On flattened map fields the value is applied
+///   to the enclosing object. Value must be non-negative integer.
+/// * `min_properties = ...` Can be used to define minimum number of properties allowed for map
+///   fields and fields with _`value_type = Object`_. On flattened map fields the value is applied
+///   to the enclosing object. Value must be non-negative integer.
 /// * `schema_with = ...` Use _`schema`_ created by provided function reference instead of the
 ///   default derived _`schema`_. The function must match to `fn() -> Into<RefOr<Schema>>`. It does
 ///   not accept arguments and must return anything that can be converted into `RefOr<Schema>`.
@@ -197,9 +425,22 @@ use self::{
 ///   [`HashMap`](std::collections::HashMap) and [`BTreeMap`](std::collections::BTreeMap).
 ///   Free form type enables use of arbitrary types within map values.
 ///   Supports formats _`additional_properties`_ and _`additional_properties = true`_.
+///   Can also be used with _`value_type = Object`_ to explicitly allow any properties.
+/// * `additional_properties_description = "..."` Can be used to describe the value schema of a
+///   map field. The description is added to the _`additionalProperties`_ schema while doc comment
+///   of the field describes the map itself. Cannot be used together with _`additional_properties`_.
 /// * `deprecated` Can be used to mark the field as deprecated in the generated OpenAPI spec but
 ///   not in the code. If you'd like to mark the field as deprecated in the code as well use
 ///   Rust's own `#[deprecated]` attribute instead.
+/// * `extensions(...)` Comma separated list of _`"x-..." = value`_ vendor extensions added to the
+///   field schema. See the struct options for details. Fields referencing other schemas are
+///   wrapped in _`allOf`_ to hold the extensions.
+///
+/// Validation attributes such as _`minimum`_, _`maximum`_ and _`max_length`_ are checked against
+/// the type of the field at compile time. E.g. using _`maximum`_ on a `String` field will
+/// result a compile error. Defining both _`minimum`_ and _`exclusive_minimum`_ (or _`maximum`_ and
+/// _`exclusive_maximum`_) for the same field is a compile error as well. Likewise _`max_length`_
+/// must not be less than _`min_length`_.
 ///
 /// #### Field nullability and required rules
 ///
@@ -210,7 +451,18 @@ use self::{
 /// * and it does not have default value provided with serde _`default`_
 ///   attribute
 ///
+/// These rules can be overridden with _`required`_ attribute. _`required`_ forces the field to be
+/// required even when it is an `Option` field, and _`required = false`_ forces the field to be
+/// non-required even when the rules above would consider it required.
+///
 /// Field is considered _`nullable`_ when field type is _`Option`_.
+/// When _`value_type`_ is defined both the nullability and the required rules are resolved from
+/// the _`value_type`_ instead of the actual field type. E.g. a custom option like wrapper with
+/// _`value_type = Option<String>`_ is nullable and non-required.
+/// With _`openapi_31`_ feature enabled nullable primitive types, arrays and maps are rendered with
+/// type array e.g. _`["string", "null"]`_ and references to other schemas with _`oneOf`_ of
+/// _`null`_ type and the _`$ref`_ instead of _`nullable`_. Nested _`Option`_ types such as
+/// _`Option<Option<T>>`_ result a single _`null`_ type.
 ///
 /// ## Xml attribute Configuration Options
 ///
@@ -221,6 +473,10 @@ use self::{
 /// * `xml(wrapped)` Will make wrapped xml element.
 /// * `xml(wrapped(name = "wrap_name"))` Will override the wrapper elements name.
 ///
+/// `wrapped` can be used with `Vec`, slice, array and set types. With these the `wrapped` options
+/// are applied to the array while the rest of the options are applied to the array items. Items
+/// and fields referencing other schemas are wrapped in _`allOf`_ to hold the xml options.
+///
 /// See [`Xml`][xml] for more details.
 ///
 /// # Partial `#[serde(...)]` attributes support
@@ -230,21 +486,56 @@ use self::{
 /// be serialized anyway. Similarly the _`rename`_ and _`rename_all`_ will reflect to the generated OpenAPI doc.
 ///
 /// * `rename_all = "..."` Supported at the container level.
+/// * `rename_all(serialize = "...", deserialize = "...")` Supported at the container level.
+///   By default the _`serialize`_ rule is used and a compiler warning is emitted if the rules differ.
+///   Use container level _`rename_context`_ to choose the rule, which also silences the warning.
+/// * `rename_all_fields = "..."` and `rename_all_fields(serialize = "...", deserialize = "...")`
+///   Supported at the container level of enums. Renames fields of named field variants. Differing
+///   rules are resolved the same way as with _`rename_all`_.
 /// * `rename = "..."` Supported **only** at the field or variant level.
+/// * `rename(serialize = "...", deserialize = "...")` Supported **only** at the field or variant level.
+///   By default the _`serialize`_ name is used and a compiler warning is emitted if the names differ.
+///   Use container level _`rename_context`_ to choose the name, which also silences the warning.
+/// * `alias = "..."` Supported **only** at the field level. Aliases are listed in the field description
+///   e.g. _`Accepts aliases: old_name, legacy_name`_.
 /// * `skip = "..."` Supported  **only** at the field or variant level.
-/// * `skip_serializing = "..."` Supported  **only** at the field or variant level.
-/// * `skip_deserializing = "..."` Supported  **only** at the field or variant level.
+/// * `skip_serializing = "..."` Supported  **only** at the field or variant level. Named fields
+///   are kept in the schema and marked _`writeOnly`_ unless the field defines _`read_only`_ or
+///   _`write_only`_ itself. Variants are left out of the schema.
+/// * `skip_deserializing = "..."` Supported  **only** at the field or variant level. Named fields
+///   are kept in the schema and marked _`readOnly`_ unless the field defines _`read_only`_ or
+///   _`write_only`_ itself. Variants are left out of the schema.
 /// * `skip_serializing_if = "..."` Supported  **only** at the field level.
 /// * `with = ...` Supported **only at field level.**
 /// * `tag = "..."` Supported at the container level. `tag` attribute works as a [discriminator field][discriminator] for an enum.
+///   Variants wrapping a single named schema e.g. `Dog(Dog)` are added to the discriminator `mapping`
+///   by their (possibly renamed) tag value. If multiple variants wrap the same schema only the first
+///   one is mapped. Adjacently tagged variants are not mapped since their payload schema does not
+///   contain the tag property. Tuple variants must wrap a single struct or map like type since
+///   serde cannot internally tag primitive or sequence payloads.
 /// * `content = "..."` Supported at the container level, allows [adjacently-tagged enums](https://serde.rs/enum-representations.html#adjacently-tagged).
 ///   This attribute requires that a `tag` is present, otherwise serde will trigger a compile-time
-///   failure.
+///   failure. Tuple variants with multiple fields are rendered as fixed size array under the
+///   _`content`_ property.
 /// * `untagged` Supported at the container level. Allows [untagged
 /// enum representation](https://serde.rs/enum-representations.html#untagged).
 /// * `default` Supported at the container level and field level according to [serde attributes].
-/// * `deny_unknown_fields` Supported at the container level.
-/// * `flatten` Supported at the field level.
+/// * `deny_unknown_fields` Supported at the container level. Structs with flattened fields are
+///   rendered as _`allOf`_ where unknown fields can only be denied with _`unevaluatedProperties`_.
+///   Thus with flattened fields it is only supported with _`openapi_31`_ feature.
+///   Schema level _`additional_properties`_ takes precedence over _`deny_unknown_fields`_.
+/// * `flatten` Supported at the field level. Multiple flattened map fields are merged to single
+///   _`additionalProperties`_ with _`oneOf`_ of the map value types. Flattened maps with same
+///   value type are ambiguous and will result a compile error. Flattened structs are rendered as
+///   _`allOf`_ items in field declaration order followed by the schema of the remaining fields.
+///   Properties are ordered alphabetically or, with _`utoipa`_ _`preserve_order`_ feature, in
+///   field declaration order while _`required`_ always follows field declaration order.
+///   Flattened maps with keys of user defined types e.g. enums are not supported and will result
+///   a compile error. Use _`value_type`_ to override the map with _`String`_ keys instead.
+/// * `transparent` Supported at the container level of named field structs. The struct is rendered
+///   as the schema of its only non-skipped field. Schema attributes must be defined on the field
+///   as container level schema attributes are not supported with _`transparent`_. Doc comment of
+///   the struct is used as the description of the schema instead of the doc comment of the field.
 ///
 /// Other _`serde`_ attributes works as is but does not have any effect on the generated OpenAPI doc.
 ///
@@ -315,6 +606,13 @@ use self::{
 /// * `repr(u*)` for unsigned integer.
 /// * `repr(i*)` for signed integer.
 ///
+/// Values of the variants are the discriminants of the enum, so explicit discriminants such as
+/// `A = 1, B = 5, C = 100` are rendered exactly as written and implicit ones continue from the
+/// previous variant.
+///
+/// The enum is rendered with _`integer`_ type and the _`format`_ of the `repr` type the same way
+/// as integer fields are e.g. _`repr(u16)`_ is rendered with _`int32`_ format.
+///
 /// **Supported schema attributes**
 ///
 /// * `example = ...` Can be method reference or _`json!(...)`_.
@@ -325,6 +623,25 @@ use self::{
 /// * `as = ...` Can be used to define alternative path and name for the schema what will be used in
 ///   the OpenAPI. E.g _`as = path::to::Pet`_. This would make the schema appear in the generated
 ///   OpenAPI spec as _`path.to.Pet`_.
+/// * `rename = ...` Literal string value. Can be used to define custom name for the schema what
+///   will be used in the OpenAPI and in the references to the schema. E.g _`rename = "Pet"`_.
+///   Fields of other schemas referencing the type resolve the custom name automatically while
+///   path operations reference schemas by name only, thus use the custom name there,
+///   e.g. _`request_body = Pet`_.
+///   __Note!__ ___Cannot be used together with _`as = ...`_.___
+/// * `bound = ...` Literal string value of additional where clause predicates for the generated
+///   `ToSchema` implementation. E.g _`bound = "T: Serialize"`_. The predicates are added to the
+///   where clause of the type in same manner as with _serde_ _`bound`_ attribute.
+/// * `const_variants` Render the enum as `oneOf` of `const` schemas instead of compact `enum` of
+///   values. Each variant schema gets the variant name as `title` and the variant doc comment as
+///   `description`. Only supported for enums without _serde_ `tag` or `untagged` attribute.
+///   Without _`openapi_31`_ feature the values are rendered as single value `enum`s instead of
+///   `const`.
+///
+/// **Supported schema attributes for variants**
+///
+/// * `rename = ...` Literal string value. Overrides the `title` of the variant schema. Requires
+///   `const_variants` on the enum.
 ///
 /// _**Create enum with numeric values.**_
 /// ```rust
@@ -342,6 +659,21 @@ use self::{
 /// }
 /// ```
 ///
+/// _**Render variants as titled `const` values with `const_variants`.**_
+/// ```rust
+/// # use utoipa::ToSchema;
+/// # #[cfg(feature = "repr")]
+/// #[derive(ToSchema)]
+/// #[repr(u8)]
+/// #[schema(const_variants)]
+/// enum JobStatus {
+///     /// Job is waiting to be run.
+///     Pending = 0,
+///     #[schema(rename = "In progress")]
+///     Running,
+///  }
+/// ```
+///
 /// _**You can use `skip` and `tag` attributes from serde.**_
 /// ```rust
 /// # use utoipa::ToSchema;
@@ -385,6 +717,70 @@ use self::{
 /// **Note!** You should never register generic type itself in `components(...)` so according above example `Status<...>` should not be registered
 /// because it will not render the type correctly and will cause an error in generated OpenAPI spec.
 ///
+/// The alias name can be omitted in which case it is derived from the type by joining the type and
+/// its generic arguments with `_`. E.g. _`#[aliases(Status<String>, Status<i32>)]`_ creates
+/// _`Status_String`_ and _`Status_i32`_ aliases which are also the names of the schemas in the
+/// OpenAPI spec.
+///
+/// Const generic parameters are supported as well and can be used in aliases e.g.
+/// _`#[aliases(Matrix3 = Matrix<3>)]`_. Integer literal const generic arguments resolve the length
+/// of fixed size arrays e.g. _`[f64; N]`_ which then render with _`minItems`_ and _`maxItems`_ of
+/// the given length.
+///
+/// Generic tuple structs and enums can be aliased the same way. The generic arguments are substituted
+/// in the fields of every variant of the enum.
+/// ```rust
+/// # use utoipa::ToSchema;
+/// #[derive(ToSchema)]
+/// #[aliases(StringOrInt = Either<String, i32>)]
+/// enum Either<L, R> {
+///     Left(L),
+///     Right(R),
+/// }
+/// ```
+///
+/// Named field structs can define _`description`_ and _`example`_ per alias with trailing `as`
+/// clause. A single attribute can be given directly e.g. _`as description = "..."`_ and multiple
+/// attributes within parentheses. Attributes of the alias override the ones of the generic type.
+/// ```rust
+/// # use utoipa::ToSchema;
+/// /// Status of an operation.
+/// #[derive(ToSchema)]
+/// #[aliases(
+///     StatusMessage = Status<String> as description = "Status with a message",
+///     StatusNumber = Status<i32> as (description = "Status with a code", example = json!({"value": 1}))
+/// )]
+/// struct Status<T> {
+///     value: T
+/// }
+/// ```
+///
+/// # Collecting referenced schemas
+///
+/// Derived `ToSchema` also implements _`schemas()`_ which returns the schema of the type together
+/// with the schemas of all the types it references or inlines recursively. Each schema is returned
+/// once per name thus types referencing each other are supported. Types given with
+/// _`value_type = ...`_, generic arguments of the type and types not implementing `ToSchema` are
+/// not collected.
+/// ```rust
+/// # use utoipa::ToSchema;
+/// #[derive(ToSchema)]
+/// struct Owner {
+///     pets: Vec<Pet>,
+/// }
+///
+/// #[derive(ToSchema)]
+/// struct Pet {
+///     owner: Option<Box<Owner>>,
+/// }
+///
+/// let names = Owner::schemas()
+///     .into_iter()
+///     .map(|(name, _)| name)
+///     .collect::<Vec<_>>();
+/// assert_eq!(names, ["Owner", "Pet"]);
+/// ```
+///
 /// # Examples
 ///
 /// _**Simple example of a Pet with descriptions and object level example.**_
@@ -500,6 +896,19 @@ use self::{
 /// }
 /// ```
 ///
+/// _**Byte collections e.g. `Vec<u8>` and `&[u8]` are rendered as [`String`] with
+/// [`SchemaFormat::KnownFormat(KnownFormat::Binary)`][binary] by default. Use `format` to change
+/// the format e.g. `format = Byte` for base64 encoded content.**_
+/// ```rust
+/// # use utoipa::ToSchema;
+/// #[derive(ToSchema)]
+/// struct Post {
+///     id: i32,
+///     #[schema(format = Byte)]
+///     value: Vec<u8>,
+/// }
+/// ```
+///
 /// _**Enforce type being used in OpenAPI spec to [`String`] with `value_type` and set format to octet stream
 /// with [`SchemaFormat::KnownFormat(KnownFormat::Binary)`][binary].**_
 /// ```rust
@@ -566,6 +975,38 @@ use self::{
 /// }
 /// ```
 ///
+/// _**Fields renamed to the same name are rejected as only one of them could be described.**_
+/// ```compile_fail
+/// #[derive(utoipa::ToSchema, serde::Serialize)]
+/// #[serde(rename_all = "camelCase")]
+/// struct User {
+///     user_id: i32,
+///     #[serde(rename = "userId")]
+///     id: i32,
+/// }
+/// ```
+///
+/// _**Differing _serde_ `serialize` and `deserialize` names emit a warning, here denied.**_
+/// ```compile_fail
+/// #![deny(deprecated)]
+/// #[derive(utoipa::ToSchema, serde::Serialize, serde::Deserialize)]
+/// struct User {
+///     #[serde(rename(serialize = "userId", deserialize = "user_id"))]
+///     id: i32,
+/// }
+/// ```
+///
+/// _**Choose the name with `rename_context` to silence the warning.**_
+/// ```rust
+/// #![deny(deprecated)]
+/// #[derive(utoipa::ToSchema, serde::Serialize, serde::Deserialize)]
+/// #[schema(rename_context = "deserialize")]
+/// struct User {
+///     #[serde(rename(serialize = "userId", deserialize = "user_id"))]
+///     id: i32,
+/// }
+/// ```
+///
 /// _**Add `title` to the enum.**_
 /// ```rust
 /// #[derive(utoipa::ToSchema)]
@@ -585,7 +1026,7 @@ use self::{
 ///     id: i32,
 ///     #[schema(max_length = 10, min_length = 5, pattern = "[a-z]*")]
 ///     value: String,
-///     #[schema(max_items = 5, min_items = 1)]
+///     #[schema(max_items = 5, min_items = 1, unique_items)]
 ///     items: Vec<String>,
 /// }
 /// ````
@@ -623,6 +1064,7 @@ use self::{
 /// More examples for _`value_type`_ in [`IntoParams` derive docs][into_params].
 ///
 /// [to_schema]: trait.ToSchema.html
+/// [to_schema_schemas]: trait.ToSchema.html#method.schemas
 /// [known_format]: openapi/schema/enum.KnownFormat.html
 /// [binary]: openapi/schema/enum.KnownFormat.html#variant.Binary
 /// [xml]: openapi/xml/struct.Xml.html
@@ -961,6 +1403,8 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// * `min_length = ...` Can be used to define minimum length for `string` types.
 ///
 /// * `pattern = ...` Can be used to define valid regular expression in _ECMA-262_ dialect the field value must match.
+///   With _`validate_pattern`_ feature enabled the regular expression is compiled at compile time
+///   and invalid pattern will result a compile error.
 ///
 /// * `max_items = ...` Can be used to define maximum items allowed for `array` fields. Value must
 ///   be non-negative integer.
@@ -968,6 +1412,9 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// * `min_items = ...` Can be used to define minimum items allowed for `array` fields. Value must
 ///   be non-negative integer.
 ///
+/// * `unique_items` Can be used to define that items of an `array` field must be unique. Can also
+///   be given as boolean e.g. _`unique_items = false`_.
+///
 /// **For example:**
 ///
 /// ```text
@@ -1416,11 +1863,12 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
             }
         }));
 
-    let path_tokens = path.to_token_stream();
+    let (path_tokens, warnings) = collect_warnings(|| path.to_token_stream());
 
     quote! {
         #path_tokens
         #ast_fn
+        #warnings
     }
     .into()
 }
@@ -1764,7 +2212,8 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 ///   The value can be any Rust type what normally could be used to serialize to JSON, or either virtual type _`Object`_
 ///   or _`Value`_, or an alias defined using `#[aliases(..)]`.
 ///   _`Object`_ will be rendered as generic OpenAPI object _(`type: object`)_.
-///   _`Value`_ will be rendered as any OpenAPI value (i.e. no `type` restriction).
+///   _`Value`_ or its alias _`Any`_ will be rendered as any OpenAPI value (i.e. no `type` restriction)
+///   which is also the schema of _`serde_json::Value`_.
 ///
 /// * `inline` If set, the schema for this field's type needs to be a [`ToSchema`][to_schema], and
 ///   the schema definition will be inlined.
@@ -1805,6 +2254,8 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// * `min_length = ...` Can be used to define minimum length for `string` types.
 ///
 /// * `pattern = ...` Can be used to define valid regular expression in _ECMA-262_ dialect the field value must match.
+///   With _`validate_pattern`_ feature enabled the regular expression is compiled at compile time
+///   and invalid pattern will result a compile error.
 ///
 /// * `max_items = ...` Can be used to define maximum items allowed for `array` fields. Value must
 ///   be non-negative integer.
@@ -1812,6 +2263,9 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// * `min_items = ...` Can be used to define minimum items allowed for `array` fields. Value must
 ///   be non-negative integer.
 ///
+/// * `unique_items` Can be used to define that items of an `array` field must be unique. Can also
+///   be given as boolean e.g. _`unique_items = false`_.
+///
 /// * `schema_with = ...` Use _`schema`_ created by provided function reference instead of the
 ///   default derived _`schema`_. The function must match to `fn() -> Into<RefOr<Schema>>`. It does
 ///   not accept arguments and must return anything that can be converted into `RefOr<Schema>`.
@@ -1832,6 +2286,8 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// generated OpenAPI doc. The following attributes are currently supported:
 ///
 /// * `rename_all = "..."` Supported at the container level.
+/// * `rename_all(serialize = "...", deserialize = "...")` Supported at the container level. The
+///   _`serialize`_ rule is used and a compiler warning is emitted if the rules differ.
 /// * `rename = "..."` Supported **only** at the field level.
 /// * `default` Supported at the container level and field level according to [serde attributes].
 /// * `skip_serializing_if = "..."` Supported  **only** at the field level.
@@ -1842,6 +2298,16 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 ///
 /// Other _`serde`_ attributes will impact the serialization but will not be reflected on the generated OpenAPI doc.
 ///
+/// _**Differing _serde_ `serialize` and `deserialize` rules emit a warning, here denied.**_
+/// ```compile_fail
+/// #![deny(deprecated)]
+/// #[derive(utoipa::IntoParams, serde::Deserialize)]
+/// #[serde(rename_all(serialize = "camelCase", deserialize = "snake_case"))]
+/// struct Filter {
+///     page_size: i32,
+/// }
+/// ```
+///
 /// # Examples
 ///
 /// _**Demonstrate [`IntoParams`][into_params] usage with resolving `Path` and `Query` parameters
@@ -1992,7 +2458,7 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 ///     id: i32,
 ///     #[param(max_length = 10, min_length = 5, pattern = "[a-z]*")]
 ///     value: String,
-///     #[param(max_items = 5, min_items = 1)]
+///     #[param(max_items = 5, min_items = 1, unique_items)]
 ///     items: Vec<String>,
 /// }
 /// ````
@@ -2045,7 +2511,13 @@ pub fn into_params(input: TokenStream) -> TokenStream {
         ident,
     };
 
-    into_params.to_token_stream().into()
+    let (into_params, warnings) = collect_warnings(|| into_params.to_token_stream());
+
+    quote! {
+        #into_params
+        #warnings
+    }
+    .into()
 }
 
 #[proc_macro_derive(ToResponse, attributes(response, content, to_schema))]
@@ -2241,6 +2713,18 @@ pub fn into_params(input: TokenStream) -> TokenStream {
 ///  }
 /// ```
 ///
+/// _**Differing _serde_ `serialize` and `deserialize` names emit a warning the same way as with
+/// `ToSchema`, here denied.**_
+/// ```compile_fail
+///  #![deny(deprecated)]
+///  #[derive(utoipa::ToResponse, serde::Serialize)]
+///  enum Status {
+///      #[serde(rename(serialize = "okay", deserialize = "ok"))]
+///      Ok,
+///      Err,
+///  }
+/// ```
+///
 /// [to_response]: trait.ToResponse.html
 /// [primitive]: https://doc.rust-lang.org/std/primitive/index.html
 /// [path]: attr.path.html
@@ -2254,10 +2738,17 @@ pub fn to_response(input: TokenStream) -> TokenStream {
         ..
     } = syn::parse_macro_input!(input);
 
-    ToResponse::new(attrs, &data, generics, ident)
-        .as_ref()
-        .map_or_else(Diagnostics::to_token_stream, ToResponse::to_token_stream)
-        .into()
+    let (to_response, warnings) = collect_warnings(|| {
+        ToResponse::new(attrs, &data, generics, ident)
+            .as_ref()
+            .map_or_else(Diagnostics::to_token_stream, ToResponse::to_token_stream)
+    });
+
+    quote! {
+        #to_response
+        #warnings
+    }
+    .into()
 }
 
 #[proc_macro_derive(
@@ -2450,7 +2941,13 @@ pub fn into_responses(input: TokenStream) -> TokenStream {
         data,
     };
 
-    into_responses.to_token_stream().into()
+    let (into_responses, warnings) = collect_warnings(|| into_responses.to_token_stream());
+
+    quote! {
+        #into_responses
+        #warnings
+    }
+    .into()
 }
 
 /// Create OpenAPI Schema from arbitrary type.
@@ -2767,14 +3264,31 @@ impl AnyValue {
 
                 Ok(AnyValue::Json(json))
             } else {
-                let method = input.parse::<ExprPath>().map_err(|error| {
+                let expr = input.parse::<Expr>().map_err(|error| {
                     syn::Error::new(
                         error.span(),
-                        "expected literal value, json!(...) or method reference",
+                        "expected literal value, json!(...), method reference or expression",
                     )
                 })?;
 
-                Ok(AnyValue::Json(quote! { #method() }))
+                match expr {
+                    // paths to enum variants and constants are values, other paths are called as methods
+                    Expr::Path(path) => {
+                        let is_value = path.path.segments.last().is_some_and(|segment| {
+                            segment
+                                .ident
+                                .to_string()
+                                .starts_with(|c: char| c.is_ascii_uppercase())
+                        });
+                        if is_value {
+                            Ok(AnyValue::Json(quote! { #path }))
+                        } else {
+                            Ok(AnyValue::Json(quote! { #path() }))
+                        }
+                    }
+                    // other expressions e.g. `Self::new(1)` are serialized at runtime
+                    expr => Ok(AnyValue::Json(quote! { #expr })),
+                }
             }
         }
     }
@@ -16,7 +16,7 @@ use crate::{
             impl_into_inner, parse_features, AllowReserved, Description, Example, ExclusiveMaximum,
             ExclusiveMinimum, Explode, Feature, Format, MaxItems, MaxLength, Maximum, MinItems,
             MinLength, Minimum, MultipleOf, Nullable, Pattern, ReadOnly, Style, ToTokensExt,
-            WriteOnly, XmlAttr,
+            UniqueItems, WriteOnly, XmlAttr,
         },
         ComponentSchema,
     },
@@ -313,7 +313,8 @@ impl Parse for ParameterFeatures {
             MinLength,
             Pattern,
             MaxItems,
-            MinItems
+            MinItems,
+            UniqueItems
         )))
     }
 }
@@ -342,7 +343,8 @@ impl ParameterFeatures {
                     | Feature::MinLength(_)
                     | Feature::Pattern(_)
                     | Feature::MaxItems(_)
-                    | Feature::MinItems(_) => {
+                    | Feature::MinItems(_)
+                    | Feature::UniqueItems(_) => {
                         schema_features.push(feature);
                     }
                     _ => {
@@ -350,8 +350,11 @@ impl NamedStructResponse<'_> {
             features: None,
             generics: None,
             rename_all: None,
+            rename_context: None,
             struct_name: Cow::Owned(ident.to_string()),
             schema_as: None,
+            schema_rename: None,
+            schema_bound: None,
         };
 
         let ty = Self::to_type(ident);
@@ -437,7 +440,10 @@ impl<'p> ToResponseNamedStructResponse<'p> {
             attributes,
             struct_name: Cow::Owned(ident.to_string()),
             rename_all: None,
+            rename_context: None,
             schema_as: None,
+            schema_rename: None,
+            schema_bound: None,
         };
         let response_type = PathType::InlineSchema(inline_schema.to_token_stream(), ty);
 
@@ -565,7 +571,7 @@ impl<'r> EnumResponse<'r> {
         });
         response_value.response_type = if content.is_empty() {
             let inline_schema =
-                EnumSchema::new(Cow::Owned(ident.to_string()), variants, attributes)?;
+                EnumSchema::new(Cow::Owned(ident.to_string()), variants, attributes, None)?;
 
             Some(PathType::InlineSchema(
                 inline_schema.into_token_stream(),
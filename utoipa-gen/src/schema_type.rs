@@ -18,8 +18,17 @@ impl SchemaType<'_> {
             .to_string()
     }
 
+    /// Get last segment of the path as [`String`] where `std::num::NonZero*` types are replaced
+    /// with their primitive integer type.
+    fn last_segment_to_primitive_string(&self) -> String {
+        let name = self.last_segment_to_string();
+        non_zero_integer(&name)
+            .map(ToString::to_string)
+            .unwrap_or(name)
+    }
+
     pub fn is_value(&self) -> bool {
-        matches!(&*self.last_segment_to_string(), "Value")
+        matches!(&*self.last_segment_to_string(), "Value" | "Any")
     }
 
     /// Check whether type is known to be primitive in which case returns true.
@@ -30,6 +39,7 @@ impl SchemaType<'_> {
             None => return false,
         };
         let name = &*last_segment.ident.to_string();
+        let name = non_zero_integer(name).unwrap_or(name);
 
         #[cfg(not(any(
             feature = "chrono",
@@ -102,7 +112,7 @@ impl SchemaType<'_> {
 
     pub fn is_integer(&self) -> bool {
         matches!(
-            &*self.last_segment_to_string(),
+            &*self.last_segment_to_primitive_string(),
             "i8" | "i16"
                 | "i32"
                 | "i64"
@@ -119,21 +129,47 @@ impl SchemaType<'_> {
 
     pub fn is_unsigned_integer(&self) -> bool {
         matches!(
-            &*self.last_segment_to_string(),
+            &*self.last_segment_to_primitive_string(),
             "u8" | "u16" | "u32" | "u64" | "u128" | "usize"
         )
     }
 
+    /// Check whether type is one of the `std::num::NonZero*` integer types.
+    pub fn is_non_zero_integer(&self) -> bool {
+        non_zero_integer(&self.last_segment_to_string()).is_some()
+    }
+
     pub fn is_number(&self) -> bool {
-        match &*self.last_segment_to_string() {
-            "f32" | "f64" => true,
+        match &*self.last_segment_to_primitive_string() {
+            "f32" | "f64" | "Number" => true,
+            #[cfg(feature = "decimal_float")]
+            "Decimal" => true,
             _ if self.is_integer() => true,
             _ => false,
         }
     }
 
     pub fn is_string(&self) -> bool {
-        matches!(&*self.last_segment_to_string(), "str" | "String")
+        match &*self.last_segment_to_string() {
+            "str" | "String" | "char" => true,
+            #[cfg(feature = "chrono")]
+            "DateTime" | "NaiveDateTime" | "NaiveDate" | "NaiveTime" => true,
+            #[cfg(any(feature = "chrono", feature = "time"))]
+            "Date" | "Duration" => true,
+            #[cfg(feature = "decimal")]
+            "Decimal" => true,
+            #[cfg(feature = "rocket_extras")]
+            "PathBuf" => true,
+            #[cfg(feature = "uuid")]
+            "Uuid" => true,
+            #[cfg(feature = "ulid")]
+            "Ulid" => true,
+            #[cfg(feature = "url")]
+            "Url" => true,
+            #[cfg(feature = "time")]
+            "PrimitiveDateTime" | "OffsetDateTime" => true,
+            _ => false,
+        }
     }
 
     pub fn is_byte(&self) -> bool {
@@ -163,9 +199,30 @@ fn is_primitive(name: &str) -> bool {
             | "i128"
             | "f32"
             | "f64"
+            | "Number"
     )
 }
 
+/// Get the primitive integer type of `std::num::NonZero*` type.
+#[inline]
+fn non_zero_integer(name: &str) -> Option<&'static str> {
+    match name {
+        "NonZeroU8" => Some("u8"),
+        "NonZeroU16" => Some("u16"),
+        "NonZeroU32" => Some("u32"),
+        "NonZeroU64" => Some("u64"),
+        "NonZeroU128" => Some("u128"),
+        "NonZeroUsize" => Some("usize"),
+        "NonZeroI8" => Some("i8"),
+        "NonZeroI16" => Some("i16"),
+        "NonZeroI32" => Some("i32"),
+        "NonZeroI64" => Some("i64"),
+        "NonZeroI128" => Some("i128"),
+        "NonZeroIsize" => Some("isize"),
+        _ => None,
+    }
+}
+
 #[inline]
 #[cfg(feature = "chrono")]
 fn is_primitive_chrono(name: &str) -> bool {
@@ -190,6 +247,7 @@ impl ToTokensDiagnostics for SchemaType<'_> {
             )
         })?;
         let name = &*last_segment.ident.to_string();
+        let name = non_zero_integer(name).unwrap_or(name);
 
         match name {
             "String" | "str" | "char" => {
@@ -201,6 +259,8 @@ impl ToTokensDiagnostics for SchemaType<'_> {
             "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64"
             | "u128" | "usize" => tokens.extend(quote! { utoipa::openapi::SchemaType::Integer }),
             "f32" | "f64" => tokens.extend(quote! { utoipa::openapi::SchemaType::Number }),
+            // serde_json::Number
+            "Number" => tokens.extend(quote! { utoipa::openapi::SchemaType::Number }),
 
             #[cfg(feature = "chrono")]
             "DateTime" | "NaiveDateTime" | "NaiveDate" | "NaiveTime" => {
@@ -296,6 +356,7 @@ impl Type<'_> {
             None => return false,
         };
         let name = &*last_segment.ident.to_string();
+        let name = non_zero_integer(name).unwrap_or(name);
 
         #[cfg(not(any(
             feature = "chrono",
@@ -372,6 +433,7 @@ impl ToTokensDiagnostics for Type<'_> {
             )
         })?;
         let name = &*last_segment.ident.to_string();
+        let name = non_zero_integer(name).unwrap_or(name);
 
         match name {
             #[cfg(feature="non_strict_integers")]
@@ -4,6 +4,38 @@ pub fn value_as_string(value: Option<&'_ Value>) -> String {
     value.unwrap_or(&Value::Null).to_string()
 }
 
+/// Remove the _`title`_ the `auto_title` feature gives to the schema of type `name` for tests not
+/// interested in it.
+#[cfg(feature = "auto_title")]
+#[allow(unused)]
+pub fn without_auto_title(mut value: Value, name: &str) -> Value {
+    if let Some(schema) = value.as_object_mut() {
+        if schema.get("title").and_then(Value::as_str) == Some(name) {
+            schema.remove("title");
+        }
+    }
+    value
+}
+
+/// Assert the schemas at given JSON pointers have the _`title`_ given by the `auto_title` feature
+/// and remove it so the rest of the `value` can be compared to the expectations without titles.
+#[cfg(feature = "auto_title")]
+#[allow(unused)]
+pub fn without_auto_titles(mut value: Value, titles: &[(&str, &str)]) -> Value {
+    for (pointer, title) in titles {
+        let schema = value
+            .pointer_mut(pointer)
+            .and_then(Value::as_object_mut)
+            .unwrap_or_else(|| panic!("expected schema object at `{pointer}`"));
+        assert_eq!(
+            schema.remove("title"),
+            Some(Value::from(*title)),
+            "expected auto title at `{pointer}`"
+        );
+    }
+    value
+}
+
 #[allow(unused)]
 pub fn assert_json_array_len(value: &Value, len: usize) {
     match value {
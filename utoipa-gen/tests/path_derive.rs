@@ -451,6 +451,27 @@ fn derive_path_with_parameter_schema() {
 
     let parameters: &Value = operation.get("parameters").unwrap();
 
+    #[cfg(not(feature = "openapi_31"))]
+    let since = json!({
+        "allOf": [
+            {
+                "$ref": "#/components/schemas/Since"
+            }
+        ],
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let since = json!({
+        "oneOf": [
+            {
+                "type": "null"
+            },
+            {
+                "$ref": "#/components/schemas/Since"
+            }
+        ]
+    });
+
     assert_json_eq!(
         parameters,
         json!([
@@ -469,14 +490,7 @@ fn derive_path_with_parameter_schema() {
                 "in": "query",
                 "name": "since",
                 "required": false,
-                "schema": {
-                    "allOf": [
-                        {
-                            "$ref": "#/components/schemas/Since"
-                        }
-                    ],
-                    "nullable": true,
-                }
+                "schema": since
             }
         ])
     );
@@ -521,6 +535,55 @@ fn derive_path_with_parameter_inline_schema() {
 
     let parameters: &Value = operation.get("parameters").unwrap();
 
+    #[cfg(not(feature = "openapi_31"))]
+    let since = json!({
+        "allOf": [
+            {
+                "properties": {
+                    "date": {
+                        "description": "Some date",
+                        "type": "string"
+                    },
+                    "time": {
+                        "description": "Some time",
+                        "type": "string"
+                    }
+                },
+                "required": [
+                    "date",
+                    "time"
+                ],
+                "type": "object"
+            }
+        ],
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let since = json!({
+        "oneOf": [
+            {
+                "type": "null"
+            },
+            {
+                "properties": {
+                    "date": {
+                        "description": "Some date",
+                        "type": "string"
+                    },
+                    "time": {
+                        "description": "Some time",
+                        "type": "string"
+                    }
+                },
+                "required": [
+                    "date",
+                    "time"
+                ],
+                "type": "object"
+            }
+        ]
+    });
+
     assert_json_eq!(
         parameters,
         json!([
@@ -539,28 +602,7 @@ fn derive_path_with_parameter_inline_schema() {
                 "in": "query",
                 "name": "since",
                 "required": false,
-                "schema": {
-                    "allOf": [
-                        {
-                            "properties": {
-                                "date": {
-                                    "description": "Some date",
-                                    "type": "string"
-                                },
-                                "time": {
-                                    "description": "Some time",
-                                    "type": "string"
-                                }
-                            },
-                            "required": [
-                                "date",
-                                "time"
-                            ],
-                            "type": "object"
-                        }
-                    ],
-                    "nullable": true,
-                }
+                "schema": since
             }
         ])
     );
@@ -718,6 +760,28 @@ fn derive_path_query_params_with_schema_features() {
     };
     let parameters = operation.get("parameters").unwrap();
 
+    #[cfg(not(feature = "openapi_31"))]
+    let value = json!({
+        "default": "value",
+        "type": "string",
+        "readOnly": true,
+        "writeOnly": true,
+        "nullable": true,
+        "xml": {
+            "name": "xml_value"
+        }
+    });
+    #[cfg(feature = "openapi_31")]
+    let value = json!({
+        "default": "value",
+        "type": ["string", "null"],
+        "readOnly": true,
+        "writeOnly": true,
+        "xml": {
+            "name": "xml_value"
+        }
+    });
+
     assert_json_eq! {
         parameters,
         json!{[
@@ -725,16 +789,7 @@ fn derive_path_query_params_with_schema_features() {
             "in": "query",
             "name": "value",
             "required": false,
-            "schema": {
-                "default": "value",
-                "type": "string",
-                "readOnly": true,
-                "writeOnly": true,
-                "nullable": true,
-                "xml": {
-                    "name": "xml_value"
-                }
-            }
+            "schema": value
           },
           {
             "in": "query",
@@ -804,6 +859,58 @@ fn derive_required_path_params() {
 
     let parameters = operation.get("parameters").unwrap();
 
+    #[cfg(not(feature = "openapi_31"))]
+    let vec_default = json!({
+        "type": "array",
+        "nullable": true,
+        "items": {
+            "type": "string"
+        }
+    });
+    #[cfg(feature = "openapi_31")]
+    let vec_default = json!({
+        "type": ["array", "null"],
+        "items": {
+            "type": "string"
+        }
+    });
+
+    #[cfg(not(feature = "openapi_31"))]
+    let string_default = json!({
+        "nullable": true,
+        "type": "string"
+    });
+    #[cfg(feature = "openapi_31")]
+    let string_default = json!({
+        "type": ["string", "null"]
+    });
+
+    #[cfg(not(feature = "openapi_31"))]
+    let vec_option = json!({
+        "nullable": true,
+        "items": {
+            "type": "string"
+        },
+        "type": "array",
+    });
+    #[cfg(feature = "openapi_31")]
+    let vec_option = json!({
+        "items": {
+            "type": "string"
+        },
+        "type": ["array", "null"],
+    });
+
+    #[cfg(not(feature = "openapi_31"))]
+    let string_option = json!({
+        "nullable": true,
+        "type": "string"
+    });
+    #[cfg(feature = "openapi_31")]
+    let string_option = json!({
+        "type": ["string", "null"]
+    });
+
     assert_json_eq!(
         parameters,
         json!([
@@ -811,22 +918,13 @@ fn derive_required_path_params() {
                 "in": "query",
                 "name": "vec_default",
                 "required": false,
-                "schema": {
-                    "type": "array",
-                    "nullable": true,
-                    "items": {
-                        "type": "string"
-                    }
-                },
+                "schema": vec_default,
             },
             {
                 "in": "query",
                 "name": "string_default",
                 "required": false,
-                "schema": {
-                    "nullable": true,
-                    "type": "string"
-                }
+                "schema": string_default
             },
             {
                 "in": "query",
@@ -851,22 +949,13 @@ fn derive_required_path_params() {
                 "in": "query",
                 "name": "vec_option",
                 "required": false,
-                "schema": {
-                    "nullable": true,
-                    "items": {
-                        "type": "string"
-                    },
-                    "type": "array",
-                },
+                "schema": vec_option,
             },
             {
                 "in": "query",
                 "name": "string_option",
                 "required": false,
-                "schema": {
-                    "nullable": true,
-                    "type": "string"
-                }
+                "schema": string_option
             },
             {
                 "in": "query",
@@ -891,6 +980,43 @@ fn derive_required_path_params() {
     )
 }
 
+#[cfg(feature = "openapi_31")]
+#[test]
+fn derive_path_params_with_nullable_type_array() {
+    let operation = api_fn_doc_with_params! {get: "/list/{id}" =>
+        #[into_params(parameter_in = Query)]
+        struct MyParams {
+            string_option: Option<String>,
+
+            string: String,
+        }
+    };
+
+    let parameters = operation.get("parameters").unwrap();
+
+    assert_json_eq!(
+        parameters,
+        json!([
+            {
+                "in": "query",
+                "name": "string_option",
+                "required": false,
+                "schema": {
+                    "type": ["string", "null"]
+                }
+            },
+            {
+                "in": "query",
+                "name": "string",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            }
+        ])
+    )
+}
+
 #[test]
 fn derive_path_params_with_serde_and_custom_rename() {
     let operation = api_fn_doc_with_params! {get: "/list/{id}" =>
@@ -915,6 +1041,32 @@ fn derive_path_params_with_serde_and_custom_rename() {
     };
     let parameters = operation.get("parameters").unwrap();
 
+    #[cfg(not(feature = "openapi_31"))]
+    let vec_default = json!({
+        "type": "array",
+        "nullable": true,
+        "items": {
+            "type": "string"
+        }
+    });
+    #[cfg(feature = "openapi_31")]
+    let vec_default = json!({
+        "type": ["array", "null"],
+        "items": {
+            "type": "string"
+        }
+    });
+
+    #[cfg(not(feature = "openapi_31"))]
+    let string = json!({
+        "nullable": true,
+        "type": "string"
+    });
+    #[cfg(feature = "openapi_31")]
+    let string = json!({
+        "type": ["string", "null"]
+    });
+
     assert_json_eq!(
         parameters,
         json!([
@@ -922,22 +1074,13 @@ fn derive_path_params_with_serde_and_custom_rename() {
                 "in": "query",
                 "name": "vecDefault",
                 "required": false,
-                "schema": {
-                    "type": "array",
-                    "nullable": true,
-                    "items": {
-                        "type": "string"
-                    }
-                },
+                "schema": vec_default,
             },
             {
                 "in": "query",
                 "name": "STRING",
                 "required": false,
-                "schema": {
-                    "nullable": true,
-                    "type": "string"
-                }
+                "schema": string
             },
             {
                 "in": "query",
@@ -980,6 +1123,22 @@ fn derive_path_params_custom_rename_all() {
     };
     let parameters = operation.get("parameters").unwrap();
 
+    #[cfg(not(feature = "openapi_31"))]
+    let vec_default = json!({
+        "type": "array",
+        "nullable": true,
+        "items": {
+            "type": "string"
+        }
+    });
+    #[cfg(feature = "openapi_31")]
+    let vec_default = json!({
+        "type": ["array", "null"],
+        "items": {
+            "type": "string"
+        }
+    });
+
     assert_json_eq!(
         parameters,
         json!([
@@ -987,13 +1146,7 @@ fn derive_path_params_custom_rename_all() {
                 "in": "query",
                 "name": "vecDefault",
                 "required": false,
-                "schema": {
-                    "type": "array",
-                    "nullable": true,
-                    "items": {
-                        "type": "string"
-                    }
-                },
+                "schema": vec_default,
             },
         ])
     )
@@ -1010,6 +1163,22 @@ fn derive_path_params_custom_rename_all_serde_will_override() {
     };
     let parameters = operation.get("parameters").unwrap();
 
+    #[cfg(not(feature = "openapi_31"))]
+    let vec_default = json!({
+        "type": "array",
+        "nullable": true,
+        "items": {
+            "type": "string"
+        }
+    });
+    #[cfg(feature = "openapi_31")]
+    let vec_default = json!({
+        "type": ["array", "null"],
+        "items": {
+            "type": "string"
+        }
+    });
+
     assert_json_eq!(
         parameters,
         json!([
@@ -1017,13 +1186,7 @@ fn derive_path_params_custom_rename_all_serde_will_override() {
                 "in": "query",
                 "name": "VEC_DEFAULT",
                 "required": false,
-                "schema": {
-                    "type": "array",
-                    "nullable": true,
-                    "items": {
-                        "type": "string"
-                    }
-                },
+                "schema": vec_default,
             },
         ])
     )
@@ -1130,6 +1293,43 @@ fn derive_path_params_intoparams() {
 
     let parameters = operation.get("parameters").unwrap();
 
+    #[cfg(not(feature = "openapi_31"))]
+    let since = json!({
+        "nullable": true,
+        "type": "string"
+    });
+    #[cfg(feature = "openapi_31")]
+    let since = json!({
+        "type": ["string", "null"]
+    });
+
+    #[cfg(not(feature = "openapi_31"))]
+    let foo_inline_option = json!({
+        "allOf": [
+            {
+                "default": "foo1",
+                "example": "foo1",
+                "enum": ["foo1", "foo2"],
+                "type": "string",
+            }
+        ],
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let foo_inline_option = json!({
+        "oneOf": [
+            {
+                "type": "null"
+            },
+            {
+                "default": "foo1",
+                "example": "foo1",
+                "enum": ["foo1", "foo2"],
+                "type": "string",
+            }
+        ]
+    });
+
     assert_json_eq!(
         parameters,
         json!([
@@ -1151,10 +1351,7 @@ fn derive_path_params_intoparams() {
                 "in": "query",
                 "name": "since",
                 "required": false,
-                "schema": {
-                    "nullable": true,
-                    "type": "string"
-                },
+                "schema": since,
                 "style": "form"
             },
             {
@@ -1185,17 +1382,7 @@ fn derive_path_params_intoparams() {
                 "in": "query",
                 "name": "foo_inline_option",
                 "required": false,
-                "schema": {
-                    "allOf": [
-                        {
-                            "default": "foo1",
-                            "example": "foo1",
-                            "enum": ["foo1", "foo2"],
-                            "type": "string",
-                        }
-                    ],
-                    "nullable": true,
-                },
+                "schema": foo_inline_option,
                 "style": "form"
             },
             {
@@ -1281,6 +1468,26 @@ fn derive_path_params_into_params_with_value_type() {
     let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
     let parameters = doc.pointer("/paths/foo/get/parameters").unwrap();
 
+    #[cfg(not(feature = "openapi_31"))]
+    let value3 = json!({
+        "nullable": true,
+        "type": "string"
+    });
+    #[cfg(feature = "openapi_31")]
+    let value3 = json!({
+        "type": ["string", "null"]
+    });
+
+    #[cfg(not(feature = "openapi_31"))]
+    let value4 = json!({
+        "nullable": true,
+        "type": "object"
+    });
+    #[cfg(feature = "openapi_31")]
+    let value4 = json!({
+        "type": ["object", "null"]
+    });
+
     assert_json_eq!(
         parameters,
         json!([{
@@ -1330,19 +1537,13 @@ fn derive_path_params_into_params_with_value_type() {
             "in": "query",
             "name": "value3",
             "required": false,
-            "schema": {
-                "nullable": true,
-                "type": "string"
-            }
+            "schema": value3
         },
         {
             "in": "query",
             "name": "value4",
             "required": false,
-            "schema": {
-                "nullable": true,
-                "type": "object"
-            }
+            "schema": value4
         },
         {
             "in": "query",
@@ -1751,6 +1952,26 @@ fn derive_into_params_required() {
 
     let value = operation.pointer("/parameters");
 
+    #[cfg(not(feature = "openapi_31"))]
+    let name2 = json!({
+        "type": "string",
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let name2 = json!({
+        "type": ["string", "null"],
+    });
+
+    #[cfg(not(feature = "openapi_31"))]
+    let name3 = json!({
+        "type": "string",
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let name3 = json!({
+        "type": ["string", "null"],
+    });
+
     assert_json_eq!(
         value,
         json!([
@@ -1766,19 +1987,13 @@ fn derive_into_params_required() {
               "in": "query",
               "name": "name2",
               "required": false,
-              "schema": {
-                  "type": "string",
-                  "nullable": true,
-              },
+              "schema": name2,
           },
           {
               "in": "query",
               "name": "name3",
               "required": true,
-              "schema": {
-                  "type": "string",
-                  "nullable": true,
-              },
+              "schema": name3,
           },
         ])
     )
@@ -1807,6 +2022,16 @@ fn derive_into_params_with_serde_skip() {
 
     let value = operation.pointer("/parameters");
 
+    #[cfg(not(feature = "openapi_31"))]
+    let name2 = json!({
+        "type": "string",
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let name2 = json!({
+        "type": ["string", "null"],
+    });
+
     assert_json_eq!(
         value,
         json!([
@@ -1822,10 +2047,7 @@ fn derive_into_params_with_serde_skip() {
               "in": "query",
               "name": "name2",
               "required": false,
-              "schema": {
-                  "type": "string",
-                  "nullable": true,
-              },
+              "schema": name2,
           },
         ])
     )
@@ -1854,6 +2076,16 @@ fn derive_into_params_with_serde_skip_deserializing() {
 
     let value = operation.pointer("/parameters");
 
+    #[cfg(not(feature = "openapi_31"))]
+    let name2 = json!({
+        "type": "string",
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let name2 = json!({
+        "type": ["string", "null"],
+    });
+
     assert_json_eq!(
         value,
         json!([
@@ -1869,10 +2101,7 @@ fn derive_into_params_with_serde_skip_deserializing() {
               "in": "query",
               "name": "name2",
               "required": false,
-              "schema": {
-                  "type": "string",
-                  "nullable": true,
-              },
+              "schema": name2,
           },
         ])
     )
@@ -1901,6 +2130,16 @@ fn derive_into_params_with_serde_skip_serializing() {
 
     let value = operation.pointer("/parameters");
 
+    #[cfg(not(feature = "openapi_31"))]
+    let name2 = json!({
+        "type": "string",
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let name2 = json!({
+        "type": ["string", "null"],
+    });
+
     assert_json_eq!(
         value,
         json!([
@@ -1916,10 +2155,7 @@ fn derive_into_params_with_serde_skip_serializing() {
               "in": "query",
               "name": "name2",
               "required": false,
-              "schema": {
-                  "type": "string",
-                  "nullable": true,
-              },
+              "schema": name2,
           },
         ])
     )
@@ -194,7 +194,6 @@ fn derive_parameters_with_all_types() {
         "[2].description" = r#""Foo numbers list""#, "Parameter description"
         "[2].required" = r#"false"#, "Parameter required"
         "[2].deprecated" = r#"null"#, "Parameter deprecated"
-        "[2].schema.type" = r#""array""#, "Parameter schema type"
         "[2].schema.format" = r#"null"#, "Parameter schema format"
         "[2].schema.items.type" = r#""integer""#, "Parameter schema items type"
         "[2].schema.items.format" = r#""int64""#, "Parameter schema items format"
@@ -215,6 +214,14 @@ fn derive_parameters_with_all_types() {
         "[4].schema.type" = r#""string""#, "Parameter schema type"
         "[4].schema.format" = r#"null"#, "Parameter schema format"
     };
+    #[cfg(not(feature = "openapi_31"))]
+    assert_value! {parameters=>
+        "[2].schema.type" = r#""array""#, "Parameter schema type"
+    };
+    #[cfg(feature = "openapi_31")]
+    assert_value! {parameters=>
+        "[2].schema.type" = r#"["array","null"]"#, "Parameter schema type"
+    };
 }
 
 mod derive_params_without_args {
@@ -286,12 +293,19 @@ fn derive_params_with_params_ext() {
         "[0].description" = r#""Foo value description""#, "Parameter description"
         "[0].required" = r#"false"#, "Parameter required"
         "[0].deprecated" = r#"true"#, "Parameter deprecated"
-        "[0].schema.type" = r#""array""#, "Parameter schema type"
         "[0].schema.items.type" = r#""string""#, "Parameter schema items type"
         "[0].style" = r#""form""#, "Parameter style"
         "[0].allowReserved" = r#"true"#, "Parameter allowReserved"
         "[0].explode" = r#"true"#, "Parameter explode"
     };
+    #[cfg(not(feature = "openapi_31"))]
+    assert_value! {parameters=>
+        "[0].schema.type" = r#""array""#, "Parameter schema type"
+    };
+    #[cfg(feature = "openapi_31")]
+    assert_value! {parameters=>
+        "[0].schema.type" = r#"["array","null"]"#, "Parameter schema type"
+    };
 }
 
 #[test]
@@ -320,6 +334,28 @@ fn derive_path_params_with_parameter_type_args() {
 
     common::assert_json_array_len(parameters, 1);
 
+    #[cfg(not(feature = "openapi_31"))]
+    let value = json!({
+        "type": "array",
+        "items": {
+            "maxLength": 20,
+            "pattern": r"\w",
+            "type": "string"
+        },
+        "maxItems": 1,
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let value = json!({
+        "type": ["array", "null"],
+        "items": {
+            "maxLength": 20,
+            "pattern": r"\w",
+            "type": "string"
+        },
+        "maxItems": 1,
+    });
+
     assert_json_eq!(
         parameters,
         json!([
@@ -329,16 +365,7 @@ fn derive_path_params_with_parameter_type_args() {
                   "required": false,
                   "deprecated": true,
                   "description": "Foo value description",
-                  "schema": {
-                      "type": "array",
-                      "items": {
-                          "maxLength": 20,
-                          "pattern": r"\w",
-                          "type": "string"
-                      },
-                      "maxItems": 1,
-                      "nullable": true,
-                  },
+                  "schema": value,
                   "style": "form",
                   "allowReserved": true,
                   "explode": true
@@ -86,18 +86,28 @@ fn derive_request_body_option_array_success() {
     let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
     let body = doc.pointer("/paths/~1foo/post/requestBody").unwrap();
 
+    #[cfg(not(feature = "openapi_31"))]
+    let schema = json!({
+        "items": {
+            "$ref": "#/components/schemas/Foo"
+        },
+        "nullable": true,
+        "type": "array",
+    });
+    #[cfg(feature = "openapi_31")]
+    let schema = json!({
+        "items": {
+            "$ref": "#/components/schemas/Foo"
+        },
+        "type": ["array", "null"],
+    });
+
     assert_json_eq!(
         body,
         json!({
             "content": {
                 "application/json": {
-                    "schema": {
-                        "items": {
-                            "$ref": "#/components/schemas/Foo"
-                        },
-                        "nullable": true,
-                        "type": "array",
-                    },
+                    "schema": schema,
                 }
             },
             "required": false,
@@ -401,19 +411,33 @@ fn derive_request_body_complex_required_explicit_false_success() {
     let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
     let body = doc.pointer("/paths/~1foo/post/requestBody").unwrap();
 
+    #[cfg(not(feature = "openapi_31"))]
+    let schema = json!({
+        "allOf": [
+        {
+            "$ref": "#/components/schemas/Foo"
+        }
+        ],
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let schema = json!({
+        "oneOf": [
+            {
+                "type": "null"
+            },
+            {
+                "$ref": "#/components/schemas/Foo"
+            }
+        ]
+    });
+
     assert_json_eq!(
         body,
         json!({
             "content": {
                 "text/xml": {
-                    "schema": {
-                        "allOf": [
-                        {
-                            "$ref": "#/components/schemas/Foo"
-                        }
-                        ],
-                        "nullable": true,
-                    }
+                    "schema": schema
                 }
             },
             "description": "Create new Foo",
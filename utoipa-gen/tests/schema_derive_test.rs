@@ -17,7 +17,15 @@ macro_rules! api_doc {
             $key $ident $( $tt )*
 
             let schema = api_doc!( @schema $ident $($tt)* );
-            serde_json::to_value(schema).unwrap()
+            let value = serde_json::to_value(schema).unwrap();
+            // title given by `auto_title` is only expected when the title is tested explicitly
+            #[cfg(feature = "auto_title")]
+            let value = if stringify!($(#[$meta])*).contains("title") {
+                value
+            } else {
+                common::without_auto_title(value, stringify!($ident))
+            };
+            value
         }
     };
     ( @schema $ident:ident < $($life:lifetime , )? $generic:ident > $($tt:tt)* ) => {
@@ -77,6 +85,8 @@ fn derive_map_ref() {
         }
     };
 
+    #[cfg(feature = "auto_title")]
+    let map = common::without_auto_titles(map, &[("/properties/map2/additionalProperties", "Foo")]);
     assert_json_eq!(
         map,
         json!({
@@ -170,2484 +180,2665 @@ fn derive_flattened_map_ref_property() {
 }
 
 #[test]
-fn derive_enum_with_additional_properties_success() {
-    let mode = api_doc! {
-        #[schema(default = "Mode1", example = "Mode2")]
-        enum Mode {
-            Mode1, Mode2
+fn derive_flattened_btree_map_ref_property() {
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Foo {
+        value: i32,
+    }
+
+    let map = api_doc! {
+        #[derive(Serialize)]
+        struct Map {
+            #[serde(flatten)]
+            map: std::collections::BTreeMap<String, Foo>,
         }
     };
 
-    assert_value! {mode=>
-        "default" = r#""Mode1""#, "Mode default"
-        "example" = r#""Mode2""#, "Mode example"
-        "enum" = r#"["Mode1","Mode2"]"#, "Mode enum variants"
-        "type" = r#""string""#, "Mode type"
-    };
+    assert_json_eq!(
+        map,
+        json!({
+            "additionalProperties": {"$ref": "#/components/schemas/Foo"},
+            "type": "object"
+        })
+    )
 }
 
 #[test]
-fn derive_enum_with_defaults_success() {
-    let mode = api_doc! {
-        enum Mode {
-            Mode1,
-            Mode2
+fn derive_map_properties_with_primitive_and_ref_values() {
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Foo {
+        value: i32,
+    }
+
+    let maps = api_doc! {
+        struct Maps {
+            hash_map_primitive: HashMap<String, i64>,
+            hash_map_ref: HashMap<String, Foo>,
+            btree_map_primitive: std::collections::BTreeMap<String, String>,
+            btree_map_ref: std::collections::BTreeMap<String, Foo>,
+            map_of_refs: HashMap<String, Vec<Foo>>,
         }
     };
 
-    assert_value! {mode=>
-        "enum" = r#"["Mode1","Mode2"]"#, "Mode enum variants"
-        "type" = r#""string""#, "Mode type"
-    };
-    assert_value! {mode=>
-        "default" = Value::Null, "Mode default"
-        "example" = Value::Null, "Mode example"
-    }
+    assert_json_eq!(
+        maps,
+        json!({
+            "properties": {
+                "hash_map_primitive": {
+                    "additionalProperties": {
+                        "type": "integer",
+                        "format": "int64"
+                    },
+                    "type": "object"
+                },
+                "hash_map_ref": {
+                    "additionalProperties": {"$ref": "#/components/schemas/Foo"},
+                    "type": "object"
+                },
+                "btree_map_primitive": {
+                    "additionalProperties": {"type": "string"},
+                    "type": "object"
+                },
+                "btree_map_ref": {
+                    "additionalProperties": {"$ref": "#/components/schemas/Foo"},
+                    "type": "object"
+                },
+                "map_of_refs": {
+                    "additionalProperties": {
+                        "items": {"$ref": "#/components/schemas/Foo"},
+                        "type": "array"
+                    },
+                    "type": "object"
+                }
+            },
+            "required": [
+                "hash_map_primitive",
+                "hash_map_ref",
+                "btree_map_primitive",
+                "btree_map_ref",
+                "map_of_refs"
+            ],
+            "type": "object"
+        })
+    )
 }
 
+#[cfg(feature = "indexmap")]
 #[test]
-fn derive_enum_with_with_custom_default_fn_success() {
-    let mode = api_doc! {
-        #[schema(default = mode_custom_default_fn)]
-        enum Mode {
-            Mode1,
-            Mode2
+fn derive_index_map_properties_same_as_hash_map() {
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Foo {
+        value: i32,
+    }
+
+    let index_maps = api_doc! {
+        struct Maps {
+            map_primitive: indexmap::IndexMap<String, i64>,
+            map_ref: indexmap::IndexMap<String, Foo>,
+            map_of_refs: Option<indexmap::IndexMap<String, Vec<Foo>>>,
         }
     };
-
-    assert_value! {mode=>
-        "default" = r#""Mode2""#, "Mode default"
-        "enum" = r#"["Mode1","Mode2"]"#, "Mode enum variants"
-        "type" = r#""string""#, "Mode type"
+    let hash_maps = api_doc! {
+        struct Maps {
+            map_primitive: HashMap<String, i64>,
+            map_ref: HashMap<String, Foo>,
+            map_of_refs: Option<HashMap<String, Vec<Foo>>>,
+        }
     };
-    assert_value! {mode=>
-        "example" = Value::Null, "Mode example"
-    }
-}
 
-fn mode_custom_default_fn() -> String {
-    "Mode2".to_string()
+    assert_json_eq!(index_maps, hash_maps);
+    assert_value! {index_maps=>
+        "properties.map_primitive.type" = r#""object""#, "Maps map_primitive type"
+        "properties.map_primitive.additionalProperties.format" = r#""int64""#, "Maps map_primitive additionalProperties format"
+        "properties.map_ref.additionalProperties.$ref" = r###""#/components/schemas/Foo""###, "Maps map_ref additionalProperties $ref"
+    }
 }
 
 #[test]
-fn derive_struct_with_defaults_success() {
-    let book = api_doc! {
-        struct Book {
-            name: String,
-            hash: String,
+fn derive_map_property_with_nullable_ref_value() {
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Foo {
+        value: i32,
+    }
+
+    let map = api_doc! {
+        #[derive(Serialize)]
+        struct Map {
+            map: HashMap<String, Option<Foo>>,
+            #[serde(flatten)]
+            flattened: std::collections::BTreeMap<String, Option<Foo>>,
         }
     };
 
-    assert_value! {book=>
-        "type" = r#""object""#, "Book type"
-        "properties.name.type" = r#""string""#, "Book name type"
-        "properties.hash.type" = r#""string""#, "Book hash type"
-        "required" = r#"["name","hash"]"#, "Book required fields"
-    };
+    #[cfg(not(feature = "openapi_31"))]
+    let nullable_foo = json!({
+        "allOf": [{"$ref": "#/components/schemas/Foo"}],
+        "nullable": true
+    });
+    #[cfg(feature = "openapi_31")]
+    let nullable_foo = json!({
+        "oneOf": [{"type": "null"}, {"$ref": "#/components/schemas/Foo"}]
+    });
+    assert_json_eq!(
+        map,
+        json!({
+            "properties": {
+                "map": {
+                    "additionalProperties": nullable_foo,
+                    "type": "object"
+                }
+            },
+            "additionalProperties": nullable_foo,
+            "required": ["map"],
+            "type": "object"
+        })
+    )
 }
 
+#[cfg(feature = "openapi_31")]
 #[test]
-fn derive_struct_with_custom_properties_success() {
-    let book = api_doc! {
-        struct Book {
-            #[schema(default = String::default)]
-            name: String,
-            #[schema(
-                default = "testhash",
-                example = "base64 text",
-                format = Byte,
-            )]
-            hash: String,
+fn derive_map_properties_with_integer_keys() {
+    let maps = api_doc! {
+        struct Maps {
+            unsigned: HashMap<u32, String>,
+            signed: std::collections::BTreeMap<i64, String>,
+            string: HashMap<String, String>,
         }
     };
 
-    assert_value! {book=>
-        "type" = r#""object""#, "Book type"
-        "properties.name.type" = r#""string""#, "Book name type"
-        "properties.name.default" = r#""""#, "Book name default"
-        "properties.hash.type" = r#""string""#, "Book hash type"
-        "properties.hash.format" = r#""byte""#, "Book hash format"
-        "properties.hash.example" = r#""base64 text""#, "Book hash example"
-        "properties.hash.default" = r#""testhash""#, "Book hash default"
-        "required" = r#"["name","hash"]"#, "Book required fields"
-    };
+    assert_json_eq!(
+        maps,
+        json!({
+            "properties": {
+                "unsigned": {
+                    "additionalProperties": {"type": "string"},
+                    "propertyNames": {
+                        "type": "string",
+                        "pattern": "^[0-9]+$"
+                    },
+                    "type": "object"
+                },
+                "signed": {
+                    "additionalProperties": {"type": "string"},
+                    "propertyNames": {
+                        "type": "string",
+                        "pattern": "^-?[0-9]+$"
+                    },
+                    "type": "object"
+                },
+                "string": {
+                    "additionalProperties": {"type": "string"},
+                    "type": "object"
+                }
+            },
+            "required": ["unsigned", "signed", "string"],
+            "type": "object"
+        })
+    )
 }
 
+#[cfg(not(feature = "openapi_31"))]
 #[test]
-fn derive_struct_with_default_attr() {
-    let book = api_doc! {
-        #[schema(default)]
-        struct Book {
-            name: String,
-            #[schema(default = 0)]
-            id: u64,
-            year: u64,
-            hash: String,
+fn derive_map_properties_with_integer_keys_without_property_names() {
+    let maps = api_doc! {
+        #[derive(Serialize)]
+        struct Maps {
+            unsigned: HashMap<u32, String>,
+            #[serde(flatten)]
+            signed: std::collections::BTreeMap<i64, String>,
         }
+    };
 
-        impl Default for Book {
-            fn default() -> Self {
-                Self {
-                    name: "No name".to_string(),
-                    id: 999,
-                    year: 2020,
-                    hash: "Test hash".to_string(),
+    assert_json_eq!(
+        maps,
+        json!({
+            "additionalProperties": {"type": "string"},
+            "properties": {
+                "unsigned": {
+                    "additionalProperties": {"type": "string"},
+                    "type": "object"
                 }
-            }
+            },
+            "required": ["unsigned"],
+            "type": "object"
+        })
+    )
+}
+
+#[cfg(feature = "openapi_31")]
+#[test]
+fn derive_flattened_map_with_integer_keys() {
+    let map = api_doc! {
+        #[derive(Serialize)]
+        struct Map {
+            #[serde(flatten)]
+            map: HashMap<u64, String>,
         }
     };
 
-    assert_value! { book =>
-        "properties.name.default" = r#""No name""#, "Book name default"
-        "properties.id.default" = r#"0"#, "Book id default"
-        "properties.year.default" = r#"2020"#, "Book year default"
-        "properties.hash.default" = r#""Test hash""#, "Book hash default"
+    assert_json_eq!(
+        map,
+        json!({
+            "additionalProperties": {"type": "string"},
+            "propertyNames": {
+                "type": "string",
+                "pattern": "^[0-9]+$"
+            },
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_flattened_map_with_enum_keys_value_type() {
+    #[derive(Serialize, Hash, PartialEq, Eq)]
+    #[allow(unused)]
+    enum Kind {
+        Cat,
+        Dog,
+    }
+
+    let map = api_doc! {
+        #[derive(Serialize)]
+        struct Map {
+            #[serde(flatten)]
+            #[schema(value_type = HashMap<String, String>)]
+            map: HashMap<Kind, String>,
+        }
     };
+
+    assert_json_eq!(
+        map,
+        json!({
+            "additionalProperties": {"type": "string"},
+            "type": "object"
+        })
+    )
 }
 
 #[test]
-fn derive_struct_with_default_attr_field() {
-    struct Book;
-    let owner = api_doc! {
-        struct Owner {
-            #[schema(default = json!({ "name": "Dune" }))]
-            favorite_book: Book,
-            #[schema(default = json!([{ "name": "The Fellowship Of The Ring" }]))]
-            books: Vec<Book>,
-            #[schema(default = json!({ "National Library": { "name": "The Stranger" } }))]
-            leases: HashMap<String, Book>,
-            #[schema(default = json!({ "name": "My Book" }))]
-            authored: Option<Book>,
+fn derive_struct_with_multiple_flattened_maps() {
+    let map = api_doc! {
+        #[derive(Serialize)]
+        struct Map {
+            id: u64,
+            #[serde(flatten)]
+            labels: HashMap<String, String>,
+            #[serde(flatten)]
+            counts: std::collections::BTreeMap<String, i32>,
         }
     };
 
     assert_json_eq!(
-        owner,
+        map,
         json!({
+            "additionalProperties": {
+                "oneOf": [
+                    {"type": "string"},
+                    {"type": "integer", "format": "int32"}
+                ]
+            },
             "properties": {
-                "favorite_book": {
-                    "allOf": [
-                        {
-                            "$ref": "#/components/schemas/Book",
-                        },
-                    ],
-                    "default": {
-                        "name": "Dune",
-                    },
-                },
-                "books": {
-                    "items": {
-                        "$ref": "#/components/schemas/Book",
-                    },
-                    "type": "array",
-                    "default": [
-                        {
-                            "name": "The Fellowship Of The Ring"
-                        }
-                    ]
-                },
-                "leases": {
-                    "additionalProperties": {
-                        "$ref": "#/components/schemas/Book",
-                    },
-                    "default": {
-                        "National Library": {
-                            "name": "The Stranger",
-                        },
-                    },
-                    "type": "object",
-                },
-                "authored": {
-                    "allOf": [
-                        {
-                            "$ref": "#/components/schemas/Book",
-                        },
-                    ],
-                    "default": {
-                        "name": "My Book",
-                    },
-                    "nullable": true,
-                },
+                "id": {
+                    "type": "integer",
+                    "format": "int64",
+                    "minimum": 0
+                }
             },
-            "required": [
-                "favorite_book",
-                "books",
-                "leases",
-            ],
-            "type": "object",
+            "required": ["id"],
+            "type": "object"
         })
-    );
+    )
 }
 
+#[cfg(feature = "openapi_31")]
 #[test]
-fn derive_struct_with_serde_default_attr() {
-    let book = api_doc! {
-        #[derive(serde::Deserialize)]
-        #[serde(default)]
-        struct Book {
-            name: String,
-            #[schema(default = 0)]
-            id: u64,
-            year: u64,
-            hash: String,
+fn derive_struct_with_multiple_flattened_integer_key_maps() {
+    let same_keys = api_doc! {
+        #[derive(Serialize)]
+        struct Map {
+            #[serde(flatten)]
+            labels: HashMap<u32, String>,
+            #[serde(flatten)]
+            counts: HashMap<u64, i32>,
         }
-
-        impl Default for Book {
-            fn default() -> Self {
-                Self {
-                    name: "No name".to_string(),
-                    id: 999,
-                    year: 2020,
-                    hash: "Test hash".to_string(),
-                }
-            }
+    };
+    let mixed_keys = api_doc! {
+        #[derive(Serialize)]
+        struct Map {
+            #[serde(flatten)]
+            labels: HashMap<String, String>,
+            #[serde(flatten)]
+            counts: HashMap<u64, i32>,
         }
     };
 
-    assert_value! { book =>
-        "properties.name.default" = r#""No name""#, "Book name default"
-        "properties.id.default" = r#"0"#, "Book id default"
-        "properties.year.default" = r#"2020"#, "Book year default"
-        "properties.hash.default" = r#""Test hash""#, "Book hash default"
-    };
+    assert_json_eq!(
+        same_keys,
+        json!({
+            "additionalProperties": {
+                "oneOf": [
+                    {"type": "string"},
+                    {"type": "integer", "format": "int32"}
+                ]
+            },
+            "propertyNames": {
+                "type": "string",
+                "pattern": "^[0-9]+$"
+            },
+            "type": "object"
+        })
+    );
+    assert_json_eq!(
+        mixed_keys,
+        json!({
+            "additionalProperties": {
+                "oneOf": [
+                    {"type": "string"},
+                    {"type": "integer", "format": "int32"}
+                ]
+            },
+            "type": "object"
+        })
+    );
 }
 
 #[test]
-fn derive_struct_with_optional_properties() {
-    struct Book;
-    let owner = api_doc! {
-        struct Owner {
-            #[schema(default = 1)]
-            id: i64,
-            enabled: Option<bool>,
-            books: Option<Vec<Book>>,
-            metadata: Option<HashMap<String, String>>,
-            optional_book: Option<Book>
+fn derive_map_properties_with_max_and_min_properties() {
+    let map = api_doc! {
+        struct Map {
+            #[schema(max_properties = 10, min_properties = 1)]
+            map: HashMap<String, String>,
+            #[schema(value_type = Object, max_properties = 5)]
+            object: String,
         }
     };
 
     assert_json_eq!(
-        owner,
+        map,
         json!({
             "properties": {
-                "id": {
-                    "type": "integer",
-                    "format": "int64",
-                    "default": 1,
-                },
-                "enabled": {
-                    "type": "boolean",
-                    "nullable": true,
-                },
-                "books": {
-                    "items": {
-                        "$ref": "#/components/schemas/Book",
-                    },
-                    "nullable": true,
-                    "type": "array"
-                },
-                "metadata": {
-                    "type": "object",
-                    "nullable": true,
-                    "additionalProperties": {
-                        "type": "string"
-                    }
+                "map": {
+                    "additionalProperties": {"type": "string"},
+                    "maxProperties": 10,
+                    "minProperties": 1,
+                    "type": "object"
                 },
-                "optional_book": {
-                    "nullable": true,
-                    "allOf": [
-                        {
-                            "$ref": "#/components/schemas/Book"
-                        }
-                    ]
+                "object": {
+                    "maxProperties": 5,
+                    "type": "object"
                 }
             },
-            "required": [
-                "id",
-            ],
+            "required": ["map", "object"],
             "type": "object"
         })
-    );
+    )
 }
 
 #[test]
-fn derive_struct_with_comments() {
-    struct Foobar;
-    let account = api_doc! {
-        /// This is user account dto object
-        ///
-        /// Detailed documentation here.
-        /// More than the first line is added to the description as well.
-        struct Account {
-            /// Database autogenerated id
-            id: i64,
-            /// Users username
-            username: String,
-            /// Role ids
-            role_ids: Vec<i32>,
-            /// Foobars
-            foobars: Vec<Foobar>,
-            /// Map description
-            map: HashMap<String, String>
-        }
-    };
+fn derive_map_properties_with_additional_properties_description() {
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Foo {
+        value: i32,
+    }
+
+    let map = api_doc! {
+        struct Map {
+            /// Scores by player name
+            #[schema(additional_properties_description = "Score of the player")]
+            scores: HashMap<String, i64>,
+            #[schema(additional_properties_description = "Foos of the group")]
+            groups: Option<HashMap<String, Vec<Foo>>>,
+        }
+    };
+
+    #[cfg(not(feature = "openapi_31"))]
+    let groups = json!({
+        "additionalProperties": {
+            "items": {"$ref": "#/components/schemas/Foo"},
+            "type": "array",
+            "description": "Foos of the group"
+        },
+        "nullable": true,
+        "type": "object"
+    });
+    #[cfg(feature = "openapi_31")]
+    let groups = json!({
+        "additionalProperties": {
+            "items": {"$ref": "#/components/schemas/Foo"},
+            "type": "array",
+            "description": "Foos of the group"
+        },
+        "type": ["object", "null"]
+    });
 
     assert_json_eq!(
-        account,
+        map,
         json!({
-            "description": "This is user account dto object\n\nDetailed documentation here.\nMore than the first line is added to the description as well.",
             "properties": {
-                "foobars": {
-                    "description": "Foobars",
-                    "type": "array",
-                    "items": {
-                        "$ref": "#/components/schemas/Foobar"
-                    }
-                },
-                "id": {
-                    "type": "integer",
-                    "format": "int64",
-                    "description": "Database autogenerated id",
-                },
-                "role_ids": {
-                    "description": "Role ids",
-                    "type": "array",
-                    "items": {
-                        "type": "integer",
-                        "format": "int32",
-                    }
-                },
-                "username": {
-                    "type": "string",
-                    "description": "Users username",
-                },
-                "map": {
-                    "description": "Map description",
-                    "type": "object",
+                "scores": {
                     "additionalProperties": {
-                        "type": "string"
+                        "type": "integer",
+                        "format": "int64",
+                        "description": "Score of the player"
                     },
-                }
+                    "description": "Scores by player name",
+                    "type": "object"
+                },
+                "groups": groups
             },
-            "required": ["id", "username", "role_ids", "foobars", "map"],
+            "required": ["scores"],
             "type": "object"
         })
     )
 }
 
 #[test]
-fn derive_enum_with_comments_success() {
-    let account = api_doc! {
-        /// This is user account status enum
-        ///
-        /// Detailed documentation here.
-        /// More than the first line is added to the description as well.
-        enum AccountStatus {
-            /// When user is valid to login, these enum variant level docs are omitted!!!!!
-            /// Since the OpenAPI spec does not have a place to put such information.
-            Enabled,
-            /// Login failed too many times
-            Locked,
-            Disabled
+fn derive_flattened_map_with_max_and_min_properties() {
+    let map = api_doc! {
+        #[derive(Serialize)]
+        struct Map {
+            #[serde(flatten)]
+            #[schema(max_properties = 10, min_properties = 1)]
+            map: HashMap<String, String>,
         }
     };
 
-    assert_value! {account=>
-        "description" = r#""This is user account status enum\n\nDetailed documentation here.\nMore than the first line is added to the description as well.""#, "AccountStatus description"
-    }
+    assert_json_eq!(
+        map,
+        json!({
+            "additionalProperties": {"type": "string"},
+            "maxProperties": 10,
+            "minProperties": 1,
+            "type": "object"
+        })
+    )
 }
 
 #[test]
-fn derive_struct_unnamed_field_single_value_type_success() {
-    let point = api_doc! {
-        struct Point(f32);
+fn derive_struct_with_max_and_min_properties() {
+    let map = api_doc! {
+        #[schema(max_properties = 2, min_properties = 1)]
+        struct Map {
+            value: String,
+        }
     };
 
-    assert_value! {point=>
-        "type" = r#""number""#, "Point type"
-        "format" = r#""float""#, "Point format"
-    }
+    assert_value! {map=>
+        "maxProperties" = r#"2"#, "Map maxProperties"
+        "minProperties" = r#"1"#, "Map minProperties"
+    };
 }
 
 #[test]
-fn derive_struct_unnamed_fields_tuple_with_same_type_success() {
-    let point = api_doc! {
-        /// Contains x and y coordinates
-        ///
-        /// Coordinates are used to pinpoint location on a map
-        struct Point(f64, f64);
+fn derive_enum_with_additional_properties_success() {
+    let mode = api_doc! {
+        #[schema(default = "Mode1", example = "Mode2")]
+        enum Mode {
+            Mode1, Mode2
+        }
     };
 
-    assert_value! {point=>
-        "type" = r#""array""#, "Point type"
-        "items.type" = r#""number""#, "Point items type"
-        "items.format" = r#""double""#, "Point items format"
-        "items.description" = r#""Contains x and y coordinates\n\nCoordinates are used to pinpoint location on a map""#, "Point items description"
-        "maxItems" = r#"2"#, "Wrapper max items"
-        "minItems" = r#"2"#, "Wrapper min items"
-    }
+    assert_value! {mode=>
+        "default" = r#""Mode1""#, "Mode default"
+        "example" = r#""Mode2""#, "Mode example"
+        "enum" = r#"["Mode1","Mode2"]"#, "Mode enum variants"
+        "type" = r#""string""#, "Mode type"
+    };
 }
 
 #[test]
-fn derive_struct_unnamed_fields_tuple_with_different_types_success() {
-    let point = api_doc! {
-        struct Point(f64, String);
+fn derive_enum_with_defaults_success() {
+    let mode = api_doc! {
+        enum Mode {
+            Mode1,
+            Mode2
+        }
     };
 
-    assert_value! {point=>
-        "type" = r#""array""#, "Point type"
-        "items.type" = r#""object""#, "Point items type"
-        "items.format" = r#"null"#, "Point items format"
+    assert_value! {mode=>
+        "enum" = r#"["Mode1","Mode2"]"#, "Mode enum variants"
+        "type" = r#""string""#, "Mode type"
+    };
+    assert_value! {mode=>
+        "default" = Value::Null, "Mode default"
+        "example" = Value::Null, "Mode example"
     }
 }
 
 #[test]
-fn derive_struct_unnamed_field_with_generic_types_success() {
-    let point = api_doc! {
-        struct Wrapper(Option<String>);
+fn derive_enum_with_with_custom_default_fn_success() {
+    let mode = api_doc! {
+        #[schema(default = mode_custom_default_fn)]
+        enum Mode {
+            Mode1,
+            Mode2
+        }
     };
 
-    assert_value! {point=>
-        "type" = r#""string""#, "Wrapper type"
+    assert_value! {mode=>
+        "default" = r#""Mode2""#, "Mode default"
+        "enum" = r#"["Mode1","Mode2"]"#, "Mode enum variants"
+        "type" = r#""string""#, "Mode type"
+    };
+    assert_value! {mode=>
+        "example" = Value::Null, "Mode example"
     }
 }
 
-#[test]
-fn derive_struct_unnamed_field_with_nested_generic_type_success() {
-    let point = api_doc! {
-        /// Some description
-        struct Wrapper(Option<Vec<i32>>);
-    };
-
-    assert_value! {point=>
-        "type" = r#""array""#, "Wrapper type"
-        "items.type" = r#""integer""#, "Wrapper items type"
-        "items.format" = r#""int32""#, "Wrapper items format"
-        "description" = r#""Some description""#, "Wrapper description"
-    }
+fn mode_custom_default_fn() -> String {
+    "Mode2".to_string()
 }
 
 #[test]
-fn derive_struct_unnamed_field_with_multiple_nested_generic_type_success() {
-    let point = api_doc! {
-        /// Some documentation
-        struct Wrapper(Option<Vec<i32>>, String);
+fn derive_struct_with_defaults_success() {
+    let book = api_doc! {
+        struct Book {
+            name: String,
+            hash: String,
+        }
     };
 
-    assert_value! {point=>
-        "type" = r#""array""#, "Wrapper type"
-        "items.type" = r#""object""#, "Wrapper items type"
-        "items.format" = r#"null"#, "Wrapper items format"
-        "description" = r#""Some documentation""#, "Wrapper description"
-    }
+    assert_value! {book=>
+        "type" = r#""object""#, "Book type"
+        "properties.name.type" = r#""string""#, "Book name type"
+        "properties.hash.type" = r#""string""#, "Book hash type"
+        "required" = r#"["name","hash"]"#, "Book required fields"
+    };
 }
 
 #[test]
-fn derive_struct_unnamed_field_vec_type_success() {
-    let point = api_doc! {
-        /// Some documentation
-        /// more documentation
-        struct Wrapper(Vec<i32>);
+fn derive_struct_with_custom_properties_success() {
+    let book = api_doc! {
+        struct Book {
+            #[schema(default = String::default)]
+            name: String,
+            #[schema(
+                default = "testhash",
+                example = "base64 text",
+                format = Byte,
+            )]
+            hash: String,
+        }
     };
 
-    assert_value! {point=>
-        "type" = r#""array""#, "Wrapper type"
-        "items.type" = r#""integer""#, "Wrapper items type"
-        "items.format" = r#""int32""#, "Wrapper items format"
-        "maxItems" = r#"null"#, "Wrapper max items"
-        "minItems" = r#"null"#, "Wrapper min items"
-        "description" = r#""Some documentation\nmore documentation""#, "Wrapper description"
-    }
+    assert_value! {book=>
+        "type" = r#""object""#, "Book type"
+        "properties.name.type" = r#""string""#, "Book name type"
+        "properties.name.default" = r#""""#, "Book name default"
+        "properties.hash.type" = r#""string""#, "Book hash type"
+        "properties.hash.format" = r#""byte""#, "Book hash format"
+        "properties.hash.example" = r#""base64 text""#, "Book hash example"
+        "properties.hash.default" = r#""testhash""#, "Book hash default"
+        "required" = r#"["name","hash"]"#, "Book required fields"
+    };
 }
 
 #[test]
-fn derive_struct_unnamed_field_single_value_default_success() {
-    let point = api_doc! {
-        #[schema(default)]
-        struct Point(f32);
+fn derive_struct_with_literal_defaults() {
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    enum Status {
+        Active,
+        Inactive,
+    }
 
-        impl Default for Point {
-            fn default() -> Self {
-                Self(3.5)
-            }
+    const DEFAULT_LIMIT: i32 = 100;
+
+    let book = api_doc! {
+        struct Book {
+            #[schema(default = "active")]
+            state: String,
+            #[schema(default = 42)]
+            count: i32,
+            #[schema(default = 1.5)]
+            ratio: f64,
+            #[schema(default = true)]
+            published: bool,
+            #[schema(default = Status::Inactive)]
+            status: Status,
+            #[schema(default = DEFAULT_LIMIT)]
+            limit: i32,
         }
     };
 
-    assert_value! {point=>
-        "type" = r#""number""#, "Point type"
-        "format" = r#""float""#, "Point format"
-        "default" = r#"3.5"#, "Point default"
-    }
+    assert_json_eq!(
+        book,
+        json!({
+            "properties": {
+                "state": {
+                    "type": "string",
+                    "default": "active"
+                },
+                "count": {
+                    "type": "integer",
+                    "format": "int32",
+                    "default": 42
+                },
+                "ratio": {
+                    "type": "number",
+                    "format": "double",
+                    "default": 1.5
+                },
+                "published": {
+                    "type": "boolean",
+                    "default": true
+                },
+                "status": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Status"
+                        }
+                    ],
+                    "default": "Inactive"
+                },
+                "limit": {
+                    "type": "integer",
+                    "format": "int32",
+                    "default": 100
+                }
+            },
+            "required": ["state", "count", "ratio", "published", "status", "limit"],
+            "type": "object"
+        })
+    );
 }
 
 #[test]
-fn derive_struct_unnamed_field_multiple_value_default_ignored() {
-    let point = api_doc! {
+fn derive_struct_with_default_attr() {
+    let book = api_doc! {
         #[schema(default)]
-        struct Point(f32, f32);
+        struct Book {
+            name: String,
+            #[schema(default = 0)]
+            id: u64,
+            year: u64,
+            hash: String,
+        }
 
-        impl Default for Point {
+        impl Default for Book {
             fn default() -> Self {
-                Self(3.5, 6.4)
+                Self {
+                    name: "No name".to_string(),
+                    id: 999,
+                    year: 2020,
+                    hash: "Test hash".to_string(),
+                }
             }
         }
     };
-    // Default values shouldn't be assigned as the struct is represented
-    // as an array
-    assert!(!point.to_string().contains("default"))
-}
 
-#[test]
-fn derive_struct_nested_vec_success() {
-    let vecs = api_doc! {
-        struct VecTest {
-            vecs: Vec<Vec<String>>
-        }
+    assert_value! { book =>
+        "properties.name.default" = r#""No name""#, "Book name default"
+        "properties.id.default" = r#"0"#, "Book id default"
+        "properties.year.default" = r#"2020"#, "Book year default"
+        "properties.hash.default" = r#""Test hash""#, "Book hash default"
     };
-
-    assert_value! {vecs=>
-        "properties.vecs.type" = r#""array""#, "Vecs property type"
-        "properties.vecs.items.type" = r#""array""#, "Vecs property items type"
-        "properties.vecs.items.items.type" = r#""string""#, "Vecs property items item type"
-        "type" = r#""object""#, "Property type"
-        "required.[0]" = r#""vecs""#, "Required properties"
-    }
-    common::assert_json_array_len(vecs.get("required").unwrap(), 1);
 }
 
 #[test]
-fn derive_struct_with_example() {
-    let pet = api_doc! {
-        #[schema(example = json!({"name": "bob the cat", "age": 8}))]
-        struct Pet {
-            name: String,
-            age: i32
+fn derive_struct_with_default_attr_field() {
+    struct Book;
+    let owner = api_doc! {
+        struct Owner {
+            #[schema(default = json!({ "name": "Dune" }))]
+            favorite_book: Book,
+            #[schema(default = json!([{ "name": "The Fellowship Of The Ring" }]))]
+            books: Vec<Book>,
+            #[schema(default = json!({ "National Library": { "name": "The Stranger" } }))]
+            leases: HashMap<String, Book>,
+            #[schema(default = json!({ "name": "My Book" }))]
+            authored: Option<Book>,
         }
     };
 
-    assert_value! {pet=>
-        "example.name" = r#""bob the cat""#, "Pet example name"
-        "example.age" = r#"8"#, "Pet example age"
-    }
-}
-
-#[test]
-fn derive_struct_with_deprecated() {
-    #[allow(deprecated)]
-    let pet = api_doc! {
-        #[deprecated]
-        struct Pet {
-            name: String,
-            #[deprecated]
-            age: i32
-        }
-    };
+    #[cfg(not(feature = "openapi_31"))]
+    let authored = json!({
+        "allOf": [
+            {
+                "$ref": "#/components/schemas/Book",
+            },
+        ],
+        "default": {
+            "name": "My Book",
+        },
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let authored = json!({
+        "allOf": [
+            {
+                "oneOf": [
+                    {
+                        "type": "null"
+                    },
+                    {
+                        "$ref": "#/components/schemas/Book",
+                    },
+                ]
+            }
+        ],
+        "default": {
+            "name": "My Book",
+        },
+    });
 
-    assert_value! {pet=>
-        "deprecated" = r#"true"#, "Pet deprecated"
-        "properties.name.type" = r#""string""#, "Pet properties name type"
-        "properties.name.deprecated" = r#"null"#, "Pet properties name deprecated"
-        "properties.age.type" = r#""integer""#, "Pet properties age type"
-        "properties.age.deprecated" = r#"true"#, "Pet properties age deprecated"
-        "example" = r#"null"#, "Pet example"
-    }
+    assert_json_eq!(
+        owner,
+        json!({
+            "properties": {
+                "favorite_book": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Book",
+                        },
+                    ],
+                    "default": {
+                        "name": "Dune",
+                    },
+                },
+                "books": {
+                    "items": {
+                        "$ref": "#/components/schemas/Book",
+                    },
+                    "type": "array",
+                    "default": [
+                        {
+                            "name": "The Fellowship Of The Ring"
+                        }
+                    ]
+                },
+                "leases": {
+                    "additionalProperties": {
+                        "$ref": "#/components/schemas/Book",
+                    },
+                    "default": {
+                        "National Library": {
+                            "name": "The Stranger",
+                        },
+                    },
+                    "type": "object",
+                },
+                "authored": authored,
+            },
+            "required": [
+                "favorite_book",
+                "books",
+                "leases",
+            ],
+            "type": "object",
+        })
+    );
 }
 
 #[test]
-fn derive_struct_with_schema_deprecated() {
-    let pet = api_doc! {
-        #[schema(deprecated)]
-        struct Pet {
+fn derive_struct_with_serde_default_attr() {
+    let book = api_doc! {
+        #[derive(serde::Deserialize)]
+        #[serde(default)]
+        struct Book {
             name: String,
-            #[schema(deprecated)]
-            age: i32
+            #[schema(default = 0)]
+            id: u64,
+            year: u64,
+            hash: String,
         }
-    };
-
-    assert_value! {pet=>
-        "deprecated" = r#"true"#, "Pet deprecated"
-        "properties.name.type" = r#""string""#, "Pet properties name type"
-        "properties.name.deprecated" = r#"null"#, "Pet properties name deprecated"
-        "properties.age.type" = r#""integer""#, "Pet properties age type"
-        "properties.age.deprecated" = r#"true"#, "Pet properties age deprecated"
-        "example" = r#"null"#, "Pet example"
-    }
-}
 
-#[test]
-fn derive_unnamed_struct_deprecated_success() {
-    #[allow(deprecated)]
-    let pet_age = api_doc! {
-        #[deprecated]
-        #[schema(example = 8)]
-        struct PetAge(u64);
+        impl Default for Book {
+            fn default() -> Self {
+                Self {
+                    name: "No name".to_string(),
+                    id: 999,
+                    year: 2020,
+                    hash: "Test hash".to_string(),
+                }
+            }
+        }
     };
 
-    assert_value! {pet_age=>
-        "deprecated" = r#"true"#, "PetAge deprecated"
-        "example" = r#"8"#, "PetAge example"
-    }
-}
-
-#[test]
-fn derive_unnamed_struct_schema_deprecated_success() {
-    let pet_age = api_doc! {
-        #[schema(deprecated, example = 8)]
-        struct PetAge(u64);
+    assert_value! { book =>
+        "properties.name.default" = r#""No name""#, "Book name default"
+        "properties.id.default" = r#"0"#, "Book id default"
+        "properties.year.default" = r#"2020"#, "Book year default"
+        "properties.hash.default" = r#""Test hash""#, "Book hash default"
     };
-
-    assert_value! {pet_age=>
-        "deprecated" = r#"true"#, "PetAge deprecated"
-        "example" = r#"8"#, "PetAge example"
-    }
 }
 
 #[test]
-fn derive_unnamed_struct_example_json_array_success() {
-    let pet_age = api_doc! {
-        #[schema(example = "0", default = i64::default)]
-        struct PetAge(i64, i64);
-    };
-
-    assert_value! {pet_age=>
-        "type" = r#""array""#, "PetAge type"
-        "items.example" = r#""0""#, "PetAge example"
-        "items.default" = r#"0"#, "PetAge default"
-        "items.type" = r#""integer""#, "PetAge default"
-        "items.format" = r#""int64""#, "PetAge default"
-        "maxItems" = r#"2"#, "PetAge max items"
-        "minItems" = r#"2"#, "PetAge min items"
+fn derive_struct_with_serde_field_default_not_required() {
+    fn default_hash() -> String {
+        String::from("0000")
     }
-}
 
-#[test]
-fn derive_enum_with_deprecated() {
-    #[allow(deprecated)]
-    let mode = api_doc! {
-        #[deprecated]
-        enum Mode {
-            Mode1, Mode2
+    let book = api_doc! {
+        #[derive(serde::Deserialize)]
+        struct Book {
+            name: String,
+            #[serde(default)]
+            year: u64,
+            #[serde(default = "default_hash")]
+            hash: String,
+            #[serde(rename = "isbn", default = "default_hash")]
+            id: String,
+            pages: u32,
         }
     };
 
-    assert_value! {mode=>
-        "enum" = r#"["Mode1","Mode2"]"#, "Mode enum variants"
-        "type" = r#""string""#, "Mode type"
-        "deprecated" = r#"true"#, "Mode deprecated"
+    assert_value! { book =>
+        "required" = r#"["name","pages"]"#, "Book required"
+        "properties.hash.type" = r#""string""#, "Book hash type"
+        "properties.isbn.type" = r#""string""#, "Book isbn type"
     };
 }
 
 #[test]
-fn derive_enum_with_schema_deprecated() {
-    let mode = api_doc! {
-        #[schema(deprecated)]
-        enum Mode {
-            Mode1, Mode2
+fn derive_struct_with_optional_properties() {
+    struct Book;
+    let owner = api_doc! {
+        struct Owner {
+            #[schema(default = 1)]
+            id: i64,
+            enabled: Option<bool>,
+            books: Option<Vec<Book>>,
+            metadata: Option<HashMap<String, String>>,
+            optional_book: Option<Book>
         }
     };
 
-    assert_value! {mode=>
-        "enum" = r#"["Mode1","Mode2"]"#, "Mode enum variants"
-        "type" = r#""string""#, "Mode type"
-        "deprecated" = r#"true"#, "Mode deprecated"
-    };
-}
-
-#[test]
-fn derive_struct_with_generics() {
-    #[allow(unused)]
-    enum Type {
-        Foo,
-        Bar,
-    }
-    let status = api_doc! {
-        struct Status<Type> {
-            t: Type
-        }
-    };
+    #[cfg(not(feature = "openapi_31"))]
+    let enabled = json!({
+        "type": "boolean",
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let enabled = json!({
+        "type": ["boolean", "null"],
+    });
+
+    #[cfg(not(feature = "openapi_31"))]
+    let books = json!({
+        "items": {
+            "$ref": "#/components/schemas/Book",
+        },
+        "nullable": true,
+        "type": "array"
+    });
+    #[cfg(feature = "openapi_31")]
+    let books = json!({
+        "items": {
+            "$ref": "#/components/schemas/Book",
+        },
+        "type": ["array", "null"]
+    });
+
+    #[cfg(not(feature = "openapi_31"))]
+    let metadata = json!({
+        "type": "object",
+        "nullable": true,
+        "additionalProperties": {
+            "type": "string"
+        }
+    });
+    #[cfg(feature = "openapi_31")]
+    let metadata = json!({
+        "type": ["object", "null"],
+        "additionalProperties": {
+            "type": "string"
+        }
+    });
+
+    #[cfg(not(feature = "openapi_31"))]
+    let optional_book = json!({
+        "nullable": true,
+        "allOf": [
+            {
+                "$ref": "#/components/schemas/Book"
+            }
+        ]
+    });
+    #[cfg(feature = "openapi_31")]
+    let optional_book = json!({
+        "oneOf": [
+            {
+                "type": "null"
+            },
+            {
+                "$ref": "#/components/schemas/Book"
+            }
+        ]
+    });
 
-    assert_value! {status=>
-        "properties.t.$ref" = r###""#/components/schemas/Type""###, "Status t field"
-    };
+    assert_json_eq!(
+        owner,
+        json!({
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "format": "int64",
+                    "default": 1,
+                },
+                "enabled": enabled,
+                "books": books,
+                "metadata": metadata,
+                "optional_book": optional_book
+            },
+            "required": [
+                "id",
+            ],
+            "type": "object"
+        })
+    );
 }
 
 #[test]
-fn derive_struct_with_lifetime_generics() {
-    #[allow(unused)]
-    let greeting = api_doc! {
-        struct Greeting<'a> {
-            greeting: &'a str
-        }
-    };
-
-    assert_value! {greeting=>
-        "properties.greeting.type" = r###""string""###, "Greeting greeting field type"
-    };
-}
-
-#[test]
-fn derive_struct_with_cow() {
-    #[allow(unused)]
-    let greeting = api_doc! {
-        struct Greeting<'a> {
-            greeting: Cow<'a, str>
-        }
-    };
-
-    common::assert_json_array_len(greeting.get("required").unwrap(), 1);
-    assert_value! {greeting=>
-        "properties.greeting.type" = r###""string""###, "Greeting greeting field type"
-        "required.[0]" = r###""greeting""###, "Greeting required"
-    };
-}
-
-#[test]
-fn derive_with_box_and_refcell() {
-    #[allow(unused)]
-    struct Foo {
-        name: &'static str,
-    }
-
-    let greeting = api_doc! {
-        struct Greeting {
-            foo: Box<Foo>,
-            ref_cell_foo: RefCell<Foo>
-        }
-    };
-
-    common::assert_json_array_len(greeting.get("required").unwrap(), 2);
-    assert_value! {greeting=>
-        "properties.foo.$ref" = r###""#/components/schemas/Foo""###, "Greeting foo field"
-        "properties.ref_cell_foo.$ref" = r###""#/components/schemas/Foo""###, "Greeting ref_cell_foo field"
-        "required.0" = r###""foo""###, "Greeting required 0"
-        "required.1" = r###""ref_cell_foo""###, "Greeting required 1"
-    };
-}
-
-#[test]
-fn derive_struct_with_inline() {
-    #[derive(utoipa::ToSchema)]
-    #[allow(unused)]
-    struct Foo {
-        name: &'static str,
-    }
-
-    let greeting = api_doc! {
-        struct Greeting {
-            #[schema(inline)]
-            foo1: Foo,
-            #[schema(inline)]
-            foo2: Option<Foo>,
-            #[schema(inline)]
-            foo3: Option<Box<Foo>>,
-            #[schema(inline)]
-            foo4: Vec<Foo>,
+fn derive_struct_with_comments() {
+    struct Foobar;
+    let account = api_doc! {
+        /// This is user account dto object
+        ///
+        /// Detailed documentation here.
+        /// More than the first line is added to the description as well.
+        struct Account {
+            /// Database autogenerated id
+            id: i64,
+            /// Users username
+            username: String,
+            /// Role ids
+            role_ids: Vec<i32>,
+            /// Foobars
+            foobars: Vec<Foobar>,
+            /// Map description
+            map: HashMap<String, String>
         }
     };
 
     assert_json_eq!(
-        &greeting,
+        account,
         json!({
+            "description": "This is user account dto object\n\nDetailed documentation here.\nMore than the first line is added to the description as well.",
             "properties": {
-                "foo1": {
-                    "properties": {
-                        "name": {
-                            "type": "string"
-                        },
-                    },
-                    "required": [
-                        "name"
-                    ],
-                    "type": "object"
+                "foobars": {
+                    "description": "Foobars",
+                    "type": "array",
+                    "items": {
+                        "$ref": "#/components/schemas/Foobar"
+                    }
                 },
-                "foo2": {
-                    "nullable": true,
-                    "allOf": [
-                     {
-                         "properties": {
-                             "name": {
-                                 "type": "string"
-                             },
-                         },
-                         "required": [
-                             "name"
-                         ],
-                         "type": "object"
-                     }
-                    ]
+                "id": {
+                    "type": "integer",
+                    "format": "int64",
+                    "description": "Database autogenerated id",
                 },
-                "foo3": {
-                    "nullable": true,
-                    "allOf": [
-                    {
-                        "properties": {
-                            "name": {
-                                "type": "string"
-                            },
-                        },
-                        "required": [
-                            "name"
-                        ],
-                        "type": "object"
+                "role_ids": {
+                    "description": "Role ids",
+                    "type": "array",
+                    "items": {
+                        "type": "integer",
+                        "format": "int32",
                     }
-                    ]
                 },
-                "foo4": {
-                    "items": {
-                        "properties": {
-                            "name": {
-                                "type": "string"
-                            },
-                        },
-                        "required": [
-                            "name"
-                        ],
-                        "type": "object"
-                    },
-                    "type": "array"
+                "username": {
+                    "type": "string",
+                    "description": "Users username",
                 },
+                "map": {
+                    "description": "Map description",
+                    "type": "object",
+                    "additionalProperties": {
+                        "type": "string"
+                    },
+                }
             },
-            "required": [
-                "foo1",
-                "foo4",
-            ],
+            "required": ["id", "username", "role_ids", "foobars", "map"],
             "type": "object"
         })
-    );
+    )
 }
 
 #[test]
-fn derive_simple_enum() {
-    let value: Value = api_doc! {
-        #[derive(Serialize)]
-        enum Bar {
-            A,
-            B,
-            C,
+fn derive_struct_with_comments_on_ref_fields() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Owner {
+        name: String,
+    }
+
+    let pet = api_doc! {
+        struct Pet {
+            /// Current owner of the pet
+            owner: Owner,
+            /// Previous owner of the pet
+            #[schema(default = json!({"name": "shelter"}))]
+            previous_owner: Owner,
         }
     };
 
+    // OpenAPI 3.0 ignores siblings of $ref thus the description is defined on allOf wrapper
+    #[cfg(not(feature = "openapi_31"))]
+    let owner = json!({
+        "allOf": [
+            {
+                "$ref": "#/components/schemas/Owner"
+            }
+        ],
+        "description": "Current owner of the pet"
+    });
+    #[cfg(feature = "openapi_31")]
+    let owner = json!({
+        "$ref": "#/components/schemas/Owner",
+        "description": "Current owner of the pet"
+    });
+
     assert_json_eq!(
-        value,
+        pet,
         json!({
-            "enum": [
-                "A",
-                "B",
-                "C",
-            ],
-            "type": "string",
+            "properties": {
+                "owner": owner,
+                "previous_owner": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Owner"
+                        }
+                    ],
+                    "default": {"name": "shelter"},
+                    "description": "Previous owner of the pet"
+                }
+            },
+            "required": ["owner", "previous_owner"],
+            "type": "object"
         })
-    );
+    )
 }
 
 #[test]
-fn derive_simple_enum_serde_tag() {
-    let value: Value = api_doc! {
-        #[derive(Serialize)]
-        #[serde(tag = "tag")]
-        enum Bar {
-            A,
-            B,
-            C,
+fn derive_enum_with_comments_success() {
+    let account = api_doc! {
+        /// This is user account status enum
+        ///
+        /// Detailed documentation here.
+        /// More than the first line is added to the description as well.
+        enum AccountStatus {
+            /// When user is valid to login, these enum variant level docs are omitted!!!!!
+            /// Since the OpenAPI spec does not have a place to put such information.
+            Enabled,
+            /// Login failed too many times
+            Locked,
+            Disabled
         }
     };
 
-    assert_json_eq!(
-        value,
-        json!({
-            "oneOf": [
-                {
-                    "type": "object",
-                    "properties": {
-                        "tag": {
-                            "type": "string",
-                            "enum": [
-                                "A",
-                            ],
-                        },
-                    },
-                    "required": [
-                        "tag",
-                    ],
-                },
-                {
-                    "type": "object",
-                    "properties": {
-                        "tag": {
-                            "type": "string",
-                            "enum": [
-                                "B",
-                            ],
-                        },
-                    },
-                    "required": [
-                        "tag",
-                    ],
-                },
-                {
-                    "type": "object",
-                    "properties": {
-                        "tag": {
-                            "type": "string",
-                            "enum": [
-                                "C",
-                            ],
-                        },
-                    },
-                    "required": [
-                        "tag",
-                    ],
-                },
-            ],
-        })
-    );
+    assert_value! {account=>
+        "description" = r#""This is user account status enum\n\nDetailed documentation here.\nMore than the first line is added to the description as well.""#, "AccountStatus description"
+    }
 }
 
 #[test]
-fn derive_simple_enum_serde_untagged() {
-    let value: Value = api_doc! {
-        #[derive(Serialize)]
-        #[serde(untagged)]
-        enum Foo {
-            One,
-            Two,
-        }
+fn derive_struct_unnamed_field_single_value_type_success() {
+    let point = api_doc! {
+        struct Point(f32);
     };
 
-    assert_json_eq!(
-        value,
-        json!({
-            "type": "object",
-            "nullable": true,
-            "default": null,
-        })
-    );
+    assert_value! {point=>
+        "type" = r#""number""#, "Point type"
+        "format" = r#""float""#, "Point format"
+    }
 }
 
 #[test]
-fn derive_struct_unnamed_field_reference_with_comment() {
-    #[derive(ToSchema, Serialize)]
-    struct Bar {
-        value: String,
+fn derive_struct_unnamed_fields_tuple_with_same_type_success() {
+    let point = api_doc! {
+        /// Contains x and y coordinates
+        ///
+        /// Coordinates are used to pinpoint location on a map
+        struct Point(f64, f64);
+    };
+
+    assert_value! {point=>
+        "type" = r#""array""#, "Point type"
+        "items.type" = r#""number""#, "Point items type"
+        "items.format" = r#""double""#, "Point items format"
+        "items.description" = r#""Contains x and y coordinates\n\nCoordinates are used to pinpoint location on a map""#, "Point items description"
+        "maxItems" = r#"2"#, "Wrapper max items"
+        "minItems" = r#"2"#, "Wrapper min items"
     }
+}
 
-    let value = api_doc! {
-        #[derive(Serialize)]
-        /// Description should not apply to $ref that is created for inner type Bar
-        struct Foo(Bar);
+#[cfg(not(feature = "openapi_31"))]
+#[test]
+fn derive_struct_unnamed_fields_tuple_with_different_types_success() {
+    let point = api_doc! {
+        struct Point(f64, String);
     };
 
-    assert_json_eq!(
-        value,
-        json!({
-          "$ref": "#/components/schemas/Bar"
-        })
-    );
+    assert_value! {point=>
+        "type" = r#""array""#, "Point type"
+        "items.type" = r#""object""#, "Point items type"
+        "items.format" = r#"null"#, "Point items format"
+    }
 }
 
-/// Derive a complex enum with named and unnamed fields.
 #[test]
-fn derive_complex_unnamed_field_reference_with_comment() {
-    #[derive(Serialize)]
-    struct CommentedReference(String);
+fn derive_struct_unnamed_field_with_generic_types_success() {
+    let point = api_doc! {
+        struct Wrapper(Option<String>);
+    };
 
-    let value: Value = api_doc! {
-        #[derive(Serialize)]
-        enum EnumWithReference {
-            /// This is comment which will not be added to the document
-            /// since $ref cannot have comments
-            UnnamedFieldWithCommentReference(CommentedReference),
-        }
+    #[cfg(not(feature = "openapi_31"))]
+    assert_value! {point=>
+        "type" = r#""string""#, "Wrapper type"
+    }
+    #[cfg(feature = "openapi_31")]
+    assert_value! {point=>
+        "type" = r#"["string","null"]"#, "Wrapper type"
+    }
+}
+
+#[test]
+fn derive_struct_unnamed_field_with_nested_generic_type_success() {
+    let point = api_doc! {
+        /// Some description
+        struct Wrapper(Option<Vec<i32>>);
     };
 
-    assert_json_eq!(
-        value,
-        json!({
-            "oneOf": [
-                {
-                    "type": "object",
-                    "properties": {
-                        "UnnamedFieldWithCommentReference": {
-                            "$ref": "#/components/schemas/CommentedReference",
-                        },
-                    },
-                    "required": ["UnnamedFieldWithCommentReference"],
-                },
-            ],
-        })
-    );
+    assert_value! {point=>
+        "items.type" = r#""integer""#, "Wrapper items type"
+        "items.format" = r#""int32""#, "Wrapper items format"
+        "description" = r#""Some description""#, "Wrapper description"
+    }
+    #[cfg(not(feature = "openapi_31"))]
+    assert_value! {point=>
+        "type" = r#""array""#, "Wrapper type"
+    }
+    #[cfg(feature = "openapi_31")]
+    assert_value! {point=>
+        "type" = r#"["array","null"]"#, "Wrapper type"
+    }
 }
 
+#[cfg(not(feature = "openapi_31"))]
 #[test]
-fn derive_enum_with_unnamed_primitive_field_with_tag() {
-    let value: Value = api_doc! {
-        #[derive(Serialize)]
-        #[serde(tag = "tag")]
-        enum EnumWithReference {
-            Value(String),
-        }
+fn derive_struct_unnamed_field_with_multiple_nested_generic_type_success() {
+    let point = api_doc! {
+        /// Some documentation
+        struct Wrapper(Option<Vec<i32>>, String);
+    };
+
+    assert_value! {point=>
+        "type" = r#""array""#, "Wrapper type"
+        "items.type" = r#""object""#, "Wrapper items type"
+        "items.format" = r#"null"#, "Wrapper items format"
+        "description" = r#""Some documentation""#, "Wrapper description"
+    }
+}
+
+#[cfg(feature = "openapi_31")]
+#[test]
+fn derive_struct_unnamed_fields_tuple_with_different_types_prefix_items() {
+    let point = api_doc! {
+        /// Point on a named map
+        struct Point(f64, String);
     };
 
     assert_json_eq!(
-        value,
+        point,
         json!({
-            "oneOf": [
+            "type": "array",
+            "items": {},
+            "prefixItems": [
                 {
-                    "type": "object",
-                    "properties": {
-                        "tag": {
-                            "type": "string",
-                            "enum": ["Value"]
-                        },
-                    },
-                    "required": ["tag"]
+                    "type": "number",
+                    "format": "double"
                 },
+                {
+                    "type": "string"
+                }
             ],
-            "discriminator": {
-                "propertyName": "tag"
-            }
+            "description": "Point on a named map",
+            "maxItems": 2,
+            "minItems": 2
         })
     );
 }
 
+#[cfg(feature = "openapi_31")]
 #[test]
-fn derive_complex_enum_with_schema_properties() {
-    let value: Value = api_doc! {
-        /// This is the description
-        #[derive(Serialize)]
-        #[schema(example = json!(EnumWithProperties::Variant2{name: String::from("foobar")}),
-            default = json!(EnumWithProperties::Variant{id: String::from("1")}))]
-        enum EnumWithProperties {
-            Variant {
-                id: String
-            },
-            Variant2{
-                name: String
-            }
-        }
+fn derive_struct_unnamed_fields_tuple_with_titles_prefix_items() {
+    #![allow(unused)]
+
+    #[derive(ToSchema)]
+    struct Unit {
+        name: String,
+    }
+
+    let measurement = api_doc! {
+        struct Measurement(
+            #[schema(title = "value")] f64,
+            String,
+            #[schema(title = "samples")] Vec<i32>,
+            #[schema(title = "unit")] Unit,
+        );
     };
 
     assert_json_eq!(
-        value,
+        measurement,
         json!({
-            "description": "This is the description",
-            "default": {
-                "Variant": {
-                    "id": "1"
-                }
-            },
-            "example": {
-                "Variant2": {
-                    "name": "foobar"
-                }
-            },
-            "oneOf": [
+            "type": "array",
+            "items": {},
+            "prefixItems": [
                 {
-                    "properties": {
-                        "Variant": {
-                            "properties": {
-                                "id": {
-                                    "type": "string"
-                                }
-                            },
-                            "required": ["id"],
-                            "type": "object"
-                        }
+                    "type": "number",
+                    "format": "double",
+                    "title": "value"
+                },
+                {
+                    "type": "string"
+                },
+                {
+                    "type": "array",
+                    "items": {
+                        "type": "integer",
+                        "format": "int32"
                     },
-                    "required": ["Variant"],
-                    "type": "object"
+                    "title": "samples"
                 },
                 {
-                    "properties": {
-                        "Variant2": {
-                            "properties": {
-                                "name": {
-                                    "type": "string"
-                                }
-                            },
-                            "required": ["name"],
-                            "type": "object"
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Unit"
                         }
-                    },
-                    "required": ["Variant2"],
-                    "type": "object"
+                    ],
+                    "title": "unit"
                 }
-            ]
+            ],
+            "maxItems": 4,
+            "minItems": 4
         })
-    )
+    );
 }
 
-// TODO fixme https://github.com/juhaku/utoipa/issues/285#issuecomment-1249625860
+#[cfg(feature = "openapi_31")]
 #[test]
-fn derive_enum_with_unnamed_single_field_with_tag() {
-    #[derive(Serialize)]
-    struct ReferenceValue(String);
+fn derive_struct_unnamed_field_with_multiple_nested_generic_type_prefix_items() {
+    let wrapper = api_doc! {
+        struct Wrapper(Option<Vec<i32>>, String);
+    };
 
-    let value: Value = api_doc! {
-        #[derive(Serialize)]
-        #[serde(tag = "enum")]
-        enum EnumWithReference {
-            Value(ReferenceValue),
+    #[cfg(not(feature = "openapi_31"))]
+    let values = json!({
+        "type": "array",
+        "items": {
+            "type": "integer",
+            "format": "int32"
+        },
+        "nullable": true
+    });
+    #[cfg(feature = "openapi_31")]
+    let values = json!({
+        "type": ["array", "null"],
+        "items": {
+            "type": "integer",
+            "format": "int32"
         }
-    };
+    });
 
     assert_json_eq!(
-        value,
+        wrapper,
         json!({
-            "oneOf": [
+            "type": "array",
+            "items": {},
+            "prefixItems": [
+                values,
                 {
-                    "allOf": [
-                        {
-                            "$ref": "#/components/schemas/ReferenceValue",
-                        },
-                        {
-                            "type": "object",
-                            "properties": {
-                                "enum": {
-                                    "type": "string",
-                                    "enum": ["Value"]
-
-                                },
-                            },
-                            "required": ["enum"]
-                        },
-                    ],
+                    "type": "string"
                 }
             ],
-            "discriminator": {
-                "propertyName": "enum"
-            }
+            "maxItems": 2,
+            "minItems": 2
         })
     );
 }
 
 #[test]
-fn derive_enum_with_named_fields_with_reference_with_tag() {
-    #[derive(Serialize)]
-    struct ReferenceValue(String);
+fn derive_struct_unnamed_field_vec_type_success() {
+    let point = api_doc! {
+        /// Some documentation
+        /// more documentation
+        struct Wrapper(Vec<i32>);
+    };
 
-    let value: Value = api_doc! {
-        #[derive(Serialize)]
-        #[serde(tag = "enum")]
-        enum EnumWithReference {
-            Value {
-                field: ReferenceValue,
-                a: String
-            },
-            UnnamedValue(ReferenceValue),
-            UnitValue,
+    assert_value! {point=>
+        "type" = r#""array""#, "Wrapper type"
+        "items.type" = r#""integer""#, "Wrapper items type"
+        "items.format" = r#""int32""#, "Wrapper items format"
+        "maxItems" = r#"null"#, "Wrapper max items"
+        "minItems" = r#"null"#, "Wrapper min items"
+        "description" = r#""Some documentation\nmore documentation""#, "Wrapper description"
+    }
+}
+
+#[test]
+fn derive_struct_unnamed_field_single_value_default_success() {
+    let point = api_doc! {
+        #[schema(default)]
+        struct Point(f32);
+
+        impl Default for Point {
+            fn default() -> Self {
+                Self(3.5)
+            }
         }
     };
 
-    assert_json_eq!(
-        value,
-        json!({
-            "oneOf": [
-                {
-                    "properties": {
-                        "a": {
-                            "type": "string"
-                        },
-                        "enum": {
-                            "enum": [
-                                "Value"
-                            ],
-                            "type": "string"
-                        },
-                        "field": {
-                            "$ref": "#/components/schemas/ReferenceValue"
-                        }
-                    },
-                    "required": [
-                        "field",
-                        "a",
-                        "enum"
-                    ],
-                    "type": "object"
-                },
-                {
-                    "allOf": [
-                        {
-                        "$ref": "#/components/schemas/ReferenceValue",
-                        },
-                        {
-                            "type": "object",
-                            "properties": {
-                                "enum": {
-                                    "type": "string",
-                                    "enum": ["UnnamedValue"]
+    assert_value! {point=>
+        "type" = r#""number""#, "Point type"
+        "format" = r#""float""#, "Point format"
+        "default" = r#"3.5"#, "Point default"
+    }
+}
 
-                                },
-                            },
-                            "required": ["enum"]
-                        }
-                    ],
-                },
-                {
-                    "properties": {
-                        "enum": {
-                            "enum": [
-                                "UnitValue"
-                            ],
-                            "type": "string"
-                        }
-                    },
-                    "required": [
-                        "enum"
-                    ],
-                    "type": "object"
-                }
-            ],
-            "discriminator": {
-                "propertyName": "enum"
+#[test]
+fn derive_struct_unnamed_field_multiple_value_default_ignored() {
+    let point = api_doc! {
+        #[schema(default)]
+        struct Point(f32, f32);
+
+        impl Default for Point {
+            fn default() -> Self {
+                Self(3.5, 6.4)
             }
+        }
+    };
+    // Default values shouldn't be assigned as the struct is represented
+    // as an array
+    assert!(!point.to_string().contains("default"))
+}
+
+#[test]
+fn derive_struct_nested_vec_success() {
+    let vecs = api_doc! {
+        struct VecTest {
+            vecs: Vec<Vec<String>>
+        }
+    };
+
+    assert_value! {vecs=>
+        "properties.vecs.type" = r#""array""#, "Vecs property type"
+        "properties.vecs.items.type" = r#""array""#, "Vecs property items type"
+        "properties.vecs.items.items.type" = r#""string""#, "Vecs property items item type"
+        "type" = r#""object""#, "Property type"
+        "required.[0]" = r#""vecs""#, "Required properties"
+    }
+    common::assert_json_array_len(vecs.get("required").unwrap(), 1);
+}
+
+#[test]
+fn derive_struct_with_example() {
+    let pet = api_doc! {
+        #[schema(example = json!({"name": "bob the cat", "age": 8}))]
+        struct Pet {
+            name: String,
+            age: i32
+        }
+    };
+
+    assert_value! {pet=>
+        "example.name" = r#""bob the cat""#, "Pet example name"
+        "example.age" = r#"8"#, "Pet example age"
+    }
+}
+
+#[test]
+fn derive_struct_with_default_example() {
+    let pet = api_doc! {
+        #[derive(Serialize, Default)]
+        #[schema(example)]
+        struct Pet {
+            name: String,
+            age: i32,
+            tags: Vec<String>,
+        }
+    };
+
+    assert_json_eq!(
+        pet.pointer("/example").unwrap(),
+        json!({
+            "name": "",
+            "age": 0,
+            "tags": []
         })
     );
 }
 
-/// Derive a complex enum with named and unnamed fields.
 #[test]
-fn derive_complex_enum() {
-    #[derive(Serialize)]
-    struct Foo(String);
+fn derive_struct_with_associated_const_example() {
+    #[derive(Serialize, ToSchema)]
+    #[schema(example = Self::SAMPLE)]
+    #[allow(unused)]
+    struct Pet {
+        #[schema(example = Self::NAME)]
+        name: &'static str,
+        #[schema(example = Self::NAME.len())]
+        name_len: usize,
+        #[cfg_attr(feature = "openapi_31", schema(examples(Self::TAGS, [Self::NAME])))]
+        tags: Vec<&'static str>,
+    }
 
-    let value: Value = api_doc! {
+    impl Pet {
+        const NAME: &'static str = "bob the cat";
+        const TAGS: [&'static str; 2] = ["cat", "indoor"];
+        const SAMPLE: Pet = Pet {
+            name: Self::NAME,
+            name_len: Self::NAME.len(),
+            tags: Vec::new(),
+        };
+    }
+
+    let pet = serde_json::to_value(<Pet as ToSchema>::schema().1).unwrap();
+
+    assert_json_eq!(
+        pet.pointer("/example").unwrap(),
+        json!({"name": "bob the cat", "name_len": 11, "tags": []})
+    );
+    assert_json_eq!(
+        pet.pointer("/properties/name/example").unwrap(),
+        json!("bob the cat")
+    );
+    assert_json_eq!(
+        pet.pointer("/properties/name_len/example").unwrap(),
+        json!(11)
+    );
+    #[cfg(feature = "openapi_31")]
+    assert_json_eq!(
+        pet.pointer("/properties/tags/examples").unwrap(),
+        json!([["cat", "indoor"], ["bob the cat"]])
+    );
+}
+
+#[test]
+fn derive_struct_with_example_and_field_examples() {
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Owner {
+        id: i32,
+    }
+
+    let pet = api_doc! {
         #[derive(Serialize)]
-        enum Bar {
-            UnitValue,
-            NamedFields {
-                id: &'static str,
-                names: Option<Vec<String>>
-            },
-            UnnamedFields(Foo),
+        #[schema(example = json!({"name": "bob the cat", "age": 8, "id": 1}))]
+        struct Pet {
+            #[schema(example = "bob")]
+            name: String,
+            #[schema(example = 3)]
+            age: i32,
+            #[serde(flatten)]
+            owner: Owner,
         }
     };
 
     assert_json_eq!(
-        value,
+        pet,
         json!({
-            "oneOf": [
-                {
-                    "type": "string",
-                    "enum": [
-                        "UnitValue",
-                    ],
-                },
+            "allOf": [
                 {
-                    "type": "object",
-                    "properties": {
-                        "NamedFields": {
-                            "type": "object",
-                            "properties": {
-                                "id": {
-                                    "type": "string",
-                                },
-                                "names": {
-                                    "type": "array",
-                                    "nullable": true,
-                                    "items": {
-                                        "type": "string",
-                                    },
-                                },
-                            },
-                            "required": [
-                                "id",
-                            ],
-                        },
-                    },
-                    "required": ["NamedFields"],
+                    "$ref": "#/components/schemas/Owner"
                 },
                 {
                     "type": "object",
                     "properties": {
-                        "UnnamedFields": {
-                            "$ref": "#/components/schemas/Foo",
+                        "name": {
+                            "type": "string",
+                            "example": "bob"
                         },
+                        "age": {
+                            "type": "integer",
+                            "format": "int32",
+                            "example": 3
+                        }
                     },
-                    "required": ["UnnamedFields"],
-                },
+                    "required": ["name", "age"]
+                }
             ],
+            "example": {
+                "name": "bob the cat",
+                "age": 8,
+                "id": 1
+            }
         })
     );
 }
 
 #[test]
-fn derive_complex_enum_title() {
-    #[derive(Serialize)]
-    struct Foo(String);
+fn derive_struct_with_serde_transparent() {
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Inner {
+        id: i32,
+    }
 
-    let value: Value = api_doc! {
+    let wrapper = api_doc! {
         #[derive(Serialize)]
-        enum Bar {
-            #[schema(title = "Unit")]
-            UnitValue,
-            #[schema(title = "Named")]
-            NamedFields {
-                id: &'static str,
-            },
-            #[schema(title = "Unnamed")]
-            UnnamedFields(Foo),
+        #[serde(transparent)]
+        struct Wrapper {
+            inner: Inner,
         }
     };
 
     assert_json_eq!(
-        value,
+        wrapper,
         json!({
-            "oneOf": [
-                {
-                    "type": "string",
-                    "title": "Unit",
-                    "enum": [
-                        "UnitValue",
-                    ],
-                },
-                {
-                    "type": "object",
-                    "title": "Named",
-                    "properties": {
-                        "NamedFields": {
-                            "type": "object",
-                            "properties": {
-                                "id": {
-                                    "type": "string",
-                                },
-                            },
-                            "required": [
-                                "id",
-                            ],
-                        },
-                    },
-                    "required": ["NamedFields"],
-                },
-                {
-                    "type": "object",
-                    "title": "Unnamed",
-                    "properties": {
-                        "UnnamedFields": {
-                            "$ref": "#/components/schemas/Foo",
-                        },
-                    },
-                    "required": ["UnnamedFields"]
-                },
-            ],
+            "$ref": "#/components/schemas/Inner"
         })
     );
-}
-
-#[test]
-fn derive_complex_enum_example() {
-    #[derive(Serialize)]
-    struct Foo(String);
 
-    let value: Value = api_doc! {
+    let id = api_doc! {
         #[derive(Serialize)]
-        enum EnumWithExample {
-            #[schema(example = "EX: Unit")]
-            UnitValue,
-            #[schema(example = "EX: Named")]
-            NamedFields {
-                #[schema(example = "EX: Named id field")]
-                id: &'static str,
-            },
-            #[schema(example = "EX: Unnamed")]
-            UnnamedFields(Foo),
+        #[serde(transparent)]
+        struct Id {
+            /// Identifier of the item.
+            #[schema(minimum = 1)]
+            value: i64,
+            #[serde(skip)]
+            marker: PhantomData<String>,
         }
     };
 
     assert_json_eq!(
-        value,
+        id,
         json!({
-            "oneOf": [
-                {
-                    "type": "string",
-                    "example": "EX: Unit",
-                    "enum": [
-                        "UnitValue",
-                    ],
-                },
-                {
-                    "type": "object",
-                    "example": "EX: Named",
-                    "properties": {
-                        "NamedFields": {
-                            "type": "object",
-                            "properties": {
-                                "id": {
-                                    "type": "string",
-                                    "example": "EX: Named id field",
-                                },
-                            },
-                            "required": [
-                                "id",
-                            ],
-                        },
-                    },
-                    "required": ["NamedFields"]
-                },
-                {
-                    "type": "object",
-                    "example": "EX: Unnamed",
-                    "properties": {
-                        "UnnamedFields": {
-                            "$ref": "#/components/schemas/Foo",
-                        },
-                    },
-                    "required": ["UnnamedFields"]
-                },
-            ],
+            "type": "integer",
+            "format": "int64",
+            "minimum": 1,
+            "description": "Identifier of the item."
         })
     );
 }
 
 #[test]
-fn derive_complex_enum_serde_rename_all() {
-    #[derive(Serialize)]
-    struct Foo(String);
-
-    let value: Value = api_doc! {
+fn derive_struct_with_serde_transparent_description() {
+    let email = api_doc! {
+        /// Email address of the user.
         #[derive(Serialize)]
-        #[serde(rename_all = "snake_case")]
-        enum Bar {
-            UnitValue,
-            NamedFields {
-                id: &'static str,
-                names: Option<Vec<String>>
-            },
-            UnnamedFields(Foo),
+        #[serde(transparent)]
+        struct Email {
+            value: String,
         }
     };
 
     assert_json_eq!(
-        value,
+        email,
         json!({
-            "oneOf": [
-                {
-                    "type": "string",
-                    "enum": [
-                        "unit_value",
-                    ],
-                },
-                {
-                    "type": "object",
-                    "properties": {
-                        "named_fields": {
-                            "type": "object",
-                            "properties": {
-                                "id": {
-                                    "type": "string",
-                                },
-                                "names": {
-                                    "type": "array",
-                                    "nullable": true,
-                                    "items": {
-                                        "type": "string",
-                                    },
-                                },
-                            },
-                            "required": [
-                                "id",
-                            ],
-                        },
-                    },
-                    "required": ["named_fields"]
-                },
-                {
-                    "type": "object",
-                    "properties": {
-                        "unnamed_fields": {
-                            "$ref": "#/components/schemas/Foo",
-                        },
-                    },
-                    "required": ["unnamed_fields"]
-                },
-            ],
+            "type": "string",
+            "description": "Email address of the user."
         })
     );
-}
-
-#[test]
-fn derive_complex_enum_serde_rename_variant() {
-    #[derive(Serialize)]
-    struct Foo(String);
 
-    let value: Value = api_doc! {
+    let id = api_doc! {
+        /// Identifier of the user.
         #[derive(Serialize)]
-        enum Bar {
-            #[serde(rename = "renamed_unit_value")]
-            UnitValue,
-            #[serde(rename = "renamed_named_fields")]
-            NamedFields {
-                #[serde(rename = "renamed_id")]
-                id: &'static str,
-                #[serde(rename = "renamed_names")]
-                names: Option<Vec<String>>
-            },
-            #[serde(rename = "renamed_unnamed_fields")]
-            UnnamedFields(Foo),
+        #[serde(transparent)]
+        struct UserId {
+            /// Inner value of the identifier.
+            value: i64,
         }
     };
 
     assert_json_eq!(
-        value,
+        id,
         json!({
-            "oneOf": [
-                {
-                    "type": "string",
-                    "enum": [
-                        "renamed_unit_value",
-                    ],
-                },
-                {
-                    "type": "object",
-                    "properties": {
-                        "renamed_named_fields": {
-                            "type": "object",
-                            "properties": {
-                                "renamed_id": {
-                                    "type": "string",
-                                },
-                                "renamed_names": {
-                                    "type": "array",
-                                    "nullable": true,
-                                    "items": {
-                                        "type": "string",
-                                    },
-                                },
-                            },
-                            "required": [
-                                "renamed_id",
-                            ],
-                        },
-                    },
-                    "required": ["renamed_named_fields"]
-                },
-                {
-                    "type": "object",
-                    "properties": {
-                        "renamed_unnamed_fields": {
-                            "$ref": "#/components/schemas/Foo",
-                        },
-                    },
-                    "required": ["renamed_unnamed_fields"]
-                },
-            ],
+            "type": "integer",
+            "format": "int64",
+            "description": "Identifier of the user."
         })
     );
 }
 
 #[test]
-fn derive_struct_custom_rename() {
-    let value: Value = api_doc! {
-        #[schema(rename_all = "SCREAMING-KEBAB-CASE")]
-        struct Post {
-            post_id: i64,
-            created_at: i64,
-            #[schema(rename = "post_comment")]
-            comment: String,
-        }
-    };
+fn derive_struct_with_deterministic_property_order() {
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Audit {
+        updated: String,
+    }
 
-    assert_json_eq!(
-        value,
-        json!({
-            "properties": {
-                "POST-ID": {
-                    "type": "integer",
-                    "format": "int64",
-                },
-                "CREATED-AT": {
-                    "type": "integer",
-                    "format": "int64",
-                },
-                "post_comment": {
-                    "type": "string",
-                },
-            },
-            "type": "object",
-            "required": [
-                "POST-ID",
-                "CREATED-AT",
-                "post_comment"
-            ]
-        })
-    )
-}
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Owner {
+        owner_id: i32,
+    }
 
-#[test]
-fn derive_complex_enum_custom_rename() {
-    let value: Value = api_doc! {
-        #[schema(rename_all = "UPPERCASE")]
-        enum PostType {
-            NewPost(String),
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Post {
+        title: String,
+        #[serde(flatten)]
+        owner: Owner,
+        body: String,
+        #[serde(flatten)]
+        audit: Audit,
+        #[serde(flatten)]
+        extra: HashMap<String, String>,
+        author: String,
+    }
 
-            #[schema(rename = "update_post", rename_all = "PascalCase")]
-            Update {
-                post_id: i64,
-                created_at: i64,
-                #[schema(rename = "post_comment")]
-                comment: String,
-            },
+    let schema = serde_json::to_string(&Post::schema().1).unwrap();
+    for _ in 0..10 {
+        assert_eq!(schema, serde_json::to_string(&Post::schema().1).unwrap());
+    }
 
-            RandomValue {
-                id: i64,
-            },
-        }
+    let value: Value = serde_json::from_str(&schema).unwrap();
+    assert_value! {value=>
+        "allOf.[0].$ref" = r##""#/components/schemas/Owner""##, "First flattened field"
+        "allOf.[1].$ref" = r##""#/components/schemas/Audit""##, "Second flattened field"
+        "allOf.[2].required" = r#"["title","body","author"]"#, "Required in declaration order"
+        "allOf.[2].additionalProperties.type" = r#""string""#, "Flattened map values"
     };
 
-    assert_json_eq!(
-        value,
-        json!({
-            "oneOf": [
-                {
-                    "properties": {
-                        "NEWPOST": {
-                            "type": "string"
-                        }
-                    },
-                    "required": ["NEWPOST"],
-                    "type": "object",
-                },
-                {
-                    "properties": {
-                        "update_post": {
-                            "properties": {
-                                "PostId": {
-                                    "type": "integer",
-                                    "format": "int64",
-                                },
-                                "CreatedAt": {
-                                    "type": "integer",
-                                    "format": "int64",
-                                },
-                                "post_comment": {
-                                    "type": "string",
-                                },
-                            },
-                            "type": "object",
-                            "required": [
-                                "PostId",
-                                "CreatedAt",
-                                "post_comment"
-                            ]
-                        }
-                    },
-                    "required": ["update_post"],
-                    "type": "object",
-                },
-                {
-                    "properties": {
-                        "RANDOMVALUE": {
-                            "properties": {
-                                "id": {
-                                    "type": "integer",
-                                    "format": "int64",
-                                },
-                            },
-                            "type": "object",
-                            "required": [
-                                "id",
-                            ]
-                        }
-                    },
-                    "required": ["RANDOMVALUE"],
-                    "type": "object",
-                }
-            ]
-        })
-    )
+    let properties = value
+        .pointer("/allOf/2/properties")
+        .and_then(Value::as_object)
+        .unwrap()
+        .keys()
+        .collect::<Vec<_>>();
+    assert_eq!(properties, ["author", "body", "title"]);
 }
 
 #[test]
-fn derive_complex_enum_use_serde_rename_over_custom_rename() {
-    let value: Value = api_doc! {
-        #[derive(serde::Deserialize)]
-        #[serde(rename_all = "lowercase")]
-        #[schema(rename_all = "UPPERCASE")]
-        enum Random {
-            #[serde(rename = "string_value")]
-            #[schema(rename = "custom_value")]
-            String(String),
-
-            Number {
-                id: i32,
-            }
+fn derive_struct_with_deprecated() {
+    #[allow(deprecated)]
+    let pet = api_doc! {
+        #[deprecated]
+        struct Pet {
+            name: String,
+            #[deprecated]
+            age: i32
         }
     };
 
-    assert_json_eq!(
-        value,
-        json!({
-            "oneOf": [
-                {
-                    "properties": {
-                        "string_value": {
-                            "type": "string",
-                        },
-                    },
-                    "type": "object",
-                    "required": ["string_value"]
-                },
-                {
-                    "properties": {
-                        "number": {
-                            "properties": {
-                                "id": {
-                                    "type": "integer",
-                                    "format": "int32",
-                                }
-                            },
-                            "type": "object",
-                            "required": ["id"]
-                        }
-                    },
-                    "type": "object",
-                    "required": ["number"]
-                }
-            ]
-        })
-    )
+    assert_value! {pet=>
+        "deprecated" = r#"true"#, "Pet deprecated"
+        "properties.name.type" = r#""string""#, "Pet properties name type"
+        "properties.name.deprecated" = r#"null"#, "Pet properties name deprecated"
+        "properties.age.type" = r#""integer""#, "Pet properties age type"
+        "properties.age.deprecated" = r#"true"#, "Pet properties age deprecated"
+        "example" = r#"null"#, "Pet example"
+    }
 }
 
 #[test]
-fn derive_struct_with_title() {
-    let value: Value = api_doc! {
-        #[schema(title = "Post")]
-        struct Post {
-            id: i64,
+fn derive_struct_with_deprecated_note() {
+    #[allow(deprecated)]
+    let pet = api_doc! {
+        /// This is a pet.
+        #[deprecated(since = "1.0.0", note = "Use `Animal` instead")]
+        struct Pet {
+            name: String,
         }
     };
 
-    assert_json_eq!(
-        value,
-        json!({
-            "properties": {
-                "id": {
-                    "type": "integer",
-                    "format": "int64",
-                }
-            },
-            "title": "Post",
-            "required": ["id"],
-            "type": "object",
-        })
-    )
-}
+    assert_value! {pet=>
+        "deprecated" = r#"true"#, "Pet deprecated"
+        "description" = r#""This is a pet.\n\nDeprecated: Use `Animal` instead""#, "Pet description"
+    }
 
-#[test]
-fn derive_enum_with_title() {
-    let value: Value = api_doc! {
-        #[schema(title = "UserType")]
-        enum UserType {
-            Admin,
-            Moderator,
-            User,
+    #[allow(deprecated)]
+    let status = api_doc! {
+        #[deprecated = "Use `State` instead"]
+        enum Status {
+            Active,
         }
     };
 
-    assert_json_eq!(
-        value,
-        json!({
-            "enum": ["Admin", "Moderator", "User"],
-            "title": "UserType",
-            "type": "string",
-        })
-    )
+    assert_value! {status=>
+        "deprecated" = r#"true"#, "Status deprecated"
+        "description" = r#""Deprecated: Use `State` instead""#, "Status description"
+    }
 }
 
 #[test]
-fn derive_complex_enum_with_title() {
-    let value: Value = api_doc! {
-        enum UserType {
-            #[schema(title = "admin")]
-            Admin(String),
-            #[schema(title = "moderator")]
-            Moderator{id: i32},
-            #[schema(title = "user")]
-            User,
+fn derive_struct_with_serde_alias() {
+    let value = api_doc! {
+        #[derive(serde::Deserialize)]
+        struct Pet {
+            /// Name of the pet.
+            #[serde(alias = "old_name", alias = "legacy_name")]
+            name: String,
+            #[serde(alias = "years")]
+            age: i32,
         }
     };
 
-    assert_json_eq!(
-        value,
-        json!({
-            "oneOf": [
-                {
-                    "properties": {
-                        "Admin": {
-                            "type": "string"
-                        }
-                    },
-                    "title": "admin",
-                    "type": "object",
-                    "required": ["Admin"]
-                },
-                {
-                    "properties": {
-                        "Moderator": {
-                            "properties": {
-                                "id": {
-                                    "type": "integer",
-                                    "format": "int32",
-                                }
-                            },
-                            "required": ["id"],
-                            "type": "object",
-                        }
-                    },
-                    "required": ["Moderator"],
-                    "title": "moderator",
-                    "type": "object",
-                },
-                {
-                    "enum": ["User"],
-                    "title": "user",
-                    "type": "string"
-                }
-            ]
-        })
-    )
+    assert_value! {value=>
+        "properties.name.description" = r#""Name of the pet.\n\nAccepts aliases: old_name, legacy_name""#, "Pet name description"
+        "properties.age.description" = r#""Accepts aliases: years""#, "Pet age description"
+    }
 }
 
-/// Derive a complex enum with the serde `tag` container attribute applied for internal tagging.
-/// Note that tuple fields are not supported.
 #[test]
-fn derive_complex_enum_serde_tag() {
-    #[derive(Serialize)]
-    #[allow(dead_code)]
-    struct Foo(String);
-
-    let value: Value = api_doc! {
-        #[derive(Serialize)]
-        #[serde(tag = "tag")]
-        enum Bar {
-            UnitValue,
-            NamedFields {
-                id: &'static str,
-                names: Option<Vec<String>>
-            },
+fn derive_struct_with_schema_deprecated() {
+    let pet = api_doc! {
+        #[schema(deprecated)]
+        struct Pet {
+            name: String,
+            #[schema(deprecated)]
+            age: i32
         }
     };
 
-    assert_json_eq!(
-        value,
-        json!({
-            "oneOf": [
-                {
-                    "type": "object",
-                    "properties": {
-                        "tag": {
-                            "type": "string",
-                            "enum": [
-                                "UnitValue",
-                            ],
-                        },
-                    },
-                    "required": [
-                        "tag",
-                    ],
-                },
-                {
-                    "type": "object",
-                    "properties": {
-                        "id": {
-                            "type": "string",
-                        },
-                        "names": {
-                            "type": "array",
-                            "nullable": true,
-                            "items": {
-                                "type": "string",
-                            },
-                        },
-                        "tag": {
-                            "type": "string",
-                            "enum": [
-                                "NamedFields",
-                            ],
-                        },
-                    },
-                    "required": [
-                        "id",
-                        "tag",
-                    ],
-                },
-            ],
-            "discriminator": {
-                "propertyName": "tag"
-            }
-        })
-    );
+    assert_value! {pet=>
+        "deprecated" = r#"true"#, "Pet deprecated"
+        "properties.name.type" = r#""string""#, "Pet properties name type"
+        "properties.name.deprecated" = r#"null"#, "Pet properties name deprecated"
+        "properties.age.type" = r#""integer""#, "Pet properties age type"
+        "properties.age.deprecated" = r#"true"#, "Pet properties age deprecated"
+        "example" = r#"null"#, "Pet example"
+    }
 }
 
 #[test]
-fn derive_serde_flatten() {
-    #[derive(Serialize)]
-    struct Metadata {
-        category: String,
-        total: u64,
+fn derive_unnamed_struct_deprecated_success() {
+    #[allow(deprecated)]
+    let pet_age = api_doc! {
+        #[deprecated]
+        #[schema(example = 8)]
+        struct PetAge(u64);
+    };
+
+    assert_value! {pet_age=>
+        "deprecated" = r#"true"#, "PetAge deprecated"
+        "example" = r#"8"#, "PetAge example"
     }
+}
 
-    #[derive(Serialize)]
-    struct Record {
-        amount: i64,
-        description: String,
-        #[serde(flatten)]
-        metadata: Metadata,
+#[test]
+fn derive_unnamed_struct_schema_deprecated_success() {
+    let pet_age = api_doc! {
+        #[schema(deprecated, example = 8)]
+        struct PetAge(u64);
+    };
+
+    assert_value! {pet_age=>
+        "deprecated" = r#"true"#, "PetAge deprecated"
+        "example" = r#"8"#, "PetAge example"
     }
+}
 
-    #[derive(Serialize)]
-    struct Pagination {
-        page: i64,
-        next_page: i64,
-        per_page: i64,
+#[test]
+fn derive_unnamed_struct_example_json_array_success() {
+    let pet_age = api_doc! {
+        #[schema(example = "0", default = i64::default)]
+        struct PetAge(i64, i64);
+    };
+
+    assert_value! {pet_age=>
+        "type" = r#""array""#, "PetAge type"
+        "items.example" = r#""0""#, "PetAge example"
+        "items.default" = r#"0"#, "PetAge default"
+        "items.type" = r#""integer""#, "PetAge default"
+        "items.format" = r#""int64""#, "PetAge default"
+        "maxItems" = r#"2"#, "PetAge max items"
+        "minItems" = r#"2"#, "PetAge min items"
     }
+}
 
-    // Single flatten field
-    let value: Value = api_doc! {
-        #[derive(Serialize)]
-        struct Record {
-            amount: i64,
-            description: String,
-            #[serde(flatten)]
-            metadata: Metadata,
+#[test]
+fn derive_enum_with_deprecated() {
+    #[allow(deprecated)]
+    let mode = api_doc! {
+        #[deprecated]
+        enum Mode {
+            Mode1, Mode2
         }
     };
 
-    assert_json_eq!(
-        value,
-        json!({
-            "allOf": [
-            {
-                "$ref": "#/components/schemas/Metadata"
-            },
-            {
-                "type": "object",
-                "properties": {
-                    "amount": {
-                        "type": "integer",
-                        "format": "int64"
-                    },
-                    "description": {
-                        "type": "string",
-                    },
-                },
-                "required": [
-                    "amount",
-                    "description"
-                ],
-            },
-            ]
-        })
-    );
+    assert_value! {mode=>
+        "enum" = r#"["Mode1","Mode2"]"#, "Mode enum variants"
+        "type" = r#""string""#, "Mode type"
+        "deprecated" = r#"true"#, "Mode deprecated"
+    };
+}
 
-    // Multiple flatten fields, with field that contain flatten as well.
-    // Record contain Metadata that is flatten as well, but it doesn't matter
-    // here as the generated spec will reference to Record directly.
-    let value: Value = api_doc! {
-        #[derive(Serialize)]
-        struct NamedFields {
-            id: &'static str,
-            #[serde(flatten)]
-            record: Record,
-            #[serde(flatten)]
-            pagination: Pagination
+#[test]
+fn derive_enum_with_schema_deprecated() {
+    let mode = api_doc! {
+        #[schema(deprecated)]
+        enum Mode {
+            Mode1, Mode2
         }
     };
 
-    assert_json_eq!(
-        value,
-        json!({
-            "allOf": [
-            {
-                "$ref": "#/components/schemas/Record"
-            },
-            {
-                "$ref": "#/components/schemas/Pagination"
-            },
-            {
-                "type": "object",
-                "properties": {
-                    "id": {
-                        "type": "string",
-                    },
-                },
-                "required": [
-                    "id",
-                ],
-            },
-            ]
-        })
-    );
+    assert_value! {mode=>
+        "enum" = r#"["Mode1","Mode2"]"#, "Mode enum variants"
+        "type" = r#""string""#, "Mode type"
+        "deprecated" = r#"true"#, "Mode deprecated"
+    };
 }
 
 #[test]
-fn derive_complex_enum_serde_untagged() {
-    let value: Value = api_doc! {
-        #[derive(Serialize)]
-        #[serde(untagged)]
-        enum Foo {
-            Bar(i32),
-            Baz(String),
+fn derive_struct_with_generics() {
+    #[allow(unused)]
+    enum Type {
+        Foo,
+        Bar,
+    }
+    let status = api_doc! {
+        struct Status<Type> {
+            t: Type
         }
     };
 
-    assert_json_eq!(
-        value,
-        json!({
-            "oneOf": [
-                {
-                    "format": "int32",
-                    "type": "integer",
-                },
-                {
-                    "type": "string",
-                },
-            ],
-        })
-    );
+    assert_value! {status=>
+        "properties.t.$ref" = r###""#/components/schemas/Type""###, "Status t field"
+    };
 }
 
 #[test]
-fn derive_complex_enum_with_ref_serde_untagged() {
-    #[derive(Serialize)]
-    struct Foo {
-        name: String,
-        age: u32,
-    }
+fn derive_struct_with_lifetime_generics() {
+    #[allow(unused)]
+    let greeting = api_doc! {
+        struct Greeting<'a> {
+            greeting: &'a str
+        }
+    };
 
-    let value: Value = api_doc! {
-        #[derive(Serialize)]
-        #[serde(untagged)]
-        enum Bar {
-            Baz(i32),
-            FooBar(Foo),
+    assert_value! {greeting=>
+        "properties.greeting.type" = r###""string""###, "Greeting greeting field type"
+    };
+}
+
+#[test]
+fn derive_struct_with_multiple_lifetime_generics() {
+    #[allow(unused)]
+    let greeting = api_doc! {
+        struct Greeting<'a, 'b: 'a, '__s> {
+            greeting: &'a str,
+            name: Cow<'b, str>,
+            language: &'__s str,
         }
     };
 
     assert_json_eq!(
-        value,
+        greeting,
         json!({
-            "oneOf": [
-                {
-                    "format": "int32",
-                    "type": "integer",
-                },
-                {
-                    "$ref": "#/components/schemas/Foo",
-                },
-            ],
+            "properties": {
+                "greeting": {"type": "string"},
+                "name": {"type": "string"},
+                "language": {"type": "string"}
+            },
+            "required": ["greeting", "name", "language"],
+            "type": "object"
         })
-    );
+    )
 }
 
 #[test]
-fn derive_complex_enum_with_ref_serde_untagged_named_fields() {
-    #[derive(Serialize, ToSchema)]
-    struct Bar {
+fn derive_enum_with_to_schema_lifetime_names() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    enum Greeting<'__s, '__s_> {
+        Hello { name: &'__s str },
+        Goodbye(&'__s_ str),
+    }
+
+    let (name, schema) = <Greeting as ToSchema>::schema();
+    let schema = serde_json::to_value(schema).unwrap();
+
+    assert_eq!(name, "Greeting");
+    assert_json_eq!(
+        schema.pointer("/oneOf/1/properties/Goodbye").unwrap(),
+        json!({"type": "string"})
+    )
+}
+
+#[test]
+fn derive_schemas_with_referenced_schemas() {
+    use std::collections::BTreeMap;
+
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Owner {
+        pets: Vec<Pet>,
+    }
+
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        owner: Option<Box<Owner>>,
+        tags: BTreeMap<String, Tag>,
+        #[schema(inline)]
+        kind: Kind,
+        #[schema(value_type = path::to::Extra)]
+        extra: String,
+    }
+
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Tag {
         name: String,
-        age: u32,
     }
 
-    let value: Value = api_doc! {
-        #[derive(Serialize)]
-        #[serde(untagged)]
-        enum Foo {
-            One { n: i32 },
-            Two { bar: Bar },
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    enum Kind {
+        Cat,
+        Dog,
+    }
+
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Page<T> {
+        items: Vec<T>,
+        owner: Owner,
+        // referenced types not implementing `ToSchema` are not collected
+        previous: Option<Other>,
+    }
+
+    #[allow(unused)]
+    struct Other;
+
+    fn names(schemas: Vec<(&str, utoipa::openapi::RefOr<utoipa::openapi::Schema>)>) -> Vec<&str> {
+        schemas.into_iter().map(|(name, _)| name).collect()
+    }
+
+    assert_eq!(names(Owner::schemas()), ["Owner", "Pet", "Tag", "Kind"]);
+    assert_eq!(names(Pet::schemas()), ["Pet", "Owner", "Tag", "Kind"]);
+    assert_eq!(
+        names(<Page<Tag> as ToSchema>::schemas()),
+        ["Page", "Owner", "Pet", "Tag", "Kind"]
+    );
+}
+
+#[test]
+fn derive_struct_with_inline_field_of_type_with_same_name() {
+    mod other {
+        #[derive(utoipa::ToSchema)]
+        #[schema(as = other::Node)]
+        #[allow(unused)]
+        pub struct Node {
+            pub value: String,
         }
-    };
+    }
 
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Node {
+        #[schema(inline)]
+        other: other::Node,
+        next: Option<Box<Node>>,
+    }
+
+    let schemas = Node::schemas()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+
+    #[cfg(not(feature = "openapi_31"))]
+    let next = json!({
+        "allOf": [
+            {
+                "$ref": "#/components/schemas/Node"
+            }
+        ],
+        "nullable": true
+    });
+    #[cfg(feature = "openapi_31")]
+    let next = json!({
+        "oneOf": [
+            {
+                "type": "null"
+            },
+            {
+                "$ref": "#/components/schemas/Node"
+            }
+        ]
+    });
+
+    let value = serde_json::to_value(Node::schema().1).unwrap();
+    #[cfg(feature = "auto_title")]
+    let value = common::without_auto_titles(value, &[("", "Node"), ("/properties/other", "Node")]);
     assert_json_eq!(
         value,
         json!({
-            "oneOf": [
-                {
+            "type": "object",
+            "properties": {
+                "other": {
+                    "type": "object",
                     "properties": {
-                      "n": {
-                        "format": "int32",
-                        "type": "integer"
-                      }
+                        "value": {
+                            "type": "string"
+                        }
                     },
-                    "required": [
-                      "n"
-                    ],
-                    "type": "object"
+                    "required": ["value"]
                 },
-                {
-                    "properties": {
-                      "bar": {
-                        "$ref": "#/components/schemas/Bar"
-                      }
-                    },
-                    "required": [
-                      "bar"
-                    ],
-                    "type": "object"
-                }
-            ]
+                "next": next
+            },
+            "required": ["other"]
         })
     );
+    assert_eq!(schemas, ["Node", "other.Node"]);
 }
 
 #[test]
-fn derive_complex_enum_with_ref_serde_untagged_named_fields_rename_all() {
-    #[derive(Serialize, ToSchema)]
-    struct Bar {
-        name: String,
-        age: u32,
+fn derive_struct_with_schema_rename_recursive_field() {
+    #[derive(ToSchema)]
+    #[schema(rename = "CustomItem")]
+    #[allow(unused)]
+    struct Item {
+        children: Vec<Item>,
     }
 
-    let value: Value = api_doc! {
-        #[derive(Serialize)]
-        #[serde(untagged)]
-        enum Foo {
-            #[serde(rename_all = "camelCase")]
-            One { some_number: i32 },
-            #[serde(rename_all = "camelCase")]
-            Two { some_bar: Bar },
-        }
-    };
+    let schemas = Item::schemas()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
 
+    let value = serde_json::to_value(Item::schema().1).unwrap();
+    #[cfg(feature = "auto_title")]
+    let value = common::without_auto_titles(value, &[("", "Item")]);
     assert_json_eq!(
         value,
         json!({
-            "oneOf": [
-                {
-                    "properties": {
-                      "someNumber": {
-                        "format": "int32",
-                        "type": "integer"
-                      }
-                    },
-                    "required": [
-                      "someNumber"
-                    ],
-                    "type": "object"
-                },
-                {
-                    "properties": {
-                      "someBar": {
-                        "$ref": "#/components/schemas/Bar"
-                      }
-                    },
-                    "required": [
-                      "someBar"
-                    ],
-                    "type": "object"
+            "type": "object",
+            "properties": {
+                "children": {
+                    "type": "array",
+                    "items": {
+                        "$ref": "#/components/schemas/CustomItem"
+                    }
                 }
-            ]
+            },
+            "required": ["children"]
         })
     );
+    assert_eq!(schemas, ["CustomItem"]);
 }
 
 #[test]
-fn derive_complex_enum_serde_adjacently_tagged() {
-    let value: Value = api_doc! {
-        #[derive(Serialize)]
-        #[serde(tag = "tag", content = "content")]
-        enum Foo {
-            Bar(i32),
-            Baz(String),
+fn derive_struct_with_cow() {
+    #[allow(unused)]
+    let greeting = api_doc! {
+        struct Greeting<'a> {
+            greeting: Cow<'a, str>
+        }
+    };
+
+    common::assert_json_array_len(greeting.get("required").unwrap(), 1);
+    assert_value! {greeting=>
+        "properties.greeting.type" = r###""string""###, "Greeting greeting field type"
+        "required.[0]" = r###""greeting""###, "Greeting required"
+    };
+}
+
+#[test]
+fn derive_struct_with_cow_str_and_slices() {
+    #[derive(ToSchema, Clone)]
+    #[allow(unused)]
+    struct Foo {
+        value: i32,
+    }
+
+    let borrowed = api_doc! {
+        struct Borrowed<'a> {
+            name: Cow<'a, str>,
+            numbers: Cow<'a, [u32]>,
+            foos: Cow<'a, [Foo]>,
+            names: Vec<Cow<'a, str>>,
         }
     };
 
     assert_json_eq!(
-        value,
+        borrowed,
         json!({
-            "oneOf": [
-                {
-                    "type": "object",
-                    "properties": {
-                        "tag": {
-                            "type": "string",
-                            "enum": [
-                                "Bar",
-                            ],
-                        },
-                        "content": {
-                            "format": "int32",
-                            "type": "integer",
-                        },
-                    },
-                    "required": [
-                        "tag",
-                        "content"
-                    ],
+            "properties": {
+                "name": {"type": "string"},
+                "numbers": {
+                    "type": "array",
+                    "items": {
+                        "type": "integer",
+                        "format": "int32",
+                        "minimum": 0
+                    }
                 },
-                {
-                    "type": "object",
-                    "properties": {
-                        "tag": {
-                            "type": "string",
-                            "enum": [
-                                "Baz",
-                            ]
-                        },
-                        "content": {
-                            "type": "string",
-                        },
-                    },
-                    "required": [
-                        "tag",
-                        "content"
-                    ],
+                "foos": {
+                    "type": "array",
+                    "items": {"$ref": "#/components/schemas/Foo"}
                 },
-            ],
-            "discriminator": {
-                "propertyName": "tag",
+                "names": {
+                    "type": "array",
+                    "items": {"type": "string"}
+                }
             },
+            "required": ["name", "numbers", "foos", "names"],
+            "type": "object"
         })
-    );
+    )
 }
 
 #[test]
-fn derive_complex_enum_with_ref_serde_adjacently_tagged() {
-    #[derive(Serialize)]
+fn derive_with_box_and_refcell() {
+    #[allow(unused)]
     struct Foo {
-        name: String,
-        age: u32,
+        name: &'static str,
     }
 
-    let value: Value = api_doc! {
-        #[derive(Serialize)]
-        #[serde(tag = "tag", content = "content")]
-        enum Bar {
-            Baz(i32),
-            FooBar(Foo),
+    let greeting = api_doc! {
+        struct Greeting {
+            foo: Box<Foo>,
+            ref_cell_foo: RefCell<Foo>
         }
     };
 
-    assert_json_eq!(
-        value,
-        json!({
-            "oneOf": [
-                {
-                    "type": "object",
-                    "properties": {
-                        "tag": {
-                            "type": "string",
-                            "enum": [
-                                "Baz",
-                            ],
-                        },
-                        "content": {
-                            "type": "integer",
-                            "format": "int32",
-                        },
-                    },
-                    "required": [
-                        "tag",
-                        "content"
-                    ],
-                },
-                {
-                    "type": "object",
-                    "properties": {
-                        "tag": {
-                            "type": "string",
-                            "enum": [
-                                "FooBar",
-                            ],
-                        },
-                        "content": {
-                            "$ref": "#/components/schemas/Foo"
-                        },
-                    },
-                    "required": [
-                        "tag",
-                        "content"
-                    ],
+    common::assert_json_array_len(greeting.get("required").unwrap(), 2);
+    assert_value! {greeting=>
+        "properties.foo.$ref" = r###""#/components/schemas/Foo""###, "Greeting foo field"
+        "properties.ref_cell_foo.$ref" = r###""#/components/schemas/Foo""###, "Greeting ref_cell_foo field"
+        "required.0" = r###""foo""###, "Greeting required 0"
+        "required.1" = r###""ref_cell_foo""###, "Greeting required 1"
+    };
+}
+
+#[test]
+fn derive_struct_with_no_recursion() {
+    let tree = api_doc! {
+        struct Tree {
+            value: i32,
+            #[schema(inline, no_recursion)]
+            children: Vec<Tree>,
+        }
+    };
+
+    assert_json_eq!(
+        &tree,
+        json!({
+            "properties": {
+                "value": {
+                    "type": "integer",
+                    "format": "int32"
                 },
-            ],
-            "discriminator": {
-                "propertyName": "tag",
+                "children": {
+                    "type": "array",
+                    "items": {
+                        "$ref": "#/components/schemas/Tree"
+                    }
+                }
             },
+            "type": "object",
+            "required": [
+                "value",
+                "children"
+            ]
         })
     );
+
+    let node = api_doc! {
+        #[schema(rename = "Node")]
+        struct Tree {
+            #[schema(inline, no_recursion)]
+            children: Vec<Self>,
+        }
+    };
+
+    assert_value! {node=>
+        "properties.children.items.$ref" = r###""#/components/schemas/Node""###, "Node children items"
+    }
+
+    let renamed_tree = api_doc! {
+        #[schema(rename = "Node")]
+        struct Tree {
+            #[schema(inline, no_recursion)]
+            children: Vec<Tree>,
+        }
+    };
+
+    assert_value! {renamed_tree=>
+        "properties.children.items.$ref" = r###""#/components/schemas/Node""###, "Renamed Tree children items"
+    }
 }
 
 #[test]
-fn derive_complex_enum_with_ref_serde_adjacently_tagged_named_fields() {
-    #[derive(Serialize, ToSchema)]
-    struct Bar {
+fn derive_struct_with_mutually_inlined_types_references_cycle() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Author {
         name: String,
-        age: u32,
+        #[schema(inline)]
+        books: Vec<Book>,
     }
 
-    let value: Value = api_doc! {
-        #[derive(Serialize)]
-        #[serde(tag = "tag", content = "content")]
-        enum Foo {
-            One { n: i32 },
-            Two { bar: Bar },
+    #[derive(ToSchema)]
+    #[schema(rename = "CustomBook")]
+    #[allow(unused)]
+    struct Book {
+        title: String,
+        #[schema(inline)]
+        author: Box<Author>,
+    }
+
+    let author = serde_json::to_value(<Author as ToSchema>::schema().1).unwrap();
+    let schemas = Author::schemas()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+
+    assert_eq!(schemas, ["Author", "CustomBook"]);
+
+    #[cfg(feature = "auto_title")]
+    let author = common::without_auto_titles(
+        author,
+        &[
+            ("", "Author"),
+            ("/properties/books/items", "Book"),
+            ("/properties/books/items/properties/author", "Author"),
+        ],
+    );
+    assert_json_eq!(
+        author,
+        json!({
+            "properties": {
+                "name": {"type": "string"},
+                "books": {
+                    "type": "array",
+                    "items": {
+                        "properties": {
+                            "title": {"type": "string"},
+                            "author": {
+                                "properties": {
+                                    "name": {"type": "string"},
+                                    "books": {
+                                        "type": "array",
+                                        "items": {"$ref": "#/components/schemas/CustomBook"}
+                                    }
+                                },
+                                "required": ["name", "books"],
+                                "type": "object"
+                            }
+                        },
+                        "required": ["title", "author"],
+                        "type": "object"
+                    }
+                }
+            },
+            "required": ["name", "books"],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_inline() {
+    #[derive(utoipa::ToSchema)]
+    #[allow(unused)]
+    struct Foo {
+        name: &'static str,
+    }
+
+    let greeting = api_doc! {
+        struct Greeting {
+            #[schema(inline)]
+            foo1: Foo,
+            #[schema(inline)]
+            foo2: Option<Foo>,
+            #[schema(inline)]
+            foo3: Option<Box<Foo>>,
+            #[schema(inline)]
+            foo4: Vec<Foo>,
         }
     };
 
+    #[cfg(not(feature = "openapi_31"))]
+    let foo2 = json!({
+        "nullable": true,
+        "allOf": [
+         {
+             "properties": {
+                 "name": {
+                     "type": "string"
+                 },
+             },
+             "required": [
+                 "name"
+             ],
+             "type": "object"
+         }
+        ]
+    });
+    #[cfg(feature = "openapi_31")]
+    let foo2 = json!({
+        "oneOf": [
+            {
+                "type": "null"
+            },
+            {
+                "properties": {
+                    "name": {
+                        "type": "string"
+                    },
+                },
+                "required": [
+                    "name"
+                ],
+                "type": "object"
+            }
+        ]
+    });
+
+    #[cfg(not(feature = "openapi_31"))]
+    let foo3 = json!({
+        "nullable": true,
+        "allOf": [
+        {
+            "properties": {
+                "name": {
+                    "type": "string"
+                },
+            },
+            "required": [
+                "name"
+            ],
+            "type": "object"
+        }
+        ]
+    });
+    #[cfg(feature = "openapi_31")]
+    let foo3 = json!({
+        "oneOf": [
+            {
+                "type": "null"
+            },
+            {
+                "properties": {
+                    "name": {
+                        "type": "string"
+                    },
+                },
+                "required": [
+                    "name"
+                ],
+                "type": "object"
+            }
+        ]
+    });
+
+    #[cfg(all(feature = "auto_title", not(feature = "openapi_31")))]
+    let nullable_foo = "allOf/0";
+    #[cfg(all(feature = "auto_title", feature = "openapi_31"))]
+    let nullable_foo = "oneOf/1";
+    #[cfg(feature = "auto_title")]
+    let greeting = common::without_auto_titles(
+        greeting,
+        &[
+            ("/properties/foo1", "Foo"),
+            (&format!("/properties/foo2/{nullable_foo}"), "Foo"),
+            (&format!("/properties/foo3/{nullable_foo}"), "Foo"),
+            ("/properties/foo4/items", "Foo"),
+        ],
+    );
     assert_json_eq!(
-        value,
+        &greeting,
         json!({
-            "oneOf": [
-                {
-                    "type": "object",
+            "properties": {
+                "foo1": {
                     "properties": {
-                        "tag": {
-                            "type": "string",
-                            "enum": [
-                                "One",
-                            ],
-                        },
-                        "content": {
-                            "type": "object",
-                            "properties": {
-                                "n": {
-                                    "format": "int32",
-                                    "type": "integer",
-                                },
-                            },
-                            "required": [
-                                "n",
-                            ],
+                        "name": {
+                            "type": "string"
                         },
                     },
                     "required": [
-                      "tag",
-                      "content"
+                        "name"
                     ],
+                    "type": "object"
                 },
-                {
-                    "type": "object",
-                    "properties": {
-                        "tag": {
-                            "type": "string",
-                            "enum": [
-                                "Two",
-                            ],
-                        },
-                        "content": {
-                            "type": "object",
-                            "properties": {
-                                "bar": {
-                                    "$ref": "#/components/schemas/Bar",
-                                },
+                "foo2": foo2,
+                "foo3": foo3,
+                "foo4": {
+                    "items": {
+                        "properties": {
+                            "name": {
+                                "type": "string"
                             },
-                            "required": [
-                                "bar",
-                            ],
                         },
+                        "required": [
+                            "name"
+                        ],
+                        "type": "object"
                     },
-                    "required": [
-                      "tag",
-                      "content",
-                    ],
+                    "type": "array"
                 },
-            ],
-            "discriminator": {
-                "propertyName": "tag",
             },
+            "required": [
+                "foo1",
+                "foo4",
+            ],
+            "type": "object"
         })
     );
 }
 
 #[test]
-fn derive_complex_enum_with_ref_serde_adjacently_tagged_named_fields_rename_all() {
-    #[derive(Serialize, ToSchema)]
-    struct Bar {
-        name: String,
-        age: u32,
-    }
-
+fn derive_simple_enum() {
     let value: Value = api_doc! {
         #[derive(Serialize)]
-        #[serde(tag = "tag", content = "content")]
-        enum Foo {
-            #[serde(rename_all = "camelCase")]
-            One { some_number: i32 },
-            #[serde(rename_all = "camelCase")]
-            Two { some_bar: Bar },
+        enum Bar {
+            A,
+            B,
+            C,
         }
     };
 
     assert_json_eq!(
         value,
         json!({
-            "oneOf": [
-                {
-                    "type": "object",
-                    "properties": {
-                        "tag": {
-                            "type": "string",
-                            "enum": [
-                                "One",
-                            ],
-                        },
-                        "content": {
-                            "type": "object",
-                            "properties": {
-                                "someNumber": {
-                                    "format": "int32",
-                                    "type": "integer",
-                                },
-                            },
-                            "required": [
-                                "someNumber",
-                            ],
-                        },
-                    },
-                    "required": [
-                      "tag",
-                      "content"
-                    ],
-                },
-                {
-                    "type": "object",
-                    "properties": {
-                        "tag": {
-                            "type": "string",
-                            "enum": [
-                                "Two",
-                            ],
-                        },
-                        "content": {
-                            "type": "object",
-                            "properties": {
-                                "someBar": {
-                                    "$ref": "#/components/schemas/Bar",
-                                },
-                            },
-                            "required": [
-                                "someBar",
-                            ],
-                        },
-                    },
-                    "required": [
-                      "tag",
-                      "content",
-                    ],
-                },
+            "enum": [
+                "A",
+                "B",
+                "C",
             ],
-            "discriminator": {
-                "propertyName": "tag",
+            "type": "string",
+        })
+    );
+}
+
+#[test]
+fn derive_simple_enum_without_variants() {
+    let value: Value = api_doc! {
+        enum Never {}
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "enum": [],
+            "type": "string",
+        })
+    );
+}
+
+#[test]
+fn derive_struct_with_simple_enum_reference() {
+    #![allow(unused)]
+
+    #[derive(ToSchema, Serialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Status {
+        Active,
+        Inactive,
+    }
+
+    #[derive(ToSchema)]
+    struct Account {
+        status: Status,
+    }
+
+    let schemas = Account::schemas()
+        .into_iter()
+        .map(|(name, schema)| (name, serde_json::to_value(schema).unwrap()))
+        .collect::<Vec<_>>();
+    #[cfg(feature = "auto_title")]
+    let schemas = schemas
+        .into_iter()
+        .map(|(name, schema)| {
+            let schema = common::without_auto_title(schema, &name);
+            (name, schema)
+        })
+        .collect::<Vec<_>>();
+
+    let value = serde_json::to_value(Account::schema().1).unwrap();
+    #[cfg(feature = "auto_title")]
+    let value = common::without_auto_titles(value, &[("", "Account")]);
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "object",
+            "properties": {
+                "status": {
+                    "$ref": "#/components/schemas/Status"
+                }
             },
+            "required": ["status"]
         })
     );
+    assert_eq!(
+        schemas,
+        [
+            ("Account", value),
+            (
+                "Status",
+                json!({
+                    "type": "string",
+                    "enum": ["active", "inactive"]
+                })
+            )
+        ]
+    );
 }
 
 #[test]
-fn derive_complex_enum_serde_tag_title() {
-    #[derive(Serialize)]
-    #[allow(dead_code)]
-    struct Foo(String);
+fn derive_simple_enum_as_const() {
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[schema(as_const)]
+        enum Kind {
+            OnlyValue,
+        }
+    };
+
+    #[cfg(not(feature = "openapi_31"))]
+    let kind = json!({
+        "enum": ["OnlyValue"],
+        "type": "string",
+    });
+    #[cfg(feature = "openapi_31")]
+    let kind = json!({
+        "const": "OnlyValue",
+        "type": "string",
+    });
+
+    assert_json_eq!(value, kind);
+}
+
+#[test]
+fn derive_simple_enum_as_const_with_rename_all() {
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(rename_all = "snake_case")]
+        #[schema(as_const)]
+        enum Kind {
+            OnlyValue,
+        }
+    };
+
+    #[cfg(not(feature = "openapi_31"))]
+    let kind = json!({
+        "enum": ["only_value"],
+        "type": "string",
+    });
+    #[cfg(feature = "openapi_31")]
+    let kind = json!({
+        "const": "only_value",
+        "type": "string",
+    });
+
+    assert_json_eq!(value, kind);
+}
+
+#[test]
+fn derive_simple_enum_variants_as_one_of() {
+    let value: Value = api_doc! {
+        /// Size of the shirt.
+        #[derive(Serialize)]
+        #[serde(rename_all = "snake_case")]
+        #[schema(variants_as_one_of, title = "ShirtSize", default = "medium")]
+        enum Size {
+            /// Fits chest up to 90 cm.
+            Small,
+            /// Fits chest up to 100 cm.
+            Medium,
+            #[schema(rename = "XL")]
+            ExtraLarge,
+            #[serde(skip)]
+            Unknown,
+        }
+    };
+
+    #[cfg(not(feature = "openapi_31"))]
+    let sizes = json!([
+        {
+            "type": "string",
+            "enum": ["small"],
+            "description": "Fits chest up to 90 cm."
+        },
+        {
+            "type": "string",
+            "enum": ["medium"],
+            "description": "Fits chest up to 100 cm."
+        },
+        {
+            "type": "string",
+            "enum": ["XL"]
+        }
+    ]);
+    #[cfg(feature = "openapi_31")]
+    let sizes = json!([
+        {
+            "type": "string",
+            "const": "small",
+            "description": "Fits chest up to 90 cm."
+        },
+        {
+            "type": "string",
+            "const": "medium",
+            "description": "Fits chest up to 100 cm."
+        },
+        {
+            "type": "string",
+            "const": "XL"
+        }
+    ]);
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": sizes,
+            "title": "ShirtSize",
+            "default": "medium",
+            "description": "Size of the shirt."
+        })
+    );
+}
 
+#[test]
+fn derive_simple_enum_serde_tag() {
     let value: Value = api_doc! {
         #[derive(Serialize)]
         #[serde(tag = "tag")]
         enum Bar {
-            #[schema(title = "Unit")]
-            UnitValue,
-            #[schema(title = "Named")]
-            NamedFields {
-                id: &'static str,
-            },
+            A,
+            B,
+            C,
         }
     };
 
@@ -2657,12 +2848,11 @@ fn derive_complex_enum_serde_tag_title() {
             "oneOf": [
                 {
                     "type": "object",
-                    "title": "Unit",
                     "properties": {
                         "tag": {
                             "type": "string",
                             "enum": [
-                                "UnitValue",
+                                "A",
                             ],
                         },
                     },
@@ -2672,300 +2862,4893 @@ fn derive_complex_enum_serde_tag_title() {
                 },
                 {
                     "type": "object",
-                    "title": "Named",
                     "properties": {
-                        "id": {
+                        "tag": {
                             "type": "string",
+                            "enum": [
+                                "B",
+                            ],
                         },
+                    },
+                    "required": [
+                        "tag",
+                    ],
+                },
+                {
+                    "type": "object",
+                    "properties": {
                         "tag": {
                             "type": "string",
                             "enum": [
-                                "NamedFields",
+                                "C",
                             ],
                         },
                     },
                     "required": [
-                        "id",
                         "tag",
                     ],
                 },
             ],
-            "discriminator": {
-                "propertyName": "tag"
-            }
         })
     );
 }
 
 #[test]
-fn derive_struct_with_read_only_and_write_only() {
-    let user = api_doc! {
-        struct User {
-            #[schema(read_only)]
-            username: String,
-            #[schema(write_only)]
-            password: String
+fn derive_simple_enum_serde_untagged() {
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(untagged)]
+        enum Foo {
+            One,
+            Two,
         }
     };
 
-    assert_value! {user=>
-        "properties.password.type" = r###""string""###, "User password type"
-        "properties.password.writeOnly" = r###"true"###, "User password write only"
-        "properties.password.readOnly" = r###"null"###, "User password read only"
-        "properties.username.type" = r###""string""###, "User username type"
-        "properties.username.readOnly" = r###"true"###, "User username read only"
-        "properties.username.writeOnly" = r###"null"###, "User username write only"
+    // OpenAPI 3.1 has `null` type for unit variants serialized as `null`
+    #[cfg(not(feature = "openapi_31"))]
+    let expected = json!({
+        "type": "object",
+        "nullable": true,
+        "default": null,
+    });
+    #[cfg(feature = "openapi_31")]
+    let expected = json!({
+        "type": "null",
+        "default": null,
+    });
+
+    assert_json_eq!(value, expected);
+}
+
+#[test]
+fn derive_struct_unnamed_field_reference_with_comment() {
+    #[derive(ToSchema, Serialize)]
+    struct Bar {
+        value: String,
     }
+
+    let value = api_doc! {
+        #[derive(Serialize)]
+        /// Description is applied to the $ref that is created for inner type Bar
+        struct Foo(Bar);
+    };
+
+    // OpenAPI 3.0 ignores siblings of $ref thus the description is defined on allOf wrapper
+    #[cfg(not(feature = "openapi_31"))]
+    let expected = json!({
+      "allOf": [
+        {
+          "$ref": "#/components/schemas/Bar"
+        }
+      ],
+      "description": "Description is applied to the $ref that is created for inner type Bar"
+    });
+    #[cfg(feature = "openapi_31")]
+    let expected = json!({
+      "$ref": "#/components/schemas/Bar",
+      "description": "Description is applied to the $ref that is created for inner type Bar"
+    });
+
+    assert_json_eq!(value, expected);
 }
 
+/// Derive a complex enum with named and unnamed fields.
 #[test]
-fn derive_struct_with_nullable_and_required() {
-    let user = api_doc! {
+fn derive_complex_unnamed_field_reference_with_comment() {
+    #[derive(Serialize)]
+    struct CommentedReference(String);
+
+    let value: Value = api_doc! {
         #[derive(Serialize)]
-        struct User {
-            #[schema(nullable)]
-            #[serde(with = "::serde_with::rust::double_option")]
-            fax: Option<Option<String>>,
-            #[schema(nullable)]
-            phone: Option<Option<String>>,
-            #[schema(nullable = false)]
-            email: String,
-            name: String,
-            #[schema(nullable)]
-            edit_history: Vec<String>,
-            #[serde(skip_serializing_if = "Vec::is_empty")]
-            friends: Vec<Option<String>>,
-            #[schema(required)]
-            updated: Option<String>,
+        enum EnumWithReference {
+            /// This is comment which will be added to the document
+            /// as description of the $ref
+            UnnamedFieldWithCommentReference(CommentedReference),
         }
     };
 
+    // OpenAPI 3.0 ignores siblings of $ref thus the description is defined on allOf wrapper
+    #[cfg(not(feature = "openapi_31"))]
+    let reference = json!({
+        "allOf": [
+            {
+                "$ref": "#/components/schemas/CommentedReference",
+            }
+        ],
+        "description": "This is comment which will be added to the document\nas description of the $ref",
+    });
+    #[cfg(feature = "openapi_31")]
+    let reference = json!({
+        "$ref": "#/components/schemas/CommentedReference",
+        "description": "This is comment which will be added to the document\nas description of the $ref",
+    });
+
     assert_json_eq!(
-        user,
+        value,
         json!({
-            "properties": {
-                "fax": {
-                    "type": "string",
-                    "nullable": true,
-                },
-                "phone": {
-                    "type": "string",
-                    "nullable": true,
-                },
-                "email": {
-                    "type": "string",
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "UnnamedFieldWithCommentReference": reference,
+                    },
+                    "required": ["UnnamedFieldWithCommentReference"],
                 },
-                "name": {
+            ],
+        })
+    );
+}
+
+#[test]
+fn derive_enum_with_unnamed_map_and_boxed_field_with_tag() {
+    #[derive(Serialize, ToSchema)]
+    struct Foo {
+        name: String,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "tag")]
+        enum EnumWithPayload {
+            Map(HashMap<String, String>),
+            Boxed(Box<Foo>),
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "allOf": [
+                        {
+                            "type": "object",
+                            "additionalProperties": {
+                                "type": "string"
+                            }
+                        },
+                        {
+                            "type": "object",
+                            "properties": {
+                                "tag": {
+                                    "type": "string",
+                                    "enum": ["Map"]
+                                },
+                            },
+                            "required": ["tag"]
+                        }
+                    ]
+                },
+                {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Foo"
+                        },
+                        {
+                            "type": "object",
+                            "properties": {
+                                "tag": {
+                                    "type": "string",
+                                    "enum": ["Boxed"]
+                                },
+                            },
+                            "required": ["tag"]
+                        }
+                    ]
+                },
+            ],
+            "discriminator": {
+                "propertyName": "tag",
+                "mapping": {
+                    "Boxed": "#/components/schemas/Foo"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_with_schema_properties() {
+    let value: Value = api_doc! {
+        /// This is the description
+        #[derive(Serialize)]
+        #[schema(example = json!(EnumWithProperties::Variant2{name: String::from("foobar")}),
+            default = json!(EnumWithProperties::Variant{id: String::from("1")}))]
+        enum EnumWithProperties {
+            Variant {
+                id: String
+            },
+            Variant2{
+                name: String
+            }
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "description": "This is the description",
+            "default": {
+                "Variant": {
+                    "id": "1"
+                }
+            },
+            "example": {
+                "Variant2": {
+                    "name": "foobar"
+                }
+            },
+            "oneOf": [
+                {
+                    "properties": {
+                        "Variant": {
+                            "properties": {
+                                "id": {
+                                    "type": "string"
+                                }
+                            },
+                            "required": ["id"],
+                            "type": "object"
+                        }
+                    },
+                    "required": ["Variant"],
+                    "type": "object"
+                },
+                {
+                    "properties": {
+                        "Variant2": {
+                            "properties": {
+                                "name": {
+                                    "type": "string"
+                                }
+                            },
+                            "required": ["name"],
+                            "type": "object"
+                        }
+                    },
+                    "required": ["Variant2"],
+                    "type": "object"
+                }
+            ]
+        })
+    )
+}
+
+// TODO fixme https://github.com/juhaku/utoipa/issues/285#issuecomment-1249625860
+#[test]
+fn derive_enum_with_unnamed_single_field_with_tag() {
+    #[derive(Serialize)]
+    struct ReferenceValue(String);
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "enum")]
+        enum EnumWithReference {
+            Value(ReferenceValue),
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/ReferenceValue",
+                        },
+                        {
+                            "type": "object",
+                            "properties": {
+                                "enum": {
+                                    "type": "string",
+                                    "enum": ["Value"]
+
+                                },
+                            },
+                            "required": ["enum"]
+                        },
+                    ],
+                }
+            ],
+            "discriminator": {
+                "propertyName": "enum",
+                "mapping": {
+                    "Value": "#/components/schemas/ReferenceValue",
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_enum_with_named_fields_with_reference_with_tag() {
+    #[derive(Serialize)]
+    struct ReferenceValue(String);
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "enum")]
+        enum EnumWithReference {
+            Value {
+                field: ReferenceValue,
+                a: String
+            },
+            UnnamedValue(ReferenceValue),
+            UnitValue,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "properties": {
+                        "a": {
+                            "type": "string"
+                        },
+                        "enum": {
+                            "enum": [
+                                "Value"
+                            ],
+                            "type": "string"
+                        },
+                        "field": {
+                            "$ref": "#/components/schemas/ReferenceValue"
+                        }
+                    },
+                    "required": [
+                        "field",
+                        "a",
+                        "enum"
+                    ],
+                    "type": "object"
+                },
+                {
+                    "allOf": [
+                        {
+                        "$ref": "#/components/schemas/ReferenceValue",
+                        },
+                        {
+                            "type": "object",
+                            "properties": {
+                                "enum": {
+                                    "type": "string",
+                                    "enum": ["UnnamedValue"]
+
+                                },
+                            },
+                            "required": ["enum"]
+                        }
+                    ],
+                },
+                {
+                    "properties": {
+                        "enum": {
+                            "enum": [
+                                "UnitValue"
+                            ],
+                            "type": "string"
+                        }
+                    },
+                    "required": [
+                        "enum"
+                    ],
+                    "type": "object"
+                }
+            ],
+            "discriminator": {
+                "propertyName": "enum",
+                "mapping": {
+                    "UnnamedValue": "#/components/schemas/ReferenceValue",
+                }
+            }
+        })
+    );
+}
+
+/// Derive a complex enum with named and unnamed fields.
+#[test]
+fn derive_complex_enum() {
+    #[derive(Serialize)]
+    struct Foo(String);
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        enum Bar {
+            UnitValue,
+            NamedFields {
+                id: &'static str,
+                names: Option<Vec<String>>
+            },
+            UnnamedFields(Foo),
+        }
+    };
+
+    #[cfg(not(feature = "openapi_31"))]
+    let names = json!({
+        "type": "array",
+        "nullable": true,
+        "items": {
+            "type": "string",
+        },
+    });
+    #[cfg(feature = "openapi_31")]
+    let names = json!({
+        "type": ["array", "null"],
+        "items": {
+            "type": "string",
+        },
+    });
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "string",
+                    "enum": [
+                        "UnitValue",
+                    ],
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "NamedFields": {
+                            "type": "object",
+                            "properties": {
+                                "id": {
+                                    "type": "string",
+                                },
+                                "names": names,
+                            },
+                            "required": [
+                                "id",
+                            ],
+                        },
+                    },
+                    "required": ["NamedFields"],
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "UnnamedFields": {
+                            "$ref": "#/components/schemas/Foo",
+                        },
+                    },
+                    "required": ["UnnamedFields"],
+                },
+            ],
+        })
+    );
+}
+
+#[cfg(not(feature = "openapi_31"))]
+#[test]
+fn derive_complex_enum_named_variant_with_option_field() {
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        enum Event {
+            Created {
+                id: i32,
+                name: Option<String>,
+                #[schema(required)]
+                owner: Option<String>,
+            },
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "Created": {
+                            "type": "object",
+                            "properties": {
+                                "id": {
+                                    "type": "integer",
+                                    "format": "int32"
+                                },
+                                "name": {
+                                    "type": "string",
+                                    "nullable": true
+                                },
+                                "owner": {
+                                    "type": "string",
+                                    "nullable": true
+                                }
+                            },
+                            "required": ["id", "owner"]
+                        }
+                    },
+                    "required": ["Created"]
+                }
+            ]
+        })
+    );
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "type")]
+        enum Event {
+            Created { id: i32, name: Option<String> },
+        }
+    };
+
+    assert_json_eq!(
+        value.pointer("/oneOf/0/required").unwrap(),
+        json!(["id", "type"])
+    );
+    assert_json_eq!(
+        value.pointer("/oneOf/0/properties/name").unwrap(),
+        json!({"type": "string", "nullable": true})
+    );
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "type", content = "data")]
+        enum Event {
+            Created { id: i32, name: Option<String> },
+        }
+    };
+
+    assert_json_eq!(
+        value.pointer("/oneOf/0/properties/data/required").unwrap(),
+        json!(["id"])
+    );
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(untagged)]
+        enum Event {
+            Created { id: i32, name: Option<String> },
+        }
+    };
+
+    assert_json_eq!(value.pointer("/oneOf/0/required").unwrap(), json!(["id"]));
+}
+
+#[test]
+fn derive_complex_enum_with_default_variant() {
+    let value: Value = api_doc! {
+        #[derive(Serialize, Default)]
+        enum Shape {
+            #[default]
+            #[schema(default)]
+            Point,
+            Circle { radius: f64 },
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "string",
+                    "enum": ["Point"]
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "Circle": {
+                            "type": "object",
+                            "properties": {
+                                "radius": {
+                                    "type": "number",
+                                    "format": "double"
+                                }
+                            },
+                            "required": ["radius"]
+                        }
+                    },
+                    "required": ["Circle"]
+                }
+            ],
+            "default": "Point"
+        })
+    );
+
+    #[derive(Serialize, ToSchema)]
+    #[serde(tag = "type")]
+    #[allow(unused)]
+    enum TaggedShape {
+        Point,
+        #[schema(default)]
+        Circle {
+            radius: f64,
+        },
+    }
+
+    impl Default for TaggedShape {
+        fn default() -> Self {
+            Self::Circle { radius: 1.0 }
+        }
+    }
+
+    let value = serde_json::to_value(TaggedShape::schema().1).unwrap();
+    assert_json_eq!(
+        value.get("default").unwrap(),
+        json!({"type": "Circle", "radius": 1.0})
+    );
+
+    let value: Value = api_doc! {
+        enum ErrorResponse {
+            #[schema(default = String::default)]
+            NotFound(String),
+        }
+    };
+
+    assert!(value.get("default").is_none());
+}
+
+#[test]
+fn derive_complex_enum_with_rust_default_variant() {
+    let value: Value = api_doc! {
+        #[derive(Serialize, Default)]
+        enum Shape {
+            Circle { radius: f64 },
+            #[default]
+            Point,
+        }
+    };
+
+    assert_json_eq!(value.get("default").unwrap(), json!("Point"));
+}
+
+#[test]
+fn derive_complex_enum_default_variant_not_returned_by_default() {
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    enum Shape {
+        Point,
+        #[schema(default)]
+        Circle {
+            radius: f64,
+        },
+    }
+
+    impl Default for Shape {
+        fn default() -> Self {
+            Self::Point
+        }
+    }
+
+    let value = serde_json::to_value(Shape::schema().1).unwrap();
+    assert!(value.get("default").is_none());
+}
+
+#[test]
+fn derive_complex_enum_title() {
+    #[derive(Serialize)]
+    struct Foo(String);
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        enum Bar {
+            #[schema(title = "Unit")]
+            UnitValue,
+            #[schema(title = "Named")]
+            NamedFields {
+                id: &'static str,
+            },
+            #[schema(title = "Unnamed")]
+            UnnamedFields(Foo),
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "string",
+                    "title": "Unit",
+                    "enum": [
+                        "UnitValue",
+                    ],
+                },
+                {
+                    "type": "object",
+                    "title": "Named",
+                    "properties": {
+                        "NamedFields": {
+                            "type": "object",
+                            "properties": {
+                                "id": {
+                                    "type": "string",
+                                },
+                            },
+                            "required": [
+                                "id",
+                            ],
+                        },
+                    },
+                    "required": ["NamedFields"],
+                },
+                {
+                    "type": "object",
+                    "title": "Unnamed",
+                    "properties": {
+                        "UnnamedFields": {
+                            "$ref": "#/components/schemas/Foo",
+                        },
+                    },
+                    "required": ["UnnamedFields"]
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_named_field_value_type() {
+    #[derive(Serialize)]
+    struct Foo;
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        enum Bar {
+            #[schema(title = "Named")]
+            NamedFields {
+                #[schema(value_type = String)]
+                id: Foo,
+                #[schema(value_type = Option<Vec<i64>>)]
+                values: Foo,
+            },
+        }
+    };
+
+    #[cfg(not(feature = "openapi_31"))]
+    let values = json!({
+        "type": "array",
+        "items": {
+            "type": "integer",
+            "format": "int64",
+        },
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let values = json!({
+        "type": ["array", "null"],
+        "items": {
+            "type": "integer",
+            "format": "int64",
+        },
+    });
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "title": "Named",
+                    "properties": {
+                        "NamedFields": {
+                            "type": "object",
+                            "properties": {
+                                "id": {
+                                    "type": "string",
+                                },
+                                "values": values,
+                            },
+                            "required": [
+                                "id",
+                            ],
+                        },
+                    },
+                    "required": ["NamedFields"],
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_serde_tag_named_field_value_type() {
+    #[derive(Serialize)]
+    struct Foo;
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "kind")]
+        enum Bar {
+            #[schema(title = "Named")]
+            NamedFields {
+                #[schema(value_type = String)]
+                id: Foo,
+            },
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "title": "Named",
+                    "properties": {
+                        "id": {
+                            "type": "string",
+                        },
+                        "kind": {
+                            "type": "string",
+                            "enum": ["NamedFields"],
+                        },
+                    },
+                    "required": ["id", "kind"],
+                },
+            ],
+            "discriminator": {
+                "propertyName": "kind"
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_serde_tag_title_with_unnamed_field() {
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Foo {
+        name: String,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "tag")]
+        enum Bar {
+            #[schema(title = "Unit")]
+            UnitValue,
+            #[schema(title = "Named")]
+            NamedFields {
+                id: &'static str,
+            },
+            #[schema(title = "Unnamed")]
+            UnnamedFields(Foo),
+        }
+    };
+
+    assert_value! {value=>
+        "oneOf.[0].title" = r#""Unit""#, "Unit variant title"
+        "oneOf.[1].title" = r#""Named""#, "Named variant title"
+        "oneOf.[2].title" = r#""Unnamed""#, "Unnamed variant title"
+    };
+}
+
+#[test]
+fn derive_complex_enum_serde_adjacently_tagged_title() {
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Foo(String);
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "tag", content = "content")]
+        enum Bar {
+            #[schema(title = "Unit")]
+            UnitValue,
+            #[schema(title = "Named")]
+            NamedFields {
+                id: &'static str,
+            },
+            #[schema(title = "Unnamed")]
+            UnnamedFields(Foo),
+        }
+    };
+
+    assert_value! {value=>
+        "oneOf.[0].title" = r#""Unit""#, "Unit variant title"
+        "oneOf.[1].title" = r#""Named""#, "Named variant title"
+        "oneOf.[2].title" = r#""Unnamed""#, "Unnamed variant title"
+    };
+}
+
+#[test]
+fn derive_complex_enum_serde_untagged_title() {
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Foo(String);
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(untagged)]
+        enum Bar {
+            #[schema(title = "Named")]
+            NamedFields {
+                id: &'static str,
+            },
+            #[schema(title = "Unnamed")]
+            UnnamedFields(Foo),
+            #[schema(title = "Primitive")]
+            Primitive(i32),
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "title": "Named",
+                    "properties": {
+                        "id": {
+                            "type": "string",
+                        },
+                    },
+                    "required": ["id"],
+                },
+                {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Foo",
+                        }
+                    ],
+                    "title": "Unnamed",
+                },
+                {
+                    "type": "integer",
+                    "format": "int32",
+                    "title": "Primitive",
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_example() {
+    #[derive(Serialize)]
+    struct Foo(String);
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        enum EnumWithExample {
+            #[schema(example = "EX: Unit")]
+            UnitValue,
+            #[schema(example = "EX: Named")]
+            NamedFields {
+                #[schema(example = "EX: Named id field")]
+                id: &'static str,
+            },
+            #[schema(example = "EX: Unnamed")]
+            UnnamedFields(Foo),
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "string",
+                    "example": "EX: Unit",
+                    "enum": [
+                        "UnitValue",
+                    ],
+                },
+                {
+                    "type": "object",
+                    "example": "EX: Named",
+                    "properties": {
+                        "NamedFields": {
+                            "type": "object",
+                            "properties": {
+                                "id": {
+                                    "type": "string",
+                                    "example": "EX: Named id field",
+                                },
+                            },
+                            "required": [
+                                "id",
+                            ],
+                        },
+                    },
+                    "required": ["NamedFields"]
+                },
+                {
+                    "type": "object",
+                    "example": "EX: Unnamed",
+                    "properties": {
+                        "UnnamedFields": {
+                            "$ref": "#/components/schemas/Foo",
+                        },
+                    },
+                    "required": ["UnnamedFields"]
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn derive_rename_all_with_all_rename_rules() {
+    macro_rules! assert_rename_all {
+        ( $( $rule:literal => $field:literal, $variant:literal );* ) => {
+            $(
+                {
+                    #[derive(Serialize, ToSchema)]
+                    #[serde(rename_all = $rule)]
+                    #[allow(unused)]
+                    struct SerdeStruct {
+                        field_name: i32,
+                    }
+
+                    #[derive(ToSchema)]
+                    #[schema(rename_all = $rule)]
+                    #[allow(unused)]
+                    struct SchemaStruct {
+                        field_name: i32,
+                    }
+
+                    #[derive(Serialize, ToSchema)]
+                    #[serde(rename_all = $rule)]
+                    #[allow(unused)]
+                    enum SerdeSimpleEnum {
+                        VariantName,
+                    }
+
+                    #[derive(ToSchema)]
+                    #[schema(rename_all = $rule)]
+                    #[allow(unused)]
+                    enum SchemaSimpleEnum {
+                        VariantName,
+                    }
+
+                    #[derive(Serialize, ToSchema)]
+                    #[serde(rename_all = $rule)]
+                    #[allow(unused)]
+                    enum SerdeComplexEnum {
+                        VariantName(i32),
+                    }
+
+                    #[derive(ToSchema)]
+                    #[schema(rename_all = $rule)]
+                    #[allow(unused)]
+                    enum SchemaComplexEnum {
+                        VariantName(i32),
+                    }
+
+                    let fields = [SerdeStruct::schema().1, SchemaStruct::schema().1];
+                    for schema in fields {
+                        let schema = serde_json::to_value(schema).unwrap();
+                        assert_eq!(schema.pointer("/required/0").unwrap(), $field, "rename_all = {}", $rule);
+                    }
+
+                    let simple_variants = [SerdeSimpleEnum::schema().1, SchemaSimpleEnum::schema().1];
+                    for schema in simple_variants {
+                        let schema = serde_json::to_value(schema).unwrap();
+                        assert_eq!(schema.pointer("/enum/0").unwrap(), $variant, "rename_all = {}", $rule);
+                    }
+
+                    let complex_variants = [SerdeComplexEnum::schema().1, SchemaComplexEnum::schema().1];
+                    for schema in complex_variants {
+                        let schema = serde_json::to_value(schema).unwrap();
+                        assert_eq!(schema.pointer("/oneOf/0/required/0").unwrap(), $variant, "rename_all = {}", $rule);
+                    }
+                }
+            )*
+        };
+    }
+
+    assert_rename_all! {
+        "lowercase" => "field_name", "variantname";
+        "UPPERCASE" => "FIELD_NAME", "VARIANTNAME";
+        "PascalCase" => "FieldName", "VariantName";
+        "camelCase" => "fieldName", "variantName";
+        "snake_case" => "field_name", "variant_name";
+        "SCREAMING_SNAKE_CASE" => "FIELD_NAME", "VARIANT_NAME";
+        "kebab-case" => "field-name", "variant-name";
+        "SCREAMING-KEBAB-CASE" => "FIELD-NAME", "VARIANT-NAME"
+    }
+}
+
+#[test]
+fn derive_complex_enum_serde_rename_all() {
+    #[derive(Serialize)]
+    struct Foo(String);
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(rename_all = "snake_case")]
+        enum Bar {
+            UnitValue,
+            NamedFields {
+                id: &'static str,
+                names: Option<Vec<String>>
+            },
+            UnnamedFields(Foo),
+        }
+    };
+
+    #[cfg(not(feature = "openapi_31"))]
+    let names = json!({
+        "type": "array",
+        "nullable": true,
+        "items": {
+            "type": "string",
+        },
+    });
+    #[cfg(feature = "openapi_31")]
+    let names = json!({
+        "type": ["array", "null"],
+        "items": {
+            "type": "string",
+        },
+    });
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "string",
+                    "enum": [
+                        "unit_value",
+                    ],
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "named_fields": {
+                            "type": "object",
+                            "properties": {
+                                "id": {
+                                    "type": "string",
+                                },
+                                "names": names,
+                            },
+                            "required": [
+                                "id",
+                            ],
+                        },
+                    },
+                    "required": ["named_fields"]
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "unnamed_fields": {
+                            "$ref": "#/components/schemas/Foo",
+                        },
+                    },
+                    "required": ["unnamed_fields"]
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_serde_rename_all_fields() {
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(rename_all = "snake_case", rename_all_fields = "camelCase")]
+        enum Event {
+            UserCreated {
+                user_id: i32,
+                display_name: String,
+            },
+            #[serde(rename_all = "kebab-case")]
+            UserRenamed {
+                user_id: i32,
+                #[serde(rename = "new")]
+                new_name: String,
+            },
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "user_created": {
+                            "type": "object",
+                            "properties": {
+                                "userId": {
+                                    "type": "integer",
+                                    "format": "int32"
+                                },
+                                "displayName": {
+                                    "type": "string"
+                                }
+                            },
+                            "required": ["userId", "displayName"]
+                        }
+                    },
+                    "required": ["user_created"]
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "user_renamed": {
+                            "type": "object",
+                            "properties": {
+                                "user-id": {
+                                    "type": "integer",
+                                    "format": "int32"
+                                },
+                                "new": {
+                                    "type": "string"
+                                }
+                            },
+                            "required": ["user-id", "new"]
+                        }
+                    },
+                    "required": ["user_renamed"]
+                }
+            ]
+        })
+    );
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "type", rename_all_fields = "PascalCase")]
+        enum Event {
+            UserCreated { user_id: i32 },
+        }
+    };
+
+    assert_json_eq!(
+        value.pointer("/oneOf/0/required").unwrap(),
+        json!(["UserId", "type"])
+    );
+}
+
+#[test]
+fn derive_complex_enum_schema_rename_all_fields() {
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "type", content = "data")]
+        #[schema(rename_all_fields = "camelCase")]
+        enum Event {
+            UserCreated { user_id: i32 },
+            #[schema(rename_all = "UPPERCASE")]
+            UserDeleted { user_id: i32 },
+        }
+    };
+
+    assert_json_eq!(
+        value.pointer("/oneOf/0/properties/data/required").unwrap(),
+        json!(["userId"])
+    );
+    assert_json_eq!(
+        value.pointer("/oneOf/1/properties/data/required").unwrap(),
+        json!(["USER_ID"])
+    );
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(untagged)]
+        #[schema(rename_all_fields = "camelCase")]
+        enum Event {
+            UserCreated { user_id: i32 },
+        }
+    };
+
+    assert_json_eq!(
+        value.pointer("/oneOf/0/required").unwrap(),
+        json!(["userId"])
+    );
+}
+
+#[test]
+fn derive_complex_enum_serde_rename_variant() {
+    #[derive(Serialize)]
+    struct Foo(String);
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        enum Bar {
+            #[serde(rename = "renamed_unit_value")]
+            UnitValue,
+            #[serde(rename = "renamed_named_fields")]
+            NamedFields {
+                #[serde(rename = "renamed_id")]
+                id: &'static str,
+                #[serde(rename = "renamed_names")]
+                names: Option<Vec<String>>
+            },
+            #[serde(rename = "renamed_unnamed_fields")]
+            UnnamedFields(Foo),
+        }
+    };
+
+    #[cfg(not(feature = "openapi_31"))]
+    let renamed_names = json!({
+        "type": "array",
+        "nullable": true,
+        "items": {
+            "type": "string",
+        },
+    });
+    #[cfg(feature = "openapi_31")]
+    let renamed_names = json!({
+        "type": ["array", "null"],
+        "items": {
+            "type": "string",
+        },
+    });
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "string",
+                    "enum": [
+                        "renamed_unit_value",
+                    ],
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "renamed_named_fields": {
+                            "type": "object",
+                            "properties": {
+                                "renamed_id": {
+                                    "type": "string",
+                                },
+                                "renamed_names": renamed_names,
+                            },
+                            "required": [
+                                "renamed_id",
+                            ],
+                        },
+                    },
+                    "required": ["renamed_named_fields"]
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "renamed_unnamed_fields": {
+                            "$ref": "#/components/schemas/Foo",
+                        },
+                    },
+                    "required": ["renamed_unnamed_fields"]
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn derive_struct_custom_rename() {
+    let value: Value = api_doc! {
+        #[schema(rename_all = "SCREAMING-KEBAB-CASE")]
+        struct Post {
+            post_id: i64,
+            created_at: i64,
+            #[schema(rename = "post_comment")]
+            comment: String,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "POST-ID": {
+                    "type": "integer",
+                    "format": "int64",
+                },
+                "CREATED-AT": {
+                    "type": "integer",
+                    "format": "int64",
+                },
+                "post_comment": {
+                    "type": "string",
+                },
+            },
+            "type": "object",
+            "required": [
+                "POST-ID",
+                "CREATED-AT",
+                "post_comment"
+            ]
+        })
+    )
+}
+
+#[test]
+fn derive_complex_enum_custom_rename() {
+    let value: Value = api_doc! {
+        #[schema(rename_all = "UPPERCASE")]
+        enum PostType {
+            NewPost(String),
+
+            #[schema(rename = "update_post", rename_all = "PascalCase")]
+            Update {
+                post_id: i64,
+                created_at: i64,
+                #[schema(rename = "post_comment")]
+                comment: String,
+            },
+
+            RandomValue {
+                id: i64,
+            },
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "properties": {
+                        "NEWPOST": {
+                            "type": "string"
+                        }
+                    },
+                    "required": ["NEWPOST"],
+                    "type": "object",
+                },
+                {
+                    "properties": {
+                        "update_post": {
+                            "properties": {
+                                "PostId": {
+                                    "type": "integer",
+                                    "format": "int64",
+                                },
+                                "CreatedAt": {
+                                    "type": "integer",
+                                    "format": "int64",
+                                },
+                                "post_comment": {
+                                    "type": "string",
+                                },
+                            },
+                            "type": "object",
+                            "required": [
+                                "PostId",
+                                "CreatedAt",
+                                "post_comment"
+                            ]
+                        }
+                    },
+                    "required": ["update_post"],
+                    "type": "object",
+                },
+                {
+                    "properties": {
+                        "RANDOMVALUE": {
+                            "properties": {
+                                "id": {
+                                    "type": "integer",
+                                    "format": "int64",
+                                },
+                            },
+                            "type": "object",
+                            "required": [
+                                "id",
+                            ]
+                        }
+                    },
+                    "required": ["RANDOMVALUE"],
+                    "type": "object",
+                }
+            ]
+        })
+    )
+}
+
+#[test]
+fn derive_complex_enum_use_serde_rename_over_custom_rename() {
+    let value: Value = api_doc! {
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "lowercase")]
+        #[schema(rename_all = "UPPERCASE")]
+        enum Random {
+            #[serde(rename = "string_value")]
+            #[schema(rename = "custom_value")]
+            String(String),
+
+            Number {
+                id: i32,
+            }
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "properties": {
+                        "string_value": {
+                            "type": "string",
+                        },
+                    },
+                    "type": "object",
+                    "required": ["string_value"]
+                },
+                {
+                    "properties": {
+                        "number": {
+                            "properties": {
+                                "id": {
+                                    "type": "integer",
+                                    "format": "int32",
+                                }
+                            },
+                            "type": "object",
+                            "required": ["id"]
+                        }
+                    },
+                    "type": "object",
+                    "required": ["number"]
+                }
+            ]
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_title() {
+    let value: Value = api_doc! {
+        #[schema(title = "Post")]
+        struct Post {
+            id: i64,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "format": "int64",
+                }
+            },
+            "title": "Post",
+            "required": ["id"],
+            "type": "object",
+        })
+    )
+}
+
+#[cfg(feature = "openapi_31")]
+#[test]
+fn derive_struct_with_examples() {
+    let value: Value = api_doc! {
+        #[schema(examples(json!({"id": 1, "tags": ["a"]}), json!({"id": 2, "tags": []})))]
+        struct Post {
+            #[schema(examples(1, 2))]
+            id: i64,
+            #[schema(examples(json!(["a", "b"])))]
+            tags: Vec<String>,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "format": "int64",
+                    "examples": [1, 2],
+                },
+                "tags": {
+                    "type": "array",
+                    "items": {
+                        "type": "string",
+                    },
+                    "examples": [["a", "b"]],
+                }
+            },
+            "examples": [
+                {"id": 1, "tags": ["a"]},
+                {"id": 2, "tags": []}
+            ],
+            "required": ["id", "tags"],
+            "type": "object",
+        })
+    )
+}
+
+#[cfg(feature = "openapi_31")]
+#[test]
+fn derive_enum_with_examples() {
+    let value: Value = api_doc! {
+        #[schema(examples("Admin", "User"))]
+        enum UserType {
+            Admin,
+            User,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "enum": ["Admin", "User"],
+            "examples": ["Admin", "User"],
+            "type": "string",
+        })
+    );
+
+    let value: Value = api_doc! {
+        #[schema(examples(json!({"Admin": {"id": 1}})))]
+        enum User {
+            Admin { id: i32 },
+            Guest,
+        }
+    };
+
+    assert_json_eq!(
+        value.get("examples").unwrap(),
+        json!([{"Admin": {"id": 1}}])
+    );
+}
+
+#[cfg(feature = "openapi_31")]
+#[test]
+fn derive_complex_enum_variants_with_examples() {
+    #[derive(Serialize, ToSchema)]
+    struct Foo(String);
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        enum Event {
+            #[schema(examples(json!({"Created": {"id": 1}}), json!({"Created": {"id": 2}})))]
+            Created { id: i32 },
+            #[schema(examples(json!({"Renamed": "foo"})))]
+            Renamed(Foo),
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "examples": [{"Created": {"id": 1}}, {"Created": {"id": 2}}],
+                    "properties": {
+                        "Created": {
+                            "type": "object",
+                            "properties": {
+                                "id": {
+                                    "type": "integer",
+                                    "format": "int32"
+                                }
+                            },
+                            "required": ["id"]
+                        }
+                    },
+                    "required": ["Created"]
+                },
+                {
+                    "type": "object",
+                    "examples": [{"Renamed": "foo"}],
+                    "properties": {
+                        "Renamed": {
+                            "$ref": "#/components/schemas/Foo"
+                        }
+                    },
+                    "required": ["Renamed"]
+                }
+            ]
+        })
+    );
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "type")]
+        enum Event {
+            #[schema(examples(json!({"type": "Created", "id": 1})))]
+            Created { id: i32 },
+        }
+    };
+
+    assert_json_eq!(
+        value.pointer("/oneOf/0/examples").unwrap(),
+        json!([{"type": "Created", "id": 1}])
+    );
+}
+
+#[test]
+fn derive_struct_with_title_and_description() {
+    let value: Value = api_doc! {
+        /// This is a post.
+        #[schema(title = "Post")]
+        struct Post {
+            id: i64,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "format": "int64",
+                }
+            },
+            "description": "This is a post.",
+            "title": "Post",
+            "required": ["id"],
+            "type": "object",
+        })
+    )
+}
+
+#[cfg(feature = "auto_title")]
+#[test]
+fn derive_schemas_with_auto_title() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+    }
+    let value = serde_json::to_value(Pet::schema().1).unwrap();
+
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "object",
+            "title": "Pet",
+            "properties": {
+                "name": {"type": "string"}
+            },
+            "required": ["name"]
+        })
+    );
+
+    let value: Value = api_doc! {
+        #[schema(title = "Animal")]
+        struct Pet {
+            name: String,
+        }
+    };
+    assert_json_eq!(value.get("title").unwrap(), json!("Animal"));
+
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    enum Color {
+        Red,
+        Green,
+    }
+    let value = serde_json::to_value(Color::schema().1).unwrap();
+
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "string",
+            "title": "Color",
+            "enum": ["Red", "Green"]
+        })
+    );
+
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    enum Shape {
+        Circle { radius: f64 },
+        Square(f64),
+    }
+    let value = serde_json::to_value(Shape::schema().1).unwrap();
+    assert_json_eq!(value.get("title").unwrap(), json!("Shape"));
+    assert_json_eq!(value.pointer("/oneOf/0/title"), json!(null));
+}
+
+#[test]
+fn derive_enum_with_title() {
+    let value: Value = api_doc! {
+        #[schema(title = "UserType")]
+        enum UserType {
+            Admin,
+            Moderator,
+            User,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "enum": ["Admin", "Moderator", "User"],
+            "title": "UserType",
+            "type": "string",
+        })
+    )
+}
+
+#[test]
+fn derive_complex_enum_with_title() {
+    let value: Value = api_doc! {
+        enum UserType {
+            #[schema(title = "admin")]
+            Admin(String),
+            #[schema(title = "moderator")]
+            Moderator{id: i32},
+            #[schema(title = "user")]
+            User,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "properties": {
+                        "Admin": {
+                            "type": "string"
+                        }
+                    },
+                    "title": "admin",
+                    "type": "object",
+                    "required": ["Admin"]
+                },
+                {
+                    "properties": {
+                        "Moderator": {
+                            "properties": {
+                                "id": {
+                                    "type": "integer",
+                                    "format": "int32",
+                                }
+                            },
+                            "required": ["id"],
+                            "type": "object",
+                        }
+                    },
+                    "required": ["Moderator"],
+                    "title": "moderator",
+                    "type": "object",
+                },
+                {
+                    "enum": ["User"],
+                    "title": "user",
+                    "type": "string"
+                }
+            ]
+        })
+    )
+}
+
+/// Derive a complex enum with the serde `tag` container attribute applied for internal tagging.
+/// Note that tuple fields are not supported.
+#[test]
+fn derive_complex_enum_serde_tag() {
+    #[derive(Serialize)]
+    #[allow(dead_code)]
+    struct Foo(String);
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "tag")]
+        enum Bar {
+            UnitValue,
+            NamedFields {
+                id: &'static str,
+                names: Option<Vec<String>>
+            },
+        }
+    };
+
+    #[cfg(not(feature = "openapi_31"))]
+    let names = json!({
+        "type": "array",
+        "nullable": true,
+        "items": {
+            "type": "string",
+        },
+    });
+    #[cfg(feature = "openapi_31")]
+    let names = json!({
+        "type": ["array", "null"],
+        "items": {
+            "type": "string",
+        },
+    });
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "tag": {
+                            "type": "string",
+                            "enum": [
+                                "UnitValue",
+                            ],
+                        },
+                    },
+                    "required": [
+                        "tag",
+                    ],
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "id": {
+                            "type": "string",
+                        },
+                        "names": names,
+                        "tag": {
+                            "type": "string",
+                            "enum": [
+                                "NamedFields",
+                            ],
+                        },
+                    },
+                    "required": [
+                        "id",
+                        "tag",
+                    ],
+                },
+            ],
+            "discriminator": {
+                "propertyName": "tag"
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_serde_flatten() {
+    #[derive(Serialize)]
+    struct Metadata {
+        category: String,
+        total: u64,
+    }
+
+    #[derive(Serialize)]
+    struct Record {
+        amount: i64,
+        description: String,
+        #[serde(flatten)]
+        metadata: Metadata,
+    }
+
+    #[derive(Serialize)]
+    struct Pagination {
+        page: i64,
+        next_page: i64,
+        per_page: i64,
+    }
+
+    // Single flatten field
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        struct Record {
+            amount: i64,
+            description: String,
+            #[serde(flatten)]
+            metadata: Metadata,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "allOf": [
+            {
+                "$ref": "#/components/schemas/Metadata"
+            },
+            {
+                "type": "object",
+                "properties": {
+                    "amount": {
+                        "type": "integer",
+                        "format": "int64"
+                    },
+                    "description": {
+                        "type": "string",
+                    },
+                },
+                "required": [
+                    "amount",
+                    "description"
+                ],
+            },
+            ]
+        })
+    );
+
+    // Multiple flatten fields, with field that contain flatten as well.
+    // Record contain Metadata that is flatten as well, but it doesn't matter
+    // here as the generated spec will reference to Record directly.
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        struct NamedFields {
+            id: &'static str,
+            #[serde(flatten)]
+            record: Record,
+            #[serde(flatten)]
+            pagination: Pagination
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "allOf": [
+            {
+                "$ref": "#/components/schemas/Record"
+            },
+            {
+                "$ref": "#/components/schemas/Pagination"
+            },
+            {
+                "type": "object",
+                "properties": {
+                    "id": {
+                        "type": "string",
+                    },
+                },
+                "required": [
+                    "id",
+                ],
+            },
+            ]
+        })
+    );
+}
+
+#[test]
+fn derive_serde_flatten_with_inner_rename_all() {
+    #[derive(Serialize, ToSchema)]
+    #[schema(rename_all = "camelCase")]
+    #[allow(unused)]
+    struct Metadata {
+        category_name: String,
+        total_count: u64,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(rename_all = "UPPERCASE")]
+        struct Record {
+            amount: i64,
+            #[serde(flatten)]
+            #[schema(inline)]
+            metadata: Metadata,
+        }
+    };
+
+    #[cfg(feature = "auto_title")]
+    let value = common::without_auto_titles(value, &[("/allOf/0", "Metadata")]);
+    assert_json_eq!(
+        value,
+        json!({
+            "allOf": [
+            {
+                "type": "object",
+                "properties": {
+                    "categoryName": {
+                        "type": "string"
+                    },
+                    "totalCount": {
+                        "type": "integer",
+                        "format": "int64",
+                        "minimum": 0
+                    }
+                },
+                "required": [
+                    "categoryName",
+                    "totalCount"
+                ]
+            },
+            {
+                "type": "object",
+                "properties": {
+                    "AMOUNT": {
+                        "type": "integer",
+                        "format": "int64"
+                    }
+                },
+                "required": [
+                    "AMOUNT"
+                ]
+            }
+            ]
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_serde_untagged() {
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(untagged)]
+        enum Foo {
+            Bar(i32),
+            Baz(String),
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "format": "int32",
+                    "type": "integer",
+                },
+                {
+                    "type": "string",
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_serde_untagged_as_any_of() {
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(untagged)]
+        #[schema(untagged_as = "anyOf")]
+        enum Foo {
+            Bar(i32),
+            Baz(i64),
+            Qux {
+                name: String,
+            },
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "anyOf": [
+                {
+                    "format": "int32",
+                    "type": "integer",
+                },
+                {
+                    "format": "int64",
+                    "type": "integer",
+                },
+                {
+                    "properties": {
+                        "name": {
+                            "type": "string"
+                        }
+                    },
+                    "required": ["name"],
+                    "type": "object"
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_serde_untagged_as_one_of() {
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(untagged)]
+        #[schema(untagged_as = "oneOf")]
+        enum Foo {
+            Bar(i32),
+            Baz(String),
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "format": "int32",
+                    "type": "integer",
+                },
+                {
+                    "type": "string",
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_serde_untagged_with_discriminator() {
+    #[derive(Serialize, ToSchema)]
+    struct Cat {
+        kind: String,
+        lives: u8,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(untagged)]
+        #[schema(discriminator = "kind")]
+        enum Pet {
+            Cat(Cat),
+            #[serde(rename_all = "camelCase")]
+            Dog {
+                #[serde(rename = "kind")]
+                dog_kind: String,
+                good_boy: bool,
+            },
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "$ref": "#/components/schemas/Cat",
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "kind": {
+                            "type": "string",
+                        },
+                        "goodBoy": {
+                            "type": "boolean",
+                        },
+                    },
+                    "required": ["kind", "goodBoy"],
+                },
+            ],
+            "discriminator": {
+                "propertyName": "kind",
+            },
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_with_ref_serde_untagged() {
+    #[derive(Serialize)]
+    struct Foo {
+        name: String,
+        age: u32,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(untagged)]
+        enum Bar {
+            Baz(i32),
+            FooBar(Foo),
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "format": "int32",
+                    "type": "integer",
+                },
+                {
+                    "$ref": "#/components/schemas/Foo",
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_with_ref_serde_untagged_named_fields() {
+    #[derive(Serialize, ToSchema)]
+    struct Bar {
+        name: String,
+        age: u32,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(untagged)]
+        enum Foo {
+            One { n: i32 },
+            Two { bar: Bar },
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "properties": {
+                      "n": {
+                        "format": "int32",
+                        "type": "integer"
+                      }
+                    },
+                    "required": [
+                      "n"
+                    ],
+                    "type": "object"
+                },
+                {
+                    "properties": {
+                      "bar": {
+                        "$ref": "#/components/schemas/Bar"
+                      }
+                    },
+                    "required": [
+                      "bar"
+                    ],
+                    "type": "object"
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_with_ref_serde_untagged_named_fields_rename_all() {
+    #[derive(Serialize, ToSchema)]
+    struct Bar {
+        name: String,
+        age: u32,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(untagged)]
+        enum Foo {
+            #[serde(rename_all = "camelCase")]
+            One { some_number: i32 },
+            #[serde(rename_all = "camelCase")]
+            Two { some_bar: Bar },
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "properties": {
+                      "someNumber": {
+                        "format": "int32",
+                        "type": "integer"
+                      }
+                    },
+                    "required": [
+                      "someNumber"
+                    ],
+                    "type": "object"
+                },
+                {
+                    "properties": {
+                      "someBar": {
+                        "$ref": "#/components/schemas/Bar"
+                      }
+                    },
+                    "required": [
+                      "someBar"
+                    ],
+                    "type": "object"
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_serde_adjacently_tagged() {
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "tag", content = "content")]
+        enum Foo {
+            Bar(i32),
+            Baz(String),
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "tag": {
+                            "type": "string",
+                            "enum": [
+                                "Bar",
+                            ],
+                        },
+                        "content": {
+                            "format": "int32",
+                            "type": "integer",
+                        },
+                    },
+                    "required": [
+                        "tag",
+                        "content"
+                    ],
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "tag": {
+                            "type": "string",
+                            "enum": [
+                                "Baz",
+                            ]
+                        },
+                        "content": {
+                            "type": "string",
+                        },
+                    },
+                    "required": [
+                        "tag",
+                        "content"
+                    ],
+                },
+            ],
+            "discriminator": {
+                "propertyName": "tag",
+            },
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_serde_adjacently_tagged_with_two_unnamed_fields() {
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "tag", content = "content")]
+        enum Foo {
+            Point(i32, i32),
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "tag": {
+                            "type": "string",
+                            "enum": [
+                                "Point",
+                            ],
+                        },
+                        "content": {
+                            "type": "array",
+                            "items": {
+                                "format": "int32",
+                                "type": "integer",
+                            },
+                            "maxItems": 2,
+                            "minItems": 2,
+                        },
+                    },
+                    "required": [
+                        "tag",
+                        "content"
+                    ],
+                },
+            ],
+            "discriminator": {
+                "propertyName": "tag",
+            },
+        })
+    );
+}
+
+#[cfg(feature = "openapi_31")]
+#[test]
+fn derive_complex_enum_serde_adjacently_tagged_with_three_unnamed_fields_prefix_items() {
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "tag", content = "content")]
+        enum Foo {
+            Triple(i32, String, bool),
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "tag": {
+                            "type": "string",
+                            "enum": [
+                                "Triple",
+                            ],
+                        },
+                        "content": {
+                            "type": "array",
+                            "items": {},
+                            "prefixItems": [
+                                {
+                                    "format": "int32",
+                                    "type": "integer",
+                                },
+                                {
+                                    "type": "string",
+                                },
+                                {
+                                    "type": "boolean",
+                                },
+                            ],
+                            "maxItems": 3,
+                            "minItems": 3,
+                        },
+                    },
+                    "required": [
+                        "tag",
+                        "content"
+                    ],
+                },
+            ],
+            "discriminator": {
+                "propertyName": "tag",
+            },
+        })
+    );
+}
+
+#[cfg(not(feature = "openapi_31"))]
+#[test]
+fn derive_complex_enum_serde_adjacently_tagged_with_three_unnamed_fields() {
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "tag", content = "content")]
+        enum Foo {
+            Triple(i32, String, bool),
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "tag": {
+                            "type": "string",
+                            "enum": [
+                                "Triple",
+                            ],
+                        },
+                        "content": {
+                            "type": "array",
+                            "items": {
+                                "type": "object",
+                            },
+                            "maxItems": 3,
+                            "minItems": 3,
+                        },
+                    },
+                    "required": [
+                        "tag",
+                        "content"
+                    ],
+                },
+            ],
+            "discriminator": {
+                "propertyName": "tag",
+            },
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_with_ref_serde_adjacently_tagged() {
+    #[derive(Serialize)]
+    struct Foo {
+        name: String,
+        age: u32,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "tag", content = "content")]
+        enum Bar {
+            Baz(i32),
+            FooBar(Foo),
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "tag": {
+                            "type": "string",
+                            "enum": [
+                                "Baz",
+                            ],
+                        },
+                        "content": {
+                            "type": "integer",
+                            "format": "int32",
+                        },
+                    },
+                    "required": [
+                        "tag",
+                        "content"
+                    ],
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "tag": {
+                            "type": "string",
+                            "enum": [
+                                "FooBar",
+                            ],
+                        },
+                        "content": {
+                            "$ref": "#/components/schemas/Foo"
+                        },
+                    },
+                    "required": [
+                        "tag",
+                        "content"
+                    ],
+                },
+            ],
+            "discriminator": {
+                "propertyName": "tag",
+            },
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_tagged_with_discriminator_mapping() {
+    #[derive(Serialize, ToSchema)]
+    struct Dog {
+        name: String,
+    }
+
+    #[derive(Serialize, ToSchema)]
+    struct Cat {
+        lives: u8,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "pet_type", rename_all = "snake_case")]
+        enum Pet {
+            GoodDog(Box<Dog>),
+            #[serde(rename = "kitty")]
+            Cat(Cat),
+            Other { name: String },
+        }
+    };
+
+    assert_json_eq!(
+        value.pointer("/discriminator").unwrap(),
+        json!({
+            "propertyName": "pet_type",
+            "mapping": {
+                "good_dog": "#/components/schemas/Dog",
+                "kitty": "#/components/schemas/Cat",
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_tagged_discriminator_mapping_with_same_payload() {
+    #[derive(Serialize, ToSchema)]
+    struct Dog {
+        name: String,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "pet_type")]
+        enum Pet {
+            Dog(Dog),
+            Puppy(Dog),
+        }
+    };
+
+    assert_json_eq!(
+        value.pointer("/discriminator").unwrap(),
+        json!({
+            "propertyName": "pet_type",
+            "mapping": {
+                "Dog": "#/components/schemas/Dog",
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_adjacently_tagged_without_discriminator_mapping() {
+    #[derive(Serialize, ToSchema)]
+    struct Dog {
+        name: String,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "pet_type", content = "pet")]
+        enum Pet {
+            Dog(Dog),
+        }
+    };
+
+    assert_json_eq!(
+        value.pointer("/discriminator").unwrap(),
+        json!({
+            "propertyName": "pet_type"
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_unnamed_variant_inline() {
+    #[derive(Serialize, ToSchema)]
+    struct Cat {
+        lives: u8,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        enum Pet {
+            #[schema(inline)]
+            Cat(Cat),
+            Other(Cat),
+        }
+    };
+
+    #[cfg(feature = "auto_title")]
+    let value = common::without_auto_titles(value, &[("/oneOf/0/properties/Cat", "Cat")]);
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "Cat": {
+                            "type": "object",
+                            "properties": {
+                                "lives": {
+                                    "type": "integer",
+                                    "format": "int32",
+                                    "minimum": 0
+                                }
+                            },
+                            "required": ["lives"]
+                        }
+                    },
+                    "required": ["Cat"]
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "Other": {
+                            "$ref": "#/components/schemas/Cat"
+                        }
+                    },
+                    "required": ["Other"]
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_tagged_unnamed_variant_inline() {
+    #[derive(Serialize, ToSchema)]
+    struct Dog {
+        name: String,
+    }
+
+    #[derive(Serialize, ToSchema)]
+    struct Cat {
+        lives: u8,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "pet_type")]
+        enum Pet {
+            Dog(Dog),
+            #[schema(inline)]
+            Cat(Cat),
+        }
+    };
+
+    #[cfg(feature = "auto_title")]
+    let value = common::without_auto_titles(value, &[("/oneOf/1/allOf/0", "Cat")]);
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Dog"
+                        },
+                        {
+                            "type": "object",
+                            "properties": {
+                                "pet_type": {
+                                    "type": "string",
+                                    "enum": ["Dog"]
+                                }
+                            },
+                            "required": ["pet_type"]
+                        }
+                    ]
+                },
+                {
+                    "allOf": [
+                        {
+                            "type": "object",
+                            "properties": {
+                                "lives": {
+                                    "type": "integer",
+                                    "format": "int32",
+                                    "minimum": 0
+                                }
+                            },
+                            "required": ["lives"]
+                        },
+                        {
+                            "type": "object",
+                            "properties": {
+                                "pet_type": {
+                                    "type": "string",
+                                    "enum": ["Cat"]
+                                }
+                            },
+                            "required": ["pet_type"]
+                        }
+                    ]
+                }
+            ],
+            "discriminator": {
+                "propertyName": "pet_type",
+                "mapping": {
+                    "Dog": "#/components/schemas/Dog"
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_adjacently_tagged_unnamed_variant_inline() {
+    #[derive(Serialize, ToSchema)]
+    struct Cat {
+        lives: u8,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "pet_type", content = "pet")]
+        enum Pet {
+            Other(Cat),
+            #[schema(inline)]
+            Cat(Cat),
+        }
+    };
+
+    #[cfg(feature = "auto_title")]
+    let value = common::without_auto_titles(value, &[("/oneOf/1/properties/pet", "Cat")]);
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "pet_type": {
+                            "type": "string",
+                            "enum": ["Other"]
+                        },
+                        "pet": {
+                            "$ref": "#/components/schemas/Cat"
+                        }
+                    },
+                    "required": ["pet_type", "pet"]
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "pet_type": {
+                            "type": "string",
+                            "enum": ["Cat"]
+                        },
+                        "pet": {
+                            "type": "object",
+                            "properties": {
+                                "lives": {
+                                    "type": "integer",
+                                    "format": "int32",
+                                    "minimum": 0
+                                }
+                            },
+                            "required": ["lives"]
+                        }
+                    },
+                    "required": ["pet_type", "pet"]
+                }
+            ],
+            "discriminator": {
+                "propertyName": "pet_type"
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_untagged_unnamed_variant_inline() {
+    #[derive(Serialize, ToSchema)]
+    struct Cat {
+        lives: u8,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(untagged)]
+        enum Pet {
+            Other(Cat),
+            #[schema(inline)]
+            Cat(Cat),
+        }
+    };
+
+    #[cfg(feature = "auto_title")]
+    let value = common::without_auto_titles(value, &[("/oneOf/1", "Cat")]);
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "$ref": "#/components/schemas/Cat"
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "lives": {
+                            "type": "integer",
+                            "format": "int32",
+                            "minimum": 0
+                        }
+                    },
+                    "required": ["lives"]
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_with_ref_serde_adjacently_tagged_named_fields() {
+    #[derive(Serialize, ToSchema)]
+    struct Bar {
+        name: String,
+        age: u32,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "tag", content = "content")]
+        enum Foo {
+            One { n: i32 },
+            Two { bar: Bar },
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "tag": {
+                            "type": "string",
+                            "enum": [
+                                "One",
+                            ],
+                        },
+                        "content": {
+                            "type": "object",
+                            "properties": {
+                                "n": {
+                                    "format": "int32",
+                                    "type": "integer",
+                                },
+                            },
+                            "required": [
+                                "n",
+                            ],
+                        },
+                    },
+                    "required": [
+                      "tag",
+                      "content"
+                    ],
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "tag": {
+                            "type": "string",
+                            "enum": [
+                                "Two",
+                            ],
+                        },
+                        "content": {
+                            "type": "object",
+                            "properties": {
+                                "bar": {
+                                    "$ref": "#/components/schemas/Bar",
+                                },
+                            },
+                            "required": [
+                                "bar",
+                            ],
+                        },
+                    },
+                    "required": [
+                      "tag",
+                      "content",
+                    ],
+                },
+            ],
+            "discriminator": {
+                "propertyName": "tag",
+            },
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_with_ref_serde_adjacently_tagged_named_fields_rename_all() {
+    #[derive(Serialize, ToSchema)]
+    struct Bar {
+        name: String,
+        age: u32,
+    }
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "tag", content = "content")]
+        enum Foo {
+            #[serde(rename_all = "camelCase")]
+            One { some_number: i32 },
+            #[serde(rename_all = "camelCase")]
+            Two { some_bar: Bar },
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "tag": {
+                            "type": "string",
+                            "enum": [
+                                "One",
+                            ],
+                        },
+                        "content": {
+                            "type": "object",
+                            "properties": {
+                                "someNumber": {
+                                    "format": "int32",
+                                    "type": "integer",
+                                },
+                            },
+                            "required": [
+                                "someNumber",
+                            ],
+                        },
+                    },
+                    "required": [
+                      "tag",
+                      "content"
+                    ],
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "tag": {
+                            "type": "string",
+                            "enum": [
+                                "Two",
+                            ],
+                        },
+                        "content": {
+                            "type": "object",
+                            "properties": {
+                                "someBar": {
+                                    "$ref": "#/components/schemas/Bar",
+                                },
+                            },
+                            "required": [
+                                "someBar",
+                            ],
+                        },
+                    },
+                    "required": [
+                      "tag",
+                      "content",
+                    ],
+                },
+            ],
+            "discriminator": {
+                "propertyName": "tag",
+            },
+        })
+    );
+}
+
+#[test]
+fn derive_complex_enum_serde_tag_title() {
+    #[derive(Serialize)]
+    #[allow(dead_code)]
+    struct Foo(String);
+
+    let value: Value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(tag = "tag")]
+        enum Bar {
+            #[schema(title = "Unit")]
+            UnitValue,
+            #[schema(title = "Named")]
+            NamedFields {
+                id: &'static str,
+            },
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "title": "Unit",
+                    "properties": {
+                        "tag": {
+                            "type": "string",
+                            "enum": [
+                                "UnitValue",
+                            ],
+                        },
+                    },
+                    "required": [
+                        "tag",
+                    ],
+                },
+                {
+                    "type": "object",
+                    "title": "Named",
+                    "properties": {
+                        "id": {
+                            "type": "string",
+                        },
+                        "tag": {
+                            "type": "string",
+                            "enum": [
+                                "NamedFields",
+                            ],
+                        },
+                    },
+                    "required": [
+                        "id",
+                        "tag",
+                    ],
+                },
+            ],
+            "discriminator": {
+                "propertyName": "tag"
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_struct_with_read_only_and_write_only() {
+    let user = api_doc! {
+        struct User {
+            #[schema(read_only)]
+            username: String,
+            #[schema(write_only)]
+            password: String
+        }
+    };
+
+    assert_value! {user=>
+        "properties.password.type" = r###""string""###, "User password type"
+        "properties.password.writeOnly" = r###"true"###, "User password write only"
+        "properties.password.readOnly" = r###"null"###, "User password read only"
+        "properties.username.type" = r###""string""###, "User username type"
+        "properties.username.readOnly" = r###"true"###, "User username read only"
+        "properties.username.writeOnly" = r###"null"###, "User username write only"
+    }
+}
+
+#[test]
+fn derive_struct_with_container_read_only_and_write_only() {
+    let user = api_doc! {
+        #[schema(read_only)]
+        struct User {
+            id: i32,
+            username: String,
+            #[schema(read_only = false)]
+            nickname: String,
+            #[schema(write_only)]
+            password: String
+        }
+    };
+    let credentials = api_doc! {
+        #[schema(write_only)]
+        struct Credentials {
+            username: String,
+            password: String
+        }
+    };
+
+    assert_json_eq!(
+        user,
+        json!({
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "format": "int32",
+                    "readOnly": true
+                },
+                "username": {
+                    "type": "string",
+                    "readOnly": true
+                },
+                "nickname": {
+                    "type": "string",
+                    "readOnly": false
+                },
+                "password": {
+                    "type": "string",
+                    "writeOnly": true
+                }
+            },
+            "required": ["id", "username", "nickname", "password"],
+            "type": "object"
+        })
+    );
+    assert_value! {credentials=>
+        "properties.username.writeOnly" = r###"true"###, "Credentials username write only"
+        "properties.password.writeOnly" = r###"true"###, "Credentials password write only"
+        "readOnly" = r###"null"###, "Credentials read only"
+        "writeOnly" = r###"null"###, "Credentials write only"
+    }
+}
+
+#[test]
+fn derive_struct_with_container_read_only_ref_field() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Profile {
+        name: String,
+    }
+
+    let user = api_doc! {
+        #[schema(read_only)]
+        struct User {
+            profile: Profile,
+            #[schema(write_only)]
+            draft: Profile,
+        }
+    };
+
+    assert_json_eq!(
+        user,
+        json!({
+            "properties": {
+                "profile": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Profile"
+                        }
+                    ],
+                    "readOnly": true
+                },
+                "draft": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Profile"
+                        }
+                    ],
+                    "writeOnly": true
+                }
+            },
+            "required": ["profile", "draft"],
+            "type": "object"
+        })
+    );
+}
+
+#[test]
+fn derive_struct_with_nullable_and_required() {
+    let user = api_doc! {
+        #[derive(Serialize)]
+        struct User {
+            #[schema(nullable)]
+            #[serde(with = "::serde_with::rust::double_option")]
+            fax: Option<Option<String>>,
+            #[schema(nullable)]
+            phone: Option<Option<String>>,
+            #[schema(nullable = false)]
+            email: String,
+            name: String,
+            #[schema(nullable)]
+            edit_history: Vec<String>,
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            friends: Vec<Option<String>>,
+            #[schema(required)]
+            updated: Option<String>,
+        }
+    };
+
+    #[cfg(not(feature = "openapi_31"))]
+    let nullable_string = json!({
+        "type": "string",
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let nullable_string = json!({
+        "type": ["string", "null"],
+    });
+
+    #[cfg(not(feature = "openapi_31"))]
+    let edit_history = json!({
+        "type": "array",
+        "items": {
+            "type": "string"
+        },
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let edit_history = json!({
+        "type": ["array", "null"],
+        "items": {
+            "type": "string"
+        },
+    });
+
+    assert_json_eq!(
+        user,
+        json!({
+            "properties": {
+                "fax": nullable_string,
+                "phone": nullable_string,
+                "email": {
+                    "type": "string",
+                },
+                "name": {
+                    "type": "string",
+                },
+                "edit_history": edit_history,
+                "friends": {
+                    "type": "array",
+                    "items": nullable_string,
+                },
+                "updated": nullable_string
+            },
+            "required": [
+                "email",
+                "name",
+                "edit_history",
+                "updated",
+            ],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_required_override() {
+    let user = api_doc! {
+        struct User {
+            #[schema(required)]
+            nickname: Option<String>,
+            #[schema(required = true)]
+            email: Option<String>,
+            #[schema(required = false)]
+            name: String,
+            #[schema(required = false)]
+            phone: Option<String>,
+            id: i64,
+        }
+    };
+
+    assert_value! {user=>
+        "required" = r#"["nickname","email","id"]"#, "User required fields"
+    };
+}
+
+#[test]
+fn derive_struct_with_option_value_type_override() {
+    #[allow(unused)]
+    enum MaybeUndefined<T> {
+        Undefined,
+        Null,
+        Value(T),
+    }
+
+    let user = api_doc! {
+        struct User {
+            #[schema(value_type = Option<String>)]
+            nickname: MaybeUndefined<String>,
+            #[schema(value_type = String)]
+            name: Option<String>,
+        }
+    };
+
+    #[cfg(not(feature = "openapi_31"))]
+    let nickname = json!({
+        "type": "string",
+        "nullable": true
+    });
+    #[cfg(feature = "openapi_31")]
+    let nickname = json!({
+        "type": ["string", "null"]
+    });
+
+    assert_json_eq!(
+        user,
+        json!({
+            "type": "object",
+            "properties": {
+                "nickname": nickname,
+                "name": {
+                    "type": "string"
+                }
+            },
+            "required": ["name"]
+        })
+    );
+}
+
+#[test]
+fn derive_struct_with_value_type_and_validation_features() {
+    #[allow(unused)]
+    struct Id(u64);
+    #[allow(unused)]
+    struct Name(String);
+
+    let user = api_doc! {
+        struct User {
+            #[schema(value_type = i64, minimum = 1, maximum = 100, multiple_of = 2)]
+            id: Id,
+            #[schema(value_type = String, min_length = 1, max_length = 10, pattern = "^[a-z]+$")]
+            name: Name,
+            #[schema(value_type = Vec<String>, min_items = 1, max_items = 3)]
+            aliases: Vec<Name>,
+        }
+    };
+
+    assert_json_eq!(
+        user,
+        json!({
+            "type": "object",
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "format": "int64",
+                    "minimum": 1,
+                    "maximum": 100,
+                    "multipleOf": 2
+                },
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 10,
+                    "pattern": "^[a-z]+$"
+                },
+                "aliases": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    },
+                    "minItems": 1,
+                    "maxItems": 3
+                }
+            },
+            "required": ["id", "name", "aliases"]
+        })
+    );
+}
+
+#[test]
+fn derive_struct_with_rename_to_name_of_skipped_field() {
+    let value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct User {
+            #[serde(skip)]
+            user_id: i32,
+            #[serde(rename = "userId")]
+            id: i32,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "object",
+            "properties": {
+                "userId": {
+                    "type": "integer",
+                    "format": "int32"
+                }
+            },
+            "required": ["userId"]
+        })
+    );
+}
+
+#[test]
+fn derive_struct_with_skip_serializing_if_non_required() {
+    fn is_zero(value: &i32) -> bool {
+        *value == 0
+    }
+
+    let value = api_doc! {
+        #[derive(Serialize)]
+        struct Item {
+            id: u64,
+            #[serde(skip_serializing_if = "String::is_empty")]
+            name: String,
+            #[serde(skip_serializing_if = "is_zero")]
+            count: i32,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "format": "int64",
+                    "minimum": 0,
+                },
+                "name": {
                     "type": "string",
                 },
-                "edit_history": {
+                "count": {
+                    "type": "integer",
+                    "format": "int32",
+                }
+            },
+            "required": [
+                "id"
+            ],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_serde_split_rename() {
+    // differing serialize and deserialize names emit a deprecation warning
+    #[allow(deprecated)]
+    let value = api_doc! {
+        #[derive(Serialize, serde::Deserialize)]
+        struct Item {
+            #[serde(rename(serialize = "itemId", deserialize = "item_id"))]
+            id: u64,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "itemId": {
+                    "type": "integer",
+                    "format": "int64",
+                    "minimum": 0,
+                }
+            },
+            "required": [
+                "itemId"
+            ],
+            "type": "object"
+        })
+    );
+
+    // choosing the name with `rename_context` does not emit a warning
+    #[deny(deprecated)]
+    let value = api_doc! {
+        #[derive(Serialize, serde::Deserialize)]
+        #[schema(rename_context = "deserialize")]
+        struct Item {
+            #[serde(rename(serialize = "itemId", deserialize = "item_id"))]
+            id: u64,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "item_id": {
+                    "type": "integer",
+                    "format": "int64",
+                    "minimum": 0,
+                }
+            },
+            "required": [
+                "item_id"
+            ],
+            "type": "object"
+        })
+    );
+}
+
+#[test]
+fn derive_enum_with_serde_split_rename() {
+    #[allow(deprecated)]
+    let value = api_doc! {
+        #[derive(Serialize, serde::Deserialize)]
+        enum Status {
+            #[serde(rename(serialize = "ACTIVE", deserialize = "active"))]
+            Active,
+            Inactive,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "enum": ["ACTIVE", "Inactive"],
+            "type": "string"
+        })
+    );
+    #[deny(deprecated)]
+    let value = api_doc! {
+        #[derive(Serialize, serde::Deserialize)]
+        #[schema(rename_context = "deserialize")]
+        enum Status {
+            #[serde(rename(serialize = "ACTIVE", deserialize = "active"))]
+            Active,
+            Inactive,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "enum": ["active", "Inactive"],
+            "type": "string"
+        })
+    );
+}
+
+#[test]
+fn derive_struct_and_enum_with_serde_split_rename_all() {
+    #[allow(deprecated)]
+    let value = api_doc! {
+        #[derive(Serialize, serde::Deserialize)]
+        #[serde(rename_all(serialize = "camelCase", deserialize = "kebab-case"))]
+        struct Item {
+            item_id: u64,
+        }
+    };
+
+    assert_value! {value=>
+        "required" = r#"["itemId"]"#, "Item serialize rename_all"
+    }
+    #[deny(deprecated)]
+    let value = api_doc! {
+        #[derive(Serialize, serde::Deserialize)]
+        #[serde(rename_all(serialize = "camelCase", deserialize = "kebab-case"))]
+        #[schema(rename_context = "deserialize")]
+        struct Item {
+            item_id: u64,
+        }
+    };
+
+    assert_value! {value=>
+        "required" = r#"["item-id"]"#, "Item deserialize rename_all"
+    }
+    #[allow(deprecated)]
+    let value = api_doc! {
+        #[derive(Serialize, serde::Deserialize)]
+        #[serde(rename_all(serialize = "SCREAMING_SNAKE_CASE", deserialize = "snake_case"))]
+        enum Status {
+            NotActive,
+        }
+    };
+
+    assert_value! {value=>
+        "enum" = r#"["NOT_ACTIVE"]"#, "Status serialize rename_all"
+    }
+    #[deny(deprecated)]
+    let value = api_doc! {
+        #[derive(Serialize, serde::Deserialize)]
+        #[serde(rename_all(serialize = "SCREAMING_SNAKE_CASE", deserialize = "snake_case"))]
+        #[schema(rename_context = "deserialize")]
+        enum Status {
+            NotActive,
+        }
+    };
+
+    assert_value! {value=>
+        "enum" = r#"["not_active"]"#, "Status deserialize rename_all"
+    }
+}
+
+#[cfg(feature = "openapi_31")]
+#[test]
+fn derive_struct_with_nullable_type_array() {
+    let value = api_doc! {
+        struct User {
+            name: Option<String>,
+            #[schema(nullable)]
+            email: String,
+            age: Option<Option<i32>>,
+            tags: Option<Vec<String>>,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "name": {
+                    "type": ["string", "null"]
+                },
+                "email": {
+                    "type": ["string", "null"]
+                },
+                "age": {
+                    "type": ["integer", "null"],
+                    "format": "int32"
+                },
+                "tags": {
+                    "type": ["array", "null"],
+                    "items": {
+                        "type": "string"
+                    }
+                }
+            },
+            "type": "object",
+            "required": [
+                "email"
+            ]
+        })
+    );
+}
+
+#[cfg(not(feature = "openapi_31"))]
+#[test]
+fn derive_struct_with_option_vec_of_options() {
+    let value = api_doc! {
+        struct User {
+            nicknames: Option<Vec<Option<String>>>,
+            scores: Vec<Option<i32>>,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "nicknames": {
+                    "type": "array",
+                    "items": {
+                        "type": "string",
+                        "nullable": true
+                    },
+                    "nullable": true
+                },
+                "scores": {
+                    "type": "array",
+                    "items": {
+                        "type": "integer",
+                        "format": "int32",
+                        "nullable": true
+                    }
+                }
+            },
+            "type": "object",
+            "required": [
+                "scores"
+            ]
+        })
+    );
+}
+
+#[cfg(feature = "openapi_31")]
+#[test]
+fn derive_struct_with_option_vec_of_options_type_array() {
+    let value = api_doc! {
+        struct User {
+            nicknames: Option<Vec<Option<String>>>,
+            scores: Vec<Option<i32>>,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "nicknames": {
+                    "type": ["array", "null"],
+                    "items": {
+                        "type": ["string", "null"]
+                    }
+                },
+                "scores": {
+                    "type": "array",
+                    "items": {
+                        "type": ["integer", "null"],
+                        "format": "int32"
+                    }
+                }
+            },
+            "type": "object",
+            "required": [
+                "scores"
+            ]
+        })
+    );
+}
+
+#[test]
+fn derive_struct_xml() {
+    let user = api_doc! {
+        #[schema(xml(name = "user", prefix = "u", namespace = "https://mynamespace.test"))]
+        struct User {
+            #[schema(xml(attribute, prefix = "u"))]
+            id: i64,
+            #[schema(xml(name = "user_name", prefix = "u"))]
+            username: String,
+            #[schema(xml(wrapped(name = "linkList"), name = "link"))]
+            links: Vec<String>,
+            #[schema(xml(wrapped, name = "photo_url"))]
+            photos_urls: Vec<String>
+        }
+    };
+
+    assert_value! {user=>
+        "xml.attribute" = r###"null"###, "User xml attribute"
+        "xml.name" = r###""user""###, "User xml name"
+        "xml.prefix" = r###""u""###, "User xml prefix"
+        "xml.namespace" = r###""https://mynamespace.test""###, "User xml namespace"
+        "properties.id.xml.attribute" = r###"true"###, "User id xml attribute"
+        "properties.id.xml.name" = r###"null"###, "User id xml name"
+        "properties.id.xml.prefix" = r###""u""###, "User id xml prefix"
+        "properties.id.xml.namespace" = r###"null"###, "User id xml namespace"
+        "properties.username.xml.attribute" = r###"null"###, "User username xml attribute"
+        "properties.username.xml.name" = r###""user_name""###, "User username xml name"
+        "properties.username.xml.prefix" = r###""u""###, "User username xml prefix"
+        "properties.username.xml.namespace" = r###"null"###, "User username xml namespace"
+        "properties.links.xml.attribute" = r###"null"###, "User links xml attribute"
+        "properties.links.xml.name" = r###""linkList""###, "User links xml name"
+        "properties.links.xml.prefix" = r###"null"###, "User links xml prefix"
+        "properties.links.xml.namespace" = r###"null"###, "User links xml namespace"
+        "properties.links.xml.wrapped" = r###"true"###, "User links xml wrapped"
+        "properties.links.items.xml.attribute" = r###"null"###, "User links xml items attribute"
+        "properties.links.items.xml.name" = r###""link""###, "User links xml items name"
+        "properties.links.items.xml.prefix" = r###"null"###, "User links xml items prefix"
+        "properties.links.items.xml.namespace" = r###"null"###, "User links xml items namespace"
+        "properties.links.items.xml.wrapped" = r###"null"###, "User links xml items wrapped"
+        "properties.photos_urls.xml.attribute" = r###"null"###, "User photos_urls xml attribute"
+        "properties.photos_urls.xml.name" = r###"null"###, "User photos_urls xml name"
+        "properties.photos_urls.xml.prefix" = r###"null"###, "User photos_urls xml prefix"
+        "properties.photos_urls.xml.namespace" = r###"null"###, "User photos_urls xml namespace"
+        "properties.photos_urls.xml.wrapped" = r###"true"###, "User photos_urls xml wrapped"
+        "properties.photos_urls.items.xml.attribute" = r###"null"###, "User photos_urls xml items attribute"
+        "properties.photos_urls.items.xml.name" = r###""photo_url""###, "User photos_urls xml items name"
+        "properties.photos_urls.items.xml.prefix" = r###"null"###, "User photos_urls xml items prefix"
+        "properties.photos_urls.items.xml.namespace" = r###"null"###, "User photos_urls xml items namespace"
+        "properties.photos_urls.items.xml.wrapped" = r###"null"###, "User photos_urls links xml items wrapped"
+    }
+}
+
+#[test]
+fn derive_struct_xml_with_optional_vec() {
+    let user = api_doc! {
+        #[schema(xml(name = "user"))]
+        struct User {
+            #[schema(xml(attribute, prefix = "u"))]
+            id: i64,
+            #[schema(xml(wrapped(name = "linkList"), name = "link"))]
+            links: Option<Vec<String>>,
+        }
+    };
+
+    #[cfg(not(feature = "openapi_31"))]
+    let links = json!({
+        "type": "array",
+        "items": {
+            "type": "string",
+            "xml": {
+                "name": "link"
+            }
+        },
+        "nullable": true,
+        "xml": {
+            "name": "linkList",
+            "wrapped": true,
+        }
+    });
+    #[cfg(feature = "openapi_31")]
+    let links = json!({
+        "type": ["array", "null"],
+        "items": {
+            "type": "string",
+            "xml": {
+                "name": "link"
+            }
+        },
+        "xml": {
+            "name": "linkList",
+            "wrapped": true,
+        }
+    });
+
+    assert_json_eq!(
+        user,
+        json!({
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "format": "int64",
+                    "xml": {
+                        "attribute": true,
+                        "prefix": "u"
+                    }
+                },
+                "links": links
+            },
+            "required": ["id"],
+            "type": "object",
+            "xml": {
+                "name": "user"
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_struct_xml_with_wrapped_collections() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Link {
+        href: String,
+    }
+
+    let user = api_doc! {
+        struct User {
+            #[schema(xml(name = "link", wrapped(name = "links")))]
+            links: Vec<Link>,
+            #[schema(xml(wrapped(name = "relatedLinks")))]
+            related_links: Vec<Link>,
+            #[schema(xml(name = "tag", wrapped(name = "tags")))]
+            tags: std::collections::BTreeSet<String>,
+            #[schema(xml(name = "homepage"))]
+            homepage: Link,
+        }
+    };
+
+    assert_json_eq!(
+        user,
+        json!({
+            "properties": {
+                "links": {
+                    "type": "array",
+                    "items": {
+                        "allOf": [{"$ref": "#/components/schemas/Link"}],
+                        "xml": {
+                            "name": "link"
+                        }
+                    },
+                    "xml": {
+                        "name": "links",
+                        "wrapped": true
+                    }
+                },
+                "related_links": {
+                    "type": "array",
+                    "items": {"$ref": "#/components/schemas/Link"},
+                    "xml": {
+                        "name": "relatedLinks",
+                        "wrapped": true
+                    }
+                },
+                "tags": {
+                    "type": "array",
+                    "items": {
+                        "type": "string",
+                        "xml": {
+                            "name": "tag"
+                        }
+                    },
+                    "uniqueItems": true,
+                    "xml": {
+                        "name": "tags",
+                        "wrapped": true
+                    }
+                },
+                "homepage": {
+                    "allOf": [{"$ref": "#/components/schemas/Link"}],
+                    "xml": {
+                        "name": "homepage"
+                    }
+                }
+            },
+            "required": ["links", "related_links", "tags", "homepage"],
+            "type": "object"
+        })
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn derive_component_with_chrono_feature() {
+    #![allow(deprecated)] // allow deprecated Date in tests as long as it is available from chrono
+    use chrono::{Date, DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+    let post = api_doc! {
+        struct Post {
+            id: i32,
+            value: String,
+            datetime: DateTime<Utc>,
+            naive_datetime: NaiveDateTime,
+            date: Date<Utc>,
+            naive_date: NaiveDate,
+            naive_time: NaiveTime,
+            duration: Duration,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.datetime.type" = r#""string""#, "Post datetime type"
+        "properties.datetime.format" = r#""date-time""#, "Post datetime format"
+        "properties.naive_datetime.type" = r#""string""#, "Post datetime type"
+        "properties.naive_datetime.format" = r#""date-time""#, "Post datetime format"
+        "properties.date.type" = r#""string""#, "Post date type"
+        "properties.date.format" = r#""date""#, "Post date format"
+        "properties.naive_date.type" = r#""string""#, "Post date type"
+        "properties.naive_date.format" = r#""date""#, "Post date format"
+        "properties.naive_time.type" = r#""string""#, "Post time type"
+        "properties.naive_time.format" = r#"null"#, "Post time format"
+        "properties.duration.type" = r#""string""#, "Post duration type"
+        "properties.duration.format" = r#"null"#, "Post duration format"
+        "properties.id.type" = r#""integer""#, "Post id type"
+        "properties.id.format" = r#""int32""#, "Post id format"
+        "properties.value.type" = r#""string""#, "Post value type"
+        "properties.value.format" = r#"null"#, "Post value format"
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn derive_component_with_chrono_feature_wrapped_types() {
+    let post = api_doc! {
+        struct Post {
+            created: chrono::DateTime<chrono::FixedOffset>,
+            updated: Option<chrono::DateTime<chrono::Utc>>,
+            dates: Vec<chrono::NaiveDate>,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.created.type" = r#""string""#, "Post created type"
+        "properties.created.format" = r#""date-time""#, "Post created format"
+        "properties.updated.format" = r#""date-time""#, "Post updated format"
+        "properties.dates.items.type" = r#""string""#, "Post dates items type"
+        "properties.dates.items.format" = r#""date""#, "Post dates items format"
+        "required" = r#"["created","dates"]"#, "Post required"
+    }
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn derive_component_with_time_feature() {
+    use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime};
+
+    let times = api_doc! {
+        struct Timetest {
+            datetime: OffsetDateTime,
+            primitive_date_time: PrimitiveDateTime,
+            date: Date,
+            duration: Duration,
+        }
+    };
+
+    assert_json_eq!(
+        &times,
+        json!({
+            "properties": {
+                "date": {
+                    "format": "date",
+                    "type": "string"
+                },
+                "datetime": {
+                    "format": "date-time",
+                    "type": "string"
+                },
+                "primitive_date_time": {
+                    "format": "date-time",
+                    "type": "string"
+                },
+                "duration": {
+                    "type": "string"
+                }
+            },
+            "required": [
+                "datetime",
+                "primitive_date_time",
+                "date",
+                "duration"
+            ],
+            "type": "object"
+        })
+    )
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn derive_component_with_time_feature_wrapped_types() {
+    let times = api_doc! {
+        struct Timetest {
+            updated: Option<time::OffsetDateTime>,
+            dates: Vec<time::Date>,
+        }
+    };
+
+    assert_value! {times=>
+        "properties.updated.format" = r#""date-time""#, "Timetest updated format"
+        "properties.dates.items.type" = r#""string""#, "Timetest dates items type"
+        "properties.dates.items.format" = r#""date""#, "Timetest dates items format"
+        "required" = r#"["dates"]"#, "Timetest required"
+    }
+}
+
+#[test]
+fn derive_struct_component_field_type_override() {
+    let post = api_doc! {
+        struct Post {
+            id: i32,
+            #[schema(value_type = String)]
+            value: i64,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.id.type" = r#""integer""#, "Post id type"
+        "properties.id.format" = r#""int32""#, "Post id format"
+        "properties.value.type" = r#""string""#, "Post value type"
+        "properties.value.format" = r#"null"#, "Post value format"
+    }
+}
+
+#[test]
+fn derive_struct_component_field_type_path_override() {
+    let post = api_doc! {
+        struct Post {
+            id: i32,
+            #[schema(value_type = path::to::Foo)]
+            value: i64,
+        }
+    };
+
+    let component_ref: &str = post
+        .pointer("/properties/value/$ref")
+        .unwrap()
+        .as_str()
+        .unwrap();
+    assert_eq!(component_ref, "#/components/schemas/path.to.Foo");
+}
+
+#[test]
+fn derive_struct_component_field_type_override_with_nested_generics() {
+    let value = api_doc! {
+        struct Post {
+            #[schema(value_type = Vec<HashMap<String, i32>>)]
+            counts: String,
+            #[schema(value_type = Option<Vec<String>>)]
+            tags: String,
+        }
+    };
+
+    #[cfg(not(feature = "openapi_31"))]
+    let tags = json!({
+        "type": "array",
+        "items": {
+            "type": "string"
+        },
+        "nullable": true
+    });
+    #[cfg(feature = "openapi_31")]
+    let tags = json!({
+        "type": ["array", "null"],
+        "items": {
+            "type": "string"
+        },
+    });
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "counts": {
                     "type": "array",
                     "items": {
-                        "type": "string"
-                    },
-                    "nullable": true,
+                        "type": "object",
+                        "additionalProperties": {
+                            "type": "integer",
+                            "format": "int32"
+                        }
+                    }
                 },
-                "friends": {
-                    "type": "array",
-                    "items": {
-                        "type": "string",
-                        "nullable": true,
-                    },
+                "tags": tags
+            },
+            "type": "object",
+            "required": [
+                "counts"
+            ]
+        })
+    );
+}
+
+#[test]
+fn derive_struct_component_field_type_override_with_format() {
+    let post = api_doc! {
+        struct Post {
+            id: i32,
+            #[schema(value_type = String, format = Byte)]
+            value: i64,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.id.type" = r#""integer""#, "Post id type"
+        "properties.id.format" = r#""int32""#, "Post id format"
+        "properties.value.type" = r#""string""#, "Post value type"
+        "properties.value.format" = r#""byte""#, "Post value format"
+    }
+}
+
+#[test]
+fn derive_struct_component_field_type_override_with_custom_format() {
+    let post = api_doc! {
+        struct Post {
+            #[schema(value_type = String, format = "uri")]
+            value: String,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.value.type" = r#""string""#, "Post value type"
+        "properties.value.format" = r#""uri""#, "Post value format"
+    }
+}
+
+#[test]
+fn derive_struct_with_known_and_custom_format() {
+    let post = api_doc! {
+        struct Post {
+            #[schema(format = Password)]
+            secret: String,
+            #[schema(format = "ipv4")]
+            address: String,
+            #[schema(format = "x-custom")]
+            tags: Vec<String>,
+            #[schema(format = "x-counter")]
+            count: Option<u64>,
+            #[schema(value_type = Object, format = "x-payload")]
+            payload: String,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.secret.format" = r#""password""#, "Post secret format"
+        "properties.address.format" = r#""ipv4""#, "Post address format"
+        "properties.tags.items.format" = r#""x-custom""#, "Post tags items format"
+        "properties.count.format" = r#""x-counter""#, "Post count format"
+        "properties.payload.type" = r#""object""#, "Post payload type"
+    }
+}
+
+#[test]
+fn derive_struct_component_field_type_override_with_format_with_vec() {
+    let post = api_doc! {
+        struct Post {
+            id: i32,
+            #[schema(value_type = String, format = Binary)]
+            value: Vec<u8>,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.id.type" = r#""integer""#, "Post id type"
+        "properties.id.format" = r#""int32""#, "Post id format"
+        "properties.value.type" = r#""string""#, "Post value type"
+        "properties.value.format" = r#""binary""#, "Post value format"
+    }
+}
+
+#[test]
+fn derive_struct_with_byte_vec_as_binary_string() {
+    let value = api_doc! {
+        struct Post<'a> {
+            value: Vec<u8>,
+            slice: &'a [u8],
+            #[schema(format = Byte)]
+            encoded: Vec<u8>,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "value": {
+                    "type": "string",
+                    "format": "binary"
                 },
-                "updated": {
+                "slice": {
                     "type": "string",
-                    "nullable": true,
+                    "format": "binary"
+                },
+                "encoded": {
+                    "type": "string",
+                    "format": "byte"
+                }
+            },
+            "type": "object",
+            "required": [
+                "value",
+                "slice",
+                "encoded"
+            ]
+        })
+    );
+}
+
+#[test]
+fn derive_unnamed_struct_schema_type_override() {
+    let value = api_doc! {
+        #[schema(value_type = String)]
+        struct Value(i64);
+    };
+
+    assert_value! {value=>
+        "type" = r#""string""#, "Value type"
+        "format" = r#"null"#, "Value format"
+    }
+}
+
+#[test]
+fn derive_unnamed_struct_schema_type_override_with_format() {
+    let value = api_doc! {
+        #[schema(value_type = String, format = Byte)]
+        struct Value(i64);
+    };
+
+    assert_value! {value=>
+        "type" = r#""string""#, "Value type"
+        "format" = r#""byte""#, "Value format"
+    }
+}
+
+#[test]
+fn derive_unnamed_struct_with_multiple_of() {
+    let value = api_doc! {
+        #[schema(multiple_of = 5)]
+        struct Quantity(u32);
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "integer",
+            "format": "int32",
+            "minimum": 0,
+            "multipleOf": 5,
+        })
+    );
+}
+
+#[test]
+fn derive_unnamed_struct_with_length_bounds() {
+    let value = api_doc! {
+        #[schema(min_length = 1, max_length = 64)]
+        struct Name(String);
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "string",
+            "minLength": 1,
+            "maxLength": 64,
+        })
+    );
+}
+
+#[test]
+fn derive_struct_override_type_with_object_type() {
+    let value = api_doc! {
+        struct Value {
+            #[schema(value_type = Object)]
+            field: String,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "object",
+            "properties": {
+                "field": {
+                    "type": "object"
+                }
+            },
+            "required": ["field"]
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_free_form_values() {
+    let value = api_doc! {
+        struct Value {
+            json: serde_json::Value,
+            #[schema(value_type = Any)]
+            any: String,
+            #[schema(value_type = Object, additional_properties)]
+            object: HashMap<String, String>,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "object",
+            "properties": {
+                "json": {},
+                "any": {},
+                "object": {
+                    "type": "object",
+                    "additionalProperties": true
+                }
+            },
+            "required": ["json", "any", "object"]
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_serde_json_types() {
+    use serde_json::{Map, Number};
+
+    let value = api_doc! {
+        struct Document {
+            value: Value,
+            map: Map<String, Value>,
+            number: Number,
+            numbers: Vec<serde_json::Number>,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "object",
+            "properties": {
+                "value": {},
+                "map": {
+                    "type": "object",
+                    "additionalProperties": {}
+                },
+                "number": {
+                    "type": "number"
+                },
+                "numbers": {
+                    "type": "array",
+                    "items": {
+                        "type": "number"
+                    }
+                }
+            },
+            "required": ["value", "map", "number", "numbers"]
+        })
+    )
+}
+
+#[test]
+fn derive_struct_override_type_with_a_reference() {
+    mod custom {
+        #[allow(dead_code)]
+        struct NewBar;
+    }
+
+    let value = api_doc! {
+        struct Value {
+            #[schema(value_type = NewBar)]
+            field: String,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "object",
+            "properties": {
+                "field": {
+                    "$ref": "#/components/schemas/NewBar"
                 }
             },
-            "required": [
-                "email",
-                "name",
-                "edit_history",
-                "updated",
-            ],
-            "type": "object"
+            "required": ["field"]
         })
     )
 }
 
 #[test]
-fn derive_struct_xml() {
-    let user = api_doc! {
-        #[schema(xml(name = "user", prefix = "u", namespace = "https://mynamespace.test"))]
-        struct User {
-            #[schema(xml(attribute, prefix = "u"))]
-            id: i64,
-            #[schema(xml(name = "user_name", prefix = "u"))]
-            username: String,
-            #[schema(xml(wrapped(name = "linkList"), name = "link"))]
-            links: Vec<String>,
-            #[schema(xml(wrapped, name = "photo_url"))]
-            photos_urls: Vec<String>
-        }
-    };
-
-    assert_value! {user=>
-        "xml.attribute" = r###"null"###, "User xml attribute"
-        "xml.name" = r###""user""###, "User xml name"
-        "xml.prefix" = r###""u""###, "User xml prefix"
-        "xml.namespace" = r###""https://mynamespace.test""###, "User xml namespace"
-        "properties.id.xml.attribute" = r###"true"###, "User id xml attribute"
-        "properties.id.xml.name" = r###"null"###, "User id xml name"
-        "properties.id.xml.prefix" = r###""u""###, "User id xml prefix"
-        "properties.id.xml.namespace" = r###"null"###, "User id xml namespace"
-        "properties.username.xml.attribute" = r###"null"###, "User username xml attribute"
-        "properties.username.xml.name" = r###""user_name""###, "User username xml name"
-        "properties.username.xml.prefix" = r###""u""###, "User username xml prefix"
-        "properties.username.xml.namespace" = r###"null"###, "User username xml namespace"
-        "properties.links.xml.attribute" = r###"null"###, "User links xml attribute"
-        "properties.links.xml.name" = r###""linkList""###, "User links xml name"
-        "properties.links.xml.prefix" = r###"null"###, "User links xml prefix"
-        "properties.links.xml.namespace" = r###"null"###, "User links xml namespace"
-        "properties.links.xml.wrapped" = r###"true"###, "User links xml wrapped"
-        "properties.links.items.xml.attribute" = r###"null"###, "User links xml items attribute"
-        "properties.links.items.xml.name" = r###""link""###, "User links xml items name"
-        "properties.links.items.xml.prefix" = r###"null"###, "User links xml items prefix"
-        "properties.links.items.xml.namespace" = r###"null"###, "User links xml items namespace"
-        "properties.links.items.xml.wrapped" = r###"null"###, "User links xml items wrapped"
-        "properties.photos_urls.xml.attribute" = r###"null"###, "User photos_urls xml attribute"
-        "properties.photos_urls.xml.name" = r###"null"###, "User photos_urls xml name"
-        "properties.photos_urls.xml.prefix" = r###"null"###, "User photos_urls xml prefix"
-        "properties.photos_urls.xml.namespace" = r###"null"###, "User photos_urls xml namespace"
-        "properties.photos_urls.xml.wrapped" = r###"true"###, "User photos_urls xml wrapped"
-        "properties.photos_urls.items.xml.attribute" = r###"null"###, "User photos_urls xml items attribute"
-        "properties.photos_urls.items.xml.name" = r###""photo_url""###, "User photos_urls xml items name"
-        "properties.photos_urls.items.xml.prefix" = r###"null"###, "User photos_urls xml items prefix"
-        "properties.photos_urls.items.xml.namespace" = r###"null"###, "User photos_urls xml items namespace"
-        "properties.photos_urls.items.xml.wrapped" = r###"null"###, "User photos_urls links xml items wrapped"
-    }
-}
+fn derive_struct_with_non_zero_integers() {
+    use std::num::{NonZeroI32, NonZeroU16, NonZeroU64, NonZeroU8};
 
-#[test]
-fn derive_struct_xml_with_optional_vec() {
-    let user = api_doc! {
-        #[schema(xml(name = "user"))]
-        struct User {
-            #[schema(xml(attribute, prefix = "u"))]
-            id: i64,
-            #[schema(xml(wrapped(name = "linkList"), name = "link"))]
-            links: Option<Vec<String>>,
+    let post = api_doc! {
+        struct Post {
+            id: NonZeroU64,
+            count: std::num::NonZeroU32,
+            #[schema(minimum = 10)]
+            limit: NonZeroU16,
+            pages: Vec<NonZeroU8>,
+            offset: NonZeroI32,
+            /// Change of the rating.
+            delta: NonZeroI32,
         }
     };
 
     assert_json_eq!(
-        user,
+        post,
         json!({
             "properties": {
                 "id": {
                     "type": "integer",
                     "format": "int64",
-                    "xml": {
-                        "attribute": true,
-                        "prefix": "u"
-                    }
+                    "minimum": 1
                 },
-                "links": {
+                "count": {
+                    "type": "integer",
+                    "format": "int32",
+                    "minimum": 1
+                },
+                "limit": {
+                    "type": "integer",
+                    "format": "int32",
+                    "minimum": 10
+                },
+                "pages": {
                     "type": "array",
                     "items": {
-                        "type": "string",
-                        "xml": {
-                            "name": "link"
-                        }
-                    },
-                    "nullable": true,
-                    "xml": {
-                        "name": "linkList",
-                        "wrapped": true,
+                        "type": "integer",
+                        "format": "int32",
+                        "minimum": 1
                     }
+                },
+                "offset": {
+                    "type": "integer",
+                    "format": "int32",
+                    "description": "Value must not be zero"
+                },
+                "delta": {
+                    "type": "integer",
+                    "format": "int32",
+                    "description": "Change of the rating.\n\nValue must not be zero"
                 }
             },
-            "required": ["id"],
-            "type": "object",
-            "xml": {
-                "name": "user"
-            }
+            "required": ["id", "count", "limit", "pages", "offset", "delta"],
+            "type": "object"
         })
-    );
+    )
+}
+
+#[cfg(feature = "decimal")]
+#[test]
+fn derive_struct_with_rust_decimal() {
+    use rust_decimal::Decimal;
+
+    let post = api_doc! {
+        struct Post {
+            id: i32,
+            rating: Decimal,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.id.type" = r#""integer""#, "Post id type"
+        "properties.id.format" = r#""int32""#, "Post id format"
+        "properties.rating.type" = r#""string""#, "Post rating type"
+        "properties.rating.format" = r#"null"#, "Post rating format"
+    }
+}
+
+#[cfg(feature = "decimal")]
+#[test]
+fn derive_struct_with_rust_decimal_with_type_override() {
+    use rust_decimal::Decimal;
+
+    let post = api_doc! {
+        struct Post {
+            id: i32,
+            #[schema(value_type = f64)]
+            rating: Decimal,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.id.type" = r#""integer""#, "Post id type"
+        "properties.id.format" = r#""int32""#, "Post id format"
+        "properties.rating.type" = r#""number""#, "Post rating type"
+        "properties.rating.format" = r#""double""#, "Post rating format"
+    }
+}
+
+#[cfg(feature = "decimal")]
+#[test]
+fn derive_struct_with_rust_decimal_as_number() {
+    use rust_decimal::Decimal;
+
+    let post = api_doc! {
+        struct Post {
+            rating: Decimal,
+            #[schema(decimal_as = "number")]
+            price: Decimal,
+            #[schema(decimal_as = "number")]
+            discount: Option<Decimal>,
+            #[schema(decimal_as = "number")]
+            history: Vec<Decimal>,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.rating.type" = r#""string""#, "Post rating type"
+        "properties.price.type" = r#""number""#, "Post price type"
+        "properties.price.format" = r#""double""#, "Post price format"
+        "properties.discount.type" = r#""number""#, "Post discount type"
+        "properties.discount.nullable" = r#"true"#, "Post discount nullable"
+        "properties.history.items.type" = r#""number""#, "Post history items type"
+        "required" = r#"["rating","price","history"]"#, "Post required"
+    }
+}
+
+#[cfg(feature = "decimal_float")]
+#[test]
+fn derive_struct_with_rust_decimal_float_as_string() {
+    use rust_decimal::Decimal;
+
+    let post = api_doc! {
+        struct Post {
+            rating: Decimal,
+            #[schema(decimal_as = "string")]
+            price: Decimal,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.rating.type" = r#""number""#, "Post rating type"
+        "properties.price.type" = r#""string""#, "Post price type"
+        "properties.price.format" = r#"null"#, "Post price format"
+    }
+}
+
+#[cfg(feature = "decimal_float")]
+#[test]
+fn derive_struct_with_rust_decimal_float() {
+    use rust_decimal::Decimal;
+
+    let post = api_doc! {
+        struct Post {
+            id: i32,
+            rating: Decimal,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.id.type" = r#""integer""#, "Post id type"
+        "properties.id.format" = r#""int32""#, "Post id format"
+        "properties.rating.type" = r#""number""#, "Post rating type"
+        "properties.rating.format" = r#""double""#, "Post rating format"
+    }
+}
+
+#[cfg(feature = "decimal_float")]
+#[test]
+fn derive_struct_with_rust_decimal_float_with_type_override() {
+    use rust_decimal::Decimal;
+
+    let post = api_doc! {
+        struct Post {
+            id: i32,
+            #[schema(value_type = String)]
+            rating: Decimal,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.id.type" = r#""integer""#, "Post id type"
+        "properties.id.format" = r#""int32""#, "Post id format"
+        "properties.rating.type" = r#""string""#, "Post rating type"
+        "properties.rating.format" = r#"null"#, "Post rating format"
+    }
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn derive_struct_with_uuid_type() {
+    use uuid::Uuid;
+
+    let post = api_doc! {
+        struct Post {
+            id: Uuid,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.id.type" = r#""string""#, "Post id type"
+        "properties.id.format" = r#""uuid""#, "Post id format"
+    }
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn derive_struct_with_wrapped_and_renamed_uuid_type() {
+    use uuid::Uuid as Id;
+
+    let post = api_doc! {
+        struct Post {
+            id: uuid::Uuid,
+            parent: Option<uuid::Uuid>,
+            tags: Vec<uuid::Uuid>,
+            #[schema(value_type = Uuid)]
+            renamed: Id,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.id.type" = r#""string""#, "Post id type"
+        "properties.id.format" = r#""uuid""#, "Post id format"
+        "properties.parent.format" = r#""uuid""#, "Post parent format"
+        "properties.tags.items.type" = r#""string""#, "Post tags items type"
+        "properties.tags.items.format" = r#""uuid""#, "Post tags items format"
+        "properties.renamed.type" = r#""string""#, "Post renamed type"
+        "properties.renamed.format" = r#""uuid""#, "Post renamed format"
+        "required" = r#"["id","tags","renamed"]"#, "Post required"
+    }
+}
+
+#[cfg(feature = "ulid")]
+#[test]
+fn derive_struct_with_ulid_type() {
+    use ulid::Ulid;
+
+    let post = api_doc! {
+        struct Post {
+            id: Ulid,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.id.type" = r#""string""#, "Post id type"
+        "properties.id.format" = r#""ulid""#, "Post id format"
+    }
 }
 
-#[cfg(feature = "chrono")]
+#[cfg(feature = "url")]
 #[test]
-fn derive_component_with_chrono_feature() {
-    #![allow(deprecated)] // allow deprecated Date in tests as long as it is available from chrono
-    use chrono::{Date, DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+fn derive_struct_with_url_type() {
+    use url::Url;
 
     let post = api_doc! {
         struct Post {
-            id: i32,
-            value: String,
-            datetime: DateTime<Utc>,
-            naive_datetime: NaiveDateTime,
-            date: Date<Utc>,
-            naive_date: NaiveDate,
-            naive_time: NaiveTime,
-            duration: Duration,
+            id: Url,
         }
     };
 
     assert_value! {post=>
-        "properties.datetime.type" = r#""string""#, "Post datetime type"
-        "properties.datetime.format" = r#""date-time""#, "Post datetime format"
-        "properties.naive_datetime.type" = r#""string""#, "Post datetime type"
-        "properties.naive_datetime.format" = r#""date-time""#, "Post datetime format"
-        "properties.date.type" = r#""string""#, "Post date type"
-        "properties.date.format" = r#""date""#, "Post date format"
-        "properties.naive_date.type" = r#""string""#, "Post date type"
-        "properties.naive_date.format" = r#""date""#, "Post date format"
-        "properties.naive_time.type" = r#""string""#, "Post time type"
-        "properties.naive_time.format" = r#"null"#, "Post time format"
-        "properties.duration.type" = r#""string""#, "Post duration type"
-        "properties.duration.format" = r#"null"#, "Post duration format"
-        "properties.id.type" = r#""integer""#, "Post id type"
-        "properties.id.format" = r#""int32""#, "Post id format"
-        "properties.value.type" = r#""string""#, "Post value type"
-        "properties.value.format" = r#"null"#, "Post value format"
+        "properties.id.type" = r#""string""#, "Post id type"
+        "properties.id.format" = r#""uri""#, "Post id format"
     }
 }
 
-#[cfg(feature = "time")]
 #[test]
-fn derive_component_with_time_feature() {
-    use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime};
-
-    let times = api_doc! {
-        struct Timetest {
-            datetime: OffsetDateTime,
-            primitive_date_time: PrimitiveDateTime,
-            date: Date,
-            duration: Duration,
+fn derive_struct_with_serde_one_directional_skip() {
+    let value = api_doc! {
+        #[derive(Serialize)]
+        struct User {
+            name: String,
+            #[serde(skip_serializing)]
+            password: String,
+            #[serde(skip_deserializing)]
+            created_at: i64,
+            #[serde(skip_deserializing)]
+            #[schema(read_only = false)]
+            updated_at: i64,
         }
     };
 
     assert_json_eq!(
-        &times,
+        value,
         json!({
             "properties": {
-                "date": {
-                    "format": "date",
+                "name": {
                     "type": "string"
                 },
-                "datetime": {
-                    "format": "date-time",
-                    "type": "string"
+                "password": {
+                    "type": "string",
+                    "writeOnly": true
                 },
-                "primitive_date_time": {
-                    "format": "date-time",
-                    "type": "string"
+                "created_at": {
+                    "type": "integer",
+                    "format": "int64",
+                    "readOnly": true
                 },
-                "duration": {
-                    "type": "string"
+                "updated_at": {
+                    "type": "integer",
+                    "format": "int64",
+                    "readOnly": false
                 }
             },
             "required": [
-                "datetime",
-                "primitive_date_time",
-                "date",
-                "duration"
+                "name",
+                "password",
+                "created_at",
+                "updated_at"
             ],
             "type": "object"
         })
@@ -2973,480 +7756,885 @@ fn derive_component_with_time_feature() {
 }
 
 #[test]
-fn derive_struct_component_field_type_override() {
+fn derive_parse_serde_field_attributes() {
+    struct S;
     let post = api_doc! {
-        struct Post {
-            id: i32,
-            #[schema(value_type = String)]
-            value: i64,
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Post<S> {
+            #[serde(rename = "uuid")]
+            id: String,
+            #[serde(skip)]
+            _p: PhantomData<S>,
+            #[serde(skip_serializing, skip_deserializing)]
+            _p2: PhantomData<S>,
+            long_field_num: i64,
         }
     };
 
-    assert_value! {post=>
-        "properties.id.type" = r#""integer""#, "Post id type"
-        "properties.id.format" = r#""int32""#, "Post id format"
-        "properties.value.type" = r#""string""#, "Post value type"
-        "properties.value.format" = r#"null"#, "Post value format"
-    }
+    assert_json_eq!(
+        post,
+        json!({
+            "properties": {
+                "longFieldNum": {
+                    "format": "int64",
+                    "type": "integer"
+                },
+                "uuid": {
+                    "type": "string"
+                }
+            },
+            "required": [
+                "uuid",
+                "longFieldNum"
+            ],
+            "type": "object"
+        })
+    )
 }
 
 #[test]
-fn derive_struct_component_field_type_path_override() {
-    let post = api_doc! {
-        struct Post {
-            id: i32,
-            #[schema(value_type = path::to::Foo)]
-            value: i64,
+fn derive_parse_serde_simple_enum_attributes() {
+    let value = api_doc! {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        enum Value {
+            A,
+            B,
+            #[serde(skip)]
+            C,
         }
     };
 
-    let component_ref: &str = post
-        .pointer("/properties/value/$ref")
-        .unwrap()
-        .as_str()
-        .unwrap();
-    assert_eq!(component_ref, "#/components/schemas/path.to.Foo");
+    assert_value! {value=>
+        "enum" = r#"["a","b"]"#, "Value enum variants"
+    }
 }
 
 #[test]
-fn derive_struct_component_field_type_override_with_format() {
-    let post = api_doc! {
-        struct Post {
-            id: i32,
-            #[schema(value_type = String, format = Byte)]
-            value: i64,
+fn derive_parse_serde_complex_enum() {
+    #[derive(Serialize)]
+    struct Foo;
+    let complex_enum = api_doc! {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        enum Bar {
+            UnitValue,
+            #[serde(rename_all = "camelCase")]
+            NamedFields {
+                #[serde(rename = "id")]
+                named_id: &'static str,
+                name_list: Option<Vec<String>>
+            },
+            UnnamedFields(Foo),
+            #[serde(skip)]
+            Random,
         }
     };
 
-    assert_value! {post=>
-        "properties.id.type" = r#""integer""#, "Post id type"
-        "properties.id.format" = r#""int32""#, "Post id format"
-        "properties.value.type" = r#""string""#, "Post value type"
-        "properties.value.format" = r#""byte""#, "Post value format"
+    assert_value! {complex_enum=>
+        "oneOf.[0].enum" = r#"["unitValue"]"#, "Unit value enum"
+        "oneOf.[0].type" = r#""string""#, "Unit value type"
+
+        "oneOf.[1].properties.namedFields.properties.id.type" = r#""string""#, "Named fields id type"
+        "oneOf.[1].properties.namedFields.properties.nameList.items.type" = r#""string""#, "Named fields nameList items type"
+        "oneOf.[1].properties.namedFields.required" = r#"["id"]"#, "Named fields required"
+
+        "oneOf.[2].properties.unnamedFields.$ref" = r###""#/components/schemas/Foo""###, "Unnamed fields ref"
+    }
+    #[cfg(not(feature = "openapi_31"))]
+    assert_value! {complex_enum=>
+        "oneOf.[1].properties.namedFields.properties.nameList.type" = r#""array""#, "Named fields nameList type"
+    }
+    #[cfg(feature = "openapi_31")]
+    assert_value! {complex_enum=>
+        "oneOf.[1].properties.namedFields.properties.nameList.type" = r#"["array","null"]"#, "Named fields nameList type"
     }
 }
 
 #[test]
-fn derive_struct_component_field_type_override_with_custom_format() {
-    let post = api_doc! {
-        struct Post {
-            #[schema(value_type = String, format = "uri")]
-            value: String,
+fn derive_component_with_generic_types_having_path_expression() {
+    let ty = api_doc! {
+        struct Bar {
+            args: Vec<std::vec::Vec<std::string::String>>
         }
     };
 
-    assert_value! {post=>
-        "properties.value.type" = r#""string""#, "Post value type"
-        "properties.value.format" = r#""uri""#, "Post value format"
+    let args = ty.pointer("/properties/args").unwrap();
+
+    assert_json_eq!(
+        args,
+        json!({
+            "items": {
+                "items": {
+                    "type": "string"
+                },
+                "type": "array"
+            },
+            "type": "array"
+        })
+    );
+}
+
+#[test]
+fn derive_component_with_aliases() {
+    struct A;
+
+    #[derive(Debug, OpenApi)]
+    #[openapi(components(schemas(MyAlias)))]
+    struct ApiDoc;
+
+    #[derive(ToSchema)]
+    #[aliases(MyAlias = Bar<A>)]
+    struct Bar<R> {
+        #[allow(dead_code)]
+        bar: R,
+    }
+
+    let doc = ApiDoc::openapi();
+    let doc_value = &serde_json::to_value(doc).unwrap();
+
+    let value = doc_value.pointer("/components/schemas").unwrap();
+    assert_value! {value=>
+        "MyAlias.properties.bar.$ref" = r###""#/components/schemas/A""###, "MyAlias aliased property"
+    }
+}
+
+#[test]
+fn derive_component_with_derived_alias_names() {
+    #[derive(ToSchema)]
+    struct User;
+
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Order;
+
+    #[derive(ToSchema)]
+    #[aliases(Page<User>, Page<Order>, Page<Vec<User>>)]
+    #[allow(unused)]
+    struct Page<T> {
+        items: Vec<T>,
+    }
+
+    #[derive(Debug, OpenApi)]
+    #[openapi(components(schemas(Page_User)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schemas = doc.pointer("/components/schemas").unwrap();
+
+    assert_value! {schemas=>
+        "Page_User.properties.items.items.$ref" = r###""#/components/schemas/User""###, "Page_User items"
+        "Page_Order.properties.items.items.$ref" = r###""#/components/schemas/Order""###, "Page_Order items"
+        "Page_Vec_User.properties.items.items.type" = r###""array""###, "Page_Vec_User items"
     }
 }
 
 #[test]
-fn derive_struct_component_field_type_override_with_format_with_vec() {
-    let post = api_doc! {
-        struct Post {
-            id: i32,
-            #[schema(value_type = String, format = Binary)]
-            value: Vec<u8>,
-        }
-    };
+fn derive_component_with_alias_features() {
+    #[derive(ToSchema)]
+    struct User;
 
-    assert_value! {post=>
-        "properties.id.type" = r#""integer""#, "Post id type"
-        "properties.id.format" = r#""int32""#, "Post id format"
-        "properties.value.type" = r#""string""#, "Post value type"
-        "properties.value.format" = r#""binary""#, "Post value format"
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Order;
+
+    /// A page of items
+    #[derive(ToSchema)]
+    #[aliases(
+        UserPage = Page<User> as description = "A page of users",
+        Page<Order> as (description = "A page of orders", example = json!({"items": []})),
+        IntPage = Page<i32>
+    )]
+    #[allow(unused)]
+    struct Page<T> {
+        items: Vec<T>,
     }
-}
 
-#[test]
-fn derive_unnamed_struct_schema_type_override() {
-    let value = api_doc! {
-        #[schema(value_type = String)]
-        struct Value(i64);
-    };
+    let (_, schema) = <Page<User> as ToSchema>::schema();
+    let schema = serde_json::to_value(schema).unwrap();
+    let aliases = Value::Object(
+        <Page<User> as ToSchema>::aliases()
+            .into_iter()
+            .map(|(name, schema)| (name.to_string(), serde_json::to_value(schema).unwrap()))
+            .collect(),
+    );
 
-    assert_value! {value=>
-        "type" = r#""string""#, "Value type"
-        "format" = r#"null"#, "Value format"
+    assert_value! {schema=>
+        "description" = r#""A page of items""#, "Page description"
+    }
+    assert_value! {aliases=>
+        "UserPage.description" = r#""A page of users""#, "UserPage description"
+        "UserPage.example" = r#"null"#, "UserPage example"
+        "Page_Order.description" = r#""A page of orders""#, "Page_Order description"
+        "Page_Order.example" = r#"{"items":[]}"#, "Page_Order example"
+        "IntPage.description" = r#""A page of items""#, "IntPage description"
     }
 }
 
 #[test]
-fn derive_unnamed_struct_schema_type_override_with_format() {
-    let value = api_doc! {
-        #[schema(value_type = String, format = Byte)]
-        struct Value(i64);
-    };
+fn derive_component_with_aliases_and_nested_generic_value_type() {
+    #[derive(Debug, OpenApi)]
+    #[openapi(components(schemas(MyAlias)))]
+    struct ApiDoc;
 
-    assert_value! {value=>
-        "type" = r#""string""#, "Value type"
-        "format" = r#""byte""#, "Value format"
+    #[derive(ToSchema)]
+    #[aliases(MyAlias = Bar<i32>)]
+    struct Bar<R> {
+        #[allow(dead_code)]
+        #[schema(value_type = Option<Vec<R>>)]
+        bar: String,
+        #[allow(dead_code)]
+        #[schema(value_type = R)]
+        baz: String,
+        #[allow(dead_code)]
+        value: PhantomData<R>,
     }
-}
 
-#[test]
-fn derive_struct_override_type_with_object_type() {
-    let value = api_doc! {
-        struct Value {
-            #[schema(value_type = Object)]
-            field: String,
-        }
-    };
+    let doc = ApiDoc::openapi();
+    let doc_value = &serde_json::to_value(doc).unwrap();
 
+    let value = doc_value
+        .pointer("/components/schemas/MyAlias/properties")
+        .unwrap();
+    #[cfg(not(feature = "openapi_31"))]
+    let bar = json!({
+        "type": "array",
+        "items": {
+            "type": "integer",
+            "format": "int32"
+        },
+        "nullable": true
+    });
+    #[cfg(feature = "openapi_31")]
+    let bar = json!({
+        "type": ["array", "null"],
+        "items": {
+            "type": "integer",
+            "format": "int32"
+        }
+    });
+    assert_json_eq!(value.get("bar").unwrap(), bar);
     assert_json_eq!(
-        value,
+        value.get("baz").unwrap(),
         json!({
-            "type": "object",
-            "properties": {
-                "field": {
-                    "type": "object"
-                }
-            },
-            "required": ["field"]
+            "type": "integer",
+            "format": "int32"
         })
-    )
+    );
 }
 
 #[test]
-fn derive_struct_override_type_with_a_reference() {
-    mod custom {
-        #[allow(dead_code)]
-        struct NewBar;
+fn derive_component_with_aliases_of_nested_generic_type_arguments() {
+    #[derive(Debug, OpenApi)]
+    #[openapi(components(schemas(Wrapper)))]
+    struct ApiDoc;
+
+    #[derive(ToSchema)]
+    #[aliases(Wrapper = Outer<Vec<Vec<i32>>>)]
+    #[allow(unused)]
+    struct Outer<T> {
+        value: T,
+        map: HashMap<String, Vec<T>>,
+        pair: (T, T),
     }
 
-    let value = api_doc! {
-        struct Value {
-            #[schema(value_type = NewBar)]
-            field: String,
+    let doc = ApiDoc::openapi();
+    let doc_value = &serde_json::to_value(doc).unwrap();
+
+    let value = doc_value
+        .pointer("/components/schemas/Wrapper/properties")
+        .unwrap();
+    let nested = json!({
+        "type": "array",
+        "items": {
+            "type": "array",
+            "items": {
+                "type": "integer",
+                "format": "int32"
+            }
         }
-    };
+    });
 
     assert_json_eq!(
         value,
         json!({
-            "type": "object",
-            "properties": {
-                "field": {
-                    "$ref": "#/components/schemas/NewBar"
+            "value": nested,
+            "map": {
+                "type": "object",
+                "additionalProperties": {
+                    "type": "array",
+                    "items": nested
                 }
             },
-            "required": ["field"]
+            "pair": {
+                "type": "array",
+                "items": {
+                    "allOf": [nested, nested]
+                }
+            }
         })
-    )
+    );
 }
 
-#[cfg(feature = "decimal")]
 #[test]
-fn derive_struct_with_rust_decimal() {
-    use rust_decimal::Decimal;
-
-    let post = api_doc! {
-        struct Post {
-            id: i32,
-            rating: Decimal,
-        }
-    };
-
-    assert_value! {post=>
-        "properties.id.type" = r#""integer""#, "Post id type"
-        "properties.id.format" = r#""int32""#, "Post id format"
-        "properties.rating.type" = r#""string""#, "Post rating type"
-        "properties.rating.format" = r#"null"#, "Post rating format"
+fn derive_complex_enum_with_aliases() {
+    #[derive(ToSchema)]
+    #[aliases(StringOrInt = Either<String, i32>)]
+    #[allow(unused)]
+    enum Either<L, R> {
+        Left(L),
+        Right(R),
+        Both { left: L, right: Vec<R> },
     }
-}
-
-#[cfg(feature = "decimal")]
-#[test]
-fn derive_struct_with_rust_decimal_with_type_override() {
-    use rust_decimal::Decimal;
 
-    let post = api_doc! {
-        struct Post {
-            id: i32,
-            #[schema(value_type = f64)]
-            rating: Decimal,
-        }
-    };
+    let aliases = <Either<String, i32> as ToSchema>::aliases();
+    let (name, schema) = aliases.first().unwrap();
+    let schema = serde_json::to_value(schema).unwrap();
 
-    assert_value! {post=>
-        "properties.id.type" = r#""integer""#, "Post id type"
-        "properties.id.format" = r#""int32""#, "Post id format"
-        "properties.rating.type" = r#""number""#, "Post rating type"
-        "properties.rating.format" = r#""double""#, "Post rating format"
-    }
+    assert_eq!(*name, "StringOrInt");
+    #[cfg(feature = "auto_title")]
+    let schema = common::without_auto_titles(schema, &[("", "StringOrInt")]);
+    assert_json_eq!(
+        schema,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "Left": {
+                            "type": "string"
+                        }
+                    },
+                    "required": ["Left"]
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "Right": {
+                            "type": "integer",
+                            "format": "int32"
+                        }
+                    },
+                    "required": ["Right"]
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "Both": {
+                            "type": "object",
+                            "properties": {
+                                "left": {
+                                    "type": "string"
+                                },
+                                "right": {
+                                    "type": "array",
+                                    "items": {
+                                        "type": "integer",
+                                        "format": "int32"
+                                    }
+                                }
+                            },
+                            "required": ["left", "right"]
+                        }
+                    },
+                    "required": ["Both"]
+                }
+            ]
+        })
+    );
 }
 
-#[cfg(feature = "decimal_float")]
 #[test]
-fn derive_struct_with_rust_decimal_float() {
-    use rust_decimal::Decimal;
+fn derive_unnamed_struct_with_aliases() {
+    #[derive(ToSchema)]
+    #[aliases(Count = Wrapper<i64>)]
+    #[allow(unused)]
+    struct Wrapper<T>(T);
 
-    let post = api_doc! {
-        struct Post {
-            id: i32,
-            rating: Decimal,
-        }
-    };
+    let aliases = <Wrapper<i64> as ToSchema>::aliases();
+    let (name, schema) = aliases.first().unwrap();
+    let schema = serde_json::to_value(schema).unwrap();
 
-    assert_value! {post=>
-        "properties.id.type" = r#""integer""#, "Post id type"
-        "properties.id.format" = r#""int32""#, "Post id format"
-        "properties.rating.type" = r#""number""#, "Post rating type"
-        "properties.rating.format" = r#""double""#, "Post rating format"
-    }
+    assert_eq!(*name, "Count");
+    assert_json_eq!(
+        schema,
+        json!({
+            "type": "integer",
+            "format": "int64"
+        })
+    );
 }
 
-#[cfg(feature = "decimal_float")]
 #[test]
-fn derive_struct_with_rust_decimal_float_with_type_override() {
-    use rust_decimal::Decimal;
-
-    let post = api_doc! {
-        struct Post {
-            id: i32,
-            #[schema(value_type = String)]
-            rating: Decimal,
-        }
-    };
+fn derive_complex_enum_as() {
+    struct Foobar;
 
-    assert_value! {post=>
-        "properties.id.type" = r#""integer""#, "Post id type"
-        "properties.id.format" = r#""int32""#, "Post id format"
-        "properties.rating.type" = r#""string""#, "Post rating type"
-        "properties.rating.format" = r#"null"#, "Post rating format"
+    #[derive(ToSchema)]
+    #[schema(as = named::BarBar)]
+    #[allow(unused)]
+    enum BarBar {
+        Foo { foo: Foobar },
     }
-}
 
-#[cfg(feature = "uuid")]
-#[test]
-fn derive_struct_with_uuid_type() {
-    use uuid::Uuid;
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(BarBar)))]
+    struct ApiDoc;
 
-    let post = api_doc! {
-        struct Post {
-            id: Uuid,
-        }
-    };
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+    let value = doc
+        .pointer("/components/schemas/named.BarBar")
+        .expect("Should have BarBar named to named.BarBar");
 
-    assert_value! {post=>
-        "properties.id.type" = r#""string""#, "Post id type"
-        "properties.id.format" = r#""uuid""#, "Post id format"
-    }
+    #[cfg(feature = "auto_title")]
+    let value = common::without_auto_titles(value.clone(), &[("", "BarBar")]);
+    assert_json_eq!(
+        &value,
+        json!({
+            "oneOf": [
+            {
+                "properties": {
+                    "Foo": {
+                        "properties": {
+                            "foo": {
+                                "$ref": "#/components/schemas/Foobar"
+                            }
+                        },
+                        "required": ["foo"],
+                        "type": "object"
+                    }
+                },
+                "required": ["Foo"],
+                "type": "object",
+            }
+            ]
+        })
+    )
 }
 
-#[cfg(feature = "ulid")]
 #[test]
-fn derive_struct_with_ulid_type() {
-    use ulid::Ulid;
+fn derive_complex_enum_with_variant_as() {
+    #[derive(ToSchema)]
+    #[schema(as = payloads::Cat)]
+    #[allow(unused)]
+    struct Cat {
+        name: String,
+    }
 
-    let post = api_doc! {
-        struct Post {
-            id: Ulid,
+    let value: Value = api_doc! {
+        enum Pet {
+            #[schema(as = payloads::Cat)]
+            Cat(Cat),
+            #[schema(as = payloads::Dog)]
+            Dog { barks: bool },
         }
     };
 
-    assert_value! {post=>
-        "properties.id.type" = r#""string""#, "Post id type"
-        "properties.id.format" = r#""ulid""#, "Post id format"
-    }
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "Cat": {
+                            "$ref": "#/components/schemas/payloads.Cat"
+                        }
+                    },
+                    "required": ["Cat"]
+                },
+                {
+                    "type": "object",
+                    "title": "payloads.Dog",
+                    "properties": {
+                        "Dog": {
+                            "type": "object",
+                            "properties": {
+                                "barks": {
+                                    "type": "boolean"
+                                }
+                            },
+                            "required": ["barks"]
+                        }
+                    },
+                    "required": ["Dog"]
+                }
+            ]
+        })
+    );
 }
 
-#[cfg(feature = "url")]
 #[test]
-fn derive_struct_with_url_type() {
-    use url::Url;
-
-    let post = api_doc! {
-        struct Post {
-            id: Url,
-        }
-    };
+fn derive_struct_as_with_inline() {
+    #[derive(ToSchema)]
+    #[schema(as = shared::Address, inline)]
+    #[allow(unused)]
+    struct Address {
+        street: String,
+    }
 
-    assert_value! {post=>
-        "properties.id.type" = r#""string""#, "Post id type"
-        "properties.id.format" = r#""uri""#, "Post id format"
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Customer {
+        #[schema(inline)]
+        billing: Address,
+        shipping: Address,
     }
-}
 
-#[test]
-fn derive_parse_serde_field_attributes() {
-    struct S;
-    let post = api_doc! {
-        #[derive(Serialize)]
-        #[serde(rename_all = "camelCase")]
-        struct Post<S> {
-            #[serde(rename = "uuid")]
-            id: String,
-            #[serde(skip)]
-            _p: PhantomData<S>,
-            #[serde(skip_serializing)]
-            _p2: PhantomData<S>,
-            long_field_num: i64,
-        }
-    };
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(Address, Customer)))]
+    struct ApiDoc;
 
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+    let address = doc
+        .pointer("/components/schemas/shared.Address")
+        .expect("Should have Address named to shared.Address");
+    let customer = doc
+        .pointer("/components/schemas/Customer")
+        .expect("Should have Customer");
+
+    #[cfg(feature = "auto_title")]
+    let address = common::without_auto_titles(address.clone(), &[("", "Address")]);
+    #[cfg(feature = "auto_title")]
+    let customer = common::without_auto_titles(
+        customer.clone(),
+        &[("", "Customer"), ("/properties/billing", "Address")],
+    );
     assert_json_eq!(
-        post,
+        address,
         json!({
             "properties": {
-                "longFieldNum": {
-                    "format": "int64",
-                    "type": "integer"
-                },
-                "uuid": {
+                "street": {
                     "type": "string"
                 }
             },
-            "required": [
-                "uuid",
-                "longFieldNum"
-            ],
+            "required": ["street"],
             "type": "object"
         })
-    )
+    );
+    assert_json_eq!(
+        customer.pointer("/properties/billing").unwrap(),
+        json!({
+            "properties": {
+                "street": {
+                    "type": "string"
+                }
+            },
+            "required": ["street"],
+            "type": "object"
+        })
+    );
+    assert!(
+        customer.pointer("/properties/shipping/$ref").is_some(),
+        "shipping should reference the schema"
+    );
 }
 
 #[test]
-fn derive_parse_serde_simple_enum_attributes() {
-    let value = api_doc! {
-        #[derive(Serialize)]
-        #[serde(rename_all = "camelCase")]
-        enum Value {
-            A,
-            B,
-            #[serde(skip)]
-            C,
-        }
-    };
+fn derive_struct_with_schema_rename() {
+    #[derive(ToSchema)]
+    #[schema(rename = "CustomItem")]
+    #[allow(unused)]
+    struct Item {
+        id: u64,
+        previous: Option<Box<Self>>,
+    }
 
-    assert_value! {value=>
-        "enum" = r#"["a","b"]"#, "Value enum variants"
+    #[derive(ToSchema)]
+    #[schema(rename = "CustomStatus")]
+    #[allow(unused)]
+    enum Status {
+        Active,
+        Inactive,
     }
-}
 
-#[test]
-fn derive_parse_serde_complex_enum() {
-    #[derive(Serialize)]
-    struct Foo;
-    let complex_enum = api_doc! {
-        #[derive(Serialize)]
-        #[serde(rename_all = "camelCase")]
-        enum Bar {
-            UnitValue,
-            #[serde(rename_all = "camelCase")]
-            NamedFields {
-                #[serde(rename = "id")]
-                named_id: &'static str,
-                name_list: Option<Vec<String>>
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(Item, Status)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+    let item = doc
+        .pointer("/components/schemas/CustomItem")
+        .expect("Should have Item named to CustomItem");
+    let status = doc
+        .pointer("/components/schemas/CustomStatus")
+        .expect("Should have Status named to CustomStatus");
+
+    #[cfg(not(feature = "openapi_31"))]
+    let previous = json!({
+        "allOf": [
+            {
+                "$ref": "#/components/schemas/CustomItem"
+            }
+        ],
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let previous = json!({
+        "oneOf": [
+            {
+                "type": "null"
             },
-            UnnamedFields(Foo),
-            #[serde(skip)]
-            Random,
-        }
-    };
+            {
+                "$ref": "#/components/schemas/CustomItem"
+            }
+        ]
+    });
 
-    assert_value! {complex_enum=>
-        "oneOf.[0].enum" = r#"["unitValue"]"#, "Unit value enum"
-        "oneOf.[0].type" = r#""string""#, "Unit value type"
+    #[cfg(feature = "auto_title")]
+    let item = common::without_auto_titles(item.clone(), &[("", "Item")]);
+    #[cfg(feature = "auto_title")]
+    let status = common::without_auto_titles(status.clone(), &[("", "Status")]);
+    assert_json_eq!(
+        item,
+        json!({
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "format": "int64",
+                    "minimum": 0,
+                },
+                "previous": previous
+            },
+            "required": ["id"],
+            "type": "object"
+        })
+    );
+    assert_json_eq!(
+        status,
+        json!({
+            "enum": ["Active", "Inactive"],
+            "type": "string"
+        })
+    );
+}
 
-        "oneOf.[1].properties.namedFields.properties.id.type" = r#""string""#, "Named fields id type"
-        "oneOf.[1].properties.namedFields.properties.nameList.type" = r#""array""#, "Named fields nameList type"
-        "oneOf.[1].properties.namedFields.properties.nameList.items.type" = r#""string""#, "Named fields nameList items type"
-        "oneOf.[1].properties.namedFields.required" = r#"["id"]"#, "Named fields required"
+#[test]
+fn derive_struct_referencing_schema_with_rename() {
+    #[derive(Serialize, ToSchema)]
+    #[schema(rename = "CustomItem")]
+    #[allow(unused)]
+    struct Item {
+        id: u64,
+    }
 
-        "oneOf.[2].properties.unnamedFields.$ref" = r###""#/components/schemas/Foo""###, "Unnamed fields ref"
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Order {
+        item: Item,
+        items: Vec<Item>,
     }
+
+    #[derive(Serialize, ToSchema)]
+    #[serde(tag = "kind")]
+    #[allow(unused)]
+    enum Entry {
+        Item(Item),
+        Order(Order),
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(Item, Order, Entry)))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+    let order = doc
+        .pointer("/components/schemas/Order")
+        .expect("Should have Order schema");
+    let entry = doc
+        .pointer("/components/schemas/Entry")
+        .expect("Should have Entry schema");
+
+    assert!(doc.pointer("/components/schemas/CustomItem").is_some());
+    #[cfg(feature = "auto_title")]
+    let order = common::without_auto_titles(order.clone(), &[("", "Order")]);
+    assert_json_eq!(
+        order,
+        json!({
+            "properties": {
+                "item": {
+                    "$ref": "#/components/schemas/CustomItem"
+                },
+                "items": {
+                    "type": "array",
+                    "items": {
+                        "$ref": "#/components/schemas/CustomItem"
+                    }
+                }
+            },
+            "required": ["item", "items"],
+            "type": "object"
+        })
+    );
+    assert_json_eq!(
+        entry.pointer("/discriminator").unwrap(),
+        json!({
+            "propertyName": "kind",
+            "mapping": {
+                "Item": "#/components/schemas/CustomItem",
+                "Order": "#/components/schemas/Order",
+            }
+        })
+    );
 }
 
 #[test]
-fn derive_component_with_generic_types_having_path_expression() {
-    let ty = api_doc! {
-        struct Bar {
-            args: Vec<std::vec::Vec<std::string::String>>
+fn derive_struct_with_fixed_size_arrays() {
+    let value = api_doc! {
+        struct Item {
+            id: [u8; 16],
+            tags: [String; 3],
+            values: Option<[i32; 2]>,
         }
     };
 
-    let args = ty.pointer("/properties/args").unwrap();
+    #[cfg(not(feature = "openapi_31"))]
+    let values = json!({
+        "type": "array",
+        "items": {
+            "type": "integer",
+            "format": "int32",
+        },
+        "maxItems": 2,
+        "minItems": 2,
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let values = json!({
+        "type": ["array", "null"],
+        "items": {
+            "type": "integer",
+            "format": "int32",
+        },
+        "maxItems": 2,
+        "minItems": 2,
+    });
 
     assert_json_eq!(
-        args,
+        value,
         json!({
-            "items": {
-                "items": {
-                    "type": "string"
+            "properties": {
+                "id": {
+                    "type": "array",
+                    "items": {
+                        "type": "integer",
+                        "format": "int32",
+                        "minimum": 0,
+                    },
+                    "maxItems": 16,
+                    "minItems": 16,
                 },
-                "type": "array"
+                "tags": {
+                    "type": "array",
+                    "items": {
+                        "type": "string",
+                    },
+                    "maxItems": 3,
+                    "minItems": 3,
+                },
+                "values": values
             },
-            "type": "array"
+            "required": ["id", "tags"],
+            "type": "object"
         })
-    );
+    )
 }
 
 #[test]
-fn derive_component_with_aliases() {
-    struct A;
-
-    #[derive(Debug, OpenApi)]
-    #[openapi(components(schemas(MyAlias)))]
-    struct ApiDoc;
-
-    #[derive(ToSchema)]
-    #[aliases(MyAlias = Bar<A>)]
-    struct Bar<R> {
-        #[allow(dead_code)]
-        bar: R,
+fn derive_component_with_bound() {
+    #[derive(ToSchema, Serialize)]
+    #[schema(bound = "T: Serialize")]
+    #[allow(unused)]
+    enum Item<T> {
+        #[schema(default)]
+        Empty,
+        #[schema(value_type = i32)]
+        Value(T),
     }
 
-    let doc = ApiDoc::openapi();
-    let doc_value = &serde_json::to_value(doc).unwrap();
+    impl<T> Default for Item<T> {
+        fn default() -> Self {
+            Self::Empty
+        }
+    }
 
-    let value = doc_value.pointer("/components/schemas").unwrap();
+    #[derive(ToSchema)]
+    #[schema(bound = "T: Clone")]
+    #[allow(unused)]
+    struct Pair<T>(#[schema(value_type = i32)] T)
+    where
+        T: Copy;
+
+    let value = serde_json::to_value(Item::<i32>::schema().1).unwrap();
     assert_value! {value=>
-        "MyAlias.properties.bar.$ref" = r###""#/components/schemas/A""###, "MyAlias aliased property"
+        "default" = r#""Empty""#, "Item default"
     }
+
+    let (name, _) = Pair::<i32>::schema();
+    assert_eq!(name, "Pair");
 }
 
 #[test]
-fn derive_complex_enum_as() {
-    struct Foobar;
+fn derive_component_with_const_generics() {
+    #[derive(ToSchema)]
+    #[aliases(Matrix3 = Matrix<3>)]
+    #[allow(unused)]
+    struct Matrix<const N: usize> {
+        data: [f64; N],
+    }
 
     #[derive(ToSchema)]
-    #[schema(as = named::BarBar)]
+    #[aliases(GridFloat = Grid<2, f32>)]
     #[allow(unused)]
-    enum BarBar {
-        Foo { foo: Foobar },
+    struct Grid<const N: usize, T> {
+        data: [T; N],
     }
 
     #[derive(OpenApi)]
-    #[openapi(components(schemas(BarBar)))]
+    #[openapi(components(schemas(Matrix3, GridFloat)))]
     struct ApiDoc;
 
-    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
-    let value = doc
-        .pointer("/components/schemas/named.BarBar")
-        .expect("Should have BarBar named to named.BarBar");
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let value = doc.pointer("/components/schemas").unwrap();
 
+    #[cfg(feature = "auto_title")]
+    let value = common::without_auto_titles(
+        value.clone(),
+        &[("/Matrix3", "Matrix3"), ("/GridFloat", "GridFloat")],
+    );
     assert_json_eq!(
-        &value,
+        value,
         json!({
-            "oneOf": [
-            {
+            "Matrix3": {
                 "properties": {
-                    "Foo": {
-                        "properties": {
-                            "foo": {
-                                "$ref": "#/components/schemas/Foobar"
-                            }
+                    "data": {
+                        "type": "array",
+                        "items": {
+                            "type": "number",
+                            "format": "double"
                         },
-                        "required": ["foo"],
-                        "type": "object"
+                        "minItems": 3,
+                        "maxItems": 3
+                    }
+                },
+                "type": "object",
+                "required": ["data"]
+            },
+            "GridFloat": {
+                "properties": {
+                    "data": {
+                        "type": "array",
+                        "items": {
+                            "type": "number",
+                            "format": "float"
+                        },
+                        "minItems": 2,
+                        "maxItems": 2
                     }
                 },
-                "required": ["Foo"],
                 "type": "object",
+                "required": ["data"]
             }
-            ]
         })
     )
 }
@@ -3475,6 +8663,15 @@ fn derive_component_with_primitive_aliases() {
 
     let value = doc_value.pointer("/components/schemas").unwrap();
 
+    #[cfg(feature = "auto_title")]
+    let value = common::without_auto_titles(
+        value.clone(),
+        &[
+            ("/Foo", "Foo"),
+            ("/BarString", "BarString"),
+            ("/BarInt", "BarInt"),
+        ],
+    );
     assert_json_eq!(
         value,
         json!({
@@ -3541,6 +8738,26 @@ fn derive_component_with_to_schema_value_type() {
         }
     };
 
+    #[cfg(not(feature = "openapi_31"))]
+    let value3 = json!({
+        "type": "string",
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let value3 = json!({
+        "type": ["string", "null"],
+    });
+
+    #[cfg(not(feature = "openapi_31"))]
+    let value4 = json!({
+        "type": "object",
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let value4 = json!({
+        "type": ["object", "null"],
+    });
+
     assert_json_eq!(
         doc,
         json!({
@@ -3567,14 +8784,8 @@ fn derive_component_with_to_schema_value_type() {
                     },
                     "type": "array"
                 },
-                "value3": {
-                    "type": "string",
-                    "nullable": true,
-                },
-                "value4": {
-                    "type": "object",
-                    "nullable": true,
-                },
+                "value3": value3,
+                "value4": value4,
                 "value5": {
                     "items": {
                         "type": "object"
@@ -3682,144 +8893,450 @@ fn derive_component_with_linked_list() {
     };
 
     assert_json_eq!(
-        example_schema,
+        example_schema,
+        json!({
+            "properties": {
+                "values": {
+                    "items": {
+                        "type": "number",
+                        "format": "double"
+                    },
+                    "type": "array"
+                }
+            },
+            "required": ["values"],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+#[cfg(feature = "smallvec")]
+fn derive_component_with_smallvec_feature() {
+    use smallvec::SmallVec;
+
+    let bar = api_doc! {
+        struct Bar<'b> {
+            links: SmallVec<[&'b str; 2]>
+        }
+    };
+
+    assert_json_eq!(
+        bar,
+        json!({
+            "properties": {
+                "links": {
+                    "items": {
+                        "type": "string"
+                    },
+                    "type": "array",
+                }
+            },
+            "required": ["links"],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_schema_with_default_field() {
+    let value = api_doc! {
+        #[derive(serde::Deserialize)]
+        struct MyValue {
+            #[serde(default)]
+            field: String
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "field": {
+                    "type": "string"
+                }
+            },
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_schema_with_default_struct() {
+    let value = api_doc! {
+        #[derive(serde::Deserialize, Default)]
+        #[serde(default)]
+        struct MyValue {
+            field: String
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "field": {
+                    "type": "string",
+                    "default": ""
+                }
+            },
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_no_additional_properties() {
+    let value = api_doc! {
+        #[derive(serde::Deserialize, Default)]
+        #[serde(deny_unknown_fields)]
+        struct MyValue {
+            field: String
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "field": {
+                    "type": "string",
+                }
+            },
+            "required": ["field"],
+            "additionalProperties": false,
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_additional_properties_override() {
+    let allowed = api_doc! {
+        #[schema(additional_properties)]
+        struct Allowed {
+            field: String
+        }
+    };
+    let forbidden = api_doc! {
+        #[schema(additional_properties = false)]
+        struct Forbidden {
+            field: String
+        }
+    };
+    let allowed_over_serde = api_doc! {
+        #[derive(serde::Deserialize)]
+        #[serde(deny_unknown_fields)]
+        #[schema(additional_properties = true)]
+        struct AllowedOverSerde {
+            field: String
+        }
+    };
+
+    assert_value! {allowed=>
+        "additionalProperties" = r#"true"#, "Allowed additionalProperties"
+    }
+    assert_value! {forbidden=>
+        "additionalProperties" = r#"false"#, "Forbidden additionalProperties"
+    }
+    assert_json_eq!(
+        allowed_over_serde,
+        json!({
+            "properties": {
+                "field": {
+                    "type": "string",
+                }
+            },
+            "required": ["field"],
+            "additionalProperties": true,
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_additional_properties_schema_type() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Extra {
+        value: String,
+    }
+
+    let referenced = api_doc! {
+        #[schema(additional_properties = Extra)]
+        struct Known {
+            field: String
+        }
+    };
+    let primitive = api_doc! {
+        #[schema(additional_properties = Vec<i32>)]
+        struct Counts {
+            total: i32
+        }
+    };
+
+    assert_json_eq!(
+        referenced,
+        json!({
+            "properties": {
+                "field": {
+                    "type": "string",
+                }
+            },
+            "required": ["field"],
+            "additionalProperties": {
+                "$ref": "#/components/schemas/Extra"
+            },
+            "type": "object"
+        })
+    );
+    assert_value! {primitive=>
+        "additionalProperties.type" = r#""array""#, "Counts additionalProperties type"
+        "additionalProperties.items.type" = r#""integer""#, "Counts additionalProperties items type"
+    }
+}
+
+#[cfg(feature = "openapi_31")]
+#[test]
+fn derive_flattened_struct_with_additional_properties_override() {
+    #[derive(serde::Deserialize, ToSchema)]
+    #[allow(unused)]
+    struct Base {
+        id: i32,
+    }
+
+    let value = api_doc! {
+        #[derive(serde::Deserialize)]
+        #[schema(additional_properties = false)]
+        struct MyValue {
+            field: String,
+            #[serde(flatten)]
+            base: Base,
+        }
+    };
+
+    assert_value! {value=>
+        "unevaluatedProperties" = r#"false"#, "MyValue unevaluatedProperties"
+        "additionalProperties" = r#"null"#, "MyValue additionalProperties"
+    }
+}
+
+#[cfg(feature = "openapi_31")]
+#[test]
+fn derive_flattened_struct_with_no_unevaluated_properties() {
+    #[derive(serde::Deserialize, ToSchema)]
+    #[allow(unused)]
+    struct Base {
+        id: i32,
+    }
+
+    let value = api_doc! {
+        #[derive(serde::Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct MyValue {
+            field: String,
+            #[serde(flatten)]
+            base: Base,
+        }
+    };
+
+    assert_json_eq!(
+        value,
         json!({
-            "properties": {
-                "values": {
-                    "items": {
-                        "type": "number",
-                        "format": "double"
+            "allOf": [
+                {
+                    "$ref": "#/components/schemas/Base"
+                },
+                {
+                    "properties": {
+                        "field": {
+                            "type": "string",
+                        }
                     },
-                    "type": "array"
+                    "required": ["field"],
+                    "type": "object"
                 }
-            },
-            "required": ["values"],
-            "type": "object"
+            ],
+            "unevaluatedProperties": false
         })
     )
 }
 
+#[cfg(not(feature = "openapi_31"))]
 #[test]
-#[cfg(feature = "smallvec")]
-fn derive_component_with_smallvec_feature() {
-    use smallvec::SmallVec;
+fn derive_flattened_struct_with_deny_unknown_fields() {
+    #[derive(serde::Deserialize, ToSchema)]
+    #[allow(unused)]
+    struct Base {
+        id: i32,
+    }
 
-    let bar = api_doc! {
-        struct Bar<'b> {
-            links: SmallVec<[&'b str; 2]>
+    let value = api_doc! {
+        #[derive(serde::Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct MyValue {
+            field: String,
+            #[serde(flatten)]
+            base: Base,
         }
     };
 
     assert_json_eq!(
-        bar,
+        value,
         json!({
-            "properties": {
-                "links": {
-                    "items": {
-                        "type": "string"
+            "allOf": [
+                {
+                    "$ref": "#/components/schemas/Base"
+                },
+                {
+                    "properties": {
+                        "field": {
+                            "type": "string",
+                        }
                     },
-                    "type": "array",
+                    "required": ["field"],
+                    "type": "object"
                 }
-            },
-            "required": ["links"],
-            "type": "object"
+            ]
         })
     )
 }
 
 #[test]
-fn derive_schema_with_default_field() {
+#[cfg(feature = "repr")]
+fn derive_schema_for_repr_enum() {
     let value = api_doc! {
         #[derive(serde::Deserialize)]
-        struct MyValue {
-            #[serde(default)]
-            field: String
+        #[repr(i32)]
+        #[schema(example = 1, default = 0)]
+        enum ExitCode {
+            Error  = -1,
+            Ok     = 0,
+            Unknow = 1,
         }
     };
 
     assert_json_eq!(
         value,
         json!({
-            "properties": {
-                "field": {
-                    "type": "string"
-                }
-            },
-            "type": "object"
+            "enum": [-1, 0, 1],
+            "type": "integer",
+            "format": "int32",
+            "default": 0,
+            "example": 1,
         })
-    )
+    );
 }
 
 #[test]
-fn derive_schema_with_default_struct() {
+#[cfg(feature = "repr")]
+fn derive_schema_for_repr_enum_with_explicit_discriminants() {
     let value = api_doc! {
-        #[derive(serde::Deserialize, Default)]
-        #[serde(default)]
-        struct MyValue {
-            field: String
+        #[repr(u8)]
+        enum Level {
+            A = 1,
+            B = 5,
+            C = 100,
+            D,
         }
     };
 
     assert_json_eq!(
         value,
         json!({
-            "properties": {
-                "field": {
-                    "type": "string",
-                    "default": ""
-                }
-            },
-            "type": "object"
+            "enum": [1, 5, 100, 101],
+            "type": "integer",
+            "format": "int32",
         })
-    )
+    );
 }
 
 #[test]
-fn derive_struct_with_no_additional_properties() {
+#[cfg(feature = "repr")]
+fn derive_schema_for_repr_enum_with_integer_format() {
     let value = api_doc! {
-        #[derive(serde::Deserialize, Default)]
-        #[serde(deny_unknown_fields)]
-        struct MyValue {
-            field: String
+        #[repr(u16)]
+        enum Port {
+            Http = 80,
+            Https = 443,
         }
     };
 
+    #[cfg(not(feature = "non_strict_integers"))]
+    let format = "int32";
+    #[cfg(feature = "non_strict_integers")]
+    let format = "uint16";
+
     assert_json_eq!(
         value,
         json!({
-            "properties": {
-                "field": {
-                    "type": "string",
-                }
-            },
-            "required": ["field"],
-            "additionalProperties": false,
-            "type": "object"
+            "enum": [80, 443],
+            "type": "integer",
+            "format": format,
         })
-    )
+    );
 }
 
 #[test]
 #[cfg(feature = "repr")]
-fn derive_schema_for_repr_enum() {
+fn derive_schema_for_repr_enum_with_explicit_discriminants_and_const_variants() {
     let value = api_doc! {
-        #[derive(serde::Deserialize)]
-        #[repr(i32)]
-        #[schema(example = 1, default = 0)]
-        enum ExitCode {
-            Error  = -1,
-            Ok     = 0,
-            Unknow = 1,
+        #[repr(i16)]
+        #[schema(const_variants)]
+        enum Level {
+            A = -10,
+            B = 5,
+            C = 1000,
         }
     };
 
+    #[cfg(not(feature = "openapi_31"))]
+    let levels = json!([
+        {
+            "type": "integer",
+            "format": "int32",
+            "enum": [-10],
+            "title": "A"
+        },
+        {
+            "type": "integer",
+            "format": "int32",
+            "enum": [5],
+            "title": "B"
+        },
+        {
+            "type": "integer",
+            "format": "int32",
+            "enum": [1000],
+            "title": "C"
+        }
+    ]);
+    #[cfg(feature = "openapi_31")]
+    let levels = json!([
+        {
+            "type": "integer",
+            "format": "int32",
+            "const": -10,
+            "title": "A"
+        },
+        {
+            "type": "integer",
+            "format": "int32",
+            "const": 5,
+            "title": "B"
+        },
+        {
+            "type": "integer",
+            "format": "int32",
+            "const": 1000,
+            "title": "C"
+        }
+    ]);
+
     assert_json_eq!(
         value,
         json!({
-            "enum": [-1, 0, 1],
-            "type": "integer",
-            "default": 0,
-            "example": 1,
+            "oneOf": levels
         })
     );
 }
@@ -3847,6 +9364,7 @@ fn derive_schema_for_tagged_repr_enum() {
                     "properties": {
                         "tag": {
                             "type": "integer",
+                            "format": "int32",
                             "enum": [
                                 0,
                             ],
@@ -3861,6 +9379,7 @@ fn derive_schema_for_tagged_repr_enum() {
                     "properties": {
                         "tag": {
                             "type": "integer",
+                            "format": "int32",
                             "enum": [
                                 1,
                             ],
@@ -3875,6 +9394,7 @@ fn derive_schema_for_tagged_repr_enum() {
                     "properties": {
                         "tag": {
                             "type": "integer",
+                            "format": "int32",
                             "enum": [
                                 2,
                             ],
@@ -3931,38 +9451,204 @@ fn derive_repr_enum_with_with_custom_default_fn_success() {
     }
 }
 
-#[cfg(feature = "repr")]
-fn repr_mode_default_fn() -> u16 {
-    1
+#[cfg(feature = "repr")]
+fn repr_mode_default_fn() -> u16 {
+    1
+}
+
+#[test]
+#[cfg(feature = "repr")]
+fn derive_repr_enum_with_with_custom_default_fn_and_example() {
+    let mode = api_doc! {
+        #[schema(default = repr_mode_default_fn, example = 1)]
+        #[repr(u16)]
+        enum ReprDefaultMode {
+            Mode1 = 0,
+            Mode2
+        }
+    };
+
+    assert_value! {mode=>
+        "default" = r#"1"#, "ReprDefaultMode default"
+        "enum" = r#"[0,1]"#, "ReprDefaultMode enum variants"
+        "type" = r#""integer""#, "ReprDefaultMode type"
+        "example" = r#"1"#, "ReprDefaultMode example"
+    };
+}
+
+#[test]
+#[cfg(feature = "repr")]
+fn derive_repr_enum_with_const_variants() {
+    let value: Value = api_doc! {
+        /// Status of the job.
+        #[derive(serde::Deserialize)]
+        #[schema(const_variants, default = repr_mode_default_fn)]
+        #[repr(u16)]
+        enum JobStatus {
+            /// Job is waiting to be run.
+            Pending = 0,
+            #[schema(rename = "In progress")]
+            Running = 1,
+            #[serde(skip)]
+            Unknown = 2,
+        }
+    };
+
+    #[cfg(not(feature = "openapi_31"))]
+    let statuses = json!([
+        {
+            "type": "integer",
+            "format": "int32",
+            "enum": [0],
+            "title": "Pending",
+            "description": "Job is waiting to be run."
+        },
+        {
+            "type": "integer",
+            "format": "int32",
+            "enum": [1],
+            "title": "In progress"
+        }
+    ]);
+    #[cfg(feature = "openapi_31")]
+    let statuses = json!([
+        {
+            "type": "integer",
+            "format": "int32",
+            "const": 0,
+            "title": "Pending",
+            "description": "Job is waiting to be run."
+        },
+        {
+            "type": "integer",
+            "format": "int32",
+            "const": 1,
+            "title": "In progress"
+        }
+    ]);
+
+    assert_json_eq!(
+        value,
+        json!({
+            "oneOf": statuses,
+            "default": 1,
+            "description": "Status of the job."
+        })
+    );
+}
+
+#[test]
+fn derive_struct_with_vec_field_with_example() {
+    let post = api_doc! {
+        struct Post {
+            id: i32,
+            #[schema(example = json!(["foobar", "barfoo"]))]
+            value: Vec<String>,
+        }
+    };
+
+    assert_json_eq!(
+        post,
+        json!({
+            "type": "object",
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "format": "int32"
+                },
+                "value": {
+                    "type": "array",
+                    "example": ["foobar", "barfoo"],
+                    "items": {
+                        "type": "string"
+                    }
+                }
+            },
+            "required": ["id", "value"]
+        })
+    );
+}
+
+#[test]
+fn derive_struct_with_ignored_field() {
+    #[derive(Serialize, ToSchema)]
+    #[allow(unused)]
+    struct Metadata {
+        created: String,
+    }
+
+    #[derive(Serialize, ToSchema)]
+    struct Post {
+        id: i32,
+        #[schema(ignore)]
+        internal_id: String,
+        #[schema(ignore = false)]
+        title: String,
+        #[serde(flatten)]
+        #[schema(ignore)]
+        metadata: Metadata,
+    }
+
+    let value = serde_json::to_value(Post::schema().1).unwrap();
+    #[cfg(feature = "auto_title")]
+    let value = common::without_auto_titles(value, &[("", "Post")]);
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "object",
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "format": "int32"
+                },
+                "title": {
+                    "type": "string"
+                }
+            },
+            "required": ["id", "title"]
+        })
+    );
+
+    let post = Post {
+        id: 1,
+        internal_id: "internal".to_string(),
+        title: "title".to_string(),
+        metadata: Metadata {
+            created: "today".to_string(),
+        },
+    };
+    assert_json_eq!(
+        serde_json::to_value(post).unwrap(),
+        json!({
+            "id": 1,
+            "internal_id": "internal",
+            "title": "title",
+            "created": "today"
+        })
+    );
 }
 
 #[test]
-#[cfg(feature = "repr")]
-fn derive_repr_enum_with_with_custom_default_fn_and_example() {
-    let mode = api_doc! {
-        #[schema(default = repr_mode_default_fn, example = 1)]
-        #[repr(u16)]
-        enum ReprDefaultMode {
-            Mode1 = 0,
-            Mode2
-        }
-    };
+fn derive_struct_with_example_with() {
+    fn post_example() -> serde_json::Value {
+        json!({ "id": 1, "tags": ["foo"] })
+    }
 
-    assert_value! {mode=>
-        "default" = r#"1"#, "ReprDefaultMode default"
-        "enum" = r#"[0,1]"#, "ReprDefaultMode enum variants"
-        "type" = r#""integer""#, "ReprDefaultMode type"
-        "example" = r#"1"#, "ReprDefaultMode example"
-    };
-}
+    fn tags_example() -> serde_json::Value {
+        json!(["foobar", "barfoo"])
+    }
+
+    fn id_example() -> serde_json::Value {
+        json!(10)
+    }
 
-#[test]
-fn derive_struct_with_vec_field_with_example() {
     let post = api_doc! {
+        #[schema(example_with = post_example)]
         struct Post {
+            #[schema(example_with = id_example)]
             id: i32,
-            #[schema(example = json!(["foobar", "barfoo"]))]
-            value: Vec<String>,
+            #[schema(example_with = tags_example)]
+            tags: Vec<String>,
         }
     };
 
@@ -3970,12 +9656,17 @@ fn derive_struct_with_vec_field_with_example() {
         post,
         json!({
             "type": "object",
+            "example": {
+                "id": 1,
+                "tags": ["foo"]
+            },
             "properties": {
                 "id": {
                     "type": "integer",
-                    "format": "int32"
+                    "format": "int32",
+                    "example": 10
                 },
-                "value": {
+                "tags": {
                     "type": "array",
                     "example": ["foobar", "barfoo"],
                     "items": {
@@ -3983,7 +9674,31 @@ fn derive_struct_with_vec_field_with_example() {
                     }
                 }
             },
-            "required": ["id", "value"]
+            "required": ["id", "tags"]
+        })
+    );
+}
+
+#[test]
+fn derive_enum_with_example_with() {
+    fn color_example() -> serde_json::Value {
+        json!("Red")
+    }
+
+    let color = api_doc! {
+        #[schema(example_with = color_example)]
+        enum Color {
+            Red,
+            Green,
+        }
+    };
+
+    assert_json_eq!(
+        color,
+        json!({
+            "type": "string",
+            "enum": ["Red", "Green"],
+            "example": "Red"
         })
     );
 }
@@ -4258,6 +9973,274 @@ fn derive_struct_with_validation_fields() {
     );
 }
 
+#[test]
+fn derive_struct_with_numeric_bounds() {
+    let value = api_doc! {
+        struct Item {
+            #[schema(minimum = 0, maximum = 100)]
+            percentage: u8,
+
+            #[schema(minimum = -1.5, maximum = 1.5)]
+            ratio: f32,
+
+            #[schema(maximum = 10)]
+            optional: Option<i64>,
+        }
+    };
+
+    let config = Config::new(CompareMode::Strict).numeric_mode(NumericMode::AssumeFloat);
+
+    #[cfg(not(feature = "openapi_31"))]
+    let optional = json!({
+        "type": "integer",
+        "format": "int64",
+        "maximum": 10.0,
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let optional = json!({
+        "type": ["integer", "null"],
+        "format": "int64",
+        "maximum": 10.0,
+    });
+
+    assert_json_matches!(
+        value,
+        json!({
+            "properties": {
+                "percentage": {
+                    "type": "integer",
+                    "format": "int32",
+                    "minimum": 0.0,
+                    "maximum": 100.0,
+                },
+                "ratio": {
+                    "type": "number",
+                    "format": "float",
+                    "minimum": -1.5,
+                    "maximum": 1.5,
+                },
+                "optional": optional
+            },
+            "type": "object",
+            "required": [
+                "percentage",
+                "ratio"
+            ]
+        }),
+        config
+    );
+}
+
+#[test]
+fn derive_struct_with_pattern() {
+    let value = api_doc! {
+        struct Item {
+            #[schema(pattern = "^[A-Z]{3}$")]
+            currency: String,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "currency": {
+                    "type": "string",
+                    "pattern": "^[A-Z]{3}$"
+                }
+            },
+            "type": "object",
+            "required": [
+                "currency"
+            ]
+        })
+    );
+}
+
+#[cfg(feature = "openapi_31")]
+#[test]
+fn derive_struct_with_content_encoding_and_media_type() {
+    let value = api_doc! {
+        struct Image {
+            #[schema(content_encoding = "base64", content_media_type = "image/png")]
+            data: String,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "data": {
+                    "type": "string",
+                    "contentEncoding": "base64",
+                    "contentMediaType": "image/png"
+                }
+            },
+            "type": "object",
+            "required": [
+                "data"
+            ]
+        })
+    );
+
+    let value = api_doc! {
+        #[schema(content_encoding = "base64", content_media_type = "image/png")]
+        struct Png(String);
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "string",
+            "contentEncoding": "base64",
+            "contentMediaType": "image/png"
+        })
+    );
+}
+
+#[test]
+fn derive_struct_with_exclusive_numeric_bounds() {
+    let value = api_doc! {
+        struct Item {
+            #[schema(exclusive_minimum = 0, exclusive_maximum = 100)]
+            count: u32,
+
+            #[schema(exclusive_minimum = -0.5, maximum = 1.5)]
+            ratio: f64,
+        }
+    };
+
+    let config = Config::new(CompareMode::Strict).numeric_mode(NumericMode::AssumeFloat);
+
+    assert_json_matches!(
+        value,
+        json!({
+            "properties": {
+                "count": {
+                    "type": "integer",
+                    "format": "int32",
+                    "exclusiveMinimum": 0.0,
+                    "exclusiveMaximum": 100.0,
+                },
+                "ratio": {
+                    "type": "number",
+                    "format": "double",
+                    "exclusiveMinimum": -0.5,
+                    "maximum": 1.5,
+                }
+            },
+            "type": "object",
+            "required": [
+                "count",
+                "ratio"
+            ]
+        }),
+        config
+    );
+}
+
+#[test]
+fn derive_struct_with_unique_items() {
+    let value = api_doc! {
+        struct Item {
+            #[schema(min_items = 1, max_items = 10, unique_items)]
+            tags: Vec<String>,
+
+            #[schema(unique_items = false)]
+            values: Vec<i32>,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "tags": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    },
+                    "minItems": 1,
+                    "maxItems": 10,
+                    "uniqueItems": true,
+                },
+                "values": {
+                    "type": "array",
+                    "items": {
+                        "type": "integer",
+                        "format": "int32"
+                    },
+                }
+            },
+            "type": "object",
+            "required": [
+                "tags",
+                "values"
+            ]
+        })
+    );
+}
+
+#[test]
+fn derive_struct_with_extensions() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Owner {
+        name: String,
+    }
+
+    let value = api_doc! {
+        #[schema(extensions("x-internal" = true, "x-owner" = json!({"team": "pets"})))]
+        struct Pet {
+            #[schema(extensions("x-order" = 1))]
+            name: String,
+            #[schema(extensions("x-tags" = "list"))]
+            tags: Vec<String>,
+            #[schema(extensions("x-ref" = "owner"))]
+            owner: Owner,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "x-order": 1
+                },
+                "tags": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    },
+                    "x-tags": "list"
+                },
+                "owner": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Owner"
+                        }
+                    ],
+                    "x-ref": "owner"
+                }
+            },
+            "type": "object",
+            "required": [
+                "name",
+                "tags",
+                "owner"
+            ],
+            "x-internal": true,
+            "x-owner": {
+                "team": "pets"
+            }
+        })
+    )
+}
+
 #[test]
 fn derive_schema_with_slice_and_array() {
     let value = api_doc! {
@@ -4275,7 +10258,9 @@ fn derive_schema_with_slice_and_array() {
                     "type": "array",
                     "items": {
                         "type": "string"
-                    }
+                    },
+                    "maxItems": 10,
+                    "minItems": 10
                 },
                 "slice": {
                     "type": "array",
@@ -4431,6 +10416,19 @@ fn derive_schema_with_generics_and_lifetimes() {
 
     let config = Config::new(CompareMode::Strict).numeric_mode(NumericMode::AssumeFloat);
 
+    #[cfg(not(feature = "openapi_31"))]
+    let nullable_string = json!({
+        "type": "string",
+        "nullable": true,
+    });
+    #[cfg(feature = "openapi_31")]
+    let nullable_string = json!({
+        "type": ["string", "null"],
+    });
+
+    #[cfg(feature = "auto_title")]
+    let value =
+        common::without_auto_titles(value, &[("/0/1", "Paginated1"), ("/1/1", "Paginated2")]);
     assert_json_matches!(
         value,
         json!([
@@ -4444,14 +10442,8 @@ fn derive_schema_with_generics_and_lifetimes() {
                                 "type": "string"
                             }
                     },
-                    "next": {
-                        "type": "string",
-                        "nullable": true,
-                    },
-                    "prev": {
-                        "type": "string",
-                        "nullable": true,
-                    },
+                    "next": nullable_string,
+                    "prev": nullable_string,
                     "total": {
                         "type": "integer",
                         "minimum": 0.0,
@@ -4474,14 +10466,8 @@ fn derive_schema_with_generics_and_lifetimes() {
                                 "type": "boolean"
                             }
                         },
-                        "next": {
-                            "type": "string",
-                            "nullable": true,
-                        },
-                        "prev": {
-                            "type": "string",
-                            "nullable": true,
-                        },
+                        "next": nullable_string,
+                        "prev": nullable_string,
                         "total": {
                             "type": "integer",
                             "minimum": 0.0
@@ -4519,6 +10505,8 @@ fn derive_struct_with_unit_alias() {
     let doc_value = serde_json::to_value(&doc).unwrap();
     let unit = doc_value.pointer("/components/schemas/TupleUnit").unwrap();
 
+    #[cfg(feature = "auto_title")]
+    let value = common::without_auto_titles(value, &[("/0/1", "UnitDataValue")]);
     assert_json_eq!(
         value,
         json!([[
@@ -4789,27 +10777,47 @@ fn derive_nullable_tuple() {
         }
     };
 
+    #[cfg(not(feature = "openapi_31"))]
+    let info = json!({
+        "items": {
+            "allOf": [
+                {
+                    "type": "string"
+                },
+                {
+                    "type": "integer",
+                    "format": "int64",
+                },
+            ]
+        },
+        "type": "array",
+        "nullable": true,
+        "deprecated": true,
+        "description": "This is description",
+    });
+    #[cfg(feature = "openapi_31")]
+    let info = json!({
+        "items": {
+            "allOf": [
+                {
+                    "type": "string"
+                },
+                {
+                    "type": "integer",
+                    "format": "int64",
+                },
+            ]
+        },
+        "type": ["array", "null"],
+        "deprecated": true,
+        "description": "This is description",
+    });
+
     assert_json_eq!(
         value,
         json!({
             "properties": {
-                "info": {
-                    "items": {
-                        "allOf": [
-                            {
-                                "type": "string"
-                            },
-                            {
-                                "type": "integer",
-                                "format": "int64",
-                            },
-                        ]
-                    },
-                    "type": "array",
-                    "nullable": true,
-                    "deprecated": true,
-                    "description": "This is description",
-                }
+                "info": info
             },
             "type": "object",
         })
@@ -4830,6 +10838,18 @@ fn derive_unit_type_untagged_enum() {
         }
     };
 
+    #[cfg(not(feature = "openapi_31"))]
+    let breakdown = json!({
+        "type": "object",
+        "nullable": true,
+        "default": null,
+    });
+    #[cfg(feature = "openapi_31")]
+    let breakdown = json!({
+        "type": "null",
+        "default": null,
+    });
+
     assert_json_eq!(
         value,
         json!({
@@ -4837,11 +10857,7 @@ fn derive_unit_type_untagged_enum() {
                 {
                     "$ref": "#/components/schemas/AggregationRequest"
                 },
-                {
-                    "type": "object",
-                    "nullable": true,
-                    "default": null,
-                }
+                breakdown
             ]
         })
     )
@@ -4990,6 +11006,69 @@ fn derive_struct_with_rc() {
     )
 }
 
+#[test]
+#[cfg(feature = "rc_schema")]
+fn derive_struct_with_smart_pointers_to_ref() {
+    use std::{rc::Rc, sync::Arc};
+
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct User {
+        name: String,
+    }
+
+    let pointers = api_doc! {
+        struct Pointers {
+            boxed: Box<User>,
+            rc: Rc<User>,
+            arc: Arc<User>,
+        }
+    };
+
+    assert_json_eq!(
+        pointers,
+        json!({
+            "properties": {
+                "boxed": {"$ref": "#/components/schemas/User"},
+                "rc": {"$ref": "#/components/schemas/User"},
+                "arc": {"$ref": "#/components/schemas/User"}
+            },
+            "required": ["boxed", "rc", "arc"],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_boxed_trait_object_value_type() {
+    #[allow(unused)]
+    trait Animal {}
+
+    let zoo = api_doc! {
+        struct Zoo {
+            #[schema(value_type = String)]
+            animal: Box<dyn Animal>,
+            #[schema(value_type = Vec<Object>)]
+            animals: Vec<Box<dyn Animal + Send>>,
+        }
+    };
+
+    assert_json_eq!(
+        zoo,
+        json!({
+            "properties": {
+                "animal": {"type": "string"},
+                "animals": {
+                    "type": "array",
+                    "items": {"type": "object"}
+                }
+            },
+            "required": ["animal", "animals"],
+            "type": "object"
+        })
+    )
+}
+
 #[test]
 fn derive_btreeset() {
     use std::collections::BTreeSet;
@@ -5050,6 +11129,222 @@ fn derive_hashset() {
     )
 }
 
+#[test]
+fn derive_set_with_min_and_max_items() {
+    use std::collections::{BTreeSet, HashSet};
+
+    let value = api_doc! {
+        struct Item {
+            #[schema(min_items = 1, max_items = 5)]
+            list: Vec<String>,
+
+            #[schema(min_items = 1, max_items = 5)]
+            hash_set: HashSet<String>,
+
+            #[schema(min_items = 2)]
+            btree_set: BTreeSet<i32>,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "list": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    },
+                    "minItems": 1,
+                    "maxItems": 5,
+                },
+                "hash_set": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    },
+                    "minItems": 1,
+                    "maxItems": 5,
+                    "uniqueItems": true,
+                },
+                "btree_set": {
+                    "type": "array",
+                    "items": {
+                        "type": "integer",
+                        "format": "int32"
+                    },
+                    "minItems": 2,
+                    "uniqueItems": true,
+                }
+            },
+            "type": "object",
+            "required": [
+                "list",
+                "hash_set",
+                "btree_set"
+            ]
+        })
+    )
+}
+
+#[cfg(feature = "validator")]
+#[test]
+fn derive_struct_with_validator_length() {
+    use validator::Validate;
+
+    let value = api_doc! {
+        #[derive(Validate)]
+        struct User {
+            #[validate(length(min = 1, max = 20))]
+            name: String,
+            #[validate(email, length(max = 100, message = "too long"))]
+            email: Option<String>,
+            #[validate(length(equal = 5))]
+            code: String,
+            #[validate(length(min = 1, max = 10))]
+            tags: Vec<String>,
+            #[validate(length(min = 2))]
+            #[schema(min_length = 4)]
+            nickname: String,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 20
+                },
+                "email": {
+                    "type": "string",
+                    "nullable": true,
+                    "maxLength": 100
+                },
+                "code": {
+                    "type": "string",
+                    "minLength": 5,
+                    "maxLength": 5
+                },
+                "tags": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    },
+                    "minItems": 1,
+                    "maxItems": 10
+                },
+                "nickname": {
+                    "type": "string",
+                    "minLength": 4
+                }
+            },
+            "required": ["name", "code", "tags", "nickname"]
+        })
+    );
+}
+
+#[cfg(feature = "validator")]
+#[test]
+fn derive_struct_with_validator_range() {
+    use validator::Validate;
+
+    let value = api_doc! {
+        #[derive(Validate)]
+        struct Item {
+            #[validate(range(min = 1, max = 5))]
+            rating: i32,
+            #[validate(range(min = -1.5, max = 2.5))]
+            offset: f64,
+            #[validate(range(exclusive_min = 0.0, exclusive_max = 1.0))]
+            ratio: f32,
+            #[validate(range(max = 100))]
+            percent: Option<u8>,
+            #[validate(range(min = 0, max = 10))]
+            #[schema(maximum = 5)]
+            level: i64,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "object",
+            "properties": {
+                "rating": {
+                    "type": "integer",
+                    "format": "int32",
+                    "minimum": 1,
+                    "maximum": 5
+                },
+                "offset": {
+                    "type": "number",
+                    "format": "double",
+                    "minimum": -1.5,
+                    "maximum": 2.5
+                },
+                "ratio": {
+                    "type": "number",
+                    "format": "float",
+                    "exclusiveMinimum": 0,
+                    "exclusiveMaximum": 1
+                },
+                "percent": {
+                    "type": "integer",
+                    "format": "int32",
+                    "nullable": true,
+                    "minimum": 0,
+                    "maximum": 100
+                },
+                "level": {
+                    "type": "integer",
+                    "format": "int64",
+                    "minimum": 0,
+                    "maximum": 5
+                }
+            },
+            "required": ["rating", "offset", "ratio", "level"]
+        })
+    );
+}
+
+#[cfg(feature = "validator")]
+#[test]
+fn derive_struct_with_validator_regex() {
+    use std::sync::LazyLock;
+
+    use validator::Validate;
+
+    static USERNAME: LazyLock<regex::Regex> =
+        LazyLock::new(|| regex::Regex::new("^[a-z0-9_]+$").unwrap());
+
+    let value = api_doc! {
+        #[derive(Validate)]
+        struct User {
+            #[validate(regex(path = *USERNAME))]
+            #[schema(pattern = "^[a-z0-9_]+$")]
+            username: String,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "object",
+            "properties": {
+                "username": {
+                    "type": "string",
+                    "pattern": "^[a-z0-9_]+$"
+                }
+            },
+            "required": ["username"]
+        })
+    );
+}
+
 #[test]
 fn derive_doc_hidden() {
     let map = api_doc! {
@@ -5119,6 +11414,18 @@ fn derive_schema_with_docstring_on_tuple_variant_first_element_option() {
         }
     };
 
+    #[cfg(not(feature = "openapi_31"))]
+    let tuple_variant_with_option_first = json!({
+        "type": "string",
+        "nullable": true,
+        "description": "doc for tuple variant with Option as first element - I now produce a description"
+    });
+    #[cfg(feature = "openapi_31")]
+    let tuple_variant_with_option_first = json!({
+        "type": ["string", "null"],
+        "description": "doc for tuple variant with Option as first element - I now produce a description"
+    });
+
     assert_json_eq!(
         value,
         json!(
@@ -5130,11 +11437,7 @@ fn derive_schema_with_docstring_on_tuple_variant_first_element_option() {
                         "TupleVariantWithOptionFirst"
                       ],
                       "properties": {
-                        "TupleVariantWithOptionFirst": {
-                          "type": "string",
-                          "nullable": true,
-                          "description": "doc for tuple variant with Option as first element - I now produce a description"
-                        }
+                        "TupleVariantWithOptionFirst": tuple_variant_with_option_first
                       }
                     },
                     {
@@ -5155,57 +11458,60 @@ fn derive_schema_with_docstring_on_tuple_variant_first_element_option() {
         )
     );
 
-    let value: Value = api_doc! {
-        /// top level doc for My enum
-        enum MyEnum {
-            /// doc for tuple variant with Option as first element - I now produce a description
-            TupleVariantWithOptionFirst(Option<String>, String),
+    #[cfg(not(feature = "openapi_31"))]
+    {
+        let value: Value = api_doc! {
+            /// top level doc for My enum
+            enum MyEnum {
+                /// doc for tuple variant with Option as first element - I now produce a description
+                TupleVariantWithOptionFirst(Option<String>, String),
 
-            /// doc for tuple variant without Option as first element - I produce a description
-            TupleVariantWithOptionSecond(String, Option<String>),
-        }
-    };
+                /// doc for tuple variant without Option as first element - I produce a description
+                TupleVariantWithOptionSecond(String, Option<String>),
+            }
+        };
 
-    assert_json_eq!(
-        value,
-        json!({
-            "description": "top level doc for My enum",
-            "oneOf": [
-                {
-                    "properties": {
-                        "TupleVariantWithOptionFirst": {
-                            "description": "doc for tuple variant with Option as first element - I now produce a description",
-                            "items": {
-                                "type": "object"
-                            },
-                            "maxItems": 2,
-                            "minItems": 2,
-                            "type": "array"
-                        }
-                    },
-                    "required": [
-                        "TupleVariantWithOptionFirst"
-                    ],
-                    "type": "object"
-                },
-                {
-                    "properties": {
-                        "TupleVariantWithOptionSecond": {
-                            "description": "doc for tuple variant without Option as first element - I produce a description",
-                            "items": {
-                                "type": "object"
-                            },
-                            "maxItems": 2,
-                            "minItems": 2,
-                            "type": "array"
-                        }
+        assert_json_eq!(
+            value,
+            json!({
+                "description": "top level doc for My enum",
+                "oneOf": [
+                    {
+                        "properties": {
+                            "TupleVariantWithOptionFirst": {
+                                "description": "doc for tuple variant with Option as first element - I now produce a description",
+                                "items": {
+                                    "type": "object"
+                                },
+                                "maxItems": 2,
+                                "minItems": 2,
+                                "type": "array"
+                            }
+                        },
+                        "required": [
+                            "TupleVariantWithOptionFirst"
+                        ],
+                        "type": "object"
                     },
-                    "required": [
-                        "TupleVariantWithOptionSecond"
-                    ],
-                    "type": "object"
-                }
-            ]
-        })
-    );
+                    {
+                        "properties": {
+                            "TupleVariantWithOptionSecond": {
+                                "description": "doc for tuple variant without Option as first element - I produce a description",
+                                "items": {
+                                    "type": "object"
+                                },
+                                "maxItems": 2,
+                                "minItems": 2,
+                                "type": "array"
+                            }
+                        },
+                        "required": [
+                            "TupleVariantWithOptionSecond"
+                        ],
+                        "type": "object"
+                    }
+                ]
+            })
+        );
+    }
 }
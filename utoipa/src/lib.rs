@@ -69,8 +69,11 @@
 //! * **decimal_float** Add support for [rust_decimal](https://crates.io/crates/rust_decimal) `Decimal` type. **By default**
 //!   it is interpreted as `Number`. This feature is mutually exclusive with **decimal** and allow to change the default type used in your
 //!   documentation for `Decimal` much like `serde_with_float` feature exposed by rust_decimal.
+//!   The representation of a single field can be changed with `decimal_as` attribute regardless of the
+//!   feature flag used. See [`ToSchema` derive docs][to_schema_derive].
 //! * **uuid** Add support for [uuid](https://github.com/uuid-rs/uuid). `Uuid` type will be presented as `String` with
-//!   format `uuid` in OpenAPI spec.
+//!   format `uuid` in OpenAPI spec. The type is recognized by its name so both `Uuid` and `uuid::Uuid` work, but
+//!   an import renamed with `use uuid::Uuid as Id` needs `value_type = Uuid` to be recognized.
 //! * **ulid** Add support for [ulid](https://github.com/dylanhart/ulid-rs). `Ulid` type will be presented as `String` with
 //!   format `ulid` in OpenAPI spec.
 //! * **url** Add support for [url](https://github.com/servo/rust-url). `Url` type will be presented as `String` with
@@ -92,6 +95,24 @@
 //! * **non_strict_integers** Add support for non-standard integer formats `int8`, `int16`, `uint8`, `uint16`, `uint32`, and `uint64`.
 //! * **rc_schema** Add `ToSchema` support for `Arc<T>` and `Rc<T>` types. **Note!** serde `rc` feature flag must be enabled separately to allow
 //!   serialization and deserialization of `Arc<T>` and `Rc<T>` types. See more about [serde feature flags](https://serde.rs/feature-flags.html).
+//! * **openapi_31** Generate schemas using OpenAPI 3.1 (JSON Schema 2020-12) keywords where 3.0 has
+//!   no equivalent. E.g. tuple structs with fields of different types will be rendered as arrays
+//!   with `prefixItems` and nullable types such as `Option<String>` will be rendered with type array
+//!   _`["string", "null"]`_ or with _`oneOf`_ of _`null`_ type and the _`$ref`_ instead of
+//!   _`nullable`_. Doc comments of fields referencing other schemas are kept as _`description`_
+//!   next to the _`$ref`_. Maps with integer keys describe the keys with _`propertyNames`_.
+//!   Schema _`examples(...)`_, _`content_encoding`_ and _`content_media_type`_ attributes are only
+//!   available with this feature.
+//!   The OpenAPI document will declare version _`3.1.0`_ accordingly.
+//! * **validate_pattern** Compile regular expressions given with `pattern = ...` attribute at compile time and
+//!   report invalid patterns as compile errors. **Note!** Patterns are checked with the
+//!   [regex](https://crates.io/crates/regex) crate syntax which does not support some _ECMA-262_ features such as look-around.
+//! * **validator** Mirror `length(...)` bounds of [validator](https://crates.io/crates/validator) crate's
+//!   `#[validate(...)]` attribute of named fields as `minLength`/`maxLength` of strings and
+//!   `minItems`/`maxItems` of arrays and `range(...)` bounds as `minimum`/`maximum` of numbers.
+//!   Fields with `regex(...)` validation must define the `pattern` explicitly with `#[schema(pattern = "...")]`.
+//! * **auto_title** Use the name of the type as the _`title`_ of named field struct and enum schemas derived with
+//!   `ToSchema` unless the schema defines the `title` explicitly.
 //!
 //! Utoipa implicitly has partial support for `serde` attributes. See [`ToSchema` derive][serde] for more details.
 //!
@@ -390,6 +411,18 @@ pub trait ToSchema<'__s> {
     /// name or inlined directly to responses, request bodies or parameters.
     fn schema() -> (&'__s str, openapi::RefOr<openapi::schema::Schema>);
 
+    /// Return the name of the schema which is used to register the schema to the
+    /// [`Components`][components] and to reference it from other schemas.
+    ///
+    /// By default the name of the [`ToSchema::schema`] is returned. Derive [`macro@ToSchema`]
+    /// implements this without constructing the schema, thus types referencing each other are
+    /// able to resolve the names of each other.
+    ///
+    /// [components]: openapi::Components
+    fn name() -> &'__s str {
+        Self::schema().0
+    }
+
     /// Optional set of alias schemas for the [`ToSchema::schema`].
     ///
     /// Typically there is no need to manually implement this method but it is instead implemented
@@ -397,6 +430,33 @@ pub trait ToSchema<'__s> {
     fn aliases() -> Vec<(&'__s str, openapi::schema::Schema)> {
         Vec::new()
     }
+
+    /// Return the [`ToSchema::schema`] of the type together with the schemas of all the types it
+    /// references recursively. Schemas are returned once per name, thus types referencing each
+    /// other are supported.
+    ///
+    /// This can be used to register a type with all of its dependencies to the
+    /// [`Components`][components] at once.
+    ///
+    /// [components]: openapi::Components
+    fn schemas() -> Vec<(&'__s str, openapi::RefOr<openapi::schema::Schema>)> {
+        let mut schemas = Vec::new();
+        Self::collect_schemas(&mut schemas);
+
+        schemas
+    }
+
+    /// Add the [`ToSchema::schema`] of the type and the schemas of the types it references to
+    /// `schemas` unless a schema with the same name is already added.
+    ///
+    /// Typically there is no need to manually implement this method but it is instead implemented
+    /// by derive [`macro@ToSchema`] to collect the schemas of the referenced types.
+    fn collect_schemas(schemas: &mut Vec<(&'__s str, openapi::RefOr<openapi::schema::Schema>)>) {
+        let (name, schema) = Self::schema();
+        if !schemas.iter().any(|(existing, _)| *existing == name) {
+            schemas.push((name, schema));
+        }
+    }
 }
 
 impl<'__s, T: ToSchema<'__s>> From<T> for openapi::RefOr<openapi::schema::Schema> {
@@ -1006,6 +1066,106 @@ pub mod __dev {
             api
         }
     }
+
+    std::thread_local! {
+        /// Type names of the schemas currently being inlined in the call stack.
+        static INLINING: std::cell::RefCell<Vec<&'static str>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    /// Removes the inlined type name from [`INLINING`] once the schema has been resolved.
+    struct InliningGuard;
+
+    impl Drop for InliningGuard {
+        fn drop(&mut self) {
+            INLINING.with(|inlining| inlining.borrow_mut().pop());
+        }
+    }
+
+    /// Get inlined schema of `T` or reference to the schema of `T` by [`ToSchema::name`] if the
+    /// schema of `T` is already being inlined further up in the call stack. This breaks cycles of
+    /// types inlining each other which would otherwise recurse infinitely.
+    ///
+    /// [`ToSchema::name`]: utoipa::ToSchema::name
+    pub fn inline_schema<'s, T: utoipa::ToSchema<'s>>(
+    ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        let type_name = std::any::type_name::<T>();
+        if INLINING.with(|inlining| inlining.borrow().contains(&type_name)) {
+            return utoipa::openapi::Ref::from_schema_name(T::name()).into();
+        }
+
+        INLINING.with(|inlining| inlining.borrow_mut().push(type_name));
+        let _guard = InliningGuard;
+
+        T::schema().1
+    }
+
+    /// Reference to a type `T` of which schemas are collected with [`CollectSchemas`] when `T`
+    /// implements [`ToSchema`][utoipa::ToSchema] and ignored with [`SkipSchemas`] otherwise. This
+    /// allows types referenced by name only to not implement [`ToSchema`][utoipa::ToSchema].
+    pub struct SchemaReference<T: ?Sized>(std::marker::PhantomData<T>);
+
+    impl<T: ?Sized> SchemaReference<T> {
+        #[allow(clippy::new_without_default)]
+        pub const fn new() -> Self {
+            Self(std::marker::PhantomData)
+        }
+    }
+
+    pub trait CollectSchemas<'s> {
+        fn collect_into(
+            &self,
+            schemas: &mut Vec<(
+                &'s str,
+                utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+            )>,
+        );
+    }
+
+    impl<'s, T: utoipa::ToSchema<'s>> CollectSchemas<'s> for SchemaReference<T> {
+        fn collect_into(
+            &self,
+            schemas: &mut Vec<(
+                &'s str,
+                utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+            )>,
+        ) {
+            T::collect_schemas(schemas)
+        }
+    }
+
+    /// Resolve the name of the schema of `T` referenced by [`SchemaReference`]. Types implementing
+    /// [`ToSchema`][utoipa::ToSchema] are referenced by their [`ToSchema::name`][utoipa::ToSchema::name]
+    /// and other types by the given default name with [`DefaultSchemaName`].
+    pub trait SchemaName<'s> {
+        fn schema_name(&self, default: &'s str) -> &'s str;
+    }
+
+    impl<'s, T: utoipa::ToSchema<'s>> SchemaName<'s> for SchemaReference<T> {
+        fn schema_name(&self, _: &'s str) -> &'s str {
+            T::name()
+        }
+    }
+
+    pub trait DefaultSchemaName<'s> {
+        fn schema_name(&self, default: &'s str) -> &'s str {
+            default
+        }
+    }
+
+    impl<'s, T: ?Sized> DefaultSchemaName<'s> for &SchemaReference<T> {}
+
+    pub trait SkipSchemas<'s> {
+        fn collect_into(
+            &self,
+            _: &mut Vec<(
+                &'s str,
+                utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+            )>,
+        ) {
+        }
+    }
+
+    impl<'s, T: ?Sized> SkipSchemas<'s> for &SchemaReference<T> {}
 }
 
 #[cfg(test)]
@@ -340,12 +340,24 @@ pub enum OpenApiVersion {
     /// Will serialize to `3.0.3` the latest from 3.0 serde.
     #[serde(rename = "3.0.3")]
     Version3,
+    /// Will serialize to `3.1.0` the latest from 3.1 serde. This is the default version with
+    /// `openapi_31` feature.
+    #[cfg(feature = "openapi_31")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "openapi_31")))]
+    #[serde(rename = "3.1.0")]
+    Version31,
 }
 
 impl Default for OpenApiVersion {
+    #[cfg(not(feature = "openapi_31"))]
     fn default() -> Self {
         Self::Version3
     }
+
+    #[cfg(feature = "openapi_31")]
+    fn default() -> Self {
+        Self::Version31
+    }
 }
 
 impl<'de> Deserialize<'de> for OpenApiVersion {
@@ -383,6 +395,8 @@ impl<'de> Deserialize<'de> for OpenApiVersion {
 
                 Ok(match (parts[0], parts.get(1).copied().unwrap_or("0")) {
                     ("3", "0") => OpenApiVersion::Version3,
+                    #[cfg(feature = "openapi_31")]
+                    ("3", "1") => OpenApiVersion::Version31,
                     _ => return Err(E::custom(format!("Unsupported version: {}", &v))),
                 })
             }
@@ -619,7 +633,7 @@ mod tests {
     #[test]
     fn serialize_openapi_json_minimal_success() -> Result<(), serde_json::Error> {
         let raw_json = include_str!("openapi/testdata/expected_openapi_minimal.json");
-        let openapi = OpenApi::new(
+        let mut openapi = OpenApi::new(
             InfoBuilder::new()
                 .title("My api")
                 .version("1.0.0")
@@ -633,6 +647,7 @@ mod tests {
                 .build(),
             Paths::new(),
         );
+        openapi.openapi = OpenApiVersion::Version3;
         let serialized = serde_json::to_string_pretty(&openapi)?;
 
         assert_eq!(
@@ -644,7 +659,7 @@ mod tests {
 
     #[test]
     fn serialize_openapi_json_with_paths_success() -> Result<(), serde_json::Error> {
-        let openapi = OpenApi::new(
+        let mut openapi = OpenApi::new(
             Info::new("My big api", "1.1.0"),
             PathsBuilder::new()
                 .path(
@@ -669,6 +684,7 @@ mod tests {
                     ),
                 ),
         );
+        openapi.openapi = OpenApiVersion::Version3;
 
         let serialized = serde_json::to_string_pretty(&openapi)?;
         let expected = include_str!("./openapi/testdata/expected_openapi_with_paths.json");
@@ -747,7 +763,7 @@ mod tests {
             value,
             json!(
                 {
-                  "openapi": "3.0.3",
+                  "openapi": OpenApiVersion::default(),
                   "info": {
                     "title": "Api",
                     "version": "v1"
@@ -874,7 +890,7 @@ mod tests {
             value,
             json!(
                 {
-                  "openapi": "3.0.3",
+                  "openapi": OpenApiVersion::default(),
                   "info": {
                     "title": "Api",
                     "version": "v1"
@@ -930,6 +946,27 @@ mod tests {
         )
     }
 
+    #[cfg(feature = "openapi_31")]
+    #[test]
+    fn serialize_openapi_json_with_openapi_31_version() -> Result<(), serde_json::Error> {
+        let openapi = OpenApi::new(Info::new("My api", "1.0.0"), Paths::new());
+        let value = serde_json::to_value(&openapi)?;
+
+        assert_eq!(value.get("openapi"), Some(&json!("3.1.0")));
+        Ok(())
+    }
+
+    #[cfg(feature = "openapi_31")]
+    #[test]
+    fn deserialize_openapi_31_versions() {
+        [r#""3.1.0""#, r#""3.1""#].iter().for_each(|v| {
+            assert!(matches!(
+                serde_json::from_str::<OpenApiVersion>(v).unwrap(),
+                OpenApiVersion::Version31,
+            ));
+        });
+    }
+
     #[test]
     fn deserialize_other_versions() {
         [r#""3.0.3""#, r#""3.0.0""#, r#""3.0""#, r#""3""#]
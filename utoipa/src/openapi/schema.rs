@@ -2,9 +2,9 @@
 //! used to define field properties, enum values, array or object types.
 //!
 //! [schema]: https://spec.openapis.org/oas/latest.html#schema-object
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 
 use super::RefOr;
@@ -286,7 +286,8 @@ pub struct Discriminator {
     pub property_name: String,
 
     /// An object to hold mappings between payload values and schema names or references.
-    /// This field can only be populated manually. There is no macro support and no
+    /// The derive macro populates this for internally tagged enums with newtype variants
+    /// wrapping a named schema. Otherwise this field can only be populated manually and there is no
     /// validation.
     #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
     pub mapping: BTreeMap<String, String>,
@@ -308,6 +309,37 @@ impl Discriminator {
             mapping: BTreeMap::new(),
         }
     }
+
+    /// Construct a new [`Discriminator`] object with property name and mappings between
+    /// payload values and schema references.
+    ///
+    /// # Examples
+    ///
+    /// Create a new [`Discriminator`] object for `pet_type` property with mapping to `Dog`.
+    /// ```rust
+    /// # use utoipa::openapi::schema::Discriminator;
+    /// let discriminator = Discriminator::with_mapping(
+    ///     "pet_type",
+    ///     [("dog", "#/components/schemas/Dog")],
+    /// );
+    /// ```
+    pub fn with_mapping<
+        P: Into<String>,
+        M: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    >(
+        property_name: P,
+        mapping: M,
+    ) -> Self {
+        Self {
+            property_name: property_name.into(),
+            mapping: mapping
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        }
+    }
 }
 
 builder! {
@@ -342,6 +374,10 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub example: Option<Value>,
 
+        /// Examples shown in UI of the value for richer documentation.
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub examples: Vec<Value>,
+
         /// Optional discriminator field can be used to aid deserialization, serialization and validation of a
         /// specific schema.
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -350,6 +386,14 @@ builder! {
         /// Set `true` to allow `"null"` to be used as value for given type.
         #[serde(default, skip_serializing_if = "is_false")]
         pub nullable: bool,
+
+        /// Optional extensions "x-something".
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "deserialize_extensions"
+        )]
+        pub extensions: Option<HashMap<String, Value>>,
     }
 }
 
@@ -411,6 +455,11 @@ impl OneOfBuilder {
         set_value!(self example example)
     }
 
+    /// Add or change examples shown in UI of the value for richer documentation.
+    pub fn examples<I: IntoIterator<Item = V>, V: Into<Value>>(mut self, examples: I) -> Self {
+        set_value!(self examples examples.into_iter().map(Into::into).collect())
+    }
+
     /// Add or change discriminator field of the composite [`OneOf`] type.
     pub fn discriminator(mut self, discriminator: Option<Discriminator>) -> Self {
         set_value!(self discriminator discriminator)
@@ -421,6 +470,11 @@ impl OneOfBuilder {
         set_value!(self nullable nullable)
     }
 
+    /// Add openapi extensions (x-something) to the [`OneOf`].
+    pub fn extensions(mut self, extensions: Option<HashMap<String, Value>>) -> Self {
+        set_value!(self extensions extensions)
+    }
+
     to_array_builder!();
 }
 
@@ -470,6 +524,10 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub example: Option<Value>,
 
+        /// Examples shown in UI of the value for richer documentation.
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub examples: Vec<Value>,
+
         /// Optional discriminator field can be used to aid deserialization, serialization and validation of a
         /// specific schema.
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -478,6 +536,34 @@ builder! {
         /// Set `true` to allow `"null"` to be used as value for given type.
         #[serde(default, skip_serializing_if = "is_false")]
         pub nullable: bool,
+
+        /// Write only property will be only sent in _write_ requests like _POST, PUT_.
+        #[serde(rename = "writeOnly", skip_serializing_if = "Option::is_none")]
+        pub write_only: Option<bool>,
+
+        /// Read only property will be only sent in _read_ requests like _GET_.
+        #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+        pub read_only: Option<bool>,
+
+        /// Additional [`Xml`] formatting of the [`AllOf`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub xml: Option<Xml>,
+
+        /// [`Schema`] for properties not evaluated by any of the [`AllOf`] items. Set to
+        /// _`false`_ to deny unknown properties of the combined schema.
+        #[serde(
+            rename = "unevaluatedProperties",
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub unevaluated_properties: Option<Box<AdditionalProperties<Schema>>>,
+
+        /// Optional extensions "x-something".
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "deserialize_extensions"
+        )]
+        pub extensions: Option<HashMap<String, Value>>,
     }
 }
 
@@ -539,6 +625,11 @@ impl AllOfBuilder {
         set_value!(self example example)
     }
 
+    /// Add or change examples shown in UI of the value for richer documentation.
+    pub fn examples<I: IntoIterator<Item = V>, V: Into<Value>>(mut self, examples: I) -> Self {
+        set_value!(self examples examples.into_iter().map(Into::into).collect())
+    }
+
     /// Add or change discriminator field of the composite [`AllOf`] type.
     pub fn discriminator(mut self, discriminator: Option<Discriminator>) -> Self {
         set_value!(self discriminator discriminator)
@@ -549,6 +640,34 @@ impl AllOfBuilder {
         set_value!(self nullable nullable)
     }
 
+    /// Add or change write only flag for [`AllOf`].
+    pub fn write_only(mut self, write_only: Option<bool>) -> Self {
+        set_value!(self write_only write_only)
+    }
+
+    /// Add or change read only flag for [`AllOf`].
+    pub fn read_only(mut self, read_only: Option<bool>) -> Self {
+        set_value!(self read_only read_only)
+    }
+
+    /// Add or change additional [`Xml`] formatting of the [`AllOf`].
+    pub fn xml(mut self, xml: Option<Xml>) -> Self {
+        set_value!(self xml xml)
+    }
+
+    /// Add or change `unevaluatedProperties` of the [`AllOf`].
+    pub fn unevaluated_properties<I: Into<AdditionalProperties<Schema>>>(
+        mut self,
+        unevaluated_properties: Option<I>,
+    ) -> Self {
+        set_value!(self unevaluated_properties unevaluated_properties.map(|unevaluated_properties| Box::new(unevaluated_properties.into())))
+    }
+
+    /// Add openapi extensions (x-something) to the [`AllOf`].
+    pub fn extensions(mut self, extensions: Option<HashMap<String, Value>>) -> Self {
+        set_value!(self extensions extensions)
+    }
+
     to_array_builder!();
 }
 
@@ -594,6 +713,10 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub example: Option<Value>,
 
+        /// Examples shown in UI of the value for richer documentation.
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub examples: Vec<Value>,
+
         /// Optional discriminator field can be used to aid deserialization, serialization and validation of a
         /// specific schema.
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -602,6 +725,14 @@ builder! {
         /// Set `true` to allow `"null"` to be used as value for given type.
         #[serde(default, skip_serializing_if = "is_false")]
         pub nullable: bool,
+
+        /// Optional extensions "x-something".
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "deserialize_extensions"
+        )]
+        pub extensions: Option<HashMap<String, Value>>,
     }
 }
 
@@ -658,6 +789,11 @@ impl AnyOfBuilder {
         set_value!(self example example)
     }
 
+    /// Add or change examples shown in UI of the value for richer documentation.
+    pub fn examples<I: IntoIterator<Item = V>, V: Into<Value>>(mut self, examples: I) -> Self {
+        set_value!(self examples examples.into_iter().map(Into::into).collect())
+    }
+
     /// Add or change discriminator field of the composite [`AnyOf`] type.
     pub fn discriminator(mut self, discriminator: Option<Discriminator>) -> Self {
         set_value!(self discriminator discriminator)
@@ -668,6 +804,11 @@ impl AnyOfBuilder {
         set_value!(self nullable nullable)
     }
 
+    /// Add openapi extensions (x-something) to the [`AnyOf`].
+    pub fn extensions(mut self, extensions: Option<HashMap<String, Value>>) -> Self {
+        set_value!(self extensions extensions)
+    }
+
     to_array_builder!();
 }
 
@@ -690,6 +831,11 @@ type ObjectPropertiesMap<K, V> = BTreeMap<K, V>;
 #[cfg(feature = "preserve_order")]
 type ObjectPropertiesMap<K, V> = indexmap::IndexMap<K, V>;
 
+#[cfg(not(feature = "openapi_31"))]
+type ObjectSchemaType = SchemaType;
+#[cfg(feature = "openapi_31")]
+type ObjectSchemaType = SchemaTypes;
+
 builder! {
     ObjectBuilder;
 
@@ -705,9 +851,10 @@ builder! {
     #[serde(rename_all = "camelCase")]
     pub struct Object {
         /// Type of [`Object`] e.g. [`SchemaType::Object`] for `object` and [`SchemaType::String`] for
-        /// `string` types.
-        #[serde(rename = "type", skip_serializing_if="SchemaType::is_value")]
-        pub schema_type: SchemaType,
+        /// `string` types. With `openapi_31` feature this is [`SchemaTypes`] which can also hold
+        /// a type array.
+        #[serde(rename = "type", skip_serializing_if="ObjectSchemaType::is_value")]
+        pub schema_type: ObjectSchemaType,
 
         /// Changes the [`Object`] title.
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -729,6 +876,11 @@ builder! {
         #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
         pub enum_values: Option<Vec<Value>>,
 
+        /// Constant value of the [`Object`]. Value must be equal to this value to be valid.
+        /// Only available in OpenAPI 3.1, use single value [`Object::enum_values`] with OpenAPI 3.0.
+        #[serde(rename = "const", skip_serializing_if = "Option::is_none")]
+        pub const_value: Option<Value>,
+
         /// Vector of required field names.
         #[serde(skip_serializing_if = "Vec::is_empty", default = "Vec::new")]
         pub required: Vec<String>,
@@ -747,6 +899,11 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub additional_properties: Option<Box<AdditionalProperties<Schema>>>,
 
+        /// [`Schema`] which every property name of the [`Object`] must be valid against. Useful
+        /// for typed maps whose keys are not arbitrary strings.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub property_names: Option<Box<Schema>>,
+
         /// Changes the [`Object`] deprecated status.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub deprecated: Option<Deprecated>,
@@ -755,6 +912,10 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub example: Option<Value>,
 
+        /// Examples shown in UI of the value for richer documentation.
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub examples: Vec<Value>,
+
         /// Write only property will be only sent in _write_ requests like _POST, PUT_.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub write_only: Option<bool>,
@@ -812,6 +973,16 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub pattern: Option<String>,
 
+        /// Define encoding used to represent the `string` content e.g. _`base64`_. See
+        /// [`contentEncoding`](https://json-schema.org/draft/2020-12/json-schema-validation#name-contentencoding).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub content_encoding: Option<String>,
+
+        /// Define media type of the `string` content e.g. _`image/png`_. See
+        /// [`contentMediaType`](https://json-schema.org/draft/2020-12/json-schema-validation#name-contentmediatype).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub content_media_type: Option<String>,
+
         /// Specify inclusive maximum amount of properties an [`Object`] can hold.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub max_properties: Option<usize>,
@@ -820,6 +991,14 @@ builder! {
         /// `0` will have same effect as omitting the attribute.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub min_properties: Option<usize>,
+
+        /// Optional extensions "x-something".
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "deserialize_extensions"
+        )]
+        pub extensions: Option<HashMap<String, Value>>,
     }
 }
 
@@ -827,6 +1006,19 @@ fn is_false(value: &bool) -> bool {
     !*value
 }
 
+/// Deserialize the `x-` prefixed keys of the schema as extensions. Other keys not known by the
+/// schema are ignored and `None` is returned if there are no extensions.
+fn deserialize_extensions<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<HashMap<String, Value>>, D::Error> {
+    let extensions = HashMap::<String, Value>::deserialize(deserializer)?
+        .into_iter()
+        .filter(|(key, _)| key.starts_with("x-"))
+        .collect::<HashMap<_, _>>();
+
+    Ok((!extensions.is_empty()).then_some(extensions))
+}
+
 impl Object {
     /// Initialize a new [`Object`] with default [`SchemaType`]. This effectively same as calling
     /// `Object::with_type(SchemaType::Object)`.
@@ -843,9 +1035,9 @@ impl Object {
     /// # use utoipa::openapi::schema::{Object, SchemaType};
     /// let object = Object::with_type(SchemaType::String);
     /// ```
-    pub fn with_type(schema_type: SchemaType) -> Self {
+    pub fn with_type<T: Into<ObjectSchemaType>>(schema_type: T) -> Self {
         Self {
-            schema_type,
+            schema_type: schema_type.into(),
             ..Default::default()
         }
     }
@@ -861,8 +1053,8 @@ impl ToArray for Object {}
 
 impl ObjectBuilder {
     /// Add or change type of the object e.g [`SchemaType::String`].
-    pub fn schema_type(mut self, schema_type: SchemaType) -> Self {
-        set_value!(self schema_type schema_type)
+    pub fn schema_type<T: Into<ObjectSchemaType>>(mut self, schema_type: T) -> Self {
+        set_value!(self schema_type schema_type.into())
     }
 
     /// Add or change additional format for detailing the schema type.
@@ -891,6 +1083,11 @@ impl ObjectBuilder {
         set_value!(self additional_properties additional_properties.map(|additional_properties| Box::new(additional_properties.into())))
     }
 
+    /// Add or change [`Schema`] which every property name of the [`Object`] must be valid against.
+    pub fn property_names<I: Into<Schema>>(mut self, property_names: Option<I>) -> Self {
+        set_value!(self property_names property_names.map(|property_names| Box::new(property_names.into())))
+    }
+
     /// Add field to the required fields of [`Object`].
     pub fn required<I: Into<String>>(mut self, required_field: I) -> Self {
         self.required.push(required_field.into());
@@ -927,11 +1124,23 @@ impl ObjectBuilder {
             enum_values.map(|values| values.into_iter().map(|enum_value| enum_value.into()).collect()))
     }
 
+    /// Add or change constant value of the [`Object`].
+    #[cfg(feature = "openapi_31")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "openapi_31")))]
+    pub fn const_value<V: Into<Value>>(mut self, const_value: Option<V>) -> Self {
+        set_value!(self const_value const_value.map(|const_value| const_value.into()))
+    }
+
     /// Add or change example shown in UI of the value for richer documentation.
     pub fn example(mut self, example: Option<Value>) -> Self {
         set_value!(self example example)
     }
 
+    /// Add or change examples shown in UI of the value for richer documentation.
+    pub fn examples<I: IntoIterator<Item = V>, V: Into<Value>>(mut self, examples: I) -> Self {
+        set_value!(self examples examples.into_iter().map(Into::into).collect())
+    }
+
     /// Add or change write only flag for [`Object`].
     pub fn write_only(mut self, write_only: Option<bool>) -> Self {
         set_value!(self write_only write_only)
@@ -992,6 +1201,16 @@ impl ObjectBuilder {
         set_value!(self pattern pattern.map(|pattern| pattern.into()))
     }
 
+    /// Set or change encoding used to represent the `string` content.
+    pub fn content_encoding<I: Into<String>>(mut self, content_encoding: Option<I>) -> Self {
+        set_value!(self content_encoding content_encoding.map(|content_encoding| content_encoding.into()))
+    }
+
+    /// Set or change media type of the `string` content.
+    pub fn content_media_type<I: Into<String>>(mut self, content_media_type: Option<I>) -> Self {
+        set_value!(self content_media_type content_media_type.map(|content_media_type| content_media_type.into()))
+    }
+
     /// Set or change maximum number of properties the [`Object`] can hold.
     pub fn max_properties(mut self, max_properties: Option<usize>) -> Self {
         set_value!(self max_properties max_properties)
@@ -1002,6 +1221,11 @@ impl ObjectBuilder {
         set_value!(self min_properties min_properties)
     }
 
+    /// Add openapi extensions (x-something) to the [`Object`].
+    pub fn extensions(mut self, extensions: Option<HashMap<String, Value>>) -> Self {
+        set_value!(self extensions extensions)
+    }
+
     to_array_builder!();
 }
 
@@ -1044,6 +1268,24 @@ impl From<ArrayBuilder> for AdditionalProperties<Schema> {
     }
 }
 
+impl From<OneOfBuilder> for AdditionalProperties<Schema> {
+    fn from(value: OneOfBuilder) -> Self {
+        Self::RefOr(RefOr::T(Schema::OneOf(value.build())))
+    }
+}
+
+impl From<AllOfBuilder> for AdditionalProperties<Schema> {
+    fn from(value: AllOfBuilder) -> Self {
+        Self::RefOr(RefOr::T(Schema::AllOf(value.build())))
+    }
+}
+
+impl From<AnyOfBuilder> for AdditionalProperties<Schema> {
+    fn from(value: AnyOfBuilder) -> Self {
+        Self::RefOr(RefOr::T(Schema::AnyOf(value.build())))
+    }
+}
+
 impl From<Ref> for AdditionalProperties<Schema> {
     fn from(value: Ref) -> Self {
         Self::RefOr(RefOr::Ref(value))
@@ -1067,6 +1309,17 @@ pub struct Ref {
     /// Reference location of the actual component.
     #[serde(rename = "$ref")]
     pub ref_location: String,
+
+    /// A description which by default should override that of the referenced component.
+    /// Description supports markdown syntax. Only allowed in OpenAPI 3.1 since 3.0 ignores
+    /// siblings of _`$ref`_.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description: Option<String>,
+
+    /// A short summary which by default should override that of the referenced component.
+    /// Only allowed in OpenAPI 3.1 since 3.0 ignores siblings of _`$ref`_.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub summary: Option<String>,
 }
 
 impl Ref {
@@ -1075,6 +1328,7 @@ impl Ref {
     pub fn new<I: Into<String>>(ref_location: I) -> Self {
         Self {
             ref_location: ref_location.into(),
+            ..Default::default()
         }
     }
 
@@ -1090,6 +1344,18 @@ impl Ref {
         Self::new(format!("#/components/responses/{}", response_name.into()))
     }
 
+    /// Add or change description which by default should override that of the referenced
+    /// component.
+    pub fn description<I: Into<String>>(mut self, description: Option<I>) -> Self {
+        set_value!(self description description.map(|description| description.into()))
+    }
+
+    /// Add or change short summary which by default should override that of the referenced
+    /// component.
+    pub fn summary<I: Into<String>>(mut self, summary: Option<I>) -> Self {
+        set_value!(self summary summary.map(|summary| summary.into()))
+    }
+
     to_array_builder!();
 }
 
@@ -1151,9 +1417,11 @@ builder! {
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[serde(rename_all = "camelCase")]
     pub struct Array {
-        /// Type will always be [`SchemaType::Array`].
+        /// Type will always be [`SchemaType::Array`]. With `openapi_31` feature this is
+        /// [`SchemaTypes`] which is a type array of [`Type::Array`] and [`Type::Null`] for
+        /// nullable [`Array`].
         #[serde(rename = "type")]
-        pub schema_type: SchemaType,
+        pub schema_type: ObjectSchemaType,
 
         /// Changes the [`Array`] title.
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -1162,6 +1430,11 @@ builder! {
         /// Schema representing the array items type.
         pub items: Box<RefOr<Schema>>,
 
+        /// Schemas of the leading items of the [`Array`] by their position. Used to describe
+        /// fixed length tuples where each position has its own type.
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub prefix_items: Vec<RefOr<Schema>>,
+
         /// Description of the [`Array`]. Markdown syntax is supported.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
@@ -1174,6 +1447,10 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub example: Option<Value>,
 
+        /// Examples shown in UI of the value for richer documentation.
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub examples: Vec<Value>,
+
         /// Default value which is provided when user has not provided the input in Swagger UI.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub default: Option<Value>,
@@ -1198,6 +1475,14 @@ builder! {
         /// Set `true` to allow `"null"` to be used as value for given type.
         #[serde(default, skip_serializing_if = "is_false")]
         pub nullable: bool,
+
+        /// Optional extensions "x-something".
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            flatten,
+            deserialize_with = "deserialize_extensions"
+        )]
+        pub extensions: Option<HashMap<String, Value>>,
     }
 }
 
@@ -1205,17 +1490,20 @@ impl Default for Array {
     fn default() -> Self {
         Self {
             title: Default::default(),
-            schema_type: SchemaType::Array,
+            schema_type: SchemaType::Array.into(),
             unique_items: bool::default(),
             items: Default::default(),
+            prefix_items: Vec::new(),
             description: Default::default(),
             deprecated: Default::default(),
             example: Default::default(),
+            examples: Vec::new(),
             default: Default::default(),
             max_items: Default::default(),
             min_items: Default::default(),
             xml: Default::default(),
             nullable: Default::default(),
+            extensions: Default::default(),
         }
     }
 }
@@ -1239,11 +1527,36 @@ impl Array {
 }
 
 impl ArrayBuilder {
+    /// Change type of the [`Array`]. With OpenAPI 3.1 nullable [`Array`] is defined with type
+    /// array _`["array", "null"]`_ instead of _`nullable`_.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::schema::{ArrayBuilder, Object, SchemaType, SchemaTypes};
+    /// let nullable_array = ArrayBuilder::new()
+    ///     .schema_type(SchemaTypes::nullable(SchemaType::Array))
+    ///     .items(Object::with_type(SchemaType::String));
+    /// ```
+    #[cfg(feature = "openapi_31")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "openapi_31")))]
+    pub fn schema_type<T: Into<SchemaTypes>>(mut self, schema_type: T) -> Self {
+        set_value!(self schema_type schema_type.into())
+    }
+
     /// Set [`Schema`] type for the [`Array`].
     pub fn items<I: Into<RefOr<Schema>>>(mut self, component: I) -> Self {
         set_value!(self items Box::new(component.into()))
     }
 
+    /// Set positional [`Schema`]s for the leading items of the [`Array`].
+    pub fn prefix_items<I: IntoIterator<Item = S>, S: Into<RefOr<Schema>>>(
+        mut self,
+        prefix_items: I,
+    ) -> Self {
+        set_value!(self prefix_items prefix_items.into_iter().map(|item| item.into()).collect())
+    }
+
     /// Add or change the title of the [`Array`].
     pub fn title<I: Into<String>>(mut self, title: Option<I>) -> Self {
         set_value!(self title title.map(|title| title.into()))
@@ -1264,6 +1577,11 @@ impl ArrayBuilder {
         set_value!(self example example)
     }
 
+    /// Add or change examples shown in UI of the value for richer documentation.
+    pub fn examples<I: IntoIterator<Item = V>, V: Into<Value>>(mut self, examples: I) -> Self {
+        set_value!(self examples examples.into_iter().map(Into::into).collect())
+    }
+
     /// Add or change default value for the object which is provided when user has not provided the input in Swagger UI.
     pub fn default(mut self, default: Option<Value>) -> Self {
         set_value!(self default default)
@@ -1294,6 +1612,11 @@ impl ArrayBuilder {
         set_value!(self nullable nullable)
     }
 
+    /// Add openapi extensions (x-something) to the [`Array`].
+    pub fn extensions(mut self, extensions: Option<HashMap<String, Value>>) -> Self {
+        set_value!(self extensions extensions)
+    }
+
     to_array_builder!();
 }
 
@@ -1361,6 +1684,118 @@ impl Default for SchemaType {
     }
 }
 
+/// Represents data type or types of [`Schema`] in OpenAPI 3.1.
+///
+/// OpenAPI 3.1 has no _`nullable`_ keyword but allows the _`type`_ to be an array of types
+/// instead, e.g. nullable `string` is represented as _`["string", "null"]`_.
+///
+/// # Examples
+///
+/// _**Create nullable `string` type.**_
+/// ```rust
+/// # use utoipa::openapi::schema::{SchemaType, SchemaTypes};
+/// let nullable_string = SchemaTypes::nullable(SchemaType::String);
+/// ```
+#[cfg(feature = "openapi_31")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "openapi_31")))]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[serde(untagged)]
+pub enum SchemaTypes {
+    /// Single [`Type`] of the content.
+    Type(Type),
+    /// Multiple [`Type`]s of the content e.g. _`["string", "null"]`_.
+    Types(Vec<Type>),
+}
+
+#[cfg(feature = "openapi_31")]
+impl SchemaTypes {
+    /// Construct a new type array of given `schema_type` and [`Type::Null`].
+    ///
+    /// [`SchemaType::Value`] already allows `null` thus it is returned as is.
+    pub fn nullable<T: Into<Type>>(schema_type: T) -> Self {
+        match schema_type.into() {
+            Type::Value => Self::Type(Type::Value),
+            type_ => Self::Types(vec![type_, Type::Null]),
+        }
+    }
+
+    fn is_value(types: &SchemaTypes) -> bool {
+        *types == SchemaTypes::Type(Type::Value)
+    }
+}
+
+#[cfg(feature = "openapi_31")]
+impl Default for SchemaTypes {
+    fn default() -> Self {
+        Self::Type(Type::default())
+    }
+}
+
+#[cfg(feature = "openapi_31")]
+impl From<SchemaType> for SchemaTypes {
+    fn from(schema_type: SchemaType) -> Self {
+        Self::Type(schema_type.into())
+    }
+}
+
+#[cfg(feature = "openapi_31")]
+impl From<Type> for SchemaTypes {
+    fn from(type_: Type) -> Self {
+        Self::Type(type_)
+    }
+}
+
+/// Represents single data type of [`SchemaTypes`] in OpenAPI 3.1.
+///
+/// This is same as [`SchemaType`] with additional [`Type::Null`] type.
+#[cfg(feature = "openapi_31")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "openapi_31")))]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[serde(rename_all = "lowercase")]
+pub enum Type {
+    /// See [`SchemaType::Object`].
+    Object,
+    /// See [`SchemaType::Value`].
+    Value,
+    /// See [`SchemaType::String`].
+    String,
+    /// See [`SchemaType::Integer`].
+    Integer,
+    /// See [`SchemaType::Number`].
+    Number,
+    /// See [`SchemaType::Boolean`].
+    Boolean,
+    /// See [`SchemaType::Array`].
+    Array,
+    /// Indicates `null` type of content. Used with [`SchemaTypes::Types`] to define nullable
+    /// type.
+    Null,
+}
+
+#[cfg(feature = "openapi_31")]
+impl Default for Type {
+    fn default() -> Self {
+        Self::Object
+    }
+}
+
+#[cfg(feature = "openapi_31")]
+impl From<SchemaType> for Type {
+    fn from(schema_type: SchemaType) -> Self {
+        match schema_type {
+            SchemaType::Object => Self::Object,
+            SchemaType::Value => Self::Value,
+            SchemaType::String => Self::String,
+            SchemaType::Integer => Self::Integer,
+            SchemaType::Number => Self::Number,
+            SchemaType::Boolean => Self::Boolean,
+            SchemaType::Array => Self::Array,
+        }
+    }
+}
+
 /// Additional format for [`SchemaType`] to fine tune the data type used. If the **format** is not
 /// supported by the UI it may default back to [`SchemaType`] alone.
 /// Format is an open value, so you can use any formats, even not those defined by the
@@ -1660,6 +2095,69 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_all_of_unevaluated_properties() {
+        let json_value = AllOfBuilder::new()
+            .item(Ref::from_schema_name("Base"))
+            .unevaluated_properties(Some(AdditionalProperties::FreeForm(false)))
+            .build();
+        assert_json_eq!(
+            json_value,
+            json!({
+                "allOf": [
+                    {"$ref": "#/components/schemas/Base"}
+                ],
+                "unevaluatedProperties": false
+            })
+        );
+    }
+
+    #[test]
+    fn test_property_names() {
+        let json_value = ObjectBuilder::new()
+            .additional_properties(Some(ObjectBuilder::new().schema_type(SchemaType::String)))
+            .property_names(Some(
+                ObjectBuilder::new()
+                    .schema_type(SchemaType::String)
+                    .pattern(Some("^[0-9]+$")),
+            ))
+            .build();
+        assert_json_eq!(
+            json_value,
+            json!({
+                "type": "object",
+                "additionalProperties": {
+                    "type": "string"
+                },
+                "propertyNames": {
+                    "type": "string",
+                    "pattern": "^[0-9]+$"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_ref_with_description_and_summary() {
+        let json_value = Ref::from_schema_name("Pet")
+            .description(Some("The pet of the owner"))
+            .summary(Some("Pet"));
+        assert_json_eq!(
+            json_value,
+            json!({
+                "$ref": "#/components/schemas/Pet",
+                "description": "The pet of the owner",
+                "summary": "Pet"
+            })
+        );
+        assert_json_eq!(
+            Ref::from_schema_name("Pet"),
+            json!({
+                "$ref": "#/components/schemas/Pet"
+            })
+        );
+    }
+
     #[test]
     fn test_object_with_title() {
         let json_value = ObjectBuilder::new().title(Some("SomeName")).build();
@@ -1686,6 +2184,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn derive_object_with_examples() {
+        let expected = r#"{"type":"object","examples":[{"age":20},{"age":30}]}"#;
+        let json_value = ObjectBuilder::new()
+            .examples([json!({"age": 20}), json!({"age": 30})])
+            .build();
+
+        let value_string = serde_json::to_string(&json_value).unwrap();
+        assert_eq!(
+            value_string, expected,
+            "value string != expected string, {value_string} != {expected}"
+        );
+    }
+
     fn get_json_path<'a>(value: &'a Value, path: &str) -> &'a Value {
         path.split('.').fold(value, |acc, fragment| {
             acc.get(fragment).unwrap_or(&serde_json::value::Value::Null)
@@ -1705,7 +2217,7 @@ mod tests {
             ),
         );
 
-        assert!(matches!(array.schema_type, SchemaType::Array));
+        assert_eq!(array.schema_type, SchemaType::Array.into());
     }
 
     #[test]
@@ -1723,7 +2235,100 @@ mod tests {
             )
             .build();
 
-        assert!(matches!(array.schema_type, SchemaType::Array));
+        assert_eq!(array.schema_type, SchemaType::Array.into());
+    }
+
+    #[cfg(feature = "openapi_31")]
+    #[test]
+    fn test_object_with_type_array() {
+        let object: Object = ObjectBuilder::new()
+            .schema_type(SchemaTypes::nullable(SchemaType::String))
+            .build();
+
+        let json_value = serde_json::to_value(&object).unwrap();
+        assert_json_eq!(
+            json_value,
+            json!({
+                "type": ["string", "null"]
+            })
+        );
+
+        let deserialized: Object = serde_json::from_value(json_value).unwrap();
+        assert_eq!(deserialized, object);
+    }
+
+    #[test]
+    fn test_object_with_extensions() {
+        let object: Object = ObjectBuilder::new()
+            .schema_type(SchemaType::String)
+            .extensions(Some(HashMap::from([(
+                "x-internal".to_string(),
+                json!({"owner": "pets"}),
+            )])))
+            .build();
+
+        let json_value = serde_json::to_value(&object).unwrap();
+        assert_json_eq!(
+            json_value,
+            json!({
+                "type": "string",
+                "x-internal": {"owner": "pets"}
+            })
+        );
+
+        let deserialized: Object = serde_json::from_value(json_value).unwrap();
+        assert_eq!(deserialized, object);
+    }
+
+    #[cfg(feature = "openapi_31")]
+    #[test]
+    fn test_object_const_value() {
+        let object: Object = ObjectBuilder::new()
+            .schema_type(SchemaType::String)
+            .const_value(Some("OnlyValue"))
+            .build();
+
+        let json_value = serde_json::to_value(object).unwrap();
+        assert_json_eq!(
+            json_value,
+            json!({
+                "type": "string",
+                "const": "OnlyValue"
+            })
+        );
+    }
+
+    #[test]
+    fn test_array_prefix_items() {
+        let array: Array = ArrayBuilder::new()
+            .prefix_items([
+                ObjectBuilder::new().schema_type(SchemaType::Number),
+                ObjectBuilder::new().schema_type(SchemaType::String),
+            ])
+            .max_items(Some(2))
+            .min_items(Some(2))
+            .build();
+
+        let json_value = serde_json::to_value(array).unwrap();
+        assert_json_eq!(
+            json_value,
+            json!({
+                "type": "array",
+                "items": {
+                    "type": "object"
+                },
+                "prefixItems": [
+                    {
+                        "type": "number"
+                    },
+                    {
+                        "type": "string"
+                    }
+                ],
+                "maxItems": 2,
+                "minItems": 2
+            })
+        );
     }
 
     #[test]